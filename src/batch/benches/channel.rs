@@ -0,0 +1,130 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use risingwave_batch::executor::test_utils::gen_data;
+use risingwave_batch::task::create_output_channel;
+use risingwave_common::hash::VirtualNode;
+use risingwave_common::row::Row;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::util::encoding_for_comparison::encode_row;
+use risingwave_common::util::sort_util::{OrderPair, OrderType};
+use risingwave_pb::batch_plan::exchange_info::{
+    BroadcastInfo, ConsistentHashInfo, Distribution, DistributionMode, HashInfo, RangeInfo,
+};
+use risingwave_pb::batch_plan::ExchangeInfo;
+use tikv_jemallocator::Jemalloc;
+use tokio::runtime::Runtime;
+
+#[global_allocator]
+static GLOBAL: Jemalloc = Jemalloc;
+
+const OUTPUT_COUNT: u32 = 4;
+
+fn create_exchange_info(mode: DistributionMode) -> ExchangeInfo {
+    let distribution = match mode {
+        DistributionMode::Single => None,
+        DistributionMode::Broadcast => Some(Distribution::BroadcastInfo(BroadcastInfo {
+            count: OUTPUT_COUNT,
+        })),
+        DistributionMode::Hash => Some(Distribution::HashInfo(HashInfo {
+            output_count: OUTPUT_COUNT,
+            key: vec![0],
+        })),
+        DistributionMode::ConsistentHash => {
+            Some(Distribution::ConsistentHashInfo(ConsistentHashInfo {
+                vmap: (0..VirtualNode::COUNT as u32)
+                    .map(|i| i % OUTPUT_COUNT)
+                    .collect(),
+                key: vec![0],
+            }))
+        }
+        DistributionMode::Range => Some(Distribution::RangeInfo(RangeInfo {
+            output_count: OUTPUT_COUNT,
+            column_orders: vec![OrderPair::new(0, OrderType::Ascending).to_protobuf()],
+            // `gen_data` generates integers in `[0, i16::MAX]`.
+            boundaries: (1..OUTPUT_COUNT as i64)
+                .map(|i| {
+                    let key = Row(vec![Some(ScalarImpl::Int64(
+                        i * (i16::MAX as i64 + 1) / OUTPUT_COUNT as i64,
+                    ))]);
+                    encode_row(&key, &[OrderPair::new(0, OrderType::Ascending)])
+                })
+                .collect(),
+        })),
+        DistributionMode::Unspecified => unreachable!(),
+    };
+    ExchangeInfo {
+        mode: mode as i32,
+        distribution,
+        ..Default::default()
+    }
+}
+
+fn bench_channel(c: &mut Criterion) {
+    const SIZE: usize = 64 * 1024;
+    let rt = Runtime::new().unwrap();
+
+    for mode in [
+        DistributionMode::Single,
+        DistributionMode::Broadcast,
+        DistributionMode::Hash,
+        DistributionMode::ConsistentHash,
+        DistributionMode::Range,
+    ] {
+        for chunk_size in &[32, 128, 512, 1024] {
+            c.bench_with_input(
+                BenchmarkId::new("OutputChannel", format!("{}({:?})", chunk_size, mode)),
+                chunk_size,
+                |b, &chunk_size| {
+                    let exchange_info = create_exchange_info(mode);
+                    b.to_async(&rt).iter_batched(
+                        || {
+                            let chunks = gen_data(
+                                chunk_size,
+                                SIZE / chunk_size,
+                                &[DataType::Int64, DataType::Varchar],
+                            );
+                            let channel = create_output_channel(&exchange_info, 16).unwrap();
+                            (chunks, channel)
+                        },
+                        |(chunks, (mut sender, receivers))| async move {
+                            // The channel is bounded, so the receivers must be drained
+                            // concurrently.
+                            let consumers = receivers
+                                .into_iter()
+                                .map(|mut receiver| {
+                                    tokio::spawn(async move {
+                                        while receiver.recv().await.unwrap().is_some() {}
+                                    })
+                                })
+                                .collect::<Vec<_>>();
+                            for chunk in chunks {
+                                sender.send(Some(chunk)).await.unwrap();
+                            }
+                            sender.send(None).await.unwrap();
+                            for consumer in consumers {
+                                consumer.await.unwrap();
+                            }
+                        },
+                        BatchSize::SmallInput,
+                    );
+                },
+            );
+        }
+    }
+}
+
+criterion_group!(benches, bench_channel);
+criterion_main!(benches);
@@ -28,35 +28,23 @@ static GLOBAL: Jemalloc = Jemalloc;
 fn create_order_by_executor(
     chunk_size: usize,
     chunk_num: usize,
-    single_column: bool,
+    key_types: &[DataType],
 ) -> BoxedExecutor {
     const CHUNK_SIZE: usize = 1024;
-    let (child, order_pairs) = if single_column {
-        let input = create_input(&[DataType::Int64], chunk_size, chunk_num);
-        (input, vec![OrderPair::new(0, OrderType::Ascending)])
-    } else {
-        let input = create_input(
-            &[
-                DataType::Int64,
-                DataType::Varchar,
-                DataType::Float32,
-                DataType::Timestamp,
-            ],
-            chunk_size,
-            chunk_num,
-        );
-        (
-            input,
-            vec![
-                OrderPair::new(0, OrderType::Ascending),
-                OrderPair::new(1, OrderType::Descending),
-                OrderPair::new(2, OrderType::Ascending),
-            ],
-        )
-    };
+    let input = create_input(key_types, chunk_size, chunk_num);
+    let order_pairs = (0..key_types.len())
+        .map(|i| {
+            let order_type = if i % 2 == 0 {
+                OrderType::Ascending
+            } else {
+                OrderType::Descending
+            };
+            OrderPair::new(i, order_type)
+        })
+        .collect();
 
     Box::new(SortExecutor::new(
-        child,
+        input,
         order_pairs,
         "SortExecutor".into(),
         CHUNK_SIZE,
@@ -67,18 +55,30 @@ fn bench_order_by(c: &mut Criterion) {
     const SIZE: usize = 1024 * 1024;
     let rt = Runtime::new().unwrap();
 
-    for single_column in [true, false] {
+    let keys = [
+        ("int64", vec![DataType::Int64]),
+        ("float64", vec![DataType::Float64]),
+        ("int32", vec![DataType::Int32]),
+        ("varchar", vec![DataType::Varchar]),
+        (
+            "multi_column",
+            vec![
+                DataType::Int64,
+                DataType::Varchar,
+                DataType::Float32,
+                DataType::Timestamp,
+            ],
+        ),
+    ];
+    for (key_name, key_types) in &keys {
         for chunk_size in &[32, 128, 512, 1024, 2048, 4096] {
             c.bench_with_input(
-                BenchmarkId::new(
-                    "SortExecutor",
-                    format!("{}(single_column: {})", chunk_size, single_column),
-                ),
+                BenchmarkId::new("SortExecutor", format!("{}({})", chunk_size, key_name)),
                 chunk_size,
                 |b, &chunk_size| {
                     let chunk_num = SIZE / chunk_size;
                     b.to_async(&rt).iter_batched(
-                        || create_order_by_executor(chunk_size, chunk_num, single_column),
+                        || create_order_by_executor(chunk_size, chunk_num, key_types),
                         |e| execute_executor(e),
                         BatchSize::SmallInput,
                     );
@@ -0,0 +1,113 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use fail::FailScenario;
+use risingwave_common::config::BatchConfig;
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::make_i32_literal;
+use risingwave_pb::batch_plan::exchange_info::DistributionMode;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::values_node::ExprTuple;
+use risingwave_pb::batch_plan::{
+    ExchangeInfo, PlanFragment, PlanNode, TaskId as ProstTaskId, TaskOutputId as ProstTaskOutputId,
+    ValuesNode,
+};
+use risingwave_pb::plan_common::Field;
+
+use crate::task::{BatchManager, ComputeNodeContext};
+
+/// Runs a task producing a single row, and returns the number of chunks fetched from its output,
+/// or the error message of the first failed response.
+async fn run_values_task() -> Result<usize, String> {
+    let manager = BatchManager::new(BatchConfig::default());
+    let plan = PlanFragment {
+        root: Some(PlanNode {
+            children: vec![],
+            identity: "Values".to_string(),
+            node_body: Some(NodeBody::Values(ValuesNode {
+                tuples: vec![ExprTuple {
+                    cells: vec![make_i32_literal(1)],
+                }],
+                fields: vec![Field {
+                    data_type: Some(DataType::Int32.to_protobuf()),
+                    name: "v".to_string(),
+                }],
+            })),
+        }),
+        exchange_info: Some(ExchangeInfo {
+            mode: DistributionMode::Single as i32,
+            ..Default::default()
+        }),
+    };
+    let task_id = ProstTaskId {
+        query_id: "".to_string(),
+        stage_id: 0,
+        task_id: 0,
+    };
+    manager
+        .fire_task(&task_id, plan, 0, ComputeNodeContext::for_test(), "")
+        .await
+        .unwrap();
+    let output_id = ProstTaskOutputId {
+        task_id: Some(task_id),
+        output_id: 0,
+    };
+    let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+    let peer_addr = "127.0.0.1:0".parse().unwrap();
+    manager.get_data(tx, peer_addr, &output_id, 0, 0).unwrap();
+
+    let mut chunks = 0;
+    while let Some(resp) = rx.recv().await {
+        match resp {
+            Ok(_) => chunks += 1,
+            Err(status) => return Err(status.message().to_string()),
+        }
+    }
+    Ok(chunks)
+}
+
+#[tokio::test]
+async fn test_failpoints_channel_send() {
+    let scenario = FailScenario::setup();
+    fail::cfg("batch_fifo_send", "return").unwrap();
+    let err = run_values_task().await.unwrap_err();
+    assert!(err.contains("injected channel send failure"), "{}", err);
+    scenario.teardown();
+}
+
+#[tokio::test]
+async fn test_failpoints_task_execute() {
+    let scenario = FailScenario::setup();
+    fail::cfg("batch_task_execute", "return").unwrap();
+    let err = run_values_task().await.unwrap_err();
+    assert!(err.contains("injected task execution failure"), "{}", err);
+    scenario.teardown();
+}
+
+#[tokio::test]
+async fn test_failpoints_output_serialize() {
+    let scenario = FailScenario::setup();
+    fail::cfg("batch_task_output_serialize", "return").unwrap();
+    let err = run_values_task().await.unwrap_err();
+    assert!(err.contains("injected serialization failure"), "{}", err);
+    scenario.teardown();
+}
+
+#[tokio::test]
+async fn test_failpoints_slow_receiver() {
+    let scenario = FailScenario::setup();
+    fail::cfg("batch_fifo_recv", "sleep(10)").unwrap();
+    assert_eq!(run_values_task().await, Ok(1));
+    scenario.teardown();
+}
@@ -35,11 +35,17 @@ pub enum BatchError {
     #[error("Failed to send result to channel")]
     SenderError,
 
+    #[error("Failed to receive data from {0}: channel closed unexpectedly")]
+    ChannelClosed(&'static str),
+
     #[error(transparent)]
     Internal(#[from] anyhow::Error),
 
     #[error("Prometheus error: {0}")]
     Prometheus(#[from] prometheus::Error),
+
+    #[error("Invalid plan:\n{0}")]
+    InvalidPlan(String),
 }
 
 impl From<BatchError> for RwError {
@@ -32,15 +32,45 @@ pub struct GrpcExchangeSource {
     stream: Streaming<GetDataResponse>,
 
     task_output_id: TaskOutputId,
+
+    /// Set if the task output is fetched with chunk credits.
+    credits: Option<ChunkCredits>,
+}
+
+/// Credit-based flow control of fetching a task output. The receiver grants the sender
+/// `chunk_credits` chunks with each `GetData` request, and only grants more after all of them have
+/// been taken, so that at most `chunk_credits` chunks are in flight on the stream.
+struct ChunkCredits {
+    client: ComputeClient,
+    chunk_credits: u32,
+    /// The number of chunks taken so far.
+    cursor: u64,
+    /// The cursor at which the granted credits are used up.
+    granted_until: u64,
+}
+
+impl ChunkCredits {
+    /// Requests the chunks of the next `chunk_credits` credits.
+    async fn grant(&mut self, task_output_id: TaskOutputId) -> Result<Streaming<GetDataResponse>> {
+        self.granted_until = self.cursor + self.chunk_credits as u64;
+        self.client
+            .get_data_from_cursor(task_output_id, self.cursor, self.chunk_credits)
+            .await
+    }
 }
 
 impl GrpcExchangeSource {
+    /// Creates a source of the output `task_output_id`. If `chunk_credits` is not 0, the output is
+    /// fetched with at most `chunk_credits` chunks in flight. Otherwise the output is streamed with
+    /// only the back-pressure of the transport.
     pub async fn create(
         client: ComputeClient,
         task_output_id: TaskOutputId,
         local_execute_plan: Option<LocalExecutePlan>,
+        chunk_credits: u32,
     ) -> Result<Self> {
         let task_id = task_output_id.get_task_id()?.clone();
+        let mut credits = None;
         let stream = match local_execute_plan {
             // When in the local execution mode, `GrpcExchangeSource` would send out
             // `ExecuteRequest` and get the data chunks back in a single RPC.
@@ -53,11 +83,21 @@ impl GrpcExchangeSource {
                 };
                 client.execute(execute_request).await?
             }
+            None if chunk_credits > 0 => {
+                let credits = credits.insert(ChunkCredits {
+                    client,
+                    chunk_credits,
+                    cursor: 0,
+                    granted_until: 0,
+                });
+                credits.grant(task_output_id.clone()).await?
+            }
             None => client.get_data(task_output_id.clone()).await?,
         };
         let source = Self {
             stream,
             task_output_id,
+            credits,
         };
         Ok(source)
     }
@@ -76,10 +116,24 @@ impl ExchangeSource for GrpcExchangeSource {
 
     fn take_data(&mut self) -> Self::TakeDataFuture<'_> {
         async {
-            let res = match self.stream.next().await {
-                None => return Ok(None),
-                Some(r) => r,
+            let res = loop {
+                match self.stream.next().await {
+                    Some(r) => break r,
+                    // The stream ends either because the credits are used up, or the output is
+                    // exhausted. Only the former can have more chunks to fetch. Note that the
+                    // next request must not be sent before the end of the stream, by when the
+                    // sender has released the output.
+                    None => match &mut self.credits {
+                        Some(credits) if credits.cursor == credits.granted_until => {
+                            self.stream = credits.grant(self.task_output_id.clone()).await?;
+                        }
+                        _ => return Ok(None),
+                    },
+                }
             };
+            if let Some(credits) = &mut self.credits {
+                credits.cursor += 1;
+            }
             let task_data = res?;
             let data = DataChunk::from_protobuf(task_data.get_record_batch()?)?.compact();
             trace!(
@@ -79,8 +79,7 @@ impl ExchangeSource for LocalExchangeSource {
 
 #[cfg(test)]
 mod tests {
-    use std::net::SocketAddr;
-    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use std::sync::Arc;
     use std::time::Duration;
 
@@ -93,6 +92,7 @@ mod tests {
         GetDataRequest, GetDataResponse, GetStreamRequest, GetStreamResponse,
     };
     use risingwave_rpc_client::ComputeClient;
+    use tokio::net::TcpListener;
     use tokio::time::sleep;
     use tokio_stream::wrappers::ReceiverStream;
     use tonic::{Request, Response, Status, Streaming};
@@ -100,8 +100,11 @@ mod tests {
     use crate::exchange_source::ExchangeSource;
     use crate::execution::grpc_exchange::GrpcExchangeSource;
 
+    /// The number of chunks in the output served by `FakeExchangeService`.
+    const OUTPUT_CHUNKS: u64 = 3;
+
     struct FakeExchangeService {
-        rpc_called: Arc<AtomicBool>,
+        rpc_calls: Arc<AtomicUsize>,
     }
 
     #[async_trait::async_trait]
@@ -111,11 +114,16 @@ mod tests {
 
         async fn get_data(
             &self,
-            _: Request<GetDataRequest>,
+            request: Request<GetDataRequest>,
         ) -> Result<Response<Self::GetDataStream>, Status> {
+            let request = request.into_inner();
             let (tx, rx) = tokio::sync::mpsc::channel(10);
-            self.rpc_called.store(true, Ordering::SeqCst);
-            for _ in 0..3 {
+            self.rpc_calls.fetch_add(1, Ordering::SeqCst);
+            let end = match request.max_chunks {
+                0 => OUTPUT_CHUNKS,
+                max_chunks => OUTPUT_CHUNKS.min(request.cursor + max_chunks as u64),
+            };
+            for _ in request.cursor..end {
                 tx.send(Ok(GetDataResponse {
                     status: None,
                     record_batch: Some(DataChunk::default()),
@@ -134,23 +142,29 @@ mod tests {
         }
     }
 
-    #[tokio::test]
-    async fn test_exchange_client() {
-        let rpc_called = Arc::new(AtomicBool::new(false));
+    /// Takes the output of `FakeExchangeService` with `chunk_credits`, and returns the number of
+    /// `GetData` requests sent.
+    async fn take_all_data(chunk_credits: u32) -> usize {
+        let rpc_calls = Arc::new(AtomicUsize::new(0));
         let server_run = Arc::new(AtomicBool::new(false));
-        let addr: SocketAddr = "127.0.0.1:12345".parse().unwrap();
 
-        // Start a server.
+        // Start a server on a port assigned by the OS.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let incoming = futures::stream::unfold(listener, |listener| async move {
+            let stream = listener.accept().await.map(|(stream, _)| stream);
+            Some((stream, listener))
+        });
         let (shutdown_send, shutdown_recv) = tokio::sync::oneshot::channel();
         let exchange_svc = ExchangeServiceServer::new(FakeExchangeService {
-            rpc_called: rpc_called.clone(),
+            rpc_calls: rpc_calls.clone(),
         });
         let cp_server_run = server_run.clone();
         let join_handle = tokio::spawn(async move {
             cp_server_run.store(true, Ordering::SeqCst);
             tonic::transport::Server::builder()
                 .add_service(exchange_svc)
-                .serve_with_shutdown(addr, async move {
+                .serve_with_incoming_shutdown(incoming, async move {
                     shutdown_recv.await.unwrap();
                 })
                 .await
@@ -165,17 +179,31 @@ mod tests {
             task_id: Some(TaskId::default()),
             ..Default::default()
         };
-        let mut src = GrpcExchangeSource::create(client, task_output_id, None)
+        let mut src = GrpcExchangeSource::create(client, task_output_id, None, chunk_credits)
             .await
             .unwrap();
-        for _ in 0..3 {
+        for _ in 0..OUTPUT_CHUNKS {
             assert!(src.take_data().await.unwrap().is_some());
         }
         assert!(src.take_data().await.unwrap().is_none());
-        assert!(rpc_called.load(Ordering::SeqCst));
 
         // Gracefully terminate the server.
         shutdown_send.send(()).unwrap();
         join_handle.await.unwrap();
+        rpc_calls.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn test_exchange_client() {
+        assert_eq!(take_all_data(0).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_client_with_credits() {
+        // Two credits fetch the chunks in two requests.
+        assert_eq!(take_all_data(2).await, 2);
+
+        // The output ends right when the credits are used up, so the last request is empty.
+        assert_eq!(take_all_data(1).await, 4);
     }
 }
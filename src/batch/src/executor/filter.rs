@@ -62,8 +62,8 @@ impl FilterExecutor {
 
             if let Bool(vis) = vis_array.as_ref() {
                 #[for_await]
-                for data_chunk in data_chunk_builder
-                    .trunc_data_chunk(data_chunk.with_visibility(vis.iter().collect()))
+                for data_chunk in
+                    data_chunk_builder.trunc_data_chunk(data_chunk.with_visibility(vis.to_bitmap()))
                 {
                     yield data_chunk;
                 }
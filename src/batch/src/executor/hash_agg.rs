@@ -22,6 +22,7 @@ use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::hash::{HashKey, HashKeyDispatcher, PrecomputedBuildHasher};
 use risingwave_common::types::DataType;
+use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
 use risingwave_expr::vector_op::agg::{AggStateFactory, BoxedAggState};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::HashAggNode;
@@ -218,47 +219,22 @@ impl<K: HashKey + Send + Sync> HashAggExecutor<K> {
         }
 
         // generate output data chunks
-        let mut result = groups.into_iter();
-        let cardinality = self.chunk_size;
-        loop {
-            let mut group_builders: Vec<_> = self
-                .group_key_types
-                .iter()
-                .map(|datatype| datatype.create_array_builder(cardinality))
-                .collect();
-
-            let mut agg_builders: Vec<_> = self
-                .agg_factories
-                .iter()
-                .map(|agg_factory| {
-                    agg_factory
-                        .get_return_type()
-                        .create_array_builder(cardinality)
-                })
-                .collect();
-
-            let mut has_next = false;
-            let mut array_len = 0;
-            for (key, states) in result.by_ref().take(cardinality) {
-                has_next = true;
-                array_len += 1;
-                key.deserialize_to_builders(&mut group_builders[..], &self.group_key_types)?;
-                states
-                    .into_iter()
-                    .zip_eq(&mut agg_builders)
-                    .try_for_each(|(mut aggregator, builder)| aggregator.output(builder))?;
-            }
-            if !has_next {
-                break; // exit loop
+        let mut chunk_builder = DataChunkBuilder::new(self.schema.data_types(), self.chunk_size);
+        let group_key_len = self.group_key_types.len();
+        for (key, states) in groups {
+            let output = chunk_builder.append_one_row_with(|builders| -> Result<()> {
+                let (group_builders, agg_builders) = builders.split_at_mut(group_key_len);
+                key.deserialize_to_builders(group_builders, &self.group_key_types)?;
+                for (mut aggregator, builder) in states.into_iter().zip_eq(agg_builders) {
+                    aggregator.output(builder)?;
+                }
+                Ok(())
+            })?;
+            if let Some(output) = output {
+                yield output;
             }
-
-            let columns = group_builders
-                .into_iter()
-                .chain(agg_builders)
-                .map(|b| b.finish().into())
-                .collect::<Vec<_>>();
-
-            let output = DataChunk::new(columns, array_len);
+        }
+        if let Some(output) = chunk_builder.consume_all() {
             yield output;
         }
     }
@@ -15,6 +15,7 @@
 use anyhow::Context;
 use futures::future::try_join_all;
 use futures_async_stream::try_stream;
+use itertools::Itertools;
 use risingwave_common::array::column::Column;
 use risingwave_common::array::{
     ArrayBuilder, DataChunk, I64ArrayBuilder, Op, PrimitiveArrayBuilder, StreamChunk,
@@ -22,6 +23,7 @@ use risingwave_common::array::{
 use risingwave_common::catalog::{Field, Schema, TableId};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::DataType;
+use risingwave_expr::expr::{build_from_prost, BoxedExpression};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_source::TableSourceManagerRef;
 
@@ -39,6 +41,9 @@ pub struct InsertExecutor {
     schema: Schema,
     identity: String,
     column_idxs: Vec<usize>,
+    /// The expressions to fill the columns not in `column_idxs` with, i.e. the DEFAULT values of
+    /// the columns not written by the INSERT.
+    default_columns: Vec<(usize, BoxedExpression)>,
 }
 
 impl InsertExecutor {
@@ -48,6 +53,7 @@ impl InsertExecutor {
         child: BoxedExecutor,
         identity: String,
         column_idxs: Vec<usize>,
+        default_columns: Vec<(usize, BoxedExpression)>,
     ) -> Self {
         Self {
             table_id,
@@ -58,6 +64,7 @@ impl InsertExecutor {
             },
             identity,
             column_idxs,
+            default_columns,
         }
     }
 }
@@ -83,6 +90,7 @@ impl InsertExecutor {
 
         let source = source_desc.source.as_table().expect("not table source");
         let row_id_index = source_desc.row_id_index;
+        let row_id_generator = self.source_manager.row_id_generator();
 
         let mut notifiers = Vec::new();
 
@@ -92,22 +100,36 @@ impl InsertExecutor {
             let len = data_chunk.cardinality();
             assert!(data_chunk.visibility().is_none());
 
+            let default_columns: Vec<_> = self
+                .default_columns
+                .iter()
+                .map(|(idx, expr)| {
+                    expr.eval(&data_chunk)
+                        .map(|array| (*idx, Column::new(array)))
+                })
+                .try_collect()?;
+
             let (mut columns, _) = data_chunk.into_parts();
 
             // No need to check for duplicate columns. This is already validated in binder
-            if !&self.column_idxs.is_sorted() {
-                let mut ordered_cols: Vec<Column> = columns.clone();
+            if !default_columns.is_empty() || !&self.column_idxs.is_sorted() {
+                let mut ordered_cols: Vec<Option<Column>> =
+                    vec![None; self.column_idxs.len() + default_columns.len()];
                 for (i, idx) in self.column_idxs.iter().enumerate() {
-                    ordered_cols[*idx] = columns[i].clone()
+                    ordered_cols[*idx] = Some(columns[i].clone())
+                }
+                for (idx, column) in default_columns {
+                    ordered_cols[idx] = Some(column)
                 }
-                columns = ordered_cols
+                columns = ordered_cols.into_iter().map(Option::unwrap).collect()
             }
 
             // if user did not specify primary ID then we need to add a col it
             if let Some(row_id_index) = row_id_index {
+                let row_ids = row_id_generator.lock().await.next_batch(len).await;
                 let mut builder = I64ArrayBuilder::new(len);
-                for _ in 0..len {
-                    builder.append_null();
+                for row_id in row_ids {
+                    builder.append(Some(row_id));
                 }
                 columns.insert(row_id_index, Column::from(builder.finish()))
             }
@@ -157,6 +179,11 @@ impl BoxedExecutorBuilder for InsertExecutor {
             .iter()
             .map(|&i| i as usize)
             .collect();
+        let default_columns = insert_node
+            .default_columns
+            .iter()
+            .map(|(&idx, expr)| build_from_prost(expr).map(|expr| (idx as usize, expr)))
+            .try_collect()?;
 
         Ok(Box::new(Self::new(
             table_id,
@@ -164,6 +191,7 @@ impl BoxedExecutorBuilder for InsertExecutor {
             child,
             source.plan_node().get_identity().clone(),
             column_idxs,
+            default_columns,
         )))
     }
 }
@@ -177,7 +205,9 @@ mod tests {
     use risingwave_common::array::{Array, ArrayImpl, I32Array, StructArray};
     use risingwave_common::catalog::schema_test_utils;
     use risingwave_common::column_nonnull;
+    use risingwave_common::test_prelude::DataChunkTestExt;
     use risingwave_common::types::DataType;
+    use risingwave_expr::expr::LiteralExpression;
     use risingwave_source::table_test_utils::create_table_source_desc_builder;
     use risingwave_source::{TableSourceManager, TableSourceManagerRef};
     use risingwave_storage::memory::MemoryStateStore;
@@ -248,6 +278,7 @@ mod tests {
             Box::new(mock_executor),
             "InsertExecutor".to_string(),
             vec![], // Ignoring insertion order
+            vec![],
         ));
         let handle = tokio::spawn(async move {
             let mut stream = insert_executor.execute();
@@ -321,4 +352,83 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_insert_executor_with_default_columns() -> Result<()> {
+        let source_manager: TableSourceManagerRef = Arc::new(TableSourceManager::default());
+
+        // Only the second column is written.
+        let mut mock_executor = MockExecutor::new(schema_test_utils::field_n::<1>(DataType::Int32));
+        mock_executor.add(DataChunk::from_pretty(
+            "i
+             2
+             4",
+        ));
+
+        // Schema of the table
+        let mut schema = schema_test_utils::ii();
+        schema.fields.push(Field::unnamed(DataType::Int64)); // row_id column
+
+        let table_id = TableId::new(0);
+        let source_builder = create_table_source_desc_builder(
+            &schema,
+            table_id,
+            Some(2),
+            vec![2],
+            source_manager.clone(),
+        );
+        let source_desc = source_builder.build().await?;
+        let source = source_desc.source.as_table().unwrap();
+        let mut reader = source
+            .stream_reader(vec![0.into(), 1.into()])
+            .await?
+            .into_stream();
+
+        // The first column is filled with its DEFAULT value.
+        let default: BoxedExpression =
+            Box::new(LiteralExpression::new(DataType::Int32, Some(42_i32.into())));
+        let insert_executor = Box::new(InsertExecutor::new(
+            table_id,
+            source_manager.clone(),
+            Box::new(mock_executor),
+            "InsertExecutor".to_string(),
+            vec![1],
+            vec![(0, default)],
+        ));
+        let handle = tokio::spawn(async move {
+            let mut stream = insert_executor.execute();
+            let result = stream.next().await.unwrap().unwrap();
+            assert_eq!(
+                result
+                    .column_at(0)
+                    .array()
+                    .as_int64()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                vec![Some(2)] // inserted rows
+            );
+        });
+
+        let chunk = reader.next().await.unwrap()?.chunk;
+        assert_eq!(
+            chunk.columns()[0]
+                .array()
+                .as_int32()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(42), Some(42)]
+        );
+        assert_eq!(
+            chunk.columns()[1]
+                .array()
+                .as_int32()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(2), Some(4)]
+        );
+
+        handle.await.unwrap();
+
+        Ok(())
+    }
 }
@@ -218,9 +218,11 @@ impl<C: BatchTaskContext> LookupExecutorBuilder for InnerSideExecutorBuilder<C>
             sources.push(self.build_prost_exchange_source(id)?);
         }
 
+        // Keep the inner side schema even if there is nothing to look up, so that the build side
+        // of the hash join still has the declared column types.
         if sources.is_empty() {
             return Ok(Box::new(DummyExecutor {
-                schema: Schema::default(),
+                schema: self.inner_side_schema.clone(),
             }));
         }
 
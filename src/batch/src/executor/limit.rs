@@ -86,9 +86,8 @@ impl LimitExecutor {
                 continue;
             }
             // process chunk
-            let mut new_vis;
             if let Some(old_vis) = data_chunk.visibility() {
-                new_vis = old_vis.iter().collect_vec();
+                let mut new_vis = old_vis.iter().collect_vec();
                 for vis in new_vis.iter_mut().filter(|x| **x) {
                     if skipped < self.offset {
                         skipped += 1;
@@ -99,18 +98,17 @@ impl LimitExecutor {
                         *vis = false;
                     }
                 }
+                yield data_chunk
+                    .with_visibility(new_vis.into_iter().collect())
+                    .compact();
             } else {
                 let chunk_size = data_chunk.capacity();
-                new_vis = vec![false; chunk_size];
                 let l = self.offset - skipped;
                 let r = min(l + self.limit - returned, chunk_size);
-                new_vis[l..r].fill(true);
                 returned += r - l;
                 skipped += l;
+                yield data_chunk.slice(l, r - l);
             }
-            yield data_chunk
-                .with_visibility(new_vis.into_iter().collect())
-                .compact();
         }
     }
 }
@@ -19,7 +19,8 @@ use futures_async_stream::try_stream;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::ToOwnedDatum;
+use risingwave_common::types::{DataType, ToOwnedDatum};
+use risingwave_common::util::encoding_for_comparison::encode_chunk;
 use risingwave_common::util::sort_util::{HeapElem, OrderPair};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::ExchangeSource as ProstExchangeSource;
@@ -39,6 +40,12 @@ pub struct MergeSortExchangeExecutorImpl<CS, C> {
     context: C,
     /// keeps one data chunk of each source if any
     source_inputs: Vec<Option<DataChunk>>,
+    /// The memcomparable-encoded sort keys of `source_inputs`, only present if
+    /// `encode_sort_keys` is set.
+    encoded_source_inputs: Vec<Option<Arc<Vec<Vec<u8>>>>>,
+    /// Whether to compare rows in the heap by their encoded sort keys instead of column by
+    /// column. See [`should_encode_sort_keys`].
+    encode_sort_keys: bool,
     order_pairs: Arc<Vec<OrderPair>>,
     min_heap: BinaryHeap<HeapElem>,
     proto_sources: Vec<ProstExchangeSource>,
@@ -61,9 +68,13 @@ impl<CS: 'static + Send + CreateSource, C: BatchTaskContext> MergeSortExchangeEx
         match res {
             Some(chunk) => {
                 assert_ne!(chunk.cardinality(), 0);
+                self.encoded_source_inputs[source_idx] = self
+                    .encode_sort_keys
+                    .then(|| Arc::new(encode_chunk(&chunk, &self.order_pairs)));
                 let _ = std::mem::replace(&mut self.source_inputs[source_idx], Some(chunk));
             }
             None => {
+                self.encoded_source_inputs[source_idx] = None;
                 let _ = std::mem::replace(&mut self.source_inputs[source_idx], None);
             }
         }
@@ -80,11 +91,31 @@ impl<CS: 'static + Send + CreateSource, C: BatchTaskContext> MergeSortExchangeEx
             chunk: chunk_ref.clone(),
             chunk_idx: source_idx,
             elem_idx: row_idx,
-            encoded_chunk: None,
+            encoded_chunk: self.encoded_source_inputs[source_idx].clone(),
         });
     }
 }
 
+/// Encoding the sort keys of every input chunk into memcomparable bytes costs a pass over the
+/// chunk, but makes each comparison in the heap a plain byte comparison. This pays off only if
+/// there is more than one source to merge and comparing the keys column by column is expensive,
+/// i.e. there are multiple sort keys or a key of variable-length type.
+fn should_encode_sort_keys(num_sources: usize, order_pairs: &[OrderPair], schema: &Schema) -> bool {
+    num_sources > 1
+        && (order_pairs.len() > 1
+            || order_pairs.iter().any(|o| {
+                matches!(
+                    schema.fields[o.column_idx].data_type,
+                    DataType::Varchar
+                        | DataType::Decimal
+                        | DataType::Interval
+                        | DataType::Struct(_)
+                        | DataType::List { .. }
+                        | DataType::Jsonb
+                )
+            }))
+}
+
 impl<CS: 'static + Send + CreateSource, C: BatchTaskContext> Executor
     for MergeSortExchangeExecutorImpl<CS, C>
 {
@@ -210,15 +241,19 @@ impl BoxedExecutorBuilder for MergeSortExchangeExecutorBuilder {
             .collect::<Vec<Field>>();
 
         let num_sources = proto_sources.len();
+        let schema = Schema { fields };
+        let encode_sort_keys = should_encode_sort_keys(num_sources, &order_pairs, &schema);
         Ok(Box::new(MergeSortExchangeExecutor::<C> {
             context: source.context().clone(),
             source_inputs: vec![None; num_sources],
+            encoded_source_inputs: vec![None; num_sources],
+            encode_sort_keys,
             order_pairs,
             min_heap: BinaryHeap::new(),
             proto_sources,
             sources: vec![],
             source_creators,
-            schema: Schema { fields },
+            schema,
             task_id: source.task_id.clone(),
             identity: source.plan_node().get_identity().clone(),
             chunk_size: source.context.get_config().developer.batch_chunk_size,
@@ -233,7 +268,6 @@ mod tests {
     use futures::StreamExt;
     use risingwave_common::array::{Array, DataChunk};
     use risingwave_common::test_prelude::DataChunkTestExt;
-    use risingwave_common::types::DataType;
     use risingwave_common::util::sort_util::OrderType;
 
     use super::*;
@@ -244,6 +278,15 @@ mod tests {
 
     #[tokio::test]
     async fn test_exchange_multiple_sources() {
+        test_exchange_multiple_sources_impl(false).await;
+    }
+
+    #[tokio::test]
+    async fn test_exchange_multiple_sources_encoded() {
+        test_exchange_multiple_sources_impl(true).await;
+    }
+
+    async fn test_exchange_multiple_sources_impl(encode_sort_keys: bool) {
         let chunk = DataChunk::from_pretty(
             "i
                      1
@@ -271,6 +314,8 @@ mod tests {
         > {
             context: ComputeNodeContext::for_test(),
             source_inputs: vec![None; proto_sources.len()],
+            encoded_source_inputs: vec![None; proto_sources.len()],
+            encode_sort_keys,
             order_pairs,
             min_heap: BinaryHeap::new(),
             proto_sources,
@@ -301,4 +346,24 @@ mod tests {
         let res = stream.next().await;
         assert!(res.is_none());
     }
+
+    #[test]
+    fn test_should_encode_sort_keys() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Varchar),
+            ],
+        };
+        let int_key = [OrderPair::new(0, OrderType::Ascending)];
+        let varchar_key = [OrderPair::new(1, OrderType::Ascending)];
+        let both_keys = [
+            OrderPair::new(0, OrderType::Ascending),
+            OrderPair::new(1, OrderType::Descending),
+        ];
+        assert!(!should_encode_sort_keys(1, &both_keys, &schema));
+        assert!(!should_encode_sort_keys(2, &int_key, &schema));
+        assert!(should_encode_sort_keys(2, &varchar_key, &schema));
+        assert!(should_encode_sort_keys(2, &both_keys, &schema));
+    }
 }
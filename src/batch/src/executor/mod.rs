@@ -38,6 +38,7 @@ mod trace;
 mod union;
 mod update;
 mod utils;
+mod validate;
 mod values;
 
 use async_recursion::async_recursion;
@@ -71,6 +72,7 @@ pub use trace::*;
 pub use union::*;
 pub use update::*;
 pub use utils::*;
+pub use validate::*;
 pub use values::*;
 
 use crate::executor::sys_row_seq_scan::SysRowSeqScanExecutorBuilder;
@@ -212,7 +214,11 @@ impl<'a, C: BatchTaskContext> ExecutorBuilder<'a, C> {
         }
         .await?;
         let input_desc = real_executor.identity().to_string();
-        Ok(Box::new(TraceExecutor::new(real_executor, input_desc)) as BoxedExecutor)
+        Ok(Box::new(TraceExecutor::new(
+            real_executor,
+            input_desc,
+            self.task_id.clone(),
+        )) as BoxedExecutor)
     }
 }
 
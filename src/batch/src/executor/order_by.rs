@@ -12,16 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use futures::future::try_join_all;
 use futures_async_stream::try_stream;
-use risingwave_common::array::DataChunk;
+use itertools::Itertools;
+use risingwave_common::array::{Array, ArrayImpl, DataChunk};
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
+use risingwave_common::row::Row2;
+use risingwave_common::types::DataType;
+use risingwave_common::util::chunk_coalesce::{adaptive_chunk_size, DataChunkBuilder};
 use risingwave_common::util::encoding_for_comparison::encode_chunk;
-use risingwave_common::util::sort_util::OrderPair;
+use risingwave_common::util::sort_util::{OrderPair, OrderType};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
+use tokio::task::JoinHandle;
 
 use super::{BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder};
+use crate::error::BatchError;
 use crate::task::BatchTaskContext;
 
 /// Sort Executor
@@ -29,7 +38,7 @@ use crate::task::BatchTaskContext;
 /// High-level idea:
 /// 1. Load data chunks from child executor
 /// 2. Serialize each row into memcomparable format
-/// 3. Sort the serialized rows by quicksort
+/// 3. Sort the serialized rows by quicksort, comparing their inlined 8-byte prefixes first
 /// 4. Build and yield data chunks according to the row order
 pub struct SortExecutor {
     child: BoxedExecutor,
@@ -37,6 +46,12 @@ pub struct SortExecutor {
     identity: String,
     schema: Schema,
     chunk_size: usize,
+    /// The target size in bytes of an output chunk. 0 means output chunks are only bounded by
+    /// `chunk_size`.
+    chunk_target_bytes: usize,
+    /// Whether to encode the sort keys of each input chunk on the blocking thread pool as soon as
+    /// it arrives, instead of encoding all chunks after the input is exhausted.
+    parallel_encoding: bool,
 }
 
 impl Executor for SortExecutor {
@@ -69,40 +84,98 @@ impl BoxedExecutorBuilder for SortExecutor {
             .iter()
             .map(OrderPair::from_prost)
             .collect();
-        Ok(Box::new(SortExecutor::new(
-            child,
-            order_pairs,
-            source.plan_node().get_identity().clone(),
-            source.context.get_config().developer.batch_chunk_size,
-        )))
+        let config = &source.context.get_config().developer;
+        Ok(Box::new(
+            SortExecutor::new(
+                child,
+                order_pairs,
+                source.plan_node().get_identity().clone(),
+                config.batch_chunk_size,
+            )
+            .with_chunk_target_bytes(config.batch_chunk_target_bytes)
+            .with_parallel_encoding(config.batch_sort_parallel_encoding),
+        ))
     }
 }
 
 impl SortExecutor {
     #[try_stream(boxed, ok = DataChunk, error = RwError)]
     async fn do_execute(self: Box<Self>) {
-        let mut chunk_builder = DataChunkBuilder::new(self.schema.data_types(), self.chunk_size);
         let mut chunks = Vec::new();
-        let mut encoded_rows = Vec::new();
+        let mut encoding_tasks = EncodingTasks::default();
+        // Encoding is unnecessary if the rows are likely to be radix sorted.
+        let parallel_encoding =
+            self.parallel_encoding && !may_radix_sort(&self.schema.data_types(), &self.order_pairs);
 
         #[for_await]
         for chunk in self.child.execute() {
-            chunks.push(chunk?.compact());
+            let chunk = chunk?.compact();
+            if parallel_encoding {
+                let chunk = chunk.clone();
+                let order_pairs = self.order_pairs.clone();
+                encoding_tasks.spawn(move || encode_chunk(&chunk, &order_pairs));
+            }
+            chunks.push(chunk);
         }
 
-        for chunk in &chunks {
-            let encoded_chunk = encode_chunk(chunk, &self.order_pairs);
-            encoded_rows.extend(
-                encoded_chunk
+        let sorted_rows = match radix_sort_keys(&chunks, &self.order_pairs) {
+            Some(keys) => {
+                let rows = chunks
+                    .iter()
+                    .flat_map(|chunk| {
+                        (0..chunk.capacity()).map(move |row_id| chunk.row_at_unchecked_vis(row_id))
+                    })
+                    .collect_vec();
+                radix_sort_indices(&keys)
                     .into_iter()
-                    .enumerate()
-                    .map(|(row_id, row)| (chunk.row_at_unchecked_vis(row_id), row)),
-            );
-        }
+                    .map(|idx| rows[idx])
+                    .collect_vec()
+            }
+            None => {
+                let encoded_chunks = if parallel_encoding {
+                    encoding_tasks.join().await?
+                } else {
+                    chunks
+                        .iter()
+                        .map(|chunk| encode_chunk(chunk, &self.order_pairs))
+                        .collect_vec()
+                };
+                let mut encoded_rows = Vec::new();
+                for (chunk, encoded_chunk) in chunks.iter().zip_eq(encoded_chunks) {
+                    encoded_rows.extend(encoded_chunk.into_iter().enumerate().map(
+                        |(row_id, key)| (key_prefix(&key), chunk.row_at_unchecked_vis(row_id), key),
+                    ));
+                }
+                // The full keys live on the heap, so only dereference them on prefix ties.
+                encoded_rows.sort_unstable_by(|(a_prefix, _, a), (b_prefix, _, b)| {
+                    a_prefix.cmp(b_prefix).then_with(|| a.cmp(b))
+                });
+                encoded_rows
+                    .into_iter()
+                    .map(|(_, row, _)| row)
+                    .collect_vec()
+            }
+        };
 
-        encoded_rows.sort_unstable_by(|(_, a), (_, b)| a.cmp(b));
+        // Estimate the row width from the first rows to keep the memory of each output chunk
+        // bounded when rows are wide.
+        let sampled_rows = sorted_rows.len().min(self.chunk_size);
+        let chunk_size = if sampled_rows == 0 {
+            self.chunk_size
+        } else {
+            let sampled_bytes: usize = sorted_rows[..sampled_rows]
+                .iter()
+                .map(|row| row.value_serialize().len())
+                .sum();
+            adaptive_chunk_size(
+                sampled_bytes / sampled_rows,
+                self.chunk_target_bytes,
+                self.chunk_size,
+            )
+        };
+        let mut chunk_builder = DataChunkBuilder::new(self.schema.data_types(), chunk_size);
 
-        for (row, _) in encoded_rows {
+        for row in sorted_rows {
             if let Some(spilled) = chunk_builder.append_one_row(row) {
                 yield spilled
             }
@@ -114,6 +187,127 @@ impl SortExecutor {
     }
 }
 
+/// The sort key encodings running on the blocking thread pool. The executor is dropped when its
+/// task is aborted, and so is this, which cancels the encodings that haven't started yet. Those
+/// already running only finish their own chunk.
+#[derive(Default)]
+struct EncodingTasks {
+    cancelled: Arc<AtomicBool>,
+    handles: Vec<JoinHandle<Option<Vec<Vec<u8>>>>>,
+}
+
+impl EncodingTasks {
+    fn spawn(&mut self, encode: impl FnOnce() -> Vec<Vec<u8>> + Send + 'static) {
+        let cancelled = self.cancelled.clone();
+        self.handles.push(tokio::task::spawn_blocking(move || {
+            (!cancelled.load(Ordering::Relaxed)).then(encode)
+        }));
+    }
+
+    /// Waits for all the encodings, in the order they are spawned.
+    async fn join(&mut self) -> Result<Vec<Vec<Vec<u8>>>> {
+        // The handles are kept in `self` while waiting, so that they are still cancelled if the
+        // executor is dropped meanwhile.
+        let encoded_chunks = try_join_all(self.handles.iter_mut())
+            .await
+            .map_err(|e| BatchError::Internal(e.into()))?;
+        self.handles.clear();
+        Ok(encoded_chunks
+            .into_iter()
+            .map(|encoded| encoded.expect("encoding is only cancelled on drop"))
+            .collect())
+    }
+}
+
+impl Drop for EncodingTasks {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        for handle in &self.handles {
+            handle.abort();
+        }
+    }
+}
+
+/// Returns the first 8 bytes of the memcomparable `key` as a big-endian integer, padded with zeros.
+/// The order of two prefixes is the order of their keys unless the prefixes are equal.
+fn key_prefix(key: &[u8]) -> u64 {
+    let mut prefix = [0u8; 8];
+    let len = key.len().min(prefix.len());
+    prefix[..len].copy_from_slice(&key[..len]);
+    u64::from_be_bytes(prefix)
+}
+
+/// Returns whether the rows may be radix sorted by [`radix_sort_keys`], judging only by the types
+/// of the sort keys.
+fn may_radix_sort(data_types: &[DataType], order_pairs: &[OrderPair]) -> bool {
+    match order_pairs {
+        [order_pair] => matches!(
+            data_types[order_pair.column_idx],
+            DataType::Int16 | DataType::Int32
+        ),
+        _ => false,
+    }
+}
+
+/// Returns the sort keys of all rows in `chunks` if the rows are ordered by a single `Int16` or
+/// `Int32` column without nulls. The keys are mapped to `u32` so that their unsigned order is the
+/// requested order, which allows [`radix_sort_indices`] to sort them without comparisons.
+fn radix_sort_keys(chunks: &[DataChunk], order_pairs: &[OrderPair]) -> Option<Vec<u32>> {
+    let [order_pair] = order_pairs else {
+        return None;
+    };
+    let descending = order_pair.order_type == OrderType::Descending;
+    let mut keys = Vec::with_capacity(chunks.iter().map(|c| c.capacity()).sum());
+    for chunk in chunks {
+        let array = chunk.column_at(order_pair.column_idx).array_ref();
+        if !array.null_bitmap().is_all_set() {
+            return None;
+        }
+        // Flipping the sign bit maps signed integers to unsigned ones in the same order.
+        let to_key = |v: u32| if descending { !v } else { v };
+        match array {
+            ArrayImpl::Int16(array) => keys.extend(
+                array
+                    .iter()
+                    .map(|v| to_key((v.unwrap() as u16 ^ (1 << 15)) as u32)),
+            ),
+            ArrayImpl::Int32(array) => {
+                keys.extend(array.iter().map(|v| to_key(v.unwrap() as u32 ^ (1 << 31))))
+            }
+            _ => return None,
+        }
+    }
+    Some(keys)
+}
+
+/// Returns the indices of `keys` in ascending order of the keys using an LSD radix sort. The sort
+/// is stable.
+fn radix_sort_indices(keys: &[u32]) -> Vec<usize> {
+    let mut indices = (0..keys.len()).collect_vec();
+    let mut buffer = vec![0; keys.len()];
+    for shift in (0..32).step_by(8) {
+        let digit = |idx: usize| ((keys[idx] >> shift) & 0xff) as usize;
+        let mut offsets = [0usize; 257];
+        for &idx in &indices {
+            offsets[digit(idx) + 1] += 1;
+        }
+        // Skip the pass if all keys share the same digit.
+        if offsets.iter().any(|&count| count == keys.len()) {
+            continue;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+        for &idx in &indices {
+            let offset = &mut offsets[digit(idx)];
+            buffer[*offset] = idx;
+            *offset += 1;
+        }
+        std::mem::swap(&mut indices, &mut buffer);
+    }
+    indices
+}
+
 impl SortExecutor {
     pub fn new(
         child: BoxedExecutor,
@@ -128,8 +322,20 @@ impl SortExecutor {
             identity,
             schema,
             chunk_size,
+            chunk_target_bytes: 0,
+            parallel_encoding: false,
         }
     }
+
+    pub fn with_parallel_encoding(mut self, parallel_encoding: bool) -> Self {
+        self.parallel_encoding = parallel_encoding;
+        self
+    }
+
+    pub fn with_chunk_target_bytes(mut self, chunk_target_bytes: usize) -> Self {
+        self.chunk_target_bytes = chunk_target_bytes;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -144,7 +350,6 @@ mod tests {
         DataType, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper,
         OrderedF32, Scalar,
     };
-    use risingwave_common::util::sort_util::OrderType;
 
     use super::*;
     use crate::executor::test_utils::MockExecutor;
@@ -699,4 +904,220 @@ mod tests {
         let res = stream.next().await;
         assert_eq!(res.unwrap().unwrap(), output_chunk)
     }
+
+    #[tokio::test]
+    async fn test_chunk_target_bytes() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Varchar),
+            ],
+        };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(DataChunk::from_pretty(
+            "i T
+             4 dddddddddddddddd
+             3 cccccccccccccccc
+             2 bbbbbbbbbbbbbbbb
+             1 aaaaaaaaaaaaaaaa",
+        ));
+        let order_pairs = vec![OrderPair {
+            column_idx: 0,
+            order_type: OrderType::Ascending,
+        }];
+        // Each row is wider than 16 bytes, so a 40-byte budget fits 2 rows per chunk at most.
+        let order_by_executor = Box::new(
+            SortExecutor::new(
+                Box::new(mock_executor),
+                order_pairs,
+                "SortExecutor".to_string(),
+                CHUNK_SIZE,
+            )
+            .with_chunk_target_bytes(40),
+        );
+
+        let chunks: Vec<_> = order_by_executor
+            .execute()
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+        assert!(chunks.len() >= 2);
+        assert!(chunks.iter().all(|chunk| chunk.cardinality() <= 2));
+        assert_eq!(chunks.iter().map(|c| c.cardinality()).sum::<usize>(), 4);
+        assert_eq!(
+            chunks[0].column_at(0).array().as_int32().value_at(0),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_key_prefix() {
+        let keys: [&[u8]; 7] = [
+            b"",
+            b"\x00",
+            b"ab",
+            b"abcdefgh",
+            b"abcdefgh\x00",
+            b"abcdefghij",
+            b"abd",
+        ];
+        for a in keys {
+            for b in keys {
+                let ordering = key_prefix(a).cmp(&key_prefix(b));
+                assert!(ordering.is_eq() || ordering == a.cmp(b), "{:?} {:?}", a, b);
+            }
+        }
+        assert_eq!(key_prefix(b"ab"), 0x6162 << 48);
+    }
+
+    #[test]
+    fn test_radix_sort() {
+        let chunks = vec![
+            DataChunk::from_pretty(
+                "i
+                 3
+                 -1
+                 2147483647",
+            ),
+            DataChunk::from_pretty(
+                "i
+                 -2147483648
+                 0
+                 3",
+            ),
+        ];
+        let values = [3, -1, i32::MAX, i32::MIN, 0, 3];
+        for order_type in [OrderType::Ascending, OrderType::Descending] {
+            let order_pairs = [OrderPair::new(0, order_type)];
+            let keys = radix_sort_keys(&chunks, &order_pairs).unwrap();
+            let sorted = radix_sort_indices(&keys)
+                .into_iter()
+                .map(|idx| values[idx])
+                .collect_vec();
+
+            let mut expected = values.to_vec();
+            expected.sort();
+            if order_type == OrderType::Descending {
+                expected.reverse();
+            }
+            assert_eq!(sorted, expected);
+        }
+
+        // Falls back to the comparison sort for nullable or non-integer keys.
+        let chunks = vec![DataChunk::from_pretty(
+            "i I
+             1 1
+             . 2",
+        )];
+        assert!(radix_sort_keys(&chunks, &[OrderPair::new(0, OrderType::Ascending)]).is_none());
+        assert!(radix_sort_keys(&chunks, &[OrderPair::new(1, OrderType::Ascending)]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_radix_sort_matches_comparison_sort() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int16),
+                Field::unnamed(DataType::Int32),
+            ],
+        };
+        let input = DataChunk::new(
+            vec![
+                column! { I16Array, [Some(5), Some(-3), Some(5), Some(0), Some(i16::MIN), Some(i16::MAX)] },
+                column! { I32Array, [Some(-7), Some(100), Some(2), Some(-7), Some(0), Some(100)] },
+            ],
+            6,
+        );
+        for (column_idx, order_type) in [
+            (0, OrderType::Ascending),
+            (0, OrderType::Descending),
+            (1, OrderType::Ascending),
+            (1, OrderType::Descending),
+        ] {
+            let order_pairs = vec![OrderPair::new(column_idx, order_type)];
+            let mut mock_executor = MockExecutor::new(schema.clone());
+            mock_executor.add(input.clone());
+            let executor = Box::new(SortExecutor::new(
+                Box::new(mock_executor),
+                order_pairs.clone(),
+                "SortExecutor".to_string(),
+                CHUNK_SIZE,
+            ));
+            let res = executor.execute().next().await.unwrap().unwrap();
+
+            // The output must be ordered the same way as the memcomparable encoded keys.
+            let encoded = encode_chunk(&res, &order_pairs);
+            assert!(encoded.is_sorted());
+            assert_eq!(res.cardinality(), input.cardinality());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parallel_encoding() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Varchar),
+                Field::unnamed(DataType::Int32),
+            ],
+        };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(DataChunk::from_pretty(
+            "T i
+             c 1
+             a 2",
+        ));
+        mock_executor.add(DataChunk::from_pretty(
+            "T i
+             b 3
+             d 4",
+        ));
+        let order_pairs = vec![OrderPair::new(0, OrderType::Ascending)];
+        let order_by_executor = Box::new(
+            SortExecutor::new(
+                Box::new(mock_executor),
+                order_pairs,
+                "SortExecutor".to_string(),
+                CHUNK_SIZE,
+            )
+            .with_parallel_encoding(true),
+        );
+
+        let res = order_by_executor.execute().next().await.unwrap().unwrap();
+        assert_eq!(
+            res,
+            DataChunk::from_pretty(
+                "T i
+                 a 2
+                 b 3
+                 c 1
+                 d 4",
+            )
+        );
+    }
+
+    #[cfg(madsim)]
+    #[tokio::test]
+    async fn test_parallel_encoding_cancelled_on_drop() {
+        use std::sync::atomic::AtomicUsize;
+        use std::time::Duration;
+
+        let encoded = Arc::new(AtomicUsize::new(0));
+        let mut encoding_tasks = EncodingTasks::default();
+        for _ in 0..4 {
+            let encoded = encoded.clone();
+            encoding_tasks.spawn(move || {
+                encoded.fetch_add(1, Ordering::Relaxed);
+                vec![]
+            });
+        }
+        // None of the encodings has started, as the simulation does not run them until this task
+        // yields.
+        drop(encoding_tasks);
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(encoded.load(Ordering::Relaxed), 0);
+
+        let mut encoding_tasks = EncodingTasks::default();
+        encoding_tasks.spawn(|| vec![vec![1]]);
+        assert_eq!(encoding_tasks.join().await.unwrap(), vec![vec![vec![1]]]);
+    }
 }
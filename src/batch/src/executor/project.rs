@@ -18,6 +18,7 @@ use risingwave_common::array::column::Column;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{Result, RwError};
+use risingwave_common::util::chunk_coalesce::split_chunk_by_target_bytes;
 use risingwave_expr::expr::{build_from_prost, BoxedExpression};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 
@@ -31,6 +32,9 @@ pub struct ProjectExecutor {
     child: BoxedExecutor,
     schema: Schema,
     identity: String,
+    /// The target size in bytes of an output chunk. 0 means output chunks are as large as the
+    /// input ones.
+    chunk_target_bytes: usize,
 }
 
 impl Executor for ProjectExecutor {
@@ -61,7 +65,10 @@ impl ProjectExecutor {
                 .try_collect()?;
             let (_, vis) = data_chunk.into_parts();
             let ret = DataChunk::new(arrays, vis);
-            yield ret
+            // The projected rows may be much wider than the input ones.
+            for chunk in split_chunk_by_target_bytes(ret, self.chunk_target_bytes) {
+                yield chunk
+            }
         }
     }
 }
@@ -95,6 +102,11 @@ impl BoxedExecutorBuilder for ProjectExecutor {
             child,
             schema: Schema { fields },
             identity: source.plan_node().get_identity().clone(),
+            chunk_target_bytes: source
+                .context
+                .get_config()
+                .developer
+                .batch_chunk_target_bytes,
         }))
     }
 }
@@ -104,6 +116,7 @@ mod tests {
     use futures::stream::StreamExt;
     use risingwave_common::array::{Array, I32Array};
     use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::collection::estimate_size::EstimateSize;
     use risingwave_common::test_prelude::*;
     use risingwave_common::types::DataType;
     use risingwave_expr::expr::{InputRefExpression, LiteralExpression};
@@ -145,6 +158,7 @@ mod tests {
             child: Box::new(mock_executor),
             schema: Schema { fields },
             identity: "ProjectExecutor".to_string(),
+            chunk_target_bytes: 0,
         });
 
         let fields = &proj_executor.schema().fields;
@@ -181,6 +195,7 @@ mod tests {
             child: values_executor2,
             schema: schema_unnamed!(DataType::Int32),
             identity: "ProjectExecutor2".to_string(),
+            chunk_target_bytes: 0,
         });
         let mut stream = proj_executor.execute();
         let chunk = stream.next().await.unwrap().unwrap();
@@ -189,4 +204,37 @@ mod tests {
             array_nonnull!(I32Array, [1]).into()
         );
     }
+
+    #[tokio::test]
+    async fn test_project_chunk_target_bytes() {
+        let chunk = DataChunk::from_pretty(
+            "i T
+             1 aaaaaaaaaaaaaaaa
+             2 bbbbbbbbbbbbbbbb
+             3 cccccccccccccccc
+             4 dddddddddddddddd",
+        );
+        let target_bytes = chunk.estimated_heap_size() / 2;
+        let schema = schema_unnamed! { DataType::Int32, DataType::Varchar };
+        let mut mock_executor = MockExecutor::new(schema.clone());
+        mock_executor.add(chunk);
+
+        let proj_executor = Box::new(ProjectExecutor {
+            expr: vec![
+                Box::new(InputRefExpression::new(DataType::Int32, 0)),
+                Box::new(InputRefExpression::new(DataType::Varchar, 1)),
+            ],
+            child: Box::new(mock_executor),
+            schema,
+            identity: "ProjectExecutor".to_string(),
+            chunk_target_bytes: target_bytes,
+        });
+        let chunks: Vec<_> = proj_executor
+            .execute()
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+        assert!(chunks.len() >= 2);
+        assert_eq!(chunks.iter().map(|c| c.cardinality()).sum::<usize>(), 4);
+    }
 }
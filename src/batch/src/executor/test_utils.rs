@@ -44,9 +44,12 @@ pub fn gen_data(batch_size: usize, batch_num: usize, data_types: &[DataType]) ->
     for i in 0..batch_num {
         let mut columns = Vec::new();
         for data_type in data_types {
-            let mut data_gen =
-                FieldGeneratorImpl::with_number_random(data_type.clone(), None, None, SEED)
-                    .unwrap();
+            let mut data_gen = match data_type {
+                DataType::Varchar => FieldGeneratorImpl::with_varchar(None, SEED),
+                DataType::Timestamp => FieldGeneratorImpl::with_timestamp(None, None, SEED),
+                _ => FieldGeneratorImpl::with_number_random(data_type.clone(), None, None, SEED),
+            }
+            .unwrap();
             let mut array_builder = data_type.create_array_builder(batch_size);
             for j in 0..batch_size {
                 array_builder.append_datum(&data_gen.generate_datum(((i + 1) * (j + 1)) as u64));
@@ -14,6 +14,7 @@
 
 use futures::stream::StreamExt;
 use futures_async_stream::try_stream;
+use global_stats_alloc::operator_allocation_stat;
 use minitrace::prelude::*;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
@@ -21,18 +22,27 @@ use risingwave_common::error::RwError;
 use tracing::event;
 
 use crate::executor::{BoxedDataChunkStream, BoxedExecutor, Executor};
+use crate::task::TaskId;
 
 /// If tracing is enabled, we build a [`TraceExecutor`] on top of the underlying executor.
 /// So the duration of performance-critical operations will be traced, such as open/next/close.
+/// The heap memory allocated by the underlying executor is counted under its identity as well.
 pub struct TraceExecutor {
     child: BoxedExecutor,
     /// Description of input executor
     input_desc: String,
+    /// The task that the executor belongs to, attached to the spans to correlate them with a
+    /// query.
+    task_id: TaskId,
 }
 
 impl TraceExecutor {
-    pub fn new(child: BoxedExecutor, input_desc: String) -> Self {
-        Self { child, input_desc }
+    pub fn new(child: BoxedExecutor, input_desc: String, task_id: TaskId) -> Self {
+        Self {
+            child,
+            input_desc,
+            task_id,
+        }
     }
 }
 
@@ -54,13 +64,18 @@ impl TraceExecutor {
     #[try_stream(boxed, ok = DataChunk, error = RwError)]
     async fn do_execute(self: Box<Self>) {
         let input_desc = self.input_desc.as_str();
+        let task_id = &self.task_id;
         let span_name = format!("{input_desc}_next");
-        let mut child_stream = self.child.execute();
+        let mut child_stream =
+            operator_allocation_stat(self.child.execute(), input_desc.to_string());
 
         let span = || {
             let mut span = Span::enter_with_local_parent("next");
             span.add_property(|| ("otel.name", span_name.to_string()));
             span.add_property(|| ("next", input_desc.to_string()));
+            span.add_property(|| ("task_id", task_id.task_id.to_string()));
+            span.add_property(|| ("stage_id", task_id.stage_id.to_string()));
+            span.add_property(|| ("query_id", task_id.query_id.to_string()));
             span
         };
 
@@ -18,6 +18,7 @@ use itertools::Itertools;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{Result, RwError};
+use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
 use risingwave_common::util::select_all;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 
@@ -29,6 +30,7 @@ use crate::task::BatchTaskContext;
 pub struct UnionExecutor {
     inputs: Vec<BoxedExecutor>,
     identity: String,
+    chunk_size: usize,
 }
 
 impl Executor for UnionExecutor {
@@ -48,6 +50,8 @@ impl Executor for UnionExecutor {
 impl UnionExecutor {
     #[try_stream(boxed, ok = DataChunk, error = RwError)]
     async fn do_execute(self: Box<Self>) {
+        let mut chunk_builder =
+            DataChunkBuilder::new(self.inputs[0].schema().data_types(), self.chunk_size);
         let mut stream = select_all(
             self.inputs
                 .into_iter()
@@ -56,9 +60,16 @@ impl UnionExecutor {
         )
         .boxed();
 
+        // Coalesce the small chunks from the inputs into chunks of `chunk_size`.
         while let Some(data_chunk) = stream.next().await {
             let data_chunk = data_chunk?;
-            yield data_chunk
+            #[for_await]
+            for spilled in chunk_builder.trunc_data_chunk(data_chunk) {
+                yield spilled
+            }
+        }
+        if let Some(spilled) = chunk_builder.consume_all() {
+            yield spilled
         }
     }
 }
@@ -75,13 +86,18 @@ impl BoxedExecutorBuilder for UnionExecutor {
         Ok(Box::new(Self::new(
             inputs,
             source.plan_node().get_identity().clone(),
+            source.context.get_config().developer.batch_chunk_size,
         )))
     }
 }
 
 impl UnionExecutor {
-    pub fn new(inputs: Vec<BoxedExecutor>, identity: String) -> Self {
-        Self { inputs, identity }
+    pub fn new(inputs: Vec<BoxedExecutor>, identity: String, chunk_size: usize) -> Self {
+        Self {
+            inputs,
+            identity,
+            chunk_size,
+        }
     }
 }
 
@@ -126,6 +142,7 @@ mod tests {
         let union_executor = Box::new(UnionExecutor {
             inputs: vec![Box::new(mock_executor1), Box::new(mock_executor2)],
             identity: "UnionExecutor".to_string(),
+            chunk_size: 4,
         });
         let fields = &union_executor.schema().fields;
         assert_eq!(fields[0].data_type, DataType::Int32);
@@ -21,6 +21,7 @@ use risingwave_common::array::{ArrayBuilder, DataChunk, Op, PrimitiveArrayBuilde
 use risingwave_common::catalog::{Field, Schema, TableId};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::DataType;
+use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
 use risingwave_expr::expr::{build_from_prost, BoxedExpression};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_source::TableSourceManagerRef;
@@ -40,6 +41,7 @@ pub struct UpdateExecutor {
     source_manager: TableSourceManagerRef,
     child: BoxedExecutor,
     exprs: Vec<BoxedExpression>,
+    chunk_size: usize,
     schema: Schema,
     identity: String,
 }
@@ -50,6 +52,7 @@ impl UpdateExecutor {
         source_manager: TableSourceManagerRef,
         child: BoxedExecutor,
         exprs: Vec<BoxedExpression>,
+        chunk_size: usize,
         identity: String,
     ) -> Self {
         assert_eq!(
@@ -63,6 +66,8 @@ impl UpdateExecutor {
             source_manager,
             child,
             exprs,
+            // Keep `U-` and `U+` of the same row in the same chunk.
+            chunk_size: chunk_size.max(2) / 2 * 2,
             // TODO: support `RETURNING`
             schema: Schema {
                 fields: vec![Field::unnamed(DataType::Int64)],
@@ -93,8 +98,21 @@ impl UpdateExecutor {
         let source = source_desc.source.as_table().expect("not table source");
 
         let schema = self.child.schema().clone();
+        let mut builder = DataChunkBuilder::new(schema.data_types(), self.chunk_size);
         let mut notifiers = Vec::new();
 
+        let write_chunk = |chunk: DataChunk| {
+            // Rows in the chunk are (U-, U+) pairs.
+            let ops = [Op::UpdateDelete, Op::UpdateInsert]
+                .into_iter()
+                .cycle()
+                .take(chunk.capacity())
+                .collect();
+            let stream_chunk = StreamChunk::from_parts(ops, chunk);
+
+            source.write_chunk(stream_chunk)
+        };
+
         #[for_await]
         for data_chunk in self.child.execute() {
             let data_chunk = data_chunk?.compact();
@@ -111,29 +129,19 @@ impl UpdateExecutor {
             };
 
             // Merge two data chunks into (U-, U+) pairs.
-            // TODO: split chunks
-            let mut builders = schema.create_array_builders(len * 2);
             for row in data_chunk
                 .rows()
                 .zip_eq(updated_data_chunk.rows())
                 .flat_map(|(a, b)| [a, b])
             {
-                for (datum_ref, builder) in row.values().zip_eq(builders.iter_mut()) {
-                    builder.append_datum(datum_ref);
+                if let Some(chunk) = builder.append_one_row(row) {
+                    notifiers.push(write_chunk(chunk)?);
                 }
             }
-            let columns = builders.into_iter().map(|b| b.finish().into()).collect();
-
-            let ops = [Op::UpdateDelete, Op::UpdateInsert]
-                .into_iter()
-                .cycle()
-                .take(len * 2)
-                .collect();
-
-            let stream_chunk = StreamChunk::new(ops, columns, None);
+        }
 
-            let notifier = source.write_chunk(stream_chunk)?;
-            notifiers.push(notifier);
+        if let Some(chunk) = builder.consume_all() {
+            notifiers.push(write_chunk(chunk)?);
         }
 
         // Wait for all chunks to be taken / written.
@@ -185,6 +193,7 @@ impl BoxedExecutorBuilder for UpdateExecutor {
             source.context().source_manager(),
             child,
             exprs,
+            source.context.get_config().developer.batch_chunk_size,
             source.plan_node().get_identity().clone(),
         )))
     }
@@ -256,6 +265,7 @@ mod tests {
             source_manager.clone(),
             Box::new(mock_executor),
             exprs,
+            1024,
             "UpdateExecutor".to_string(),
         ));
 
@@ -0,0 +1,728 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of a plan fragment before building executors from it, so that a malformed plan is
+//! reported with all of its problems at once, instead of failing in the middle of execution.
+
+use std::fmt::Display;
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{DataType, DataTypeName};
+use risingwave_pb::batch_plan::exchange_info::Distribution;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::{ExchangeInfo, PlanFragment, PlanNode};
+use risingwave_pb::expr::expr_node::RexNode;
+use risingwave_pb::expr::{AggCall, ExprNode};
+use risingwave_pb::plan_common::{Field, JoinType};
+
+use crate::error::BatchError;
+
+/// Checks child counts, column index bounds, type agreement between expressions and the schema
+/// of their inputs, and exchange keys of `plan`. All problems found are reported in one error.
+pub fn validate_plan_fragment(plan: &PlanFragment) -> Result<()> {
+    let root = plan
+        .root
+        .as_ref()
+        .ok_or_else(|| ErrorCode::InternalError("plan fragment has no root".to_string()))?;
+    let mut validator = PlanValidator::default();
+    let output_types = validator.visit(root);
+    if let (Some(exchange_info), Some(output_types)) = (&plan.exchange_info, output_types) {
+        validator.check_exchange_info(root, exchange_info, &output_types);
+    }
+    validator.finish()
+}
+
+#[derive(Default)]
+struct PlanValidator {
+    problems: Vec<String>,
+}
+
+impl PlanValidator {
+    fn report(&mut self, node: &PlanNode, problem: impl Display) {
+        self.problems
+            .push(format!("{}: {}", node.identity, problem));
+    }
+
+    fn finish(self) -> Result<()> {
+        if self.problems.is_empty() {
+            Ok(())
+        } else {
+            Err(BatchError::InvalidPlan(self.problems.join("\n")).into())
+        }
+    }
+
+    /// Validates the subtree rooted at `node` and returns its output types, or `None` if they are
+    /// unknown, either because the plan is invalid or the node is not inspected.
+    fn visit(&mut self, node: &PlanNode) -> Option<Vec<DataType>> {
+        let inputs = node
+            .children
+            .iter()
+            .map(|child| self.visit(child))
+            .collect_vec();
+        let Some(body) = &node.node_body else {
+            self.report(node, "missing node body");
+            return None;
+        };
+
+        let (min_children, max_children) = expected_children(body);
+        if inputs.len() < min_children || inputs.len() > max_children {
+            self.report(
+                node,
+                format!(
+                    "expected {} children, got {}",
+                    if min_children == max_children {
+                        min_children.to_string()
+                    } else {
+                        format!("at least {}", min_children)
+                    },
+                    inputs.len()
+                ),
+            );
+            return None;
+        }
+
+        // Checks below need the output types of all children.
+        let inputs: Vec<Vec<DataType>> = inputs.into_iter().collect::<Option<_>>()?;
+        let input = inputs.first().map(Vec::as_slice).unwrap_or_default();
+
+        match body {
+            NodeBody::Project(project) => {
+                for expr in &project.select_list {
+                    self.check_expr(node, expr, input);
+                }
+                project.select_list.iter().map(expr_type).collect()
+            }
+            NodeBody::Filter(filter) => {
+                if let Some(cond) = &filter.search_condition {
+                    self.check_expr(node, cond, input);
+                    if expr_type(cond) != Some(DataType::Boolean) {
+                        self.report(node, "filter condition is not a boolean expression");
+                    }
+                }
+                Some(input.to_vec())
+            }
+            NodeBody::Update(update) => {
+                for expr in &update.exprs {
+                    self.check_expr(node, expr, input);
+                }
+                Some(vec![DataType::Int64])
+            }
+            NodeBody::Insert(_) | NodeBody::Delete(_) => Some(vec![DataType::Int64]),
+            NodeBody::Sort(sort) => {
+                self.check_indices(node, sort.column_orders.iter().map(|o| o.index), input);
+                Some(input.to_vec())
+            }
+            NodeBody::TopN(top_n) => {
+                self.check_indices(node, top_n.column_orders.iter().map(|o| o.index), input);
+                Some(input.to_vec())
+            }
+            NodeBody::GroupTopN(top_n) => {
+                self.check_indices(node, top_n.column_orders.iter().map(|o| o.index), input);
+                self.check_indices(node, top_n.group_key.iter().copied(), input);
+                Some(input.to_vec())
+            }
+            NodeBody::Limit(_) => Some(input.to_vec()),
+            NodeBody::HashAgg(agg) => {
+                self.check_indices(node, agg.group_key.iter().copied(), input);
+                for agg_call in &agg.agg_calls {
+                    self.check_agg_call(node, agg_call, input);
+                }
+                let mut types: Vec<_> = agg
+                    .group_key
+                    .iter()
+                    .map(|&i| input.get(i as usize).cloned())
+                    .collect::<Option<_>>()?;
+                for agg_call in &agg.agg_calls {
+                    types.push(agg_call_type(agg_call)?);
+                }
+                Some(types)
+            }
+            NodeBody::SortAgg(agg) => {
+                for expr in &agg.group_key {
+                    self.check_expr(node, expr, input);
+                }
+                for agg_call in &agg.agg_calls {
+                    self.check_agg_call(node, agg_call, input);
+                }
+                agg.group_key
+                    .iter()
+                    .map(expr_type)
+                    .chain(agg.agg_calls.iter().map(agg_call_type))
+                    .collect()
+            }
+            NodeBody::HashJoin(join) => {
+                let (left, right) = (&inputs[0], &inputs[1]);
+                self.check_join_keys(node, &join.left_key, &join.right_key, left, right);
+                let full = [left.as_slice(), right.as_slice()].concat();
+                if let Some(cond) = &join.condition {
+                    self.check_expr(node, cond, &full);
+                }
+                self.join_output(node, join.join_type, &join.output_indices, left, right)
+            }
+            NodeBody::SortMergeJoin(join) => {
+                let (left, right) = (&inputs[0], &inputs[1]);
+                self.check_join_keys(node, &join.left_key, &join.right_key, left, right);
+                self.join_output(node, join.join_type, &join.output_indices, left, right)
+            }
+            NodeBody::NestedLoopJoin(join) => {
+                let (left, right) = (&inputs[0], &inputs[1]);
+                let full = [left.as_slice(), right.as_slice()].concat();
+                if let Some(cond) = &join.join_cond {
+                    self.check_expr(node, cond, &full);
+                }
+                self.join_output(node, join.join_type, &join.output_indices, left, right)
+            }
+            NodeBody::Union(_) => {
+                for (i, other) in inputs.iter().enumerate().skip(1) {
+                    if !types_agree(input, other) {
+                        self.report(
+                            node,
+                            format!("child {} has types {:?}, expected {:?}", i, other, input),
+                        );
+                    }
+                }
+                Some(input.to_vec())
+            }
+            NodeBody::Values(values) => {
+                let fields: Vec<_> = values
+                    .fields
+                    .iter()
+                    .map(field_type)
+                    .collect::<Option<_>>()?;
+                for (i, tuple) in values.tuples.iter().enumerate() {
+                    if tuple.cells.len() != fields.len() {
+                        self.report(
+                            node,
+                            format!(
+                                "row {} has {} values, expected {}",
+                                i,
+                                tuple.cells.len(),
+                                fields.len()
+                            ),
+                        );
+                    }
+                    for cell in &tuple.cells {
+                        self.check_expr(node, cell, &[]);
+                    }
+                }
+                Some(fields)
+            }
+            NodeBody::Exchange(exchange) => exchange.input_schema.iter().map(field_type).collect(),
+            NodeBody::MergeSortExchange(merge_sort) => {
+                let input_schema = &merge_sort.exchange.as_ref()?.input_schema;
+                let types: Vec<_> = input_schema.iter().map(field_type).collect::<Option<_>>()?;
+                let indices = merge_sort.column_orders.iter().map(|o| o.index);
+                self.check_indices(node, indices, &types);
+                Some(types)
+            }
+            NodeBody::RowSeqScan(scan) => {
+                let columns = &scan.table_desc.as_ref()?.columns;
+                let mut types = Vec::with_capacity(scan.column_ids.len());
+                for column_id in &scan.column_ids {
+                    match columns.iter().find(|c| c.column_id == *column_id) {
+                        Some(column) => types.push(DataType::from(column.column_type.as_ref()?)),
+                        None => {
+                            self.report(node, format!("column id {} not in table", column_id));
+                            return None;
+                        }
+                    }
+                }
+                Some(types)
+            }
+            NodeBody::SysRowSeqScan(scan) => scan
+                .column_descs
+                .iter()
+                .map(|c| c.column_type.as_ref().map(DataType::from))
+                .collect(),
+            // The output of these nodes is not inspected.
+            NodeBody::HopWindow(_)
+            | NodeBody::TableFunction(_)
+            | NodeBody::Expand(_)
+            | NodeBody::LocalLookupJoin(_)
+            | NodeBody::ProjectSet(_)
+            | NodeBody::DistributedLookupJoin(_)
+            | NodeBody::Source(_) => None,
+        }
+    }
+
+    /// Checks that every input ref in `expr` is within `input` and agrees with its type.
+    fn check_expr(&mut self, node: &PlanNode, expr: &ExprNode, input: &[DataType]) {
+        match &expr.rex_node {
+            Some(RexNode::InputRef(input_ref)) => {
+                let idx = input_ref.column_idx;
+                match usize::try_from(idx).ok().and_then(|idx| input.get(idx)) {
+                    Some(input_type) => {
+                        let Some(ref_type) = expr_type(expr) else {
+                            self.report(node, format!("input ref ${} has no type", idx));
+                            return;
+                        };
+                        if !type_agrees(&ref_type, input_type) {
+                            self.report(
+                                node,
+                                format!(
+                                    "input ref ${} has type {}, but the input column is {}",
+                                    idx, ref_type, input_type
+                                ),
+                            );
+                        }
+                    }
+                    None => self.report(
+                        node,
+                        format!(
+                            "input ref ${} is out of range for input with {} columns",
+                            idx,
+                            input.len()
+                        ),
+                    ),
+                }
+            }
+            Some(RexNode::FuncCall(func_call)) => {
+                for child in &func_call.children {
+                    self.check_expr(node, child, input);
+                }
+            }
+            Some(RexNode::Constant(_)) | None => {}
+        }
+    }
+
+    fn check_agg_call(&mut self, node: &PlanNode, agg_call: &AggCall, input: &[DataType]) {
+        for arg in &agg_call.args {
+            let Some(arg_ref) = &arg.input else { continue };
+            let idx = arg_ref.column_idx;
+            match usize::try_from(idx).ok().and_then(|idx| input.get(idx)) {
+                Some(input_type) => {
+                    let Some(arg_type) = &arg.r#type else { continue };
+                    let arg_type = DataType::from(arg_type);
+                    if !type_agrees(&arg_type, input_type) {
+                        self.report(
+                            node,
+                            format!(
+                                "aggregation argument ${} has type {}, but the input column is {}",
+                                idx, arg_type, input_type
+                            ),
+                        );
+                    }
+                }
+                None => self.report(
+                    node,
+                    format!(
+                        "aggregation argument ${} is out of range for input with {} columns",
+                        idx,
+                        input.len()
+                    ),
+                ),
+            }
+        }
+        if let Some(filter) = &agg_call.filter {
+            self.check_expr(node, filter, input);
+        }
+    }
+
+    fn check_indices(
+        &mut self,
+        node: &PlanNode,
+        indices: impl IntoIterator<Item = u32>,
+        input: &[DataType],
+    ) {
+        for idx in indices {
+            if idx as usize >= input.len() {
+                self.report(
+                    node,
+                    format!(
+                        "column index {} is out of range for input with {} columns",
+                        idx,
+                        input.len()
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_join_keys(
+        &mut self,
+        node: &PlanNode,
+        left_key: &[i32],
+        right_key: &[i32],
+        left: &[DataType],
+        right: &[DataType],
+    ) {
+        if left_key.len() != right_key.len() {
+            self.report(
+                node,
+                format!(
+                    "{} left keys do not match {} right keys",
+                    left_key.len(),
+                    right_key.len()
+                ),
+            );
+            return;
+        }
+        for (&l, &r) in left_key.iter().zip_eq(right_key) {
+            let l_type = usize::try_from(l).ok().and_then(|l| left.get(l));
+            let r_type = usize::try_from(r).ok().and_then(|r| right.get(r));
+            match (l_type, r_type) {
+                (Some(l_type), Some(r_type)) => {
+                    if !type_agrees(l_type, r_type) {
+                        self.report(
+                            node,
+                            format!(
+                                "join key types do not agree: left ${} is {}, right ${} is {}",
+                                l, l_type, r, r_type
+                            ),
+                        );
+                    }
+                }
+                _ => self.report(
+                    node,
+                    format!(
+                        "join key (${}, ${}) is out of range for inputs with {} and {} columns",
+                        l,
+                        r,
+                        left.len(),
+                        right.len()
+                    ),
+                ),
+            }
+        }
+    }
+
+    fn join_output(
+        &mut self,
+        node: &PlanNode,
+        join_type: i32,
+        output_indices: &[u32],
+        left: &[DataType],
+        right: &[DataType],
+    ) -> Option<Vec<DataType>> {
+        let original = match JoinType::from_i32(join_type) {
+            Some(JoinType::LeftSemi | JoinType::LeftAnti) => left.to_vec(),
+            Some(JoinType::RightSemi | JoinType::RightAnti) => right.to_vec(),
+            Some(JoinType::Inner | JoinType::LeftOuter | JoinType::RightOuter)
+            | Some(JoinType::FullOuter) => [left, right].concat(),
+            Some(JoinType::Unspecified) | None => {
+                self.report(node, format!("invalid join type {}", join_type));
+                return None;
+            }
+        };
+        self.check_indices(node, output_indices.iter().copied(), &original);
+        output_indices
+            .iter()
+            .map(|&i| original.get(i as usize).cloned())
+            .collect()
+    }
+
+    fn check_exchange_info(
+        &mut self,
+        root: &PlanNode,
+        exchange_info: &ExchangeInfo,
+        output_types: &[DataType],
+    ) {
+        match &exchange_info.distribution {
+            Some(Distribution::HashInfo(hash_info)) => {
+                if hash_info.output_count == 0 {
+                    self.report(root, "hash exchange has no outputs");
+                }
+                self.check_indices(root, hash_info.key.iter().copied(), output_types);
+            }
+            Some(Distribution::ConsistentHashInfo(hash_info)) => {
+                if hash_info.vmap.is_empty() {
+                    self.report(root, "consistent hash exchange has an empty vnode mapping");
+                }
+                self.check_indices(root, hash_info.key.iter().copied(), output_types);
+            }
+            Some(Distribution::RangeInfo(range_info)) => {
+                if range_info.output_count == 0 {
+                    self.report(root, "range exchange has no outputs");
+                } else if range_info.boundaries.len() >= range_info.output_count as usize {
+                    self.report(
+                        root,
+                        format!(
+                            "range exchange has {} boundaries for {} outputs",
+                            range_info.boundaries.len(),
+                            range_info.output_count
+                        ),
+                    );
+                }
+                if range_info.boundaries.windows(2).any(|w| w[0] >= w[1]) {
+                    self.report(
+                        root,
+                        "range exchange boundaries are not strictly increasing",
+                    );
+                }
+                self.check_indices(
+                    root,
+                    range_info.column_orders.iter().map(|order| order.index),
+                    output_types,
+                );
+            }
+            Some(Distribution::BroadcastInfo(broadcast_info)) => {
+                if broadcast_info.count == 0 {
+                    self.report(root, "broadcast exchange has no outputs");
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Returns the minimum and maximum number of children of a node.
+fn expected_children(body: &NodeBody) -> (usize, usize) {
+    match body {
+        NodeBody::RowSeqScan(_)
+        | NodeBody::SysRowSeqScan(_)
+        | NodeBody::Exchange(_)
+        | NodeBody::MergeSortExchange(_)
+        | NodeBody::Values(_)
+        | NodeBody::TableFunction(_)
+        | NodeBody::Source(_) => (0, 0),
+        NodeBody::Insert(_)
+        | NodeBody::Delete(_)
+        | NodeBody::Update(_)
+        | NodeBody::Project(_)
+        | NodeBody::Filter(_)
+        | NodeBody::HashAgg(_)
+        | NodeBody::SortAgg(_)
+        | NodeBody::Sort(_)
+        | NodeBody::TopN(_)
+        | NodeBody::GroupTopN(_)
+        | NodeBody::Limit(_)
+        | NodeBody::HopWindow(_)
+        | NodeBody::Expand(_)
+        | NodeBody::ProjectSet(_)
+        | NodeBody::LocalLookupJoin(_)
+        | NodeBody::DistributedLookupJoin(_) => (1, 1),
+        NodeBody::HashJoin(_) | NodeBody::SortMergeJoin(_) | NodeBody::NestedLoopJoin(_) => (2, 2),
+        NodeBody::Union(_) => (1, usize::MAX),
+    }
+}
+
+fn expr_type(expr: &ExprNode) -> Option<DataType> {
+    expr.return_type.as_ref().map(DataType::from)
+}
+
+fn agg_call_type(agg_call: &AggCall) -> Option<DataType> {
+    agg_call.return_type.as_ref().map(DataType::from)
+}
+
+fn field_type(field: &Field) -> Option<DataType> {
+    field.data_type.as_ref().map(DataType::from)
+}
+
+/// Nested types are only compared by their kinds, since the names of struct fields may differ.
+fn type_agrees(a: &DataType, b: &DataType) -> bool {
+    match (a, b) {
+        (DataType::Struct(_), _) | (DataType::List { .. }, _) => {
+            DataTypeName::from(a) == DataTypeName::from(b)
+        }
+        _ => a == b,
+    }
+}
+
+fn types_agree(a: &[DataType], b: &[DataType]) -> bool {
+    a.len() == b.len() && a.iter().zip_eq(b).all(|(a, b)| type_agrees(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_expr::expr::{make_i32_literal, make_input_ref};
+    use risingwave_pb::batch_plan::exchange_info::{DistributionMode, RangeInfo};
+    use risingwave_pb::batch_plan::values_node::ExprTuple;
+    use risingwave_pb::batch_plan::{FilterNode, HashJoinNode, ProjectNode, ValuesNode};
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::plan_common::ColumnOrder;
+
+    use super::*;
+
+    fn node(identity: &str, body: NodeBody, children: Vec<PlanNode>) -> PlanNode {
+        PlanNode {
+            children,
+            node_body: Some(body),
+            identity: identity.to_string(),
+        }
+    }
+
+    fn values(row_len: usize) -> PlanNode {
+        let body = NodeBody::Values(ValuesNode {
+            tuples: vec![ExprTuple {
+                cells: (0..row_len as i32).map(make_i32_literal).collect(),
+            }],
+            fields: vec![
+                Field {
+                    data_type: Some(DataType::Int32.to_protobuf()),
+                    name: "v".to_string(),
+                };
+                2
+            ],
+        });
+        node("Values", body, vec![])
+    }
+
+    fn project(select_list: Vec<ExprNode>, input: PlanNode) -> PlanNode {
+        node(
+            "Project",
+            NodeBody::Project(ProjectNode { select_list }),
+            vec![input],
+        )
+    }
+
+    fn fragment(root: PlanNode) -> PlanFragment {
+        PlanFragment {
+            root: Some(root),
+            exchange_info: None,
+        }
+    }
+
+    #[test]
+    fn test_valid_plan() {
+        let plan = project(
+            vec![
+                make_input_ref(1, TypeName::Int32),
+                make_input_ref(0, TypeName::Int32),
+            ],
+            values(2),
+        );
+        validate_plan_fragment(&fragment(plan)).unwrap();
+    }
+
+    #[test]
+    fn test_aggregated_problems() {
+        let plan = project(
+            vec![
+                make_input_ref(2, TypeName::Int32),
+                make_input_ref(0, TypeName::Int64),
+            ],
+            values(2),
+        );
+        let err = validate_plan_fragment(&fragment(plan))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Project: input ref $2 is out of range"),
+            "{}",
+            err
+        );
+        assert!(
+            err.contains("Project: input ref $0 has type bigint"),
+            "{}",
+            err
+        );
+
+        let plan = node(
+            "HashJoin",
+            NodeBody::HashJoin(HashJoinNode {
+                join_type: JoinType::Inner as i32,
+                left_key: vec![0],
+                right_key: vec![5],
+                output_indices: vec![0, 4],
+                ..Default::default()
+            }),
+            vec![values(3), values(2)],
+        );
+        let err = validate_plan_fragment(&fragment(plan))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Values: row 0 has 3 values, expected 2"),
+            "{}",
+            err
+        );
+
+        let plan = node(
+            "HashJoin",
+            NodeBody::HashJoin(HashJoinNode {
+                join_type: JoinType::Inner as i32,
+                left_key: vec![0],
+                right_key: vec![5],
+                output_indices: vec![0, 4],
+                ..Default::default()
+            }),
+            vec![values(2), values(2)],
+        );
+        let err = validate_plan_fragment(&fragment(plan))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("HashJoin: join key ($0, $5) is out of range"),
+            "{}",
+            err
+        );
+        assert!(
+            err.contains("HashJoin: column index 4 is out of range"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_wrong_children() {
+        let filter = FilterNode {
+            search_condition: Some(make_input_ref(0, TypeName::Int32)),
+        };
+        let plan = node("Filter", NodeBody::Filter(filter.clone()), vec![]);
+        let err = validate_plan_fragment(&fragment(plan))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Filter: expected 1 children, got 0"),
+            "{}",
+            err
+        );
+
+        let plan = node("Filter", NodeBody::Filter(filter), vec![values(2)]);
+        let err = validate_plan_fragment(&fragment(plan))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Filter: filter condition is not a boolean"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_range_exchange() {
+        let fragment = PlanFragment {
+            root: Some(values(2)),
+            exchange_info: Some(ExchangeInfo {
+                mode: DistributionMode::Range as i32,
+                distribution: Some(Distribution::RangeInfo(RangeInfo {
+                    output_count: 2,
+                    column_orders: vec![ColumnOrder {
+                        index: 2,
+                        ..Default::default()
+                    }],
+                    boundaries: vec![vec![2], vec![1]],
+                })),
+                ..Default::default()
+            }),
+        };
+        let err = validate_plan_fragment(&fragment).unwrap_err().to_string();
+        assert!(
+            err.contains("Values: range exchange has 2 boundaries for 2 outputs"),
+            "{}",
+            err
+        );
+        assert!(
+            err.contains("Values: range exchange boundaries are not strictly increasing"),
+            "{}",
+            err
+        );
+        assert!(
+            err.contains("Values: column index 2 is out of range"),
+            "{}",
+            err
+        );
+    }
+}
@@ -36,6 +36,10 @@ pub mod executor;
 pub mod rpc;
 pub mod task;
 
+#[cfg(test)]
+#[cfg(feature = "failpoints")]
+mod batch_failpoints;
+
 #[macro_use]
 extern crate tracing;
 #[macro_use]
@@ -57,6 +57,7 @@ impl TaskService for BatchServiceImpl {
             task_id,
             plan,
             epoch,
+            resource_group,
         } = request.into_inner();
 
         let res = self
@@ -69,6 +70,7 @@ impl TaskService for BatchServiceImpl {
                     self.env.clone(),
                     TaskId::from(task_id.as_ref().expect("no task id found")),
                 ),
+                &resource_group,
             )
             .await;
         match res {
@@ -16,13 +16,12 @@ use std::fmt::{Debug, Formatter};
 use std::future::Future;
 
 use risingwave_common::array::DataChunk;
-use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
 use risingwave_pb::batch_plan::exchange_info::BroadcastInfo;
 use risingwave_pb::batch_plan::*;
 use tokio::sync::mpsc;
 
-use crate::error::BatchError::SenderError;
+use crate::error::BatchError::{ChannelClosed, SenderError};
 use crate::error::Result as BatchResult;
 use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
 use crate::task::data_chunk_in_channel::DataChunkInChannel;
@@ -72,7 +71,7 @@ impl ChanReceiver for BroadcastReceiver {
             match self.receiver.recv().await {
                 Some(data_chunk) => Ok(data_chunk),
                 // Early close should be treated as an error.
-                None => Err(InternalError("broken broadcast_channel".to_string()).into()),
+                None => Err(ChannelClosed("broadcast_channel").into()),
             }
         }
     }
@@ -14,12 +14,14 @@
 
 use std::future::Future;
 
+use anyhow::anyhow;
+use fail::fail_point;
 use risingwave_common::array::DataChunk;
 use risingwave_common::error::Result;
 use risingwave_pb::batch_plan::exchange_info::DistributionMode as ShuffleDistributionMode;
 use risingwave_pb::batch_plan::ExchangeInfo;
 
-use crate::error::Result as BatchResult;
+use crate::error::{BatchError, Result as BatchResult};
 use crate::task::broadcast_channel::{new_broadcast_channel, BroadcastReceiver, BroadcastSender};
 use crate::task::consistent_hash_shuffle_channel::{
     new_consistent_shuffle_channel, ConsistentHashShuffleReceiver, ConsistentHashShuffleSender,
@@ -29,6 +31,9 @@ use crate::task::fifo_channel::{new_fifo_channel, FifoReceiver, FifoSender};
 use crate::task::hash_shuffle_channel::{
     new_hash_shuffle_channel, HashShuffleReceiver, HashShuffleSender,
 };
+use crate::task::range_shuffle_channel::{
+    new_range_shuffle_channel, RangeShuffleReceiver, RangeShuffleSender,
+};
 
 pub(super) trait ChanSender: Send {
     type SendFuture<'a>: Future<Output = BatchResult<()>> + Send
@@ -46,15 +51,28 @@ pub enum ChanSenderImpl {
     ConsistentHashShuffle(ConsistentHashShuffleSender),
     Fifo(FifoSender),
     Broadcast(BroadcastSender),
+    RangeShuffle(RangeShuffleSender),
 }
 
 impl ChanSenderImpl {
-    pub(super) async fn send(&mut self, chunk: Option<DataChunk>) -> BatchResult<()> {
+    pub async fn send(&mut self, chunk: Option<DataChunk>) -> BatchResult<()> {
+        match self {
+            Self::HashShuffle(_) => fail_point!("batch_hash_shuffle_send", injected_send_error),
+            Self::ConsistentHashShuffle(_) => {
+                fail_point!("batch_consistent_hash_shuffle_send", injected_send_error)
+            }
+            Self::Fifo(_) => fail_point!("batch_fifo_send", injected_send_error),
+            Self::Broadcast(_) => fail_point!("batch_broadcast_send", injected_send_error),
+            Self::RangeShuffle(_) => {
+                fail_point!("batch_range_shuffle_send", injected_send_error)
+            }
+        }
         match self {
             Self::HashShuffle(sender) => sender.send(chunk).await,
             Self::ConsistentHashShuffle(sender) => sender.send(chunk).await,
             Self::Fifo(sender) => sender.send(chunk).await,
             Self::Broadcast(sender) => sender.send(chunk).await,
+            Self::RangeShuffle(sender) => sender.send(chunk).await,
         }
     }
 }
@@ -73,19 +91,46 @@ pub enum ChanReceiverImpl {
     ConsistentHashShuffle(ConsistentHashShuffleReceiver),
     Fifo(FifoReceiver),
     Broadcast(BroadcastReceiver),
+    RangeShuffle(RangeShuffleReceiver),
 }
 
 impl ChanReceiverImpl {
-    pub(super) async fn recv(&mut self) -> Result<Option<DataChunkInChannel>> {
+    pub async fn recv(&mut self) -> Result<Option<DataChunkInChannel>> {
+        match self {
+            Self::HashShuffle(_) => fail_point!("batch_hash_shuffle_recv", injected_recv_error),
+            Self::ConsistentHashShuffle(_) => {
+                fail_point!("batch_consistent_hash_shuffle_recv", injected_recv_error)
+            }
+            Self::Fifo(_) => fail_point!("batch_fifo_recv", injected_recv_error),
+            Self::Broadcast(_) => fail_point!("batch_broadcast_recv", injected_recv_error),
+            Self::RangeShuffle(_) => {
+                fail_point!("batch_range_shuffle_recv", injected_recv_error)
+            }
+        }
         match self {
             Self::HashShuffle(receiver) => receiver.recv().await,
             Self::ConsistentHashShuffle(receiver) => receiver.recv().await,
             Self::Broadcast(receiver) => receiver.recv().await,
             Self::Fifo(receiver) => receiver.recv().await,
+            Self::RangeShuffle(receiver) => receiver.recv().await,
         }
     }
 }
 
+// With the `failpoints` feature, the `batch_<kind>_send` and `batch_<kind>_recv` failpoints, where
+// `<kind>` is one of `fifo`, `broadcast`, `hash_shuffle`, `consistent_hash_shuffle` and
+// `range_shuffle`, return an error when configured with `return`. They can also be configured with
+// `sleep(ms)` to simulate a slow peer, or with `panic`.
+fn injected_send_error(_: Option<String>) -> BatchResult<()> {
+    Err(BatchError::Internal(anyhow!(
+        "injected channel send failure"
+    )))
+}
+
+fn injected_recv_error(_: Option<String>) -> Result<Option<DataChunkInChannel>> {
+    Err(BatchError::Internal(anyhow!("injected channel receive failure")).into())
+}
+
 /// Output-channel is a synchronous, bounded single-producer-multiple-consumer queue.
 /// The producer is the local task executor, the consumer is
 /// [`ExchangeService`](risingwave_pb::task_service::exchange_service_server::ExchangeService).
@@ -103,6 +148,9 @@ pub fn create_output_channel(
         ShuffleDistributionMode::Broadcast => {
             Ok(new_broadcast_channel(shuffle, output_channel_size))
         }
+        ShuffleDistributionMode::Range => {
+            Ok(new_range_shuffle_channel(shuffle, output_channel_size))
+        }
         ShuffleDistributionMode::Unspecified => unreachable!(),
     }
 }
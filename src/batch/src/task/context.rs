@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use risingwave_common::catalog::SysCatalogReaderRef;
 use risingwave_common::config::BatchConfig;
 use risingwave_common::error::Result;
@@ -22,7 +24,7 @@ use risingwave_storage::StateStoreImpl;
 
 use super::TaskId;
 use crate::executor::BatchTaskMetricsWithTaskLabels;
-use crate::task::{BatchEnvironment, TaskOutput, TaskOutputId};
+use crate::task::{BatchEnvironment, BatchResultCache, TaskOutput, TaskOutputId};
 
 /// Context for batch task execution.
 ///
@@ -53,6 +55,9 @@ pub trait BatchTaskContext: Clone + Send + Sync + 'static {
 
     /// Get config for batch environment
     fn get_config(&self) -> &BatchConfig;
+
+    /// Get the cache of task outputs. None indicates that the outputs are not cached.
+    fn result_cache(&self) -> Option<Arc<BatchResultCache>>;
 }
 
 /// Batch task context on compute node.
@@ -97,6 +102,10 @@ impl BatchTaskContext for ComputeNodeContext {
     fn get_config(&self) -> &BatchConfig {
         self.env.config()
     }
+
+    fn result_cache(&self) -> Option<Arc<BatchResultCache>> {
+        self.env.task_manager().result_cache()
+    }
 }
 
 impl ComputeNodeContext {
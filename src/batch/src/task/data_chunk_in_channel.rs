@@ -17,7 +17,7 @@ use risingwave_pb::data::DataChunk as ProstDataChunk;
 use tokio::sync::OnceCell;
 
 #[derive(Debug, Clone)]
-pub(super) struct DataChunkInChannel {
+pub struct DataChunkInChannel {
     data_chunk: DataChunk,
     /// If the data chunk is only needed to transfer locally,
     /// this field should not be initialized.
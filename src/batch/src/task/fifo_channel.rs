@@ -16,11 +16,10 @@ use std::fmt::{Debug, Formatter};
 use std::future::Future;
 
 use risingwave_common::array::DataChunk;
-use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
 use tokio::sync::mpsc;
 
-use crate::error::BatchError::SenderError;
+use crate::error::BatchError::{ChannelClosed, SenderError};
 use crate::error::Result as BatchResult;
 use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
 use crate::task::data_chunk_in_channel::DataChunkInChannel;
@@ -59,7 +58,7 @@ impl ChanReceiver for FifoReceiver {
             match self.receiver.recv().await {
                 Some(data_chunk) => Ok(data_chunk),
                 // Early close should be treated as error.
-                None => Err(InternalError("broken fifo_channel".to_string()).into()),
+                None => Err(ChannelClosed("fifo_channel").into()),
             }
         }
     }
@@ -19,14 +19,13 @@ use std::option::Option;
 
 use risingwave_common::array::DataChunk;
 use risingwave_common::buffer::Bitmap;
-use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
 use risingwave_common::util::hash_util::Crc32FastBuilder;
 use risingwave_pb::batch_plan::exchange_info::HashInfo;
 use risingwave_pb::batch_plan::*;
 use tokio::sync::mpsc;
 
-use crate::error::BatchError::SenderError;
+use crate::error::BatchError::{ChannelClosed, SenderError};
 use crate::error::Result as BatchResult;
 use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
 use crate::task::data_chunk_in_channel::DataChunkInChannel;
@@ -157,7 +156,7 @@ impl ChanReceiver for HashShuffleReceiver {
             match self.receiver.recv().await {
                 Some(data_chunk) => Ok(data_chunk),
                 // Early close should be treated as error.
-                None => Err(InternalError("broken hash_shuffle_channel".to_string()).into()),
+                None => Err(ChannelClosed("hash_shuffle_channel").into()),
             }
         }
     }
@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(any(test, feature = "test"))]
+pub use channel::{create_output_channel, ChanReceiverImpl, ChanSenderImpl};
 pub use context::*;
+#[cfg(any(test, feature = "test"))]
+pub use data_chunk_in_channel::DataChunkInChannel;
 pub use env::*;
+pub use resource_group::*;
+pub use result_cache::*;
 pub use task_execution::*;
 pub use task_manager::*;
 
@@ -25,5 +31,8 @@ mod data_chunk_in_channel;
 mod env;
 mod fifo_channel;
 mod hash_shuffle_channel;
+mod range_shuffle_channel;
+mod resource_group;
+mod result_cache;
 mod task_execution;
 mod task_manager;
@@ -0,0 +1,220 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::ops::BitAnd;
+
+use risingwave_common::array::DataChunk;
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::error::Result;
+use risingwave_common::util::encoding_for_comparison::encode_chunk;
+use risingwave_common::util::key_sampler::range_partition;
+use risingwave_common::util::sort_util::OrderPair;
+use risingwave_pb::batch_plan::exchange_info::RangeInfo;
+use risingwave_pb::batch_plan::*;
+use tokio::sync::mpsc;
+
+use crate::error::BatchError::{ChannelClosed, SenderError};
+use crate::error::Result as BatchResult;
+use crate::task::channel::{ChanReceiver, ChanReceiverImpl, ChanSender, ChanSenderImpl};
+use crate::task::data_chunk_in_channel::DataChunkInChannel;
+
+/// Partitions rows into ranges of the sort key, split by the boundaries given in the plan. Every
+/// sender of the exchange shares the same boundaries, so that each range ends up in one output.
+pub struct RangeShuffleSender {
+    senders: Vec<mpsc::Sender<Option<DataChunkInChannel>>>,
+    order_pairs: Vec<OrderPair>,
+    boundaries: Vec<Vec<u8>>,
+}
+
+impl Debug for RangeShuffleSender {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RangeShuffleSender")
+            .field("order_pairs", &self.order_pairs)
+            .field("boundaries", &self.boundaries)
+            .finish()
+    }
+}
+
+pub struct RangeShuffleReceiver {
+    receiver: mpsc::Receiver<Option<DataChunkInChannel>>,
+}
+
+impl ChanSender for RangeShuffleSender {
+    type SendFuture<'a> = impl Future<Output = BatchResult<()>> + 'a;
+
+    fn send(&mut self, chunk: Option<DataChunk>) -> Self::SendFuture<'_> {
+        async move {
+            match chunk {
+                Some(c) => self.send_chunk(c).await,
+                None => self.send_done().await,
+            }
+        }
+    }
+}
+
+impl RangeShuffleSender {
+    async fn send_chunk(&mut self, chunk: DataChunk) -> BatchResult<()> {
+        let partitions = encode_chunk(&chunk, &self.order_pairs)
+            .iter()
+            .map(|key| range_partition(&self.boundaries, key))
+            .collect::<Vec<_>>();
+
+        for (sink_id, sender) in self.senders.iter().enumerate() {
+            let vis_map: Bitmap = partitions
+                .iter()
+                .map(|&partition| partition == sink_id)
+                .collect();
+            let vis_map = if let Some(visibility) = chunk.get_visibility_ref() {
+                vis_map.bitand(visibility)
+            } else {
+                vis_map
+            };
+            let new_data_chunk = chunk.with_visibility(vis_map);
+            trace!(
+                "send to sink:{}, cardinality:{}",
+                sink_id,
+                new_data_chunk.cardinality()
+            );
+            if new_data_chunk.cardinality() > 0 {
+                sender
+                    .send(Some(DataChunkInChannel::new(new_data_chunk)))
+                    .await
+                    .map_err(|_| SenderError)?
+            }
+        }
+        Ok(())
+    }
+
+    async fn send_done(&mut self) -> BatchResult<()> {
+        for sender in &self.senders {
+            sender.send(None).await.map_err(|_| SenderError)?
+        }
+
+        Ok(())
+    }
+}
+
+impl ChanReceiver for RangeShuffleReceiver {
+    type RecvFuture<'a> = impl Future<Output = Result<Option<DataChunkInChannel>>> + 'a;
+
+    fn recv(&mut self) -> Self::RecvFuture<'_> {
+        async move {
+            match self.receiver.recv().await {
+                Some(data_chunk) => Ok(data_chunk),
+                // Early close should be treated as error.
+                None => Err(ChannelClosed("range_shuffle_channel").into()),
+            }
+        }
+    }
+}
+
+pub fn new_range_shuffle_channel(
+    shuffle: &ExchangeInfo,
+    output_channel_size: usize,
+) -> (ChanSenderImpl, Vec<ChanReceiverImpl>) {
+    let range_info = match shuffle.distribution {
+        Some(exchange_info::Distribution::RangeInfo(ref v)) => v.clone(),
+        _ => RangeInfo::default(),
+    };
+
+    let output_count = range_info.output_count as usize;
+    let mut senders = Vec::with_capacity(output_count);
+    let mut receivers = Vec::with_capacity(output_count);
+    for _ in 0..output_count {
+        let (s, r) = mpsc::channel(output_channel_size);
+        senders.push(s);
+        receivers.push(r);
+    }
+    let channel_sender = ChanSenderImpl::RangeShuffle(RangeShuffleSender {
+        senders,
+        order_pairs: range_info
+            .column_orders
+            .iter()
+            .map(OrderPair::from_prost)
+            .collect(),
+        boundaries: range_info.boundaries,
+    });
+    let channel_receivers = receivers
+        .into_iter()
+        .map(|receiver| ChanReceiverImpl::RangeShuffle(RangeShuffleReceiver { receiver }))
+        .collect::<Vec<_>>();
+    (channel_sender, channel_receivers)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, DataChunk, I32Array};
+    use risingwave_common::row::Row;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_common::util::encoding_for_comparison::encode_row;
+    use risingwave_common::util::sort_util::{OrderPair, OrderType};
+    use risingwave_pb::batch_plan::exchange_info::{Distribution, DistributionMode, RangeInfo};
+    use risingwave_pb::batch_plan::ExchangeInfo;
+
+    use super::new_range_shuffle_channel;
+
+    #[tokio::test]
+    async fn test_range_shuffle() {
+        let order_pairs = vec![OrderPair::new(0, OrderType::Ascending)];
+        let shuffle = ExchangeInfo {
+            mode: DistributionMode::Range as i32,
+            distribution: Some(Distribution::RangeInfo(RangeInfo {
+                output_count: 4,
+                column_orders: order_pairs.iter().map(OrderPair::to_protobuf).collect(),
+                boundaries: [250, 500, 750]
+                    .into_iter()
+                    .map(|v| encode_row(&Row(vec![Some(ScalarImpl::Int32(v))]), &order_pairs))
+                    .collect(),
+            })),
+            ..Default::default()
+        };
+        let (mut sender, mut receivers) = new_range_shuffle_channel(&shuffle, 64);
+
+        for i in 0..10 {
+            let values = (0..100).map(|j| Some(j * 10 + i)).collect::<Vec<_>>();
+            let array = I32Array::from_slice(&values);
+            let chunk = DataChunk::new(vec![array.into()], 100);
+            sender.send(Some(chunk)).await.unwrap();
+        }
+        sender.send(None).await.unwrap();
+
+        let mut ranges = vec![];
+        for receiver in &mut receivers {
+            let mut values = vec![];
+            while let Some(chunk) = receiver.recv().await.unwrap() {
+                let chunk = chunk.into_data_chunk().compact();
+                let array = chunk.column_at(0).array();
+                values.extend(array.as_int32().iter().map(|v| v.unwrap()));
+            }
+            assert!(!values.is_empty());
+            ranges.push((
+                *values.iter().min().unwrap(),
+                *values.iter().max().unwrap(),
+                values.len(),
+            ));
+        }
+
+        assert_eq!(
+            ranges,
+            vec![
+                (0, 249, 250),
+                (250, 499, 250),
+                (500, 749, 250),
+                (750, 999, 250)
+            ]
+        );
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use global_stats_alloc::{task_local_alloc_installed, TaskLocalBytesAllocated};
+use parking_lot::Mutex;
+use risingwave_common::config::ResourceGroupConfig;
+use risingwave_common::error::{ErrorCode, Result};
+use tokio::sync::{OnceCell, OwnedSemaphorePermit, Semaphore};
+
+/// Admits the batch queries tagged with a resource group, so that the queries of one group can not
+/// take the compute node from the others.
+///
+/// Admission is per query, not per task. The first task of a query on the compute node takes a
+/// slot of the group, and the following tasks of the query share it until the last one
+/// terminates. A task blocked on its output channel therefore never holds a slot that its parent
+/// task is waiting for.
+///
+/// A group runs at most `max_concurrent_queries` queries at a time. Further queries wait for a
+/// running one to terminate, and are rejected if `max_queued_queries` queries are already waiting,
+/// if they wait longer than `queue_timeout_ms`, or if the running queries of the group hold more
+/// than its `memory_share`.
+///
+/// The memory share is only checked when a query is admitted, and running queries are not stopped
+/// when they grow past it. It relies on the memory counted by the task-local allocator, so it is
+/// not enforced, with a warning, when the compute node is built without the `task-local-alloc`
+/// feature.
+pub struct AdmissionController {
+    groups: HashMap<String, ResourceGroup>,
+}
+
+struct ResourceGroup {
+    config: ResourceGroupConfig,
+    running: Arc<Semaphore>,
+    queued: AtomicUsize,
+    /// The memory that the running queries of the group may hold, from `memory_share`.
+    memory_limit: Option<usize>,
+    /// Whether it has been warned that `memory_limit` is not enforced.
+    memory_limit_warned: AtomicBool,
+    /// The queries with a live task on this compute node, by query id.
+    queries: Mutex<HashMap<String, Weak<QueryAdmission>>>,
+}
+
+/// The admission of a query, shared by its tasks.
+#[derive(Default)]
+struct QueryAdmission {
+    /// The slot of the query in the group, taken by the first task of the query.
+    permit: OnceCell<OwnedSemaphorePermit>,
+    /// The memory of the tasks of the query.
+    memory_usage: Mutex<Vec<TaskLocalBytesAllocated>>,
+}
+
+/// Held by an admitted task until it terminates. The slot of the query in the resource group is
+/// released once all of its tasks terminate.
+pub struct AdmissionPermit {
+    query: Arc<QueryAdmission>,
+}
+
+impl AdmissionPermit {
+    /// Counts the memory of a task against the memory share of the group.
+    pub fn add_memory_usage(&self, memory_usage: TaskLocalBytesAllocated) {
+        self.query.memory_usage.lock().push(memory_usage);
+    }
+}
+
+/// Counts a query as queued in its resource group while it is alive, including when the
+/// admission is cancelled.
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl AdmissionController {
+    pub fn new(
+        configs: &HashMap<String, ResourceGroupConfig>,
+        total_memory_available_bytes: usize,
+    ) -> Self {
+        let groups = configs
+            .iter()
+            .map(|(name, config)| {
+                let group = ResourceGroup {
+                    config: config.clone(),
+                    running: Arc::new(Semaphore::new(config.max_concurrent_queries)),
+                    queued: AtomicUsize::new(0),
+                    memory_limit: config
+                        .memory_share
+                        .map(|share| (total_memory_available_bytes as f64 * share) as usize),
+                    memory_limit_warned: AtomicBool::new(false),
+                    queries: Mutex::new(HashMap::new()),
+                };
+                (name.clone(), group)
+            })
+            .collect();
+        Self { groups }
+    }
+
+    /// Waits until the tasks of query `query_id` in `group` are allowed to run. Tasks without a
+    /// group, i.e. `group` is empty, are admitted at once without a permit.
+    pub async fn admit(&self, group: &str, query_id: &str) -> Result<Option<AdmissionPermit>> {
+        if group.is_empty() {
+            return Ok(None);
+        }
+        let resource_group = self.groups.get(group).ok_or_else(|| {
+            ErrorCode::InternalError(format!("resource group \"{}\" does not exist", group))
+        })?;
+
+        let query = {
+            let mut queries = resource_group.queries.lock();
+            match queries.get(query_id).and_then(Weak::upgrade) {
+                Some(query) => query,
+                None => {
+                    queries.retain(|_, query| query.strong_count() > 0);
+                    let query = Arc::new(QueryAdmission::default());
+                    queries.insert(query_id.to_string(), Arc::downgrade(&query));
+                    query
+                }
+            }
+        };
+        // Concurrent tasks of the query wait for the same slot.
+        query
+            .permit
+            .get_or_try_init(|| resource_group.acquire(group))
+            .await?;
+        Ok(Some(AdmissionPermit { query }))
+    }
+}
+
+impl ResourceGroup {
+    /// Returns the memory held by the running queries of the group.
+    fn memory_usage(&self) -> usize {
+        self.queries
+            .lock()
+            .values()
+            .filter_map(Weak::upgrade)
+            .map(|query| {
+                query
+                    .memory_usage
+                    .lock()
+                    .iter()
+                    .map(TaskLocalBytesAllocated::val)
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+
+    /// Takes a slot for a new query of the group.
+    async fn acquire(&self, group: &str) -> Result<OwnedSemaphorePermit> {
+        if let Some(memory_limit) = self.memory_limit {
+            if task_local_alloc_installed() {
+                let memory_usage = self.memory_usage();
+                if memory_usage >= memory_limit {
+                    return Err(ErrorCode::InternalError(format!(
+                        "resource group \"{}\" holds {} bytes, exceeding its memory share of {} \
+                         bytes",
+                        group, memory_usage, memory_limit
+                    ))
+                    .into());
+                }
+            } else if !self.memory_limit_warned.swap(true, Ordering::Relaxed) {
+                tracing::warn!(
+                    "the memory share of resource group \"{}\" is not enforced, as the memory of \
+                     batch tasks is only counted with the task-local-alloc feature",
+                    group
+                );
+            }
+        }
+
+        if let Ok(permit) = self.running.clone().try_acquire_owned() {
+            return Ok(permit);
+        }
+
+        let queued = self.queued.fetch_add(1, Ordering::Relaxed);
+        let _guard = QueuedGuard(&self.queued);
+        if queued >= self.config.max_queued_queries {
+            return Err(ErrorCode::InternalError(format!(
+                "resource group \"{}\" is full with {} running and {} queued queries",
+                group, self.config.max_concurrent_queries, queued
+            ))
+            .into());
+        }
+        let timeout = Duration::from_millis(self.config.queue_timeout_ms);
+        match tokio::time::timeout(timeout, self.running.clone().acquire_owned()).await {
+            // The semaphore is never closed.
+            Ok(permit) => Ok(permit.unwrap()),
+            Err(_) => Err(ErrorCode::InternalError(format!(
+                "resource group \"{}\" did not admit the query within {} ms",
+                group, self.config.queue_timeout_ms
+            ))
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    use futures::FutureExt;
+    use global_stats_alloc::{allocation_stat, TaskLocalAlloc};
+
+    use super::*;
+
+    fn controller(max_concurrent_queries: usize, max_queued_queries: usize) -> AdmissionController {
+        let config = ResourceGroupConfig {
+            max_concurrent_queries,
+            max_queued_queries,
+            queue_timeout_ms: 60_000,
+            memory_share: None,
+        };
+        AdmissionController::new(&HashMap::from([("etl".to_string(), config)]), 0)
+    }
+
+    #[tokio::test]
+    async fn test_admit_without_group() {
+        let controller = controller(0, 0);
+        assert!(controller.admit("", "q1").await.unwrap().is_none());
+
+        let err = controller.admit("adhoc", "q1").await.err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("resource group \"adhoc\" does not exist"));
+    }
+
+    #[tokio::test]
+    async fn test_admit_queue_and_reject() {
+        let controller = controller(1, 1);
+        let running = controller.admit("etl", "q1").await.unwrap().unwrap();
+
+        // The second query waits for the running one.
+        let mut queued = controller.admit("etl", "q2").boxed();
+        assert!((&mut queued).now_or_never().is_none());
+
+        // The third query is rejected as the queue is full.
+        let err = controller.admit("etl", "q3").await.err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("resource group \"etl\" is full with 1 running and 1 queued queries"));
+
+        drop(running);
+        let admitted = tokio::time::timeout(Duration::from_secs(1), queued)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(admitted.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_admit_tasks_of_admitted_query() {
+        let controller = controller(1, 1);
+        let child = controller.admit("etl", "q1").await.unwrap().unwrap();
+
+        // The parent task of a running query shares its slot instead of waiting for it.
+        let parent = controller.admit("etl", "q1").now_or_never().unwrap();
+        let parent = parent.unwrap().unwrap();
+
+        // The slot is released once the last task of the query terminates.
+        drop(child);
+        assert!(controller.admit("etl", "q2").now_or_never().is_none());
+        drop(parent);
+        let other = controller.admit("etl", "q2").now_or_never().unwrap();
+        assert!(other.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_queued() {
+        let controller = controller(1, 1);
+        let _running = controller.admit("etl", "q1").await.unwrap().unwrap();
+
+        let mut queued = controller.admit("etl", "q2").boxed();
+        assert!((&mut queued).now_or_never().is_none());
+        drop(queued);
+
+        // The cancelled query has left the queue.
+        let mut queued = controller.admit("etl", "q3").boxed();
+        assert!((&mut queued).now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_queue_timeout() {
+        let config = ResourceGroupConfig {
+            max_concurrent_queries: 1,
+            max_queued_queries: 1,
+            queue_timeout_ms: 10,
+            memory_share: None,
+        };
+        let controller = AdmissionController::new(&HashMap::from([("etl".to_string(), config)]), 0);
+        let _running = controller.admit("etl", "q1").await.unwrap().unwrap();
+
+        let err = controller.admit("etl", "q2").await.err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("resource group \"etl\" did not admit the query within 10 ms"));
+    }
+
+    #[tokio::test]
+    async fn test_memory_share() {
+        static ALLOC: TaskLocalAlloc<System> = TaskLocalAlloc::new(System);
+
+        let config = ResourceGroupConfig {
+            max_concurrent_queries: 2,
+            max_queued_queries: 0,
+            queue_timeout_ms: 60_000,
+            memory_share: Some(0.5),
+        };
+        let controller =
+            AdmissionController::new(&HashMap::from([("etl".to_string(), config)]), 200);
+
+        let running = controller.admit("etl", "q1").await.unwrap().unwrap();
+        let memory_usage = TaskLocalBytesAllocated::new();
+        running.add_memory_usage(memory_usage.clone());
+        let layout = Layout::from_size_align(100, 8).unwrap();
+        let ptr = allocation_stat(async { unsafe { ALLOC.alloc(layout) } }, memory_usage).await;
+
+        // The running query holds the whole share of the group.
+        let err = controller.admit("etl", "q2").await.err().unwrap();
+        assert!(err.to_string().contains(
+            "resource group \"etl\" holds 100 bytes, exceeding its memory share of 100 bytes"
+        ));
+
+        unsafe { ALLOC.dealloc(ptr, layout) };
+        assert!(controller.admit("etl", "q2").await.unwrap().is_some());
+    }
+}
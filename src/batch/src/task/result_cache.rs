@@ -0,0 +1,287 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use prost::Message;
+use risingwave_common::array::DataChunk;
+use risingwave_common::collection::estimate_size::EstimateSize;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::PlanNode;
+
+/// Caches the output of read-only plan fragments on a compute node.
+///
+/// An entry is keyed by the fingerprint of the fragment, i.e. its encoded plan, and the committed
+/// epoch of the snapshot it was computed at, so the results of different epochs can be cached
+/// side by side. Entries are evicted in LRU order once the estimated size of the cached chunks
+/// exceeds the capacity, which also ages out the results of the epochs no longer read.
+pub struct BatchResultCache {
+    /// The capacity in bytes.
+    capacity: usize,
+    inner: Mutex<ResultCacheInner>,
+}
+
+/// The fingerprint of a fragment and the epoch it was executed at.
+type ResultCacheKey = (Vec<u8>, u64);
+
+#[derive(Default)]
+struct ResultCacheInner {
+    entries: HashMap<ResultCacheKey, ResultCacheEntry>,
+    /// The total charge of the cached entries.
+    usage: usize,
+    /// Incremented on every access to order the entries by recency.
+    access_seq: u64,
+}
+
+struct ResultCacheEntry {
+    chunks: Arc<Vec<DataChunk>>,
+    charge: usize,
+    last_access: u64,
+}
+
+impl ResultCacheInner {
+    fn next_access(&mut self) -> u64 {
+        self.access_seq += 1;
+        self.access_seq
+    }
+}
+
+impl BatchResultCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(ResultCacheInner::default()),
+        }
+    }
+
+    /// Returns the fingerprint of `plan` to look up the cache with, or `None` if the output of
+    /// `plan` does not only depend on the snapshot it reads, e.g. it modifies tables or consumes
+    /// the output of other tasks.
+    pub fn fingerprint(plan: &PlanNode) -> Option<Vec<u8>> {
+        if Self::is_cacheable(plan) {
+            Some(plan.encode_to_vec())
+        } else {
+            None
+        }
+    }
+
+    fn is_cacheable(plan: &PlanNode) -> bool {
+        let read_only = matches!(
+            plan.node_body,
+            Some(
+                NodeBody::Project(_)
+                    | NodeBody::HashAgg(_)
+                    | NodeBody::Filter(_)
+                    | NodeBody::Sort(_)
+                    | NodeBody::NestedLoopJoin(_)
+                    | NodeBody::TopN(_)
+                    | NodeBody::SortAgg(_)
+                    | NodeBody::RowSeqScan(_)
+                    | NodeBody::Limit(_)
+                    | NodeBody::Values(_)
+                    | NodeBody::HashJoin(_)
+                    | NodeBody::SortMergeJoin(_)
+                    | NodeBody::HopWindow(_)
+                    | NodeBody::TableFunction(_)
+                    | NodeBody::Expand(_)
+                    | NodeBody::ProjectSet(_)
+                    | NodeBody::Union(_)
+                    | NodeBody::GroupTopN(_)
+            )
+        );
+        read_only && plan.children.iter().all(Self::is_cacheable)
+    }
+
+    /// Returns the cached output of the fragment with `fingerprint` at `epoch`.
+    pub fn get(&self, fingerprint: &[u8], epoch: u64) -> Option<Arc<Vec<DataChunk>>> {
+        let mut inner = self.inner.lock();
+        let access = inner.next_access();
+        let entry = inner.entries.get_mut(&(fingerprint.to_vec(), epoch))?;
+        entry.last_access = access;
+        Some(entry.chunks.clone())
+    }
+
+    /// Caches `chunks` as the output of the fragment with `fingerprint` at `epoch`.
+    fn insert(&self, fingerprint: Vec<u8>, epoch: u64, chunks: Vec<DataChunk>, charge: usize) {
+        if charge > self.capacity {
+            return;
+        }
+        let key = (fingerprint, epoch);
+        let mut inner = self.inner.lock();
+        if let Some(old) = inner.entries.remove(&key) {
+            inner.usage -= old.charge;
+        }
+        while inner.usage + charge > self.capacity {
+            let lru = inner
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_access)
+                .map(|(key, _)| key.clone())
+                .unwrap();
+            let evicted = inner.entries.remove(&lru).unwrap();
+            inner.usage -= evicted.charge;
+        }
+        let last_access = inner.next_access();
+        inner.usage += charge;
+        inner.entries.insert(
+            key,
+            ResultCacheEntry {
+                chunks: Arc::new(chunks),
+                charge,
+                last_access,
+            },
+        );
+    }
+
+    /// Returns a spool to collect the output of the fragment with `fingerprint` at `epoch`.
+    pub fn spool(self: &Arc<Self>, fingerprint: Vec<u8>, epoch: u64) -> ResultSpool {
+        ResultSpool {
+            cache: self.clone(),
+            fingerprint,
+            epoch,
+            chunks: Some(vec![]),
+            charge: 0,
+        }
+    }
+
+    /// Returns the estimated size in bytes of the cached entries.
+    pub fn usage(&self) -> usize {
+        self.inner.lock().usage
+    }
+}
+
+/// Collects the output chunks of a task, and caches them in the [`BatchResultCache`] on
+/// [`ResultSpool::finish`]. Spooling stops once the output is larger than the capacity of the
+/// cache.
+pub struct ResultSpool {
+    cache: Arc<BatchResultCache>,
+    fingerprint: Vec<u8>,
+    epoch: u64,
+    /// `None` if the output has outgrown the cache.
+    chunks: Option<Vec<DataChunk>>,
+    charge: usize,
+}
+
+impl ResultSpool {
+    pub fn push(&mut self, chunk: &DataChunk) {
+        if let Some(chunks) = &mut self.chunks {
+            let chunk = chunk.clone().compact();
+            self.charge += chunk.estimated_size();
+            if self.charge > self.cache.capacity {
+                self.chunks = None;
+            } else {
+                chunks.push(chunk);
+            }
+        }
+    }
+
+    /// Caches the collected output. Must only be called once the task has produced all of it.
+    pub fn finish(self) {
+        if let Some(chunks) = self.chunks {
+            self.cache
+                .insert(self.fingerprint, self.epoch, chunks, self.charge);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::DataChunkTestExt;
+    use risingwave_pb::batch_plan::{InsertNode, ValuesNode};
+
+    use super::*;
+
+    fn values_plan(children: Vec<PlanNode>) -> PlanNode {
+        PlanNode {
+            children,
+            identity: "Values".to_string(),
+            node_body: Some(NodeBody::Values(ValuesNode::default())),
+        }
+    }
+
+    fn spool_chunk(cache: &Arc<BatchResultCache>, fingerprint: &[u8], epoch: u64, chunk: &str) {
+        let mut spool = cache.spool(fingerprint.to_vec(), epoch);
+        spool.push(&DataChunk::from_pretty(chunk));
+        spool.finish();
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let values = values_plan(vec![]);
+        assert_eq!(
+            BatchResultCache::fingerprint(&values),
+            Some(values.encode_to_vec())
+        );
+
+        let insert = PlanNode {
+            children: vec![values.clone()],
+            identity: "Insert".to_string(),
+            node_body: Some(NodeBody::Insert(InsertNode::default())),
+        };
+        assert_eq!(BatchResultCache::fingerprint(&insert), None);
+        assert_eq!(
+            BatchResultCache::fingerprint(&values_plan(vec![insert])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_at_epoch() {
+        let cache = Arc::new(BatchResultCache::new(1 << 20));
+        spool_chunk(&cache, b"a", 2, "i\n 1\n 2");
+        assert_eq!(cache.get(b"a", 2).unwrap().len(), 1);
+        assert!(cache.get(b"b", 2).is_none());
+
+        // Results are only served at the epoch they were computed at.
+        assert!(cache.get(b"a", 1).is_none());
+        assert!(cache.get(b"a", 3).is_none());
+
+        // A newer epoch does not invalidate the results of the older ones.
+        spool_chunk(&cache, b"a", 3, "i\n 1");
+        spool_chunk(&cache, b"b", 1, "i\n 1");
+        assert_eq!(cache.get(b"a", 2).unwrap()[0].cardinality(), 2);
+        assert_eq!(cache.get(b"a", 3).unwrap()[0].cardinality(), 1);
+        assert!(cache.get(b"b", 1).is_some());
+        assert!(cache.get(b"b", 2).is_none());
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let chunk = "i\n 1\n 2\n 3";
+        let mut spool = Arc::new(BatchResultCache::new(usize::MAX)).spool(vec![], 1);
+        spool.push(&DataChunk::from_pretty(chunk));
+        let charge = spool.charge;
+
+        let cache = Arc::new(BatchResultCache::new(charge * 2));
+        spool_chunk(&cache, b"a", 1, chunk);
+        spool_chunk(&cache, b"b", 1, chunk);
+        assert!(cache.get(b"a", 1).is_some());
+        spool_chunk(&cache, b"c", 1, chunk);
+        assert!(cache.get(b"a", 1).is_some());
+        assert!(cache.get(b"b", 1).is_none());
+        assert!(cache.get(b"c", 1).is_some());
+        assert_eq!(cache.usage(), charge * 2);
+
+        // Outputs larger than the capacity are not cached.
+        let mut spool = cache.spool(b"d".to_vec(), 1);
+        for _ in 0..3 {
+            spool.push(&DataChunk::from_pretty(chunk));
+        }
+        spool.finish();
+        assert!(cache.get(b"d", 1).is_none());
+        assert!(cache.get(b"a", 1).is_some());
+    }
+}
@@ -12,15 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+use anyhow::anyhow;
+use fail::fail_point;
 use futures::StreamExt;
+use global_stats_alloc::{allocation_stat, TaskLocalBytesAllocated};
 use minitrace::prelude::*;
 use parking_lot::Mutex;
 use risingwave_common::array::DataChunk;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::util::chunk_coalesce::split_chunk_by_target_bytes;
+use risingwave_pb::batch_plan::exchange_info::ExchangeMode;
 use risingwave_pb::batch_plan::{
     PlanFragment, TaskId as ProstTaskId, TaskOutputId as ProstOutputId,
 };
@@ -33,11 +40,12 @@ use tonic::Status;
 
 use crate::error::BatchError::SenderError;
 use crate::error::{BatchError, Result as BatchResult};
-use crate::executor::{BoxedExecutor, ExecutorBuilder};
+use crate::executor::{validate_plan_fragment, BoxedDataChunkStream, ExecutorBuilder};
 use crate::rpc::service::exchange::ExchangeWriter;
 use crate::rpc::service::task_service::TaskInfoResponseResult;
 use crate::task::channel::{create_output_channel, ChanReceiverImpl, ChanSenderImpl};
-use crate::task::BatchTaskContext;
+use crate::task::data_chunk_in_channel::DataChunkInChannel;
+use crate::task::{AdmissionPermit, BatchResultCache, BatchTaskContext, ResultSpool};
 
 // Now we will only at most have 2 status for each status channel. Running -> Failed or Finished.
 const TASK_STATUS_BUFFER_SIZE: usize = 2;
@@ -105,13 +113,73 @@ impl TaskOutputId {
     }
 }
 
+/// The chunks taken from a task output with a cursor but not yet acknowledged by the consumer.
+#[derive(Default)]
+struct RetainedChunks {
+    /// The cursor of the first chunk in `chunks`.
+    cursor: u64,
+    chunks: VecDeque<DataChunkInChannel>,
+    /// The number of chunks to keep after they are acknowledged, so that the outputs of
+    /// [`ExchangeMode::Blocking`] exchanges can be fetched again from the beginning. Once more
+    /// chunks are retained, the acknowledged ones are released as usual.
+    max_retained: usize,
+    /// Whether the end of the output has been received.
+    finished: bool,
+}
+
+impl RetainedChunks {
+    /// The cursor right after the last chunk taken from the output.
+    fn end_cursor(&self) -> u64 {
+        self.cursor + self.chunks.len() as u64
+    }
+}
+
 pub struct TaskOutput {
     receiver: ChanReceiverImpl,
     output_id: TaskOutputId,
     failure: Arc<Mutex<Option<RwError>>>,
+    retained: RetainedChunks,
 }
 
 impl TaskOutput {
+    async fn write_chunk(
+        output_id: &TaskOutputId,
+        writer: &mut dyn ExchangeWriter,
+        chunk: &DataChunkInChannel,
+    ) -> Result<()> {
+        trace!(
+            "Task output id: {:?}, data len: {:?}",
+            output_id,
+            chunk.cardinality()
+        );
+        fail_point!("batch_task_output_serialize", |_| Err(
+            BatchError::Internal(anyhow!("injected serialization failure")).into()
+        ));
+        let pb = chunk.to_protobuf().await;
+        let resp = GetDataResponse {
+            status: Default::default(),
+            record_batch: Some(pb),
+        };
+        writer.write(resp).await
+    }
+
+    /// Receives the next chunk, or `None` at the end of the output.
+    async fn recv(&mut self) -> Result<Option<DataChunkInChannel>> {
+        match self.receiver.recv().await {
+            Ok(chunk) => Ok(chunk),
+            Err(e) => {
+                let possible_err = self.failure.lock().take();
+                if let Some(err) = possible_err {
+                    // Task error
+                    Err(err)
+                } else {
+                    // Channel error
+                    Err(e)
+                }
+            }
+        }
+    }
+
     /// Write the data in serialized format to `ExchangeWriter`.
     /// Return whether the data stream is finished.
     async fn take_data_inner(
@@ -126,36 +194,11 @@ impl TaskOutput {
             if limited && cnt >= at_most_num {
                 return Ok(false);
             }
-            match self.receiver.recv().await {
+            match self.recv().await? {
                 // Received some data
-                Ok(Some(chunk)) => {
-                    trace!(
-                        "Task output id: {:?}, data len: {:?}",
-                        self.output_id,
-                        chunk.cardinality()
-                    );
-                    let pb = chunk.to_protobuf().await;
-                    let resp = GetDataResponse {
-                        status: Default::default(),
-                        record_batch: Some(pb),
-                    };
-                    writer.write(resp).await?;
-                }
+                Some(chunk) => Self::write_chunk(&self.output_id, writer, &chunk).await?,
                 // Reached EOF
-                Ok(None) => {
-                    break;
-                }
-                // Error happened
-                Err(e) => {
-                    let possible_err = self.failure.lock().take();
-                    return if let Some(err) = possible_err {
-                        // Task error
-                        Err(err)
-                    } else {
-                        // Channel error
-                        Err(e)
-                    };
-                }
+                None => break,
             }
             cnt += 1;
         }
@@ -172,6 +215,58 @@ impl TaskOutput {
         self.take_data_inner(writer, Some(num)).await
     }
 
+    /// Acknowledges the chunks before `cursor`, so that they are no longer retained, and then takes
+    /// at most `num` chunks starting from `cursor` and writes them in serialized format to
+    /// `ExchangeWriter`. The written chunks are retained until a later call acknowledges them, so
+    /// that a fetch failed midway can be retried from the same cursor.
+    /// Return whether the data stream is finished.
+    pub async fn take_data_from_cursor(
+        &mut self,
+        writer: &mut dyn ExchangeWriter,
+        cursor: u64,
+        num: usize,
+    ) -> Result<bool> {
+        if cursor < self.retained.cursor || cursor > self.retained.end_cursor() {
+            return Err(ErrorCode::InternalError(format!(
+                "cursor {} of {:?} is out of the retained chunks [{}, {}]",
+                cursor,
+                self.output_id,
+                self.retained.cursor,
+                self.retained.end_cursor(),
+            ))
+            .into());
+        }
+        while self.retained.cursor < cursor
+            && self.retained.chunks.len() > self.retained.max_retained
+        {
+            self.retained.chunks.pop_front();
+            self.retained.cursor += 1;
+        }
+
+        let skipped = (cursor - self.retained.cursor) as usize;
+        for chunk in self.retained.chunks.iter().skip(skipped).take(num) {
+            Self::write_chunk(&self.output_id, writer, chunk).await?;
+        }
+        for _ in self.retained.chunks.len() - skipped..num {
+            if self.retained.finished {
+                return Ok(true);
+            }
+            match self.recv().await? {
+                Some(chunk) => {
+                    // Retain the chunk before writing it, in case the writing fails.
+                    self.retained.chunks.push_back(chunk);
+                    let chunk = self.retained.chunks.back().unwrap();
+                    Self::write_chunk(&self.output_id, writer, chunk).await?;
+                }
+                None => {
+                    self.retained.finished = true;
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
     /// Take all data and write the data in serialized format to `ExchangeWriter`.
     pub async fn take_data(&mut self, writer: &mut dyn ExchangeWriter) -> Result<()> {
         let finish = self.take_data_inner(writer, None).await?;
@@ -181,12 +276,19 @@ impl TaskOutput {
 
     /// Directly takes data without serialization.
     pub async fn direct_take_data(&mut self) -> Result<Option<DataChunk>> {
-        Ok(self.receiver.recv().await?.map(|c| c.into_data_chunk()))
+        let chunk = self.receiver.recv().await?.map(|c| c.into_data_chunk());
+        Ok(chunk)
     }
 
     pub fn id(&self) -> &TaskOutputId {
         &self.output_id
     }
+
+    /// Returns whether the acknowledged chunks of the output may be retained for re-reads, even
+    /// after it's finished.
+    pub fn retains_acknowledged(&self) -> bool {
+        self.retained.max_retained > 0
+    }
 }
 
 /// `BatchTaskExecution` represents a single task execution.
@@ -200,8 +302,12 @@ pub struct BatchTaskExecution<C> {
     /// Task state.
     state: Mutex<TaskStatus>,
 
-    /// Receivers data of the task.
-    receivers: Mutex<Vec<Option<ChanReceiverImpl>>>,
+    /// Receivers data of the task, along with the chunks retained for each of them.
+    receivers: Mutex<Vec<Option<(ChanReceiverImpl, RetainedChunks)>>>,
+
+    /// Set once the task is aborted, after which the outputs are dropped along with their
+    /// retained chunks.
+    outputs_released: AtomicBool,
 
     /// Context for task execution
     context: C,
@@ -220,6 +326,13 @@ pub struct BatchTaskExecution<C> {
 
     /// Runtime for the batch tasks.
     runtime: &'static Runtime,
+
+    /// The admission of the query of the task to its resource group, released once the execution
+    /// terminates.
+    admission_permit: Mutex<Option<AdmissionPermit>>,
+
+    /// The heap memory allocated by the execution and not freed yet.
+    memory_usage: TaskLocalBytesAllocated,
 }
 
 impl<C: BatchTaskContext> BatchTaskExecution<C> {
@@ -236,15 +349,28 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
             plan,
             state: Mutex::new(TaskStatus::Pending),
             receivers: Mutex::new(Vec::new()),
+            outputs_released: AtomicBool::new(false),
             failure: Arc::new(Mutex::new(None)),
             epoch,
             shutdown_tx: Mutex::new(None),
             state_rx: Mutex::new(None),
             context,
             runtime,
+            admission_permit: Mutex::new(None),
+            memory_usage: TaskLocalBytesAllocated::new(),
         })
     }
 
+    pub fn set_admission_permit(&self, admission_permit: AdmissionPermit) {
+        *self.admission_permit.lock() = Some(admission_permit);
+    }
+
+    /// Returns the counter of the heap memory allocated by the execution and its executors. This
+    /// includes the output chunks that have not been taken.
+    pub fn memory_usage(&self) -> TaskLocalBytesAllocated {
+        self.memory_usage.clone()
+    }
+
     pub fn get_task_id(&self) -> &TaskId {
         &self.task_id
     }
@@ -262,28 +388,56 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
             serde_json::to_string_pretty(self.plan.get_root()?).unwrap()
         );
 
-        let exec = ExecutorBuilder::new(
-            self.plan.root.as_ref().unwrap(),
-            &self.task_id,
-            self.context.clone(),
-            self.epoch,
-        )
-        .build()
-        .await?;
+        validate_plan_fragment(&self.plan)?;
+        let root = self.plan.root.as_ref().unwrap();
+
+        // Read-only fragments executed at the same epoch before are served from the result cache.
+        let result_cache = self.context.result_cache().and_then(|cache| {
+            let fingerprint = BatchResultCache::fingerprint(root)?;
+            Some((cache, fingerprint))
+        });
+        let cached = result_cache
+            .as_ref()
+            .and_then(|(cache, fingerprint)| cache.get(fingerprint, self.epoch));
+        let (data_chunk_stream, spool): (BoxedDataChunkStream, _) = match cached {
+            Some(chunks) => {
+                trace!("Serve plan [{:?}] from the result cache", self.task_id);
+                let chunks = chunks.iter().cloned().map(Ok).collect::<Vec<_>>();
+                (futures::stream::iter(chunks).boxed(), None)
+            }
+            None => {
+                let exec =
+                    ExecutorBuilder::new(root, &self.task_id, self.context.clone(), self.epoch)
+                        .build()
+                        .await?;
+                let spool =
+                    result_cache.map(|(cache, fingerprint)| cache.spool(fingerprint, self.epoch));
+                (exec.execute(), spool)
+            }
+        };
 
         // Init shutdown channel and data receivers.
-        let (sender, receivers) = create_output_channel(
-            self.plan.get_exchange_info()?,
-            self.context
-                .get_config()
-                .developer
-                .batch_output_channel_size,
-        )?;
+        let exchange_info = self.plan.get_exchange_info()?;
+        let developer_config = &self.context.get_config().developer;
+        let (output_channel_size, max_retained) = match exchange_info.get_exchange_mode()? {
+            ExchangeMode::Pipelined => (developer_config.batch_output_channel_size, 0),
+            ExchangeMode::Blocking => {
+                let buffer_size = developer_config.batch_blocking_output_buffer_size;
+                (buffer_size, buffer_size)
+            }
+        };
+        let (sender, receivers) = create_output_channel(exchange_info, output_channel_size)?;
         let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<u64>();
         *self.shutdown_tx.lock() = Some(shutdown_tx);
         self.receivers
             .lock()
-            .extend(receivers.into_iter().map(Some));
+            .extend(receivers.into_iter().map(|receiver| {
+                let retained = RetainedChunks {
+                    max_retained,
+                    ..Default::default()
+                };
+                Some((receiver, retained))
+            }));
         let failure = self.failure.clone();
         let task_id = self.task_id.clone();
 
@@ -298,8 +452,11 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         // Clone `self` to make compiler happy because of the move block.
         let t_1 = self.clone();
         let t_2 = self.clone();
+        let admission_permit = self.admission_permit.lock().take();
         // Spawn task for real execution.
         self.runtime.spawn(async move {
+            // Hold the permit until the execution terminates.
+            let _admission_permit = admission_permit;
             trace!("Executing plan [{:?}]", task_id);
             let mut sender = sender;
             let mut state_tx = state_tx;
@@ -309,7 +466,13 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
                 // We should only pass a reference of sender to execution because we should only
                 // close it after task error has been set.
                 if let Err(e) = t_1
-                    .try_execute(exec, &mut sender, shutdown_rx, &mut state_tx)
+                    .try_execute(
+                        data_chunk_stream,
+                        spool,
+                        &mut sender,
+                        shutdown_rx,
+                        &mut state_tx,
+                    )
                     .in_span({
                         let mut span = Span::enter_with_local_parent("batch_execute");
                         span.add_property(|| ("task_id", task_id.task_id.to_string()));
@@ -334,7 +497,8 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
 
             if let Some(task_metrics) = task_metrics {
                 let monitor = TaskMonitor::new();
-                let join_handle = t_2.runtime.spawn(monitor.instrument(task(task_id.clone())));
+                let task = allocation_stat(task(task_id.clone()), t_2.memory_usage.clone());
+                let join_handle = t_2.runtime.spawn(monitor.instrument(task));
                 if let Err(join_error) = join_handle.await && join_error.is_panic() {
                     error!("Batch task {:?} panic!", task_id);
                 }
@@ -366,7 +530,8 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
                     .with_label_values(labels)
                     .set(cumulative.total_slow_poll_duration.as_secs_f64());
             } else {
-                let join_handle = t_2.runtime.spawn(task(task_id.clone()));
+                let task = allocation_stat(task(task_id.clone()), t_2.memory_usage.clone());
+                let join_handle = t_2.runtime.spawn(task);
                 if let Err(join_error) = join_handle.await && join_error.is_panic() {
                     error!("Batch task {:?} panic!", task_id);
                 }
@@ -408,16 +573,19 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         *self.state.lock() = task_status;
     }
 
+    /// Sends the output chunks of the task to `sender`. If `spool` is given, the output is also
+    /// collected into it, and cached once the task finishes.
     pub async fn try_execute(
         &self,
-        root: BoxedExecutor,
+        mut data_chunk_stream: BoxedDataChunkStream,
+        mut spool: Option<ResultSpool>,
         sender: &mut ChanSenderImpl,
         mut shutdown_rx: Receiver<u64>,
         state_tx: &mut tokio::sync::mpsc::Sender<TaskInfoResponseResult>,
     ) -> Result<()> {
-        let mut data_chunk_stream = root.execute();
+        let chunk_target_bytes = self.context.get_config().developer.batch_chunk_target_bytes;
         let mut state = TaskStatus::Unspecified;
-        loop {
+        'output: loop {
             tokio::select! {
             // We prioritize abort signal over normal data chunks.
             biased;
@@ -427,20 +595,31 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
             }
             res = data_chunk_stream.next() => {
                 if let Some(data_chunk) = res {
-                if let Err(e) = sender.send(Some(data_chunk?)).await {
+                fail_point!("batch_task_execute", |_| Err(BatchError::Internal(
+                    anyhow!("injected task execution failure")
+                )
+                .into()));
+                let data_chunk = data_chunk?;
+                if let Some(spool) = &mut spool {
+                    spool.push(&data_chunk);
+                }
+                // Wide chunks are split to bound the size of each message sent to the receivers.
+                for data_chunk in split_chunk_by_target_bytes(data_chunk, chunk_target_bytes) {
+                    if let Err(e) = sender.send(Some(data_chunk)).await {
                         match e {
                             BatchError::SenderError => {
                                 // This is possible since when we have limit executor in parent
                                 // stage, it may early stop receiving data from downstream, which
                                 // leads to close of channel.
                                 warn!("Task receiver closed!");
-                                break;
+                                break 'output;
                             },
                             x => {
                                 return Err(InternalError(format!("Failed to send data: {:?}", x)))?;
                             }
                         }
                     }
+                }
                 } else {
                     state = TaskStatus::Finished;
                     break;
@@ -450,6 +629,11 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         }
 
         *self.state.lock() = state;
+        // Only cache complete outputs, i.e. not of aborted tasks or those whose receiver has
+        // stopped early.
+        if state == TaskStatus::Finished && let Some(spool) = spool {
+            spool.finish();
+        }
         if let Err(e) = sender.send(None).await {
             match e {
                 BatchError::SenderError => {
@@ -473,6 +657,8 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         Ok(())
     }
 
+    /// Stops the execution and releases the outputs. It's also called on finished tasks once
+    /// their query ends, to release the chunks retained by the outputs of blocking exchanges.
     pub fn abort_task(&self) {
         if let Some(sender) = self.shutdown_tx.lock().take() {
             // No need to set state to be Aborted here cuz it will be set by shutdown receiver.
@@ -483,27 +669,65 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
                 info!("Abort task {:?} done", self.task_id);
             }
         };
+        self.release_outputs();
+    }
+
+    /// Drops the outputs along with their retained chunks. The outputs being served are dropped
+    /// once they are returned.
+    fn release_outputs(&self) {
+        self.outputs_released.store(true, Ordering::Release);
+        for output in self.receivers.lock().iter_mut() {
+            output.take();
+        }
     }
 
     pub fn get_task_output(&self, output_id: &ProstOutputId) -> Result<TaskOutput> {
         let task_id = TaskId::from(output_id.get_task_id()?);
-        let receiver = self.receivers.lock()[output_id.get_output_id() as usize]
-            .take()
-            .ok_or_else(|| {
-                ErrorCode::InternalError(format!(
-                    "Task{:?}'s output{} has already been taken.",
-                    task_id,
-                    output_id.get_output_id(),
-                ))
-            })?;
+        let output_idx = output_id.get_output_id() as usize;
+        if self.outputs_released.load(Ordering::Acquire) {
+            return Err(ErrorCode::InternalError(format!(
+                "Task{:?}'s outputs have been released.",
+                task_id
+            ))
+            .into());
+        }
+        let (receiver, retained) = self.receivers.lock()[output_idx].take().ok_or_else(|| {
+            ErrorCode::InternalError(format!(
+                "Task{:?}'s output{} has already been taken.",
+                task_id,
+                output_id.get_output_id(),
+            ))
+        })?;
         let task_output = TaskOutput {
             receiver,
             output_id: output_id.try_into()?,
             failure: self.failure.clone(),
+            retained,
         };
         Ok(task_output)
     }
 
+    /// Puts back an output taken by `get_task_output`, so that the rest of it can be taken later.
+    pub fn return_task_output(&self, task_output: TaskOutput) {
+        let output_id = task_output.output_id.output_id as usize;
+        let mut receivers = self.receivers.lock();
+        // Checked with the lock held, so that the output can't be put back after the release.
+        if !self.outputs_released.load(Ordering::Acquire) {
+            receivers[output_id] = Some((task_output.receiver, task_output.retained));
+        }
+    }
+
+    /// Returns the number of chunks retained by the outputs that are not being served.
+    #[cfg(test)]
+    pub(super) fn retained_chunk_count(&self) -> usize {
+        self.receivers
+            .lock()
+            .iter()
+            .flatten()
+            .map(|(_, retained)| retained.chunks.len())
+            .sum()
+    }
+
     pub fn check_if_running(&self) -> Result<()> {
         if *self.state.lock() != TaskStatus::Running {
             return Err(ErrorCode::InternalError(format!(
@@ -515,6 +739,14 @@ impl<C: BatchTaskContext> BatchTaskExecution<C> {
         Ok(())
     }
 
+    /// Returns true if the task has reached a final state and will make no further progress.
+    pub fn is_terminated(&self) -> bool {
+        matches!(
+            *self.state.lock(),
+            TaskStatus::Finished | TaskStatus::Failed | TaskStatus::Aborted
+        )
+    }
+
     pub fn check_if_aborted(&self) -> Result<bool> {
         match *self.state.lock() {
             TaskStatus::Aborted => Ok(true),
@@ -555,4 +787,127 @@ mod tests {
             "TaskOutputId { query_id: \"abc\", stage_id: 2, task_id: 1, output_id: 3 }"
         );
     }
+    #[tokio::test]
+    async fn test_take_data_from_cursor_retains_chunks() {
+        use risingwave_pb::batch_plan::exchange_info::DistributionMode;
+        use risingwave_pb::batch_plan::ExchangeInfo;
+
+        use crate::rpc::service::exchange::GrpcExchangeWriter;
+
+        let shuffle = ExchangeInfo {
+            mode: DistributionMode::Single as i32,
+            ..Default::default()
+        };
+        let (mut sender, mut receivers) = create_output_channel(&shuffle, 4).unwrap();
+        for i in 1..=3 {
+            sender.send(Some(DataChunk::new_dummy(i))).await.unwrap();
+        }
+        sender.send(None).await.unwrap();
+        let mut task_output = TaskOutput {
+            receiver: receivers.remove(0),
+            output_id: TaskOutputId::default(),
+            failure: Arc::new(Mutex::new(None)),
+            retained: RetainedChunks::default(),
+        };
+
+        // The consumer goes away before the chunks are written.
+        let (tx, rx) = tokio::sync::mpsc::channel(2);
+        drop(rx);
+        let mut writer = GrpcExchangeWriter::new(tx);
+        assert!(task_output
+            .take_data_from_cursor(&mut writer, 0, 2)
+            .await
+            .is_err());
+
+        // The chunk taken by the failed request is served again.
+        let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+        let mut writer = GrpcExchangeWriter::new(tx);
+        assert!(!task_output
+            .take_data_from_cursor(&mut writer, 0, 2)
+            .await
+            .unwrap());
+        for cardinality in 1..=2 {
+            let chunk = rx.recv().await.unwrap().unwrap().record_batch.unwrap();
+            assert_eq!(chunk.cardinality, cardinality);
+        }
+
+        // Resuming from the cursor acknowledges the chunks before it.
+        let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+        let mut writer = GrpcExchangeWriter::new(tx);
+        assert!(task_output
+            .take_data_from_cursor(&mut writer, 2, 2)
+            .await
+            .unwrap());
+        let chunk = rx.recv().await.unwrap().unwrap().record_batch.unwrap();
+        assert_eq!(chunk.cardinality, 3);
+        assert!(task_output
+            .take_data_from_cursor(&mut writer, 0, 2)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_blocking_output_retains_acknowledged_chunks() {
+        use risingwave_pb::batch_plan::exchange_info::DistributionMode;
+        use risingwave_pb::batch_plan::ExchangeInfo;
+
+        use crate::rpc::service::exchange::GrpcExchangeWriter;
+
+        let shuffle = ExchangeInfo {
+            mode: DistributionMode::Single as i32,
+            exchange_mode: ExchangeMode::Blocking as i32,
+            ..Default::default()
+        };
+        let (mut sender, mut receivers) = create_output_channel(&shuffle, 8).unwrap();
+        for i in 1..=4 {
+            sender.send(Some(DataChunk::new_dummy(i))).await.unwrap();
+        }
+        sender.send(None).await.unwrap();
+        let mut task_output = TaskOutput {
+            receiver: receivers.remove(0),
+            output_id: TaskOutputId::default(),
+            failure: Arc::new(Mutex::new(None)),
+            retained: RetainedChunks {
+                max_retained: 2,
+                ..Default::default()
+            },
+        };
+        async fn take(task_output: &mut TaskOutput, cursor: u64) -> Result<(bool, Vec<u32>)> {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+            let mut writer = GrpcExchangeWriter::new(tx);
+            let finished = task_output
+                .take_data_from_cursor(&mut writer, cursor, 2)
+                .await?;
+            drop(writer);
+            let mut cardinalities = vec![];
+            while let Some(resp) = rx.recv().await {
+                cardinalities.push(resp.unwrap().record_batch.unwrap().cardinality);
+            }
+            Ok((finished, cardinalities))
+        }
+
+        assert_eq!(
+            take(&mut task_output, 0).await.unwrap(),
+            (false, vec![1, 2])
+        );
+        // Moving the cursor forward does not release the chunks before it while they fit.
+        assert_eq!(
+            take(&mut task_output, 2).await.unwrap(),
+            (false, vec![3, 4])
+        );
+        assert_eq!(
+            take(&mut task_output, 0).await.unwrap(),
+            (false, vec![1, 2])
+        );
+        assert_eq!(task_output.retained.chunks.len(), 4);
+
+        // Beyond the limit, the acknowledged chunks are released.
+        assert_eq!(take(&mut task_output, 4).await.unwrap(), (true, vec![]));
+        assert_eq!(task_output.retained.chunks.len(), 2);
+        assert!(take(&mut task_output, 0).await.is_err());
+        assert_eq!(
+            take(&mut task_output, 2).await.unwrap(),
+            (false, vec![3, 4])
+        );
+    }
 }
@@ -14,8 +14,11 @@
 
 use std::collections::{hash_map, HashMap};
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
+use global_stats_alloc::TaskLocalBytesAllocated;
 use parking_lot::Mutex;
 use risingwave_common::config::BatchConfig;
 use risingwave_common::error::ErrorCode::{self, TaskNotFound};
@@ -30,7 +33,10 @@ use tonic::Status;
 
 use crate::rpc::service::exchange::GrpcExchangeWriter;
 use crate::rpc::service::task_service::TaskInfoResponseResult;
-use crate::task::{BatchTaskExecution, ComputeNodeContext, TaskId, TaskOutput, TaskOutputId};
+use crate::task::{
+    AdmissionController, BatchResultCache, BatchTaskExecution, ComputeNodeContext, TaskId,
+    TaskOutput, TaskOutputId,
+};
 
 /// `BatchManager` is responsible for managing all batch tasks.
 #[derive(Clone)]
@@ -43,6 +49,15 @@ pub struct BatchManager {
 
     /// Batch configuration
     config: BatchConfig,
+
+    /// Set once the compute node starts shutting down. No new task will be accepted afterwards.
+    shutting_down: Arc<AtomicBool>,
+
+    /// Cache of the outputs of read-only tasks. `None` if disabled.
+    result_cache: Option<Arc<BatchResultCache>>,
+
+    /// Admits tasks to their resource groups.
+    admission: Arc<AdmissionController>,
 }
 
 impl BatchManager {
@@ -58,6 +73,14 @@ impl BatchManager {
                 .build()
                 .unwrap()
         };
+        let result_cache = match config.developer.batch_result_cache_capacity {
+            0 => None,
+            capacity => Some(Arc::new(BatchResultCache::new(capacity))),
+        };
+        let admission = Arc::new(AdmissionController::new(
+            &config.resource_groups,
+            config.total_memory_available_bytes,
+        ));
         BatchManager {
             tasks: Arc::new(Mutex::new(HashMap::new())),
             // Leak the runtime to avoid runtime shutting-down in the main async context.
@@ -65,18 +88,36 @@ impl BatchManager {
             // stream manager.
             runtime: Box::leak(Box::new(runtime)),
             config,
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            result_cache,
+            admission,
         }
     }
 
+    /// Creates and executes a task once its query is admitted to `resource_group`, which is empty
+    /// for no resource group.
     pub async fn fire_task(
         &self,
         tid: &ProstTaskId,
         plan: PlanFragment,
         epoch: u64,
         context: ComputeNodeContext,
+        resource_group: &str,
     ) -> Result<()> {
         trace!("Received task id: {:?}, plan: {:?}", tid, plan);
+        if self.shutting_down.load(Ordering::Acquire) {
+            return Err(ErrorCode::InternalError(format!(
+                "can not create task {:?}: compute node is shutting down",
+                tid
+            ))
+            .into());
+        }
+        let admission_permit = self.admission.admit(resource_group, &tid.query_id).await?;
         let task = BatchTaskExecution::new(tid, plan, context, epoch, self.runtime)?;
+        if let Some(admission_permit) = admission_permit {
+            admission_permit.add_memory_usage(task.memory_usage());
+            task.set_admission_permit(admission_permit);
+        }
         let task_id = task.get_task_id().clone();
         let task = Arc::new(task);
         // Here the task id insert into self.tasks is put in front of `.async_execute`, cuz when
@@ -96,15 +137,46 @@ impl BatchManager {
         ret
     }
 
+    /// Serves the task output to `tx`. If `max_chunks` is 0, all the remaining chunks are sent.
+    /// Otherwise at most `max_chunks` chunks starting from `cursor` are sent, and the output is
+    /// kept for later requests to continue from the advanced cursor. The chunks sent are retained
+    /// until a later request acknowledges them with its cursor, so a request failed midway can be
+    /// retried with the same cursor. The outputs of blocking exchanges also retain the acknowledged
+    /// chunks while they fit in the buffer, until the task is aborted once its query ends.
     pub fn get_data(
         &self,
         tx: Sender<std::result::Result<GetDataResponse, Status>>,
         peer_addr: SocketAddr,
         pb_task_output_id: &ProstTaskOutputId,
+        cursor: u64,
+        max_chunks: u32,
     ) -> Result<()> {
         let task_id = TaskOutputId::try_from(pb_task_output_id)?;
         tracing::trace!(target: "events::compute::exchange", peer_addr = %peer_addr, from = ?task_id, "serve exchange RPC");
         let mut task_output = self.take_output(pb_task_output_id)?;
+        if max_chunks > 0 {
+            let task = self
+                .tasks
+                .lock()
+                .get(&task_id.task_id)
+                .cloned()
+                .ok_or(TaskNotFound)?;
+            self.runtime.spawn(async move {
+                let mut writer = GrpcExchangeWriter::new(tx.clone());
+                let result = task_output
+                    .take_data_from_cursor(&mut writer, cursor, max_chunks as usize)
+                    .await;
+                if !matches!(result, Ok(true)) || task_output.retains_acknowledged() {
+                    // Keep the output along with its retained chunks, even if the writing failed.
+                    task.return_task_output(task_output);
+                }
+                match result {
+                    Ok(_) => Ok(()),
+                    Err(e) => tx.send(Err(e.into())).await,
+                }
+            });
+            return Ok(());
+        }
         self.runtime.spawn(async move {
             let mut writer = GrpcExchangeWriter::new(tx.clone());
             match task_output.take_data(&mut writer).await {
@@ -186,6 +258,30 @@ impl BatchManager {
         }
     }
 
+    /// Stops accepting new tasks and waits for the running ones to terminate. Tasks that are
+    /// still running after `drain_timeout` are aborted.
+    pub async fn shutdown(&self, drain_timeout: Duration) {
+        self.shutting_down.store(true, Ordering::Release);
+
+        let all_terminated = || self.tasks.lock().values().all(|task| task.is_terminated());
+        let drain = async {
+            while !all_terminated() {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        };
+        if tokio::time::timeout(drain_timeout, drain).await.is_err() {
+            let tasks = self.tasks.lock();
+            let running = tasks.values().filter(|task| !task.is_terminated());
+            for task in running {
+                warn!(
+                    "Abort task {:?} which is still running after the drain timeout",
+                    task.get_task_id()
+                );
+                task.abort_task();
+            }
+        }
+    }
+
     /// Return the receivers for streaming RPC.
     pub fn get_task_receiver(
         &self,
@@ -201,6 +297,19 @@ impl BatchManager {
     pub fn config(&self) -> &BatchConfig {
         &self.config
     }
+
+    /// Returns the counters of the heap memory allocated by each task and its executors.
+    pub fn get_task_memory_usage(&self) -> HashMap<TaskId, TaskLocalBytesAllocated> {
+        self.tasks
+            .lock()
+            .iter()
+            .map(|(task_id, task)| (task_id.clone(), task.memory_usage()))
+            .collect()
+    }
+
+    pub fn result_cache(&self) -> Option<Arc<BatchResultCache>> {
+        self.result_cache.clone()
+    }
 }
 
 impl Default for BatchManager {
@@ -211,10 +320,12 @@ impl Default for BatchManager {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use risingwave_common::config::BatchConfig;
     use risingwave_common::types::DataType;
     use risingwave_expr::expr::make_i32_literal;
-    use risingwave_pb::batch_plan::exchange_info::DistributionMode;
+    use risingwave_pb::batch_plan::exchange_info::{DistributionMode, ExchangeMode};
     use risingwave_pb::batch_plan::plan_node::NodeBody;
     use risingwave_pb::batch_plan::{
         ExchangeInfo, PlanFragment, PlanNode, TableFunctionNode, TaskId as ProstTaskId,
@@ -269,7 +380,7 @@ mod tests {
             }),
             exchange_info: Some(ExchangeInfo {
                 mode: DistributionMode::Single as i32,
-                distribution: None,
+                ..Default::default()
             }),
         };
         let context = ComputeNodeContext::for_test();
@@ -279,11 +390,11 @@ mod tests {
             task_id: 0,
         };
         manager
-            .fire_task(&task_id, plan.clone(), 0, context.clone())
+            .fire_task(&task_id, plan.clone(), 0, context.clone(), "")
             .await
             .unwrap();
         let err = manager
-            .fire_task(&task_id, plan, 0, context)
+            .fire_task(&task_id, plan, 0, context, "")
             .await
             .unwrap_err();
         assert!(err
@@ -291,6 +402,112 @@ mod tests {
             .contains("can not create duplicate task with the same id"));
     }
 
+    #[tokio::test]
+    async fn test_get_data_with_cursor() {
+        let manager = BatchManager::new(BatchConfig::default());
+        let plan = PlanFragment {
+            root: Some(PlanNode {
+                children: vec![],
+                identity: "".to_string(),
+                node_body: Some(NodeBody::Values(ValuesNode {
+                    tuples: vec![],
+                    fields: vec![],
+                })),
+            }),
+            exchange_info: Some(ExchangeInfo {
+                mode: DistributionMode::Single as i32,
+                ..Default::default()
+            }),
+        };
+        let context = ComputeNodeContext::for_test();
+        let task_id = ProstTaskId {
+            query_id: "".to_string(),
+            stage_id: 0,
+            task_id: 0,
+        };
+        manager
+            .fire_task(&task_id, plan, 0, context, "")
+            .await
+            .unwrap();
+        let output_id = ProstTaskOutputId {
+            task_id: Some(task_id),
+            output_id: 0,
+        };
+        let peer_addr = "127.0.0.1:0".parse().unwrap();
+
+        // Nothing has been fetched yet, so the cursor must be 0.
+        let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+        manager.get_data(tx, peer_addr, &output_id, 1, 1).unwrap();
+        let err = rx.recv().await.unwrap().unwrap_err();
+        assert!(err.message().contains("out of the retained chunks"));
+
+        // The output is kept after a rejected request.
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        manager.get_data(tx, peer_addr, &output_id, 0, 1).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_blocking_output_released_after_abort() {
+        let manager = BatchManager::new(BatchConfig::default());
+        let plan = PlanFragment {
+            root: Some(PlanNode {
+                children: vec![],
+                identity: "".to_string(),
+                node_body: Some(NodeBody::TableFunction(TableFunctionNode {
+                    table_function: Some(TableFunction {
+                        function_type: Type::Generate as i32,
+                        args: vec![
+                            make_i32_literal(1),
+                            make_i32_literal(i32::MAX),
+                            make_i32_literal(1),
+                        ],
+                        return_type: Some(DataType::Int32.to_protobuf()),
+                    }),
+                })),
+            }),
+            exchange_info: Some(ExchangeInfo {
+                mode: DistributionMode::Single as i32,
+                exchange_mode: ExchangeMode::Blocking as i32,
+                ..Default::default()
+            }),
+        };
+        let context = ComputeNodeContext::for_test();
+        let task_id = ProstTaskId {
+            query_id: "".to_string(),
+            stage_id: 0,
+            task_id: 0,
+        };
+        manager
+            .fire_task(&task_id, plan, 0, context, "")
+            .await
+            .unwrap();
+        let task = manager
+            .tasks
+            .lock()
+            .get(&TaskId::from(&task_id))
+            .cloned()
+            .unwrap();
+        let output_id = ProstTaskOutputId {
+            task_id: Some(task_id.clone()),
+            output_id: 0,
+        };
+        let peer_addr = "127.0.0.1:0".parse().unwrap();
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+        manager.get_data(tx, peer_addr, &output_id, 0, 2).unwrap();
+        for _ in 0..2 {
+            rx.recv().await.unwrap().unwrap();
+        }
+        // The fetched chunks are retained once the output is returned.
+        while task.retained_chunk_count() < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        manager.abort_task(&task_id);
+        assert_eq!(task.retained_chunk_count(), 0);
+        assert!(manager.take_output(&output_id).is_err());
+    }
+
     #[tokio::test]
     async fn test_task_aborted() {
         let manager = BatchManager::new(BatchConfig::default());
@@ -314,7 +531,7 @@ mod tests {
             }),
             exchange_info: Some(ExchangeInfo {
                 mode: DistributionMode::Single as i32,
-                distribution: None,
+                ..Default::default()
             }),
         };
         let context = ComputeNodeContext::for_test();
@@ -324,7 +541,7 @@ mod tests {
             task_id: 0,
         };
         manager
-            .fire_task(&task_id, plan.clone(), 0, context.clone())
+            .fire_task(&task_id, plan.clone(), 0, context.clone(), "")
             .await
             .unwrap();
         manager.abort_task(&task_id);
@@ -332,4 +549,58 @@ mod tests {
         let res = manager.wait_until_task_aborted(&task_id).await;
         assert_eq!(res, Ok(()));
     }
+
+    #[tokio::test]
+    async fn test_shutdown() {
+        let manager = BatchManager::new(BatchConfig::default());
+        let plan = PlanFragment {
+            root: Some(PlanNode {
+                children: vec![],
+                identity: "".to_string(),
+                node_body: Some(NodeBody::TableFunction(TableFunctionNode {
+                    table_function: Some(TableFunction {
+                        function_type: Type::Generate as i32,
+                        args: vec![
+                            make_i32_literal(1),
+                            make_i32_literal(i32::MAX),
+                            make_i32_literal(1),
+                        ],
+                        return_type: Some(DataType::Int32.to_protobuf()),
+                    }),
+                })),
+            }),
+            exchange_info: Some(ExchangeInfo {
+                mode: DistributionMode::Single as i32,
+                ..Default::default()
+            }),
+        };
+        let context = ComputeNodeContext::for_test();
+        let task_id = ProstTaskId {
+            query_id: "".to_string(),
+            stage_id: 0,
+            task_id: 0,
+        };
+        manager
+            .fire_task(&task_id, plan.clone(), 0, context.clone(), "")
+            .await
+            .unwrap();
+
+        // The long running task is aborted once the drain timeout elapses.
+        manager.shutdown(Duration::from_millis(100)).await;
+        let res = manager
+            .wait_until_task_aborted(&TaskId::from(&task_id))
+            .await;
+        assert_eq!(res, Ok(()));
+
+        // No new task is accepted after shutdown.
+        let task_id = ProstTaskId {
+            task_id: 1,
+            ..task_id
+        };
+        let err = manager
+            .fire_task(&task_id, plan, 0, context, "")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("compute node is shutting down"));
+    }
 }
@@ -16,9 +16,16 @@
 
 use tikv_jemallocator::Jemalloc;
 
+#[cfg(not(feature = "task-local-alloc"))]
 #[global_allocator]
 static GLOBAL: Jemalloc = Jemalloc;
 
+/// Attributes the memory to the batch tasks and streaming actors allocating it.
+#[cfg(feature = "task-local-alloc")]
+#[global_allocator]
+static GLOBAL: global_stats_alloc::TaskLocalAlloc<Jemalloc> =
+    global_stats_alloc::TaskLocalAlloc::new(Jemalloc);
+
 #[cfg_attr(coverage, no_coverage)]
 fn main() {
     use clap::StructOpt;
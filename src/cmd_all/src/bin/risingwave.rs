@@ -17,9 +17,16 @@
 
 use tikv_jemallocator::Jemalloc;
 
+#[cfg(not(feature = "task-local-alloc"))]
 #[global_allocator]
 static GLOBAL: Jemalloc = Jemalloc;
 
+/// Attributes the memory to the batch tasks and streaming actors allocating it.
+#[cfg(feature = "task-local-alloc")]
+#[global_allocator]
+static GLOBAL: global_stats_alloc::TaskLocalAlloc<Jemalloc> =
+    global_stats_alloc::TaskLocalAlloc::new(Jemalloc);
+
 use std::collections::HashMap;
 use std::env;
 
@@ -0,0 +1,56 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::row::Row;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+const CHUNK_SIZE: usize = 1024;
+
+/// Builds a chunk of `(i, "value-i")` rows. Every `delete_every`-th row deletes the row inserted
+/// just before it, or the chunk is insert-only if `delete_every` is 0.
+fn gen_chunk(delete_every: usize) -> StreamChunk {
+    let rows = (0..CHUNK_SIZE)
+        .map(|i| {
+            let (op, key) = if delete_every != 0 && i % delete_every == delete_every - 1 {
+                (Op::Delete, i - 1)
+            } else {
+                (Op::Insert, i)
+            };
+            let row = Row::new(vec![
+                Some(ScalarImpl::Int64(key as i64)),
+                Some(ScalarImpl::Utf8(format!("value-{}", key))),
+            ]);
+            (op, row)
+        })
+        .collect::<Vec<_>>();
+    StreamChunk::from_rows(&rows, &[DataType::Int64, DataType::Varchar])
+}
+
+fn bench_cancel_out(c: &mut Criterion) {
+    for (name, delete_every) in [("insert only", 0), ("half cancelled", 2), ("mixed", 16)] {
+        let chunk = gen_chunk(delete_every);
+        c.bench_with_input(BenchmarkId::new("cancel_out", name), &chunk, |b, chunk| {
+            b.iter_batched(
+                || chunk.clone(),
+                |chunk| chunk.cancel_out(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+}
+
+criterion_group!(benches, bench_cancel_out);
+criterion_main!(benches);
@@ -24,6 +24,7 @@ use crate::array::column::Column;
 use crate::array::data_chunk_iter::RowRef;
 use crate::array::{ArrayBuilderImpl, StructValue};
 use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::collection::estimate_size::EstimateSize;
 use crate::hash::HashCode;
 use crate::row::{Row, Row2};
 use crate::types::struct_type::StructType;
@@ -130,6 +131,42 @@ impl DataChunk {
         DataChunk::new(self.columns.clone(), visibility)
     }
 
+    /// Copies the `len` rows starting from the physical row `offset` into a new chunk, keeping
+    /// their visibility. The new chunk does not share the arrays of `self`.
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(
+            offset + len <= self.capacity(),
+            "slice [{}, {}) is out of the chunk capacity {}",
+            offset,
+            offset + len,
+            self.capacity()
+        );
+        if offset == 0 && len == self.capacity() {
+            return self.clone();
+        }
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| {
+                let array = column.array_ref();
+                let mut builder = array.create_builder(len);
+                for idx in offset..offset + len {
+                    builder.append_array_element(array, idx);
+                }
+                Column::new(Arc::new(builder.finish()))
+            })
+            .collect();
+        let vis = match &self.vis2 {
+            Vis::Bitmap(bitmap) => Vis::Bitmap(
+                (offset..offset + len)
+                    .map(|idx| bitmap.is_set(idx))
+                    .collect(),
+            ),
+            Vis::Compact(_) => Vis::Compact(len),
+        };
+        DataChunk::new(columns, vis)
+    }
+
     pub fn visibility(&self) -> Option<&Bitmap> {
         self.get_visibility_ref()
     }
@@ -436,6 +473,22 @@ impl DataChunk {
     }
 }
 
+impl EstimateSize for DataChunk {
+    fn estimated_heap_size(&self) -> usize {
+        let visibility = match &self.vis2 {
+            Vis::Bitmap(bitmap) => bitmap.estimated_heap_size(),
+            Vis::Compact(_) => 0,
+        };
+        self.columns.capacity() * std::mem::size_of::<Column>()
+            + self
+                .columns
+                .iter()
+                .map(|column| column.array_ref().estimated_size())
+                .sum::<usize>()
+            + visibility
+    }
+}
+
 impl fmt::Debug for DataChunk {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -743,6 +796,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_slice() {
+        let chunk = DataChunk::from_pretty(
+            "I I
+             1 5
+             2 6
+             3 7
+             4 8",
+        );
+        let sliced = chunk.slice(1, 2);
+        assert_eq!(sliced.capacity(), 2);
+        assert_eq!(sliced.cardinality(), 2);
+        assert_eq!(
+            sliced,
+            DataChunk::from_pretty(
+                "I I
+                 2 6
+                 3 7",
+            )
+        );
+
+        // Invisible rows stay invisible.
+        let chunk = chunk.with_visibility([true, false, true, true].into_iter().collect());
+        let sliced = chunk.slice(1, 3);
+        assert_eq!(sliced.capacity(), 3);
+        assert_eq!(sliced.cardinality(), 2);
+        assert_eq!(
+            sliced.compact(),
+            DataChunk::from_pretty(
+                "I I
+                 3 7
+                 4 8",
+            )
+        );
+    }
+
     #[test]
     fn test_no_column_chunk() {
         let chunk = DataChunk::new_dummy(10);
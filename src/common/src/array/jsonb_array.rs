@@ -0,0 +1,1003 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::hash::{Hash, Hasher};
+use std::io::{Cursor, Read};
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use byteorder::{BigEndian, ReadBytesExt};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use risingwave_pb::common::buffer::CompressionType;
+use risingwave_pb::common::Buffer;
+use risingwave_pb::data::{Array as ProstArray, ArrayType as ProstArrayType};
+use rust_decimal::Decimal as RustDecimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::value::RawValue;
+
+use super::{
+    Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, ArrayIterator, ArrayMeta, ArrayResult,
+};
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::collection::estimate_size::EstimateSize;
+use crate::types::to_binary::ToBinary;
+use crate::types::to_text::ToText;
+use crate::types::Scalar;
+
+/// A parsed JSON value. Unlike [`serde_json::Value`], numbers are kept in their exact text, which
+/// `serde_json` would round to `f64` unless its `arbitrary_precision` feature is enabled for the
+/// whole workspace. Object keys are sorted as in PostgreSQL.
+#[derive(Debug, Clone)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(Box<str>),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+/// An owned `jsonb` value.
+#[derive(Debug, Clone)]
+pub struct JsonbVal(Box<Value>);
+
+/// A reference to a `jsonb` value, which is either a [`JsonbVal`] or an element of a
+/// [`JsonbArray`].
+#[derive(Debug, Clone, Copy)]
+pub struct JsonbRef<'a>(&'a Value);
+
+impl JsonbVal {
+    pub(crate) fn new(value: Value) -> Self {
+        Self(Box::new(value))
+    }
+
+    /// The JSON `null` value, which is different from a SQL `NULL`.
+    pub fn null() -> Self {
+        Self::new(Value::Null)
+    }
+
+    /// Deserialize from the memcomparable encoding written by [`JsonbRef::memcmp_serialize`].
+    pub fn memcmp_deserialize(
+        deserializer: &mut memcomparable::Deserializer<impl Buf>,
+    ) -> memcomparable::Result<Self> {
+        struct Visitor;
+        impl<'a> serde::de::Visitor<'a> for Visitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a jsonb value")
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(v)
+            }
+        }
+        let bytes = deserializer.deserialize_byte_buf(Visitor)?;
+        let mut inner_deserializer = memcomparable::Deserializer::new(bytes.as_slice());
+        Ok(Self::new(memcmp_deserialize_value(
+            &mut inner_deserializer,
+        )?))
+    }
+
+    /// Deserialize from the value encoding written by [`JsonbRef::value_serialize`].
+    pub fn value_deserialize(mut buf: &[u8]) -> Option<Self> {
+        let value = value_deserialize_value(&mut buf)?;
+        buf.is_empty().then(|| Self::new(value))
+    }
+}
+
+impl FromStr for JsonbVal {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_value(serde_json::from_str(s)?).map(Self::new)
+    }
+}
+
+/// Numbers keep the text given by `serde_json`, which has rounded those out of the range of `i64`
+/// and `u64` to `f64` when parsing them.
+impl From<serde_json::Value> for JsonbVal {
+    fn from(value: serde_json::Value) -> Self {
+        Self::new(from_serde_value(value))
+    }
+}
+
+impl<'a> From<&'a JsonbVal> for JsonbRef<'a> {
+    fn from(val: &'a JsonbVal) -> Self {
+        Self(&val.0)
+    }
+}
+
+/// Values are equal if they compare equal, e.g., the numbers `1` and `1.0` are equal.
+impl PartialEq for JsonbVal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for JsonbVal {}
+
+impl PartialOrd for JsonbVal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbVal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_scalar_ref().cmp(&other.as_scalar_ref())
+    }
+}
+
+impl<'a> JsonbRef<'a> {
+    pub(crate) fn value(&self) -> &'a Value {
+        self.0
+    }
+
+    /// Converts to a [`serde_json::Value`]. Numbers out of the range of `f64` are converted to
+    /// strings, and the other numbers may be rounded.
+    pub fn to_serde_value(&self) -> serde_json::Value {
+        to_serde_value(self.0)
+    }
+
+    pub fn hash_scalar_inner<H: Hasher>(&self, state: &mut H) {
+        hash_value(self.0, state)
+    }
+
+    /// Returns `true` if this is the JSON `null` value.
+    pub fn is_jsonb_null(&self) -> bool {
+        matches!(self.0, Value::Null)
+    }
+
+    /// Returns the name of the type of the outermost value, as `jsonb_typeof` in PostgreSQL.
+    pub fn type_name(&self) -> &'static str {
+        match self.0 {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
+    /// Returns the number of elements of the outermost array.
+    pub fn array_len(&self) -> Result<usize, String> {
+        match self.0 {
+            Value::Array(array) => Ok(array.len()),
+            _ => Err(format!(
+                "cannot get array length of a jsonb {}",
+                self.type_name()
+            )),
+        }
+    }
+
+    /// Returns the field of an object with the given key, or `None` if this is not an object or
+    /// the key does not exist.
+    pub fn access_object_field(&self, key: &str) -> Option<JsonbRef<'a>> {
+        match self.0 {
+            Value::Object(object) => object.get(key).map(JsonbRef),
+            _ => None,
+        }
+    }
+
+    /// Returns the element of an array at the given index, or `None` if this is not an array or
+    /// the index is out of bounds. Negative indexes count from the end of the array.
+    pub fn access_array_element(&self, idx: i32) -> Option<JsonbRef<'a>> {
+        let Value::Array(array) = self.0 else {
+            return None;
+        };
+        let idx = if idx < 0 {
+            array.len().checked_sub(idx.unsigned_abs() as usize)?
+        } else {
+            idx as usize
+        };
+        array.get(idx).map(JsonbRef)
+    }
+
+    /// Returns the value at the given path, where each element of the path is either an object
+    /// key or an array index, as `#>` in PostgreSQL.
+    pub fn access_path<'b>(&self, path: impl IntoIterator<Item = &'b str>) -> Option<JsonbRef<'a>> {
+        path.into_iter().try_fold(*self, |v, key| match v.0 {
+            Value::Object(_) => v.access_object_field(key),
+            Value::Array(_) => v.access_array_element(key.parse().ok()?),
+            _ => None,
+        })
+    }
+
+    /// Returns the text of a string value without quotes, or the JSON text of any other value.
+    /// The JSON `null` value is converted to a SQL `NULL`, as `->>` in PostgreSQL.
+    pub fn as_text(&self) -> Option<String> {
+        match self.0 {
+            Value::Null => None,
+            Value::String(s) => Some(s.clone()),
+            _ => Some(self.to_text()),
+        }
+    }
+
+    /// Serialize to a memcomparable encoding, whose order is the same as [`Ord`].
+    pub fn memcmp_serialize(
+        &self,
+        serializer: &mut memcomparable::Serializer<impl BufMut>,
+    ) -> memcomparable::Result<()> {
+        let mut inner_serializer = memcomparable::Serializer::new(vec![]);
+        memcmp_serialize_value(self.0, &mut inner_serializer)?;
+        serializer.serialize_bytes(&inner_serializer.into_inner())
+    }
+
+    /// Serialize to the value encoding, which is a binary form of the value, so that it's not
+    /// parsed again on deserialization. Numbers are kept in their exact text.
+    pub fn value_serialize(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        value_serialize_value(self.0, &mut buf);
+        buf
+    }
+}
+
+impl PartialEq for JsonbRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for JsonbRef<'_> {}
+
+impl PartialOrd for JsonbRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Values are ordered as in PostgreSQL: `Object > Array > Boolean > Number > String > Null`.
+/// Arrays and objects with more elements are greater, and are otherwise compared element by
+/// element. Object keys are compared in their sorted order.
+impl Ord for JsonbRef<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_value(self.0, other.0)
+    }
+}
+
+impl ToText for JsonbRef<'_> {
+    fn to_text(&self) -> String {
+        let mut s = String::new();
+        write_value(self.0, &mut s).unwrap();
+        s
+    }
+}
+
+/// The binary format of PostgreSQL is a version number `1` followed by the JSON text.
+impl ToBinary for JsonbRef<'_> {
+    fn to_binary(&self) -> Option<Bytes> {
+        let mut output = BytesMut::new();
+        output.put_u8(1);
+        output.put_slice(self.to_text().as_bytes());
+        Some(output.freeze())
+    }
+}
+
+impl fmt::Display for JsonbRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_value(self.0, f)
+    }
+}
+
+impl fmt::Display for JsonbVal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_scalar_ref().fmt(f)
+    }
+}
+
+/// Writes the JSON text in the output format of PostgreSQL, which puts a space after each `,`
+/// and `:`.
+fn write_value(value: &Value, f: &mut impl Write) -> fmt::Result {
+    match value {
+        Value::Array(array) => {
+            f.write_char('[')?;
+            for (i, v) in array.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write_value(v, f)?;
+            }
+            f.write_char(']')
+        }
+        Value::Object(object) => {
+            f.write_char('{')?;
+            for (i, (k, v)) in object.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write_string(k, f)?;
+                f.write_str(": ")?;
+                write_value(v, f)?;
+            }
+            f.write_char('}')
+        }
+        Value::Null => f.write_str("null"),
+        Value::Bool(b) => write!(f, "{}", b),
+        Value::Number(n) => f.write_str(n),
+        Value::String(s) => write_string(s, f),
+    }
+}
+
+/// Writes a string as a quoted and escaped JSON string.
+fn write_string(s: &str, f: &mut impl Write) -> fmt::Result {
+    f.write_str(&serde_json::to_string(s).map_err(|_| fmt::Error)?)
+}
+
+/// Parses a JSON value from its raw text. The nested values are parsed as [`RawValue`]s as well,
+/// so that the text of numbers is kept.
+fn parse_value(raw: &RawValue) -> serde_json::Result<Value> {
+    let text = raw.get().trim();
+    let value = match text.as_bytes().first() {
+        Some(b'{') => Value::Object(
+            serde_json::from_str::<BTreeMap<String, &RawValue>>(text)?
+                .into_iter()
+                .map(|(k, v)| Ok((k, parse_value(v)?)))
+                .collect::<serde_json::Result<_>>()?,
+        ),
+        Some(b'[') => Value::Array(
+            serde_json::from_str::<Vec<&RawValue>>(text)?
+                .into_iter()
+                .map(parse_value)
+                .collect::<serde_json::Result<_>>()?,
+        ),
+        Some(b'"') => Value::String(serde_json::from_str(text)?),
+        Some(b't' | b'f') => Value::Bool(serde_json::from_str(text)?),
+        Some(b'n') => Value::Null,
+        _ => Value::Number(text.into()),
+    };
+    Ok(value)
+}
+
+/// Returns the number with the given text, or `None` if the text is not a JSON number.
+fn parse_number(text: &str) -> Option<Value> {
+    match parse_value(serde_json::from_str(text).ok()?).ok()? {
+        number @ Value::Number(_) => Some(number),
+        _ => None,
+    }
+}
+
+fn from_serde_value(value: serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(b) => Value::Bool(b),
+        serde_json::Value::Number(n) => Value::Number(n.to_string().into()),
+        serde_json::Value::String(s) => Value::String(s),
+        serde_json::Value::Array(array) => {
+            Value::Array(array.into_iter().map(from_serde_value).collect())
+        }
+        serde_json::Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .map(|(k, v)| (k, from_serde_value(v)))
+                .collect(),
+        ),
+    }
+}
+
+fn to_serde_value(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Bool(b) => serde_json::Value::Bool(*b),
+        Value::Number(n) => {
+            serde_json::from_str(n).unwrap_or_else(|_| serde_json::Value::String(n.to_string()))
+        }
+        Value::String(s) => serde_json::Value::String(s.clone()),
+        Value::Array(array) => serde_json::Value::Array(array.iter().map(to_serde_value).collect()),
+        Value::Object(object) => serde_json::Value::Object(
+            object
+                .iter()
+                .map(|(k, v)| (k.clone(), to_serde_value(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// The rank of each kind of value in the sort order, also used as the tag in the memcomparable
+/// encoding.
+fn kind_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::String(_) => 1,
+        Value::Number(_) => 2,
+        Value::Bool(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+/// The exact value of a number if it fits in a decimal, which is the case for all numbers but those
+/// out of the range or precision of [`RustDecimal`]. The latter are only approximated by `f64`.
+fn number_as_decimal(text: &str) -> Option<RustDecimal> {
+    let decimal = if text.contains(['e', 'E']) {
+        RustDecimal::from_scientific(text)
+    } else {
+        RustDecimal::from_str_exact(text)
+    }
+    .ok()?;
+    // Normalize `-0` and the trailing zeros, so that equal numbers are represented the same.
+    Some(if decimal.is_zero() {
+        RustDecimal::ZERO
+    } else {
+        decimal.normalize()
+    })
+}
+
+/// Returns the value of a number as `f64`, with `-0.0` normalized to `0.0` and the numbers out of
+/// the range of `f64` saturated to infinities.
+fn number_as_f64(text: &str) -> f64 {
+    // Parsing saturates to infinities.
+    text.parse::<f64>().unwrap() + 0.0
+}
+
+const NUMBER_DECIMAL: u8 = 0;
+const NUMBER_FLOAT: u8 = 1;
+
+/// Numbers are compared by their values, so that `1` and `1.0` are equal as in PostgreSQL. They are
+/// first ordered by their `f64` approximations, and the ties are broken by the exact decimal
+/// values. The few numbers that don't fit in a decimal are ordered after the decimals of the same
+/// approximation, and are only equal if their texts are.
+fn cmp_number(l: &str, r: &str) -> Ordering {
+    number_as_f64(l)
+        .partial_cmp(&number_as_f64(r))
+        .unwrap()
+        .then_with(|| match (number_as_decimal(l), number_as_decimal(r)) {
+            (Some(l), Some(r)) => l.cmp(&r),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => l.cmp(r),
+        })
+}
+
+fn cmp_value(l: &Value, r: &Value) -> Ordering {
+    match (l, r) {
+        (Value::String(l), Value::String(r)) => l.cmp(r),
+        (Value::Number(l), Value::Number(r)) => cmp_number(l, r),
+        (Value::Bool(l), Value::Bool(r)) => l.cmp(r),
+        (Value::Array(l), Value::Array(r)) => l.len().cmp(&r.len()).then_with(|| {
+            l.iter()
+                .zip(r)
+                .map(|(l, r)| cmp_value(l, r))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        }),
+        (Value::Object(l), Value::Object(r)) => l.len().cmp(&r.len()).then_with(|| {
+            l.iter()
+                .zip(r)
+                .map(|((lk, lv), (rk, rv))| lk.cmp(rk).then_with(|| cmp_value(lv, rv)))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        }),
+        _ => kind_rank(l).cmp(&kind_rank(r)),
+    }
+}
+
+fn hash_value<H: Hasher>(value: &Value, state: &mut H) {
+    kind_rank(value).hash(state);
+    match value {
+        Value::Null => {}
+        Value::Bool(b) => b.hash(state),
+        Value::Number(n) => match number_as_decimal(n) {
+            Some(decimal) => {
+                NUMBER_DECIMAL.hash(state);
+                decimal.serialize().hash(state);
+            }
+            None => {
+                NUMBER_FLOAT.hash(state);
+                n.hash(state);
+            }
+        },
+        Value::String(s) => s.hash(state),
+        Value::Array(array) => {
+            array.len().hash(state);
+            array.iter().for_each(|v| hash_value(v, state));
+        }
+        Value::Object(object) => {
+            object.len().hash(state);
+            for (k, v) in object {
+                k.hash(state);
+                hash_value(v, state);
+            }
+        }
+    }
+}
+
+fn memcmp_serialize_value(
+    value: &Value,
+    serializer: &mut memcomparable::Serializer<impl BufMut>,
+) -> memcomparable::Result<()> {
+    kind_rank(value).serialize(&mut *serializer)?;
+    match value {
+        Value::Null => {}
+        Value::String(s) => s.serialize(&mut *serializer)?,
+        Value::Number(n) => {
+            number_as_f64(n).serialize(&mut *serializer)?;
+            match number_as_decimal(n) {
+                Some(decimal) => {
+                    NUMBER_DECIMAL.serialize(&mut *serializer)?;
+                    serializer.serialize_decimal(memcomparable::Decimal::Normalized(decimal))?;
+                }
+                None => {
+                    NUMBER_FLOAT.serialize(&mut *serializer)?;
+                    n.serialize(&mut *serializer)?;
+                }
+            }
+        }
+        Value::Bool(b) => b.serialize(&mut *serializer)?,
+        Value::Array(array) => {
+            (array.len() as u32).serialize(&mut *serializer)?;
+            for v in array {
+                memcmp_serialize_value(v, serializer)?;
+            }
+        }
+        Value::Object(object) => {
+            (object.len() as u32).serialize(&mut *serializer)?;
+            for (k, v) in object {
+                k.serialize(&mut *serializer)?;
+                memcmp_serialize_value(v, serializer)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn memcmp_deserialize_value(
+    deserializer: &mut memcomparable::Deserializer<impl Buf>,
+) -> memcomparable::Result<Value> {
+    let tag = u8::deserialize(&mut *deserializer)?;
+    let value = match tag {
+        0 => Value::Null,
+        1 => Value::String(String::deserialize(&mut *deserializer)?),
+        2 => {
+            f64::deserialize(&mut *deserializer)?;
+            let text = match u8::deserialize(&mut *deserializer)? {
+                NUMBER_DECIMAL => match deserializer.deserialize_decimal()? {
+                    memcomparable::Decimal::Normalized(decimal) => decimal.to_string(),
+                    _ => return Err(memcomparable::Error::InvalidTagEncoding(tag as _)),
+                },
+                _ => String::deserialize(&mut *deserializer)?,
+            };
+            parse_number(&text).ok_or(memcomparable::Error::InvalidTagEncoding(tag as _))?
+        }
+        3 => Value::Bool(bool::deserialize(&mut *deserializer)?),
+        4 => {
+            let len = u32::deserialize(&mut *deserializer)?;
+            let mut array = Vec::with_capacity(len as usize);
+            for _ in 0..len {
+                array.push(memcmp_deserialize_value(deserializer)?);
+            }
+            Value::Array(array)
+        }
+        5 => {
+            let len = u32::deserialize(&mut *deserializer)?;
+            let mut object = BTreeMap::new();
+            for _ in 0..len {
+                let k = String::deserialize(&mut *deserializer)?;
+                object.insert(k, memcmp_deserialize_value(deserializer)?);
+            }
+            Value::Object(object)
+        }
+        _ => return Err(memcomparable::Error::InvalidTagEncoding(tag as _)),
+    };
+    Ok(value)
+}
+
+const VALUE_NULL: u8 = 0;
+const VALUE_FALSE: u8 = 1;
+const VALUE_TRUE: u8 = 2;
+const VALUE_NUMBER: u8 = 3;
+const VALUE_STRING: u8 = 4;
+const VALUE_ARRAY: u8 = 5;
+const VALUE_OBJECT: u8 = 6;
+
+/// Writes a value as a tag, followed by the length-prefixed text of a number or a string, or the
+/// length-prefixed elements of an array or an object.
+fn value_serialize_value(value: &Value, buf: &mut Vec<u8>) {
+    fn put_str(s: &str, buf: &mut Vec<u8>) {
+        buf.put_u32_le(s.len() as u32);
+        buf.put_slice(s.as_bytes());
+    }
+    match value {
+        Value::Null => buf.put_u8(VALUE_NULL),
+        Value::Bool(false) => buf.put_u8(VALUE_FALSE),
+        Value::Bool(true) => buf.put_u8(VALUE_TRUE),
+        Value::Number(n) => {
+            buf.put_u8(VALUE_NUMBER);
+            put_str(n, buf);
+        }
+        Value::String(s) => {
+            buf.put_u8(VALUE_STRING);
+            put_str(s, buf);
+        }
+        Value::Array(array) => {
+            buf.put_u8(VALUE_ARRAY);
+            buf.put_u32_le(array.len() as u32);
+            for v in array {
+                value_serialize_value(v, buf);
+            }
+        }
+        Value::Object(object) => {
+            buf.put_u8(VALUE_OBJECT);
+            buf.put_u32_le(object.len() as u32);
+            for (k, v) in object {
+                put_str(k, buf);
+                value_serialize_value(v, buf);
+            }
+        }
+    }
+}
+
+/// Reads a value written by [`value_serialize_value`], or returns `None` if the encoding is
+/// invalid.
+fn value_deserialize_value(buf: &mut &[u8]) -> Option<Value> {
+    fn get_u32(buf: &mut &[u8]) -> Option<u32> {
+        (buf.remaining() >= 4).then(|| buf.get_u32_le())
+    }
+    fn get_str(buf: &mut &[u8]) -> Option<String> {
+        let len = get_u32(buf)? as usize;
+        if buf.remaining() < len {
+            return None;
+        }
+        let s = std::str::from_utf8(&buf[..len]).ok()?.to_owned();
+        buf.advance(len);
+        Some(s)
+    }
+    if !buf.has_remaining() {
+        return None;
+    }
+    let value = match buf.get_u8() {
+        VALUE_NULL => Value::Null,
+        VALUE_FALSE => Value::Bool(false),
+        VALUE_TRUE => Value::Bool(true),
+        VALUE_NUMBER => parse_number(&get_str(buf)?)?,
+        VALUE_STRING => Value::String(get_str(buf)?),
+        VALUE_ARRAY => {
+            let len = get_u32(buf)?;
+            let mut array = Vec::with_capacity((len as usize).min(buf.remaining()));
+            for _ in 0..len {
+                array.push(value_deserialize_value(buf)?);
+            }
+            Value::Array(array)
+        }
+        VALUE_OBJECT => {
+            let len = get_u32(buf)?;
+            let mut object = BTreeMap::new();
+            for _ in 0..len {
+                let k = get_str(buf)?;
+                object.insert(k, value_deserialize_value(buf)?);
+            }
+            Value::Object(object)
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[derive(Debug)]
+pub struct JsonbArrayBuilder {
+    bitmap: BitmapBuilder,
+    data: Vec<Value>,
+}
+
+impl ArrayBuilder for JsonbArrayBuilder {
+    type ArrayType = JsonbArray;
+
+    fn with_meta(capacity: usize, _meta: ArrayMeta) -> Self {
+        Self {
+            bitmap: BitmapBuilder::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn append(&mut self, value: Option<JsonbRef<'_>>) {
+        match value {
+            Some(x) => {
+                self.bitmap.append(true);
+                self.data.push(x.0.clone());
+            }
+            None => {
+                self.bitmap.append(false);
+                self.data.push(Value::Null);
+            }
+        }
+    }
+
+    fn append_array(&mut self, other: &JsonbArray) {
+        for bit in other.bitmap.iter() {
+            self.bitmap.append(bit);
+        }
+        self.data.extend_from_slice(&other.data);
+    }
+
+    fn pop(&mut self) -> Option<()> {
+        self.data.pop().map(|_| self.bitmap.pop().unwrap())
+    }
+
+    fn finish(self) -> JsonbArray {
+        JsonbArray {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+        }
+    }
+}
+
+/// An array of `jsonb` values. The values of null slots are placeholders and never read.
+#[derive(Debug, Clone)]
+pub struct JsonbArray {
+    bitmap: Bitmap,
+    data: Vec<Value>,
+}
+
+impl EstimateSize for JsonbArray {
+    fn estimated_heap_size(&self) -> usize {
+        // FIXME: the nested values of objects and arrays are not counted.
+        self.bitmap.estimated_heap_size() + self.data.capacity() * std::mem::size_of::<Value>()
+    }
+}
+
+impl Array for JsonbArray {
+    type Builder = JsonbArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = JsonbVal;
+    type RefItem<'a> = JsonbRef<'a>;
+
+    fn value_at(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        if !self.is_null(idx) {
+            Some(JsonbRef(&self.data[idx]))
+        } else {
+            None
+        }
+    }
+
+    unsafe fn value_at_unchecked(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        if !self.is_null_unchecked(idx) {
+            Some(JsonbRef(self.data.get_unchecked(idx)))
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        ArrayIterator::new(self)
+    }
+
+    /// Each non-null value is encoded in the value encoding, prefixed by the length in `u32`.
+    fn to_protobuf(&self) -> ProstArray {
+        let mut data_buffer = Vec::new();
+        for value in self.iter().flatten() {
+            let bytes = value.value_serialize();
+            data_buffer.put_u32(bytes.len() as u32);
+            data_buffer.put_slice(&bytes);
+        }
+        ProstArray {
+            array_type: ProstArrayType::Jsonb as i32,
+            null_bitmap: Some(self.bitmap.to_protobuf()),
+            values: vec![Buffer {
+                compression: CompressionType::None as i32,
+                body: data_buffer,
+            }],
+            struct_array_data: None,
+            list_array_data: None,
+        }
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn into_null_bitmap(self) -> Bitmap {
+        self.bitmap
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    fn create_builder(&self, capacity: usize) -> ArrayBuilderImpl {
+        let array_builder = JsonbArrayBuilder::new(capacity);
+        ArrayBuilderImpl::Jsonb(array_builder)
+    }
+}
+
+impl JsonbArray {
+    pub fn from_protobuf(array: &ProstArray) -> ArrayResult<ArrayImpl> {
+        ensure!(
+            array.get_values().len() == 1,
+            "Must have only 1 buffer in a jsonb array"
+        );
+        let buf = array.get_values()[0].get_body().as_slice();
+        let bitmap: Bitmap = array.get_null_bitmap()?.into();
+
+        let mut builder = JsonbArrayBuilder::new(bitmap.len());
+        let mut cursor = Cursor::new(buf);
+        let mut bytes = Vec::new();
+        for not_null in bitmap.iter() {
+            if not_null {
+                let len = cursor
+                    .read_u32::<BigEndian>()
+                    .map_err(|e| anyhow!("failed to read jsonb length: {}", e))?;
+                bytes.resize(len as usize, 0);
+                cursor
+                    .read_exact(&mut bytes)
+                    .map_err(|e| anyhow!("failed to read jsonb value: {}", e))?;
+                let value = JsonbVal::value_deserialize(&bytes)
+                    .ok_or_else(|| anyhow!("invalid jsonb value"))?;
+                builder.append(Some(value.as_scalar_ref()));
+            } else {
+                builder.append(None);
+            }
+        }
+        Ok(builder.finish().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn jsonb(s: &str) -> JsonbVal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_jsonb_to_text() {
+        assert_eq!(
+            jsonb(r#"{"b":[1,2.5,"x"],"a":{"c":null}}"#)
+                .as_scalar_ref()
+                .to_text(),
+            r#"{"a": {"c": null}, "b": [1, 2.5, "x"]}"#
+        );
+        assert_eq!(jsonb(r#""a\"b""#).as_scalar_ref().to_text(), r#""a\"b""#);
+        assert_eq!(
+            jsonb(r#""a\"b""#).as_scalar_ref().as_text().unwrap(),
+            r#"a"b"#
+        );
+        assert_eq!(jsonb("null").as_scalar_ref().as_text(), None);
+    }
+
+    #[test]
+    fn test_jsonb_access() {
+        let v = jsonb(r#"{"a": [1, {"b": "c"}, 3]}"#);
+        let v = v.as_scalar_ref();
+        assert_eq!(v.access_object_field("a").unwrap().type_name(), "array");
+        assert_eq!(v.access_object_field("x"), None);
+        let a = v.access_object_field("a").unwrap();
+        assert_eq!(a.array_len().unwrap(), 3);
+        assert_eq!(a.access_array_element(-1).unwrap().to_text(), "3");
+        assert_eq!(a.access_array_element(-4), None);
+        assert_eq!(a.access_array_element(3), None);
+        assert_eq!(v.access_path(["a", "1", "b"]).unwrap().to_text(), r#""c""#);
+        assert_eq!(v.access_path(["a", "x"]), None);
+        assert!(v.array_len().is_err());
+    }
+
+    #[test]
+    fn test_jsonb_order() {
+        let sorted = [
+            "null",
+            r#""""#,
+            r#""a""#,
+            "-1",
+            "0",
+            "0.5",
+            "18446744073709551614",
+            "18446744073709551615",
+            "1e30",
+            "2e30",
+            "false",
+            "true",
+            "[]",
+            "[2]",
+            "[1, 2]",
+            "[1, [3]]",
+            "{}",
+            r#"{"a": 1}"#,
+            r#"{"b": 0}"#,
+            r#"{"a": 1, "b": 2}"#,
+        ]
+        .map(jsonb);
+        assert!(sorted.iter().tuple_windows().all(|(a, b)| a < b));
+
+        let encode = |v: &JsonbVal| {
+            let mut serializer = memcomparable::Serializer::new(vec![]);
+            v.as_scalar_ref().memcmp_serialize(&mut serializer).unwrap();
+            serializer.into_inner()
+        };
+        let encoded = sorted.iter().map(encode).collect_vec();
+        assert!(encoded.iter().tuple_windows().all(|(a, b)| a < b));
+        for (v, bytes) in sorted.iter().zip_eq(encoded) {
+            let mut deserializer = memcomparable::Deserializer::new(bytes.as_slice());
+            assert_eq!(&JsonbVal::memcmp_deserialize(&mut deserializer).unwrap(), v);
+        }
+        assert_eq!(encode(&jsonb("-0.0")), encode(&jsonb("0.0")));
+        assert_eq!(encode(&jsonb("1")), encode(&jsonb("1.00")));
+    }
+
+    #[test]
+    fn test_jsonb_number() {
+        // Numbers are compared by their values.
+        assert_eq!(jsonb("1"), jsonb("1.0"));
+        assert_eq!(jsonb("[1, 2e1]"), jsonb("[1.0, 20]"));
+        assert!(jsonb("0.1") < jsonb("0.10000000000000001"));
+        assert!(jsonb("9007199254740993") > jsonb("9007199254740992"));
+
+        let hash = |v: &JsonbVal| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            v.as_scalar_ref().hash_scalar_inner(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&jsonb("1")), hash(&jsonb("1.0")));
+        assert_eq!(hash(&jsonb("-0")), hash(&jsonb("0")));
+
+        // Numbers are kept in their exact text.
+        let v = jsonb("[12345678901234567890.12345678901234567890, 1.0]");
+        assert_eq!(
+            v.as_scalar_ref().to_text(),
+            "[12345678901234567890.12345678901234567890, 1.0]"
+        );
+        let decoded = JsonbVal::value_deserialize(&v.as_scalar_ref().value_serialize()).unwrap();
+        assert_eq!(decoded.to_string(), v.to_string());
+    }
+
+    #[test]
+    fn test_jsonb_serde_value() {
+        let v = jsonb(r#"{"a": [1, -2.5, "x", null, true], "b": 1e400}"#);
+        // `serde_json` can't represent numbers out of the range of `f64`.
+        assert_eq!(
+            v.as_scalar_ref().to_serde_value(),
+            serde_json::json!({"a": [1, -2.5, "x", null, true], "b": "1e400"})
+        );
+        assert_eq!(
+            JsonbVal::from(serde_json::json!({"a": [1, -2.5, "x", null, true]})),
+            jsonb(r#"{"a": [1, -2.5, "x", null, true]}"#)
+        );
+    }
+
+    #[test]
+    fn test_jsonb_value_encoding() {
+        let v = jsonb(r#"{"a": [true, false, null, "x\"y"], "b": {"c": -1.5e-3}}"#);
+        let bytes = v.as_scalar_ref().value_serialize();
+        let decoded = JsonbVal::value_deserialize(&bytes).unwrap();
+        assert_eq!(decoded.to_string(), v.to_string());
+
+        assert!(JsonbVal::value_deserialize(&bytes[..bytes.len() - 1]).is_none());
+        assert!(JsonbVal::value_deserialize(&[bytes.as_slice(), &[0]].concat()).is_none());
+        assert!(JsonbVal::value_deserialize(&[]).is_none());
+    }
+
+    #[test]
+    fn test_jsonb_array_protobuf() {
+        let mut builder = JsonbArrayBuilder::new(3);
+        builder.append(Some(jsonb(r#"{"a": [1, "x"]}"#).as_scalar_ref()));
+        builder.append(None);
+        builder.append(Some(jsonb("null").as_scalar_ref()));
+        let array = builder.finish();
+        let prost = array.to_protobuf();
+        let decoded = JsonbArray::from_protobuf(&prost).unwrap();
+        assert_eq!(decoded, ArrayImpl::Jsonb(array));
+    }
+}
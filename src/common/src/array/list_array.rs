@@ -27,6 +27,7 @@ use super::{
     Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, ArrayIterator, ArrayMeta, ArrayResult, RowRef,
 };
 use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::collection::estimate_size::EstimateSize;
 use crate::types::to_text::ToText;
 use crate::types::{
     deserialize_datum_from, hash_datum, serialize_datum_into, DataType, Datum, DatumRef, Scalar,
@@ -154,6 +155,14 @@ pub struct ListArray {
     len: usize,
 }
 
+impl EstimateSize for ListArray {
+    fn estimated_heap_size(&self) -> usize {
+        self.bitmap.estimated_heap_size()
+            + self.offsets.capacity() * std::mem::size_of::<usize>()
+            + self.value.estimated_size()
+    }
+}
+
 impl Array for ListArray {
     type Builder = ListArrayBuilder;
     type Iter<'a> = ArrayIterator<'a, Self>;
@@ -406,10 +415,23 @@ impl<'a> ListRef<'a> {
         iter_elems_ref!(self, it, { it.collect() })
     }
 
+    /// Returns the number of elements in the list, not counting the elements of nested lists.
+    pub fn len(&self) -> usize {
+        match self {
+            ListRef::Indexed { arr, idx } => arr.offsets[*idx + 1] - arr.offsets[*idx],
+            ListRef::ValueRef { val } => val.values().len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the element at the 1-based `index`, or `None` if it is out of range.
     pub fn value_at(&self, index: usize) -> ArrayResult<DatumRef<'a>> {
         match self {
             ListRef::Indexed { arr, idx } => {
-                if index <= arr.value.len() {
+                if index <= self.len() {
                     Ok(arr.value.value_at(arr.offsets[*idx] + index - 1))
                 } else {
                     Ok(None)
@@ -998,5 +1020,10 @@ mod tests {
         // Get 2nd value from ListRef
         let scalar = list_ref.value_at(2).unwrap();
         assert_eq!(scalar, Some(types::ScalarRefImpl::Int32(5)));
+
+        // Indices out of the range of a list do not reach the following lists.
+        let list_ref = arr.value_at(0).unwrap();
+        assert_eq!(list_ref.len(), 3);
+        assert_eq!(list_ref.value_at(4).unwrap(), None);
     }
 }
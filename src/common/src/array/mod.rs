@@ -24,6 +24,7 @@ mod decimal_array;
 pub mod error;
 pub mod interval_array;
 mod iterator;
+pub mod jsonb_array;
 pub mod list_array;
 mod macros;
 mod primitive_array;
@@ -49,6 +50,7 @@ pub use data_chunk_iter::RowRef;
 pub use decimal_array::{DecimalArray, DecimalArrayBuilder};
 pub use interval_array::{IntervalArray, IntervalArrayBuilder};
 pub use iterator::{ArrayImplIterator, ArrayIterator};
+pub use jsonb_array::{JsonbArray, JsonbArrayBuilder, JsonbRef, JsonbVal};
 pub use list_array::{ListArray, ListArrayBuilder, ListRef, ListValue};
 use paste::paste;
 pub use primitive_array::{PrimitiveArray, PrimitiveArrayBuilder, PrimitiveArrayItemType};
@@ -60,6 +62,7 @@ pub use vis::{Vis, VisRef};
 
 pub use self::error::ArrayError;
 use crate::buffer::Bitmap;
+use crate::collection::estimate_size::EstimateSize;
 pub use crate::row::{Row, RowDeserializer};
 use crate::types::*;
 pub type ArrayResult<T> = std::result::Result<T, ArrayError>;
@@ -261,13 +264,23 @@ trait CompactableArray: Array {
     fn compact(&self, visibility: &Bitmap, cardinality: usize) -> Self;
 }
 
+/// If no more than 1 out of `SPARSE_SELECTIVITY_FACTOR` rows is visible, `compact` gathers the
+/// visible rows by the set bits of the visibility instead of zipping it with the whole array.
+const SPARSE_SELECTIVITY_FACTOR: usize = 8;
+
 impl<A: Array> CompactableArray for A {
     fn compact(&self, visibility: &Bitmap, cardinality: usize) -> Self {
         use itertools::Itertools;
         let mut builder = A::Builder::with_meta(cardinality, self.array_meta());
-        for (elem, visible) in self.iter().zip_eq(visibility.iter()) {
-            if visible {
-                builder.append(elem);
+        if cardinality * SPARSE_SELECTIVITY_FACTOR <= visibility.len() {
+            for idx in visibility.ones() {
+                builder.append(self.value_at(idx));
+            }
+        } else {
+            for (elem, visible) in self.iter().zip_eq(visibility.iter()) {
+                if visible {
+                    builder.append(elem);
+                }
             }
         }
         builder.finish()
@@ -299,7 +312,8 @@ macro_rules! for_all_variants {
             { NaiveDateTime, naivedatetime, NaiveDateTimeArray, NaiveDateTimeArrayBuilder },
             { NaiveTime, naivetime, NaiveTimeArray, NaiveTimeArrayBuilder },
             { Struct, struct, StructArray, StructArrayBuilder },
-            { List, list, ListArray, ListArrayBuilder }
+            { List, list, ListArray, ListArrayBuilder },
+            { Jsonb, jsonb, JsonbArray, JsonbArrayBuilder }
         }
     };
 }
@@ -347,6 +361,12 @@ impl From<ListArray> for ArrayImpl {
     }
 }
 
+impl From<JsonbArray> for ArrayImpl {
+    fn from(arr: JsonbArray) -> Self {
+        Self::Jsonb(arr)
+    }
+}
+
 /// `impl_convert` implements several conversions for `Array` and `ArrayBuilder`.
 /// * `ArrayImpl -> &Array` with `impl.as_int16()`.
 /// * `ArrayImpl -> Array` with `impl.into_int16()`.
@@ -581,6 +601,14 @@ macro_rules! impl_array {
                 }
             }
         }
+
+        impl EstimateSize for ArrayImpl {
+            fn estimated_heap_size(&self) -> usize {
+                match self {
+                    $( Self::$variant_name(inner) => inner.estimated_heap_size(), )*
+                }
+            }
+        }
     }
 }
 
@@ -617,6 +645,7 @@ impl ArrayImpl {
             ProstArrayType::Interval => read_interval_unit_array(array, cardinality)?,
             ProstArrayType::Struct => StructArray::from_protobuf(array)?,
             ProstArrayType::List => ListArray::from_protobuf(array)?,
+            ProstArrayType::Jsonb => JsonbArray::from_protobuf(array)?,
             ProstArrayType::Unspecified => unreachable!(),
         };
         Ok(array)
@@ -661,6 +690,20 @@ mod tests {
         assert_eq!(array.iter().collect::<Vec<Option<i32>>>(), vec![Some(60)]);
     }
 
+    #[test]
+    fn test_compact() {
+        let array = I32Array::from_slice(&(0..64).map(Some).collect_vec());
+        // Both the sparse and the dense path give the same result.
+        for step in [1, 2, 16] {
+            let visibility: Bitmap = (0..64).map(|i| i % step == 0).collect();
+            let compacted = array.compact(&visibility, visibility.num_high_bits());
+            assert_eq!(
+                compacted.iter().collect_vec(),
+                (0..64).step_by(step).map(Some).collect_vec()
+            );
+        }
+    }
+
     use num_traits::cast::AsPrimitive;
     use num_traits::ops::checked::CheckedAdd;
 
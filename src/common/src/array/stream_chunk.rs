@@ -12,14 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 use itertools::Itertools;
 use risingwave_pb::data::{Op as ProstOp, StreamChunk as ProstStreamChunk};
 
 use super::{ArrayResult, DataChunkTestExt};
 use crate::array::column::Column;
-use crate::array::{DataChunk, Vis};
+use crate::array::{DataChunk, RowRef, Vis};
 use crate::buffer::Bitmap;
 use crate::row::{Row, Row2};
 use crate::types::to_text::ToText;
@@ -165,14 +167,87 @@ impl StreamChunk {
             })
             .collect();
         let mut new_ops = Vec::with_capacity(cardinality);
-        for (op, visible) in ops.into_iter().zip_eq(visibility.iter()) {
-            if visible {
-                new_ops.push(op);
+        for (i, (&op, visible)) in ops.iter().zip_eq(visibility.iter()).enumerate() {
+            if !visible {
+                continue;
             }
+            // If only one row of an update pair is visible, it becomes a plain insert or delete, so
+            // that the update rows in the compacted chunk still come in adjacent pairs.
+            let op = match op {
+                Op::UpdateDelete
+                    if !(ops.get(i + 1) == Some(&Op::UpdateInsert) && visibility.is_set(i + 1)) =>
+                {
+                    Op::Delete
+                }
+                Op::UpdateInsert
+                    if !(i > 0 && ops[i - 1] == Op::UpdateDelete && visibility.is_set(i - 1)) =>
+                {
+                    Op::Insert
+                }
+                op => op,
+            };
+            new_ops.push(op);
         }
         StreamChunk::new(new_ops, columns, None)
     }
 
+    /// Cancels out an insert and a later delete of an identical row in the chunk, as well as a
+    /// delete and a later insert of an identical row, since the pair has no effect. Update rows are
+    /// treated as inserts and deletes. Returns the compacted chunk, or the chunk as is if no row is
+    /// cancelled out.
+    pub fn cancel_out(self) -> Self {
+        let n = self.capacity();
+        let mut visibility = match self.visibility() {
+            Some(bitmap) => bitmap.iter().collect_vec(),
+            None => vec![true; n],
+        };
+        let is_insert = |i: usize| matches!(self.ops[i], Op::Insert | Op::UpdateInsert);
+
+        // Most chunks are insert-only or delete-only, and have nothing to cancel out.
+        let (mut has_insert, mut has_delete) = (false, false);
+        for i in (0..n).filter(|&i| visibility[i]) {
+            if is_insert(i) {
+                has_insert = true;
+            } else {
+                has_delete = true;
+            }
+        }
+        if !(has_insert && has_delete) {
+            return self;
+        }
+
+        let cancelled = {
+            // The rows not cancelled out yet, with whether they are inserts. All rows in each list
+            // are of the same kind, as an opposite row cancels out the last one instead.
+            let mut pending: HashMap<RowKey<'_>, Vec<(usize, bool)>> = HashMap::new();
+            let mut cancelled = false;
+            for i in 0..n {
+                if !visibility[i] {
+                    continue;
+                }
+                let is_insert = is_insert(i);
+                let rows = pending
+                    .entry(RowKey(self.data.row_at_unchecked_vis(i)))
+                    .or_default();
+                match rows.last() {
+                    Some(&(j, last_is_insert)) if last_is_insert != is_insert => {
+                        rows.pop();
+                        visibility[i] = false;
+                        visibility[j] = false;
+                        cancelled = true;
+                    }
+                    _ => rows.push((i, is_insert)),
+                }
+            }
+            cancelled
+        };
+        if !cancelled {
+            return self;
+        }
+        let (ops, columns, _) = self.into_inner();
+        StreamChunk::new(ops, columns, Some(visibility.into_iter().collect())).compact()
+    }
+
     pub fn into_parts(self) -> (DataChunk, Vec<Op>) {
         (self.data, self.ops)
     }
@@ -274,6 +349,17 @@ impl StreamChunk {
     }
 }
 
+/// A row of a chunk as a key of a hash map. It is hashed and compared by its values, without
+/// copying them into an owned row.
+#[derive(PartialEq, Eq)]
+struct RowKey<'a>(RowRef<'a>);
+
+impl Hash for RowKey<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.values().for_each(|datum| datum.hash(state));
+    }
+}
+
 impl fmt::Debug for StreamChunk {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -445,4 +531,70 @@ mod tests {
 +----+---+---+"
         );
     }
+
+    #[test]
+    fn test_cancel_out() {
+        let chunk = StreamChunk::from_pretty(
+            "  I I
+             + 1 1
+             + 2 2
+             - 1 1
+            U- 2 2
+            U+ 2 3
+             - 3 3 D
+             + 3 3
+             - 4 4
+             + 4 4
+             - 4 4",
+        );
+        assert_eq!(
+            chunk.cancel_out(),
+            StreamChunk::from_pretty(
+                "  I I
+                 + 2 3
+                 + 3 3
+                 - 4 4",
+            )
+        );
+
+        // Chunks with nothing to cancel out are returned as is, including invisible rows.
+        for pretty in [
+            "  I I
+             + 1 1
+             + 1 1 D",
+            "  I I
+             - 1 1 D
+             + 1 1",
+            "  I I
+             + 1 1
+             - 2 2",
+        ] {
+            let chunk = StreamChunk::from_pretty(pretty);
+            assert_eq!(chunk.clone().cancel_out(), chunk);
+        }
+    }
+
+    #[test]
+    fn test_compact_update_pairs() {
+        let chunk = StreamChunk::from_pretty(
+            "  I
+            U- 1
+            U+ 2 D
+            U- 3 D
+            U+ 4
+            U- 5
+            U+ 6
+             + 7 D",
+        );
+        assert_eq!(
+            chunk.compact(),
+            StreamChunk::from_pretty(
+                "  I
+                 - 1
+                 + 4
+                U- 5
+                U+ 6",
+            )
+        );
+    }
 }
@@ -27,6 +27,7 @@ use super::{
 };
 use crate::array::ArrayRef;
 use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::collection::estimate_size::EstimateSize;
 use crate::types::to_text::ToText;
 use crate::types::{
     deserialize_datum_from, hash_datum, serialize_datum_into, DataType, Datum, DatumRef, Scalar,
@@ -152,6 +153,17 @@ impl StructArrayBuilder {
     }
 }
 
+impl EstimateSize for StructArray {
+    fn estimated_heap_size(&self) -> usize {
+        self.bitmap.estimated_heap_size()
+            + self
+                .children
+                .iter()
+                .map(|child| child.estimated_size())
+                .sum::<usize>()
+    }
+}
+
 impl Array for StructArray {
     type Builder = StructArrayBuilder;
     type Iter<'a> = ArrayIterator<'a, Self>;
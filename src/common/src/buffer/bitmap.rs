@@ -40,6 +40,7 @@ use itertools::Itertools;
 use risingwave_pb::common::buffer::CompressionType;
 use risingwave_pb::common::Buffer as ProstBuffer;
 
+use crate::collection::estimate_size::EstimateSize;
 use crate::util::bit_util;
 
 #[derive(Default, Debug)]
@@ -215,7 +216,23 @@ impl Bitmap {
 
     /// Return the next set bit index on or after `bit_idx`.
     pub fn next_set_bit(&self, bit_idx: usize) -> Option<usize> {
-        (bit_idx..self.len()).find(|&idx| unsafe { self.is_set_unchecked(idx) })
+        if bit_idx >= self.len() {
+            return None;
+        }
+        // Skip a whole byte at a time if none of its bits is set.
+        let mut byte_idx = bit_idx / 8;
+        let mut byte = self.bits[byte_idx] & (0xffu8 << (bit_idx % 8));
+        loop {
+            if byte != 0 {
+                let idx = byte_idx * 8 + byte.trailing_zeros() as usize;
+                return (idx < self.len()).then_some(idx);
+            }
+            byte_idx += 1;
+            if byte_idx >= self.bits.len() {
+                return None;
+            }
+            byte = self.bits[byte_idx];
+        }
     }
 
     pub fn num_high_bits(&self) -> usize {
@@ -291,11 +308,26 @@ impl Bitmap {
         Bitmap::from_bytes_with_num_bits(bits, lhs.num_bits)
     }
 
+    /// Returns an iterator over the indices of the set bits. Bytes with no bit set are skipped as
+    /// a whole, so this is much faster than checking the bits one by one on sparse bitmaps.
     pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
-        self.iter()
+        let num_bits = self.num_bits;
+        self.bits
+            .iter()
             .enumerate()
-            .filter(|(_, bit)| *bit)
-            .map(|(pos, _)| pos)
+            .flat_map(|(byte_idx, &byte)| {
+                let mut byte = byte;
+                std::iter::from_fn(move || {
+                    if byte == 0 {
+                        return None;
+                    }
+                    let bit_idx = byte.trailing_zeros() as usize;
+                    // Clear the lowest set bit.
+                    byte &= byte - 1;
+                    Some(byte_idx * 8 + bit_idx)
+                })
+            })
+            .take_while(move |&idx| idx < num_bits)
     }
 
     #[cfg(test)]
@@ -462,6 +494,12 @@ impl PartialEq for Bitmap {
     }
 }
 
+impl EstimateSize for Bitmap {
+    fn estimated_heap_size(&self) -> usize {
+        self.bits.len()
+    }
+}
+
 pub struct BitmapIter<'a> {
     bits: &'a Bytes,
     idx: usize,
@@ -643,6 +681,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bitmap_ones() {
+        let bitmap = Bitmap::from_bytes(Bytes::from_static(&[0b01001010, 0, 0b10000001]));
+        assert_eq!(bitmap.ones().collect_vec(), vec![1, 3, 6, 16, 23]);
+        assert_eq!(bitmap.next_set_bit(0), Some(1));
+        assert_eq!(bitmap.next_set_bit(4), Some(6));
+        assert_eq!(bitmap.next_set_bit(7), Some(16));
+        assert_eq!(bitmap.next_set_bit(17), Some(23));
+        assert_eq!(bitmap.next_set_bit(24), None);
+
+        // Bits beyond the length are ignored.
+        let bitmap: Bitmap = [false, true, false, false, true].into_iter().collect();
+        assert_eq!(bitmap.ones().collect_vec(), vec![1, 4]);
+        assert_eq!(bitmap.next_set_bit(2), Some(4));
+        assert_eq!(bitmap.next_set_bit(5), None);
+
+        let bitmap = Bitmap::all_high_bits(10);
+        assert_eq!(bitmap.ones().collect_vec(), (0..10).collect_vec());
+        assert_eq!(bitmap.next_set_bit(9), Some(9));
+    }
+
     #[test]
     fn test_bitmap_from_protobuf() {
         let bitmap_bytes = vec![3u8 /* len % 8 */, 0b0101_0010, 0b110];
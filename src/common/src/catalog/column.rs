@@ -117,7 +117,7 @@ impl ColumnDesc {
     }
 
     /// Find `column_desc` in `field_descs` by name.
-    pub fn field(&self, name: &String) -> crate::error::Result<(ColumnDesc, i32)> {
+    pub fn field(&self, name: &str) -> crate::error::Result<(ColumnDesc, i32)> {
         if let DataType::Struct { .. } = self.data_type {
             for (index, col) in self.field_descs.iter().enumerate() {
                 if col.name == *name {
@@ -254,4 +254,23 @@ pub mod tests {
         let desc: ColumnDesc = build_prost_desc().into();
         assert_eq!(desc, build_desc());
     }
+
+    #[test]
+    fn test_field_lookup() {
+        let desc = build_desc();
+        let (city, index) = desc.field("country.city").unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(city.column_id.get_id(), 4);
+        assert!(desc.field("country.unknown").is_err());
+        assert!(city.field_descs[0].field("address").is_err());
+
+        let flattened = desc.flatten();
+        assert_eq!(
+            flattened
+                .iter()
+                .map(|d| d.column_id.get_id())
+                .collect::<Vec<_>>(),
+            vec![5, 1, 4, 2, 3]
+        );
+    }
 }
@@ -69,6 +69,22 @@ impl DatabaseId {
     pub fn placeholder() -> i32 {
         i32::MAX - 1
     }
+
+    pub fn database_id(&self) -> i32 {
+        self.database_id
+    }
+}
+
+impl From<i32> for DatabaseId {
+    fn from(id: i32) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<DatabaseId> for i32 {
+    fn from(id: DatabaseId) -> Self {
+        id.database_id
+    }
 }
 
 #[derive(Clone, Debug, Default, Hash, PartialOrd, PartialEq, Eq)]
@@ -84,6 +100,22 @@ impl SchemaId {
     pub fn placeholder() -> i32 {
         i32::MAX - 1
     }
+
+    pub fn schema_id(&self) -> i32 {
+        self.schema_id
+    }
+}
+
+impl From<i32> for SchemaId {
+    fn from(id: i32) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<SchemaId> for i32 {
+    fn from(id: SchemaId) -> Self {
+        id.schema_id
+    }
 }
 
 #[derive(Clone, Copy, Debug, Display, Default, Hash, PartialOrd, PartialEq, Eq, Ord)]
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -58,6 +59,15 @@ pub struct ServerConfig {
 
     #[serde(default = "default::connection_pool_size")]
     pub connection_pool_size: u16,
+
+    /// TLS of the gRPC channels between nodes, including the exchange of batch and streaming
+    /// data. The channels are unencrypted if not set.
+    #[serde(default)]
+    pub tls: Option<GrpcTlsConfig>,
+
+    /// Whether to refuse to start without `tls`, so that no traffic between nodes is unencrypted.
+    #[serde(default)]
+    pub require_tls: bool,
 }
 
 impl Default for ServerConfig {
@@ -66,6 +76,40 @@ impl Default for ServerConfig {
     }
 }
 
+impl ServerConfig {
+    /// Returns the TLS config of the gRPC channels between nodes, or an error if TLS is required
+    /// but not configured.
+    pub fn grpc_tls(&self) -> Result<Option<&GrpcTlsConfig>> {
+        if self.require_tls && self.tls.is_none() {
+            return Err(InternalError(
+                "`server.require_tls` is set but `server.tls` is not configured".to_owned(),
+            )
+            .into());
+        }
+        Ok(self.tls.as_ref())
+    }
+}
+
+/// The PEM files for mutual TLS between nodes. Every node serves with its own certificate, and
+/// only trusts the peers whose certificates are signed by the CA.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GrpcTlsConfig {
+    /// The path of the certificate of this node.
+    pub cert: String,
+
+    /// The path of the private key of this node.
+    pub key: String,
+
+    /// The path of the CA certificate that signs the certificates of all the nodes.
+    pub ca_cert: String,
+
+    /// The name to verify the certificates of the peers against, instead of their host names.
+    /// Useful when all the nodes share a certificate.
+    #[serde(default)]
+    pub domain_name: Option<String>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BatchConfig {
     /// The thread number of the batch task runtime in the compute node. The default value is
@@ -75,6 +119,16 @@ pub struct BatchConfig {
 
     #[serde(default)]
     pub developer: DeveloperConfig,
+
+    /// The resource groups that batch tasks can be admitted to, by name. The tasks of a query are
+    /// admitted to the group in the session variable `RW_RESOURCE_GROUP`, or run without limits
+    /// if it is not set.
+    #[serde(default)]
+    pub resource_groups: HashMap<String, ResourceGroupConfig>,
+
+    /// The memory that the memory shares of resource groups are taken from.
+    #[serde(default = "default::total_memory_available_bytes")]
+    pub total_memory_available_bytes: usize,
 }
 
 impl Default for BatchConfig {
@@ -83,6 +137,34 @@ impl Default for BatchConfig {
     }
 }
 
+/// The limits of the batch queries of a resource group on each compute node. All the tasks of a
+/// query on a compute node are admitted together, so that they never wait for each other.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResourceGroupConfig {
+    /// The maximum number of queries of the group running tasks at the same time.
+    pub max_concurrent_queries: usize,
+
+    /// The maximum number of queries of the group waiting for a running one to terminate. More
+    /// queries are rejected.
+    #[serde(default)]
+    pub max_queued_queries: usize,
+
+    /// How long a query waits in the queue before it is rejected. A query admitted on one compute
+    /// node can be queued on another behind a query that is waiting for it, so the wait must be
+    /// bounded.
+    #[serde(default = "default::resource_group_queue_timeout_ms")]
+    pub queue_timeout_ms: u64,
+
+    /// The share of the memory of the compute node, between 0 and 1, that the running queries of
+    /// the group may hold. No more queries are admitted to a group over its share, but running
+    /// queries are not stopped when they grow past it. Only enforced when the compute node is
+    /// built with the `task-local-alloc` feature, which accounts the memory of batch tasks.
+    /// Otherwise it is ignored with a warning.
+    #[serde(default)]
+    pub memory_share: Option<f64>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct StreamingConfig {
@@ -268,10 +350,38 @@ pub struct DeveloperConfig {
     #[serde(default = "default::developer::batch_output_channel_size")]
     pub batch_output_channel_size: usize,
 
+    /// The number of chunks buffered for each output of a blocking exchange. The task waits for
+    /// the consumer once its channel is full. The chunks already fetched are retained for re-reads
+    /// from the beginning as long as the output fits in this number of chunks.
+    #[serde(default = "default::developer::batch_blocking_output_buffer_size")]
+    pub batch_blocking_output_buffer_size: usize,
+
+    /// The number of chunks a remote batch exchange grants the upstream task to send at a time.
+    /// More chunks are only requested after these have been consumed. Set to 0 to stream the
+    /// whole output with only the back-pressure of gRPC.
+    #[serde(default = "default::developer::batch_exchange_chunk_credits")]
+    pub batch_exchange_chunk_credits: u32,
+
     /// The size of a chunk produced by `RowSeqScanExecutor`
     #[serde(default = "default::developer::batch_chunk_size")]
     pub batch_chunk_size: usize,
 
+    /// The target size in bytes of a chunk produced by sort and projection, and sent by exchange
+    /// senders. Chunks of wide rows will contain fewer rows than `batch_chunk_size` to stay within
+    /// this budget. Set to 0 to disable.
+    #[serde(default = "default::developer::batch_chunk_target_bytes")]
+    pub batch_chunk_target_bytes: usize,
+
+    /// Whether `SortExecutor` encodes the sort keys of input chunks on the blocking thread pool
+    /// as they arrive, overlapping the encoding with the execution of its input.
+    #[serde(default = "default::developer::batch_sort_parallel_encoding")]
+    pub batch_sort_parallel_encoding: bool,
+
+    /// The capacity in bytes of the cache of the outputs of read-only batch tasks, keyed by their
+    /// plan and snapshot epoch. Set to 0 to disable.
+    #[serde(default = "default::developer::batch_result_cache_capacity")]
+    pub batch_result_cache_capacity: usize,
+
     /// Set to true to enable per-executor row count metrics. This will produce a lot of timeseries
     /// and might affect the prometheus performance. If you only need actor input and output
     /// rows data, see `stream_actor_in_record_cnt` and `stream_actor_out_record_cnt` instead.
@@ -299,9 +409,27 @@ pub struct DeveloperConfig {
     #[serde(default = "default::developer::unsafe_stream_extreme_cache_size")]
     pub unsafe_stream_extreme_cache_size: usize,
 
+    /// Limit number of the cached entries (one per group key) in a group top-n executor.
+    #[serde(default = "default::developer::unsafe_stream_group_top_n_cache_size")]
+    pub unsafe_stream_group_top_n_cache_size: usize,
+
     /// The maximum size of the chunk produced by executor at a time.
     #[serde(default = "default::developer::stream_chunk_size")]
     pub stream_chunk_size: usize,
+
+    /// The maximum rows per second a source reads from each of its splits. Set to 0 to disable.
+    /// Can be changed for a source at runtime with `risectl meta set-source-rate-limit`.
+    #[serde(default = "default::developer::stream_source_rate_limit_rows_per_second")]
+    pub stream_source_rate_limit_rows_per_second: u64,
+
+    /// The maximum bytes per second a source reads from each of its splits, estimated by the
+    /// encoded size of the rows. Set to 0 to disable.
+    #[serde(default = "default::developer::stream_source_rate_limit_bytes_per_second")]
+    pub stream_source_rate_limit_bytes_per_second: u64,
+
+    /// The milliseconds of the rate limits that a source can read in a burst after being idle.
+    #[serde(default = "default::developer::stream_source_rate_limit_burst_ms")]
+    pub stream_source_rate_limit_burst_ms: u64,
 }
 
 impl Default for DeveloperConfig {
@@ -447,15 +575,39 @@ mod default {
         false
     }
 
+    pub fn resource_group_queue_timeout_ms() -> u64 {
+        60_000
+    }
+
     pub mod developer {
         pub fn batch_output_channel_size() -> usize {
             64
         }
 
+        pub fn batch_blocking_output_buffer_size() -> usize {
+            1024
+        }
+
+        pub fn batch_exchange_chunk_credits() -> u32 {
+            64
+        }
+
         pub fn batch_chunk_size() -> usize {
             1024
         }
 
+        pub fn batch_chunk_target_bytes() -> usize {
+            4 << 20
+        }
+
+        pub fn batch_sort_parallel_encoding() -> bool {
+            false
+        }
+
+        pub fn batch_result_cache_capacity() -> usize {
+            0
+        }
+
         pub fn stream_enable_executor_row_count() -> bool {
             false
         }
@@ -480,9 +632,25 @@ mod default {
             1 << 10
         }
 
+        pub fn unsafe_stream_group_top_n_cache_size() -> usize {
+            1 << 16
+        }
+
         pub fn stream_chunk_size() -> usize {
             1024
         }
+
+        pub fn stream_source_rate_limit_rows_per_second() -> u64 {
+            0
+        }
+
+        pub fn stream_source_rate_limit_bytes_per_second() -> u64 {
+            0
+        }
+
+        pub fn stream_source_rate_limit_burst_ms() -> u64 {
+            1000
+        }
     }
 }
 
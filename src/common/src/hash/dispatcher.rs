@@ -102,6 +102,7 @@ fn hash_key_size(data_type: &DataType) -> HashKeySize {
         DataType::Varchar => HashKeySize::Variable,
         DataType::Struct { .. } => HashKeySize::Variable,
         DataType::List { .. } => HashKeySize::Variable,
+        DataType::Jsonb => HashKeySize::Variable,
     }
 }
 
@@ -32,18 +32,18 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 
 use crate::array::{
-    Array, ArrayBuilder, ArrayBuilderImpl, ArrayError, ArrayImpl, ArrayResult, DataChunk, ListRef,
-    StructRef,
+    Array, ArrayBuilder, ArrayBuilderImpl, ArrayError, ArrayImpl, ArrayResult, DataChunk, JsonbRef,
+    ListRef, StructRef,
 };
 use crate::collection::estimate_size::EstimateSize;
 use crate::hash::vnode::VirtualNode;
 use crate::row::Row;
 use crate::types::{
-    DataType, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper,
-    OrderedF32, OrderedF64, ScalarRef, ToOwnedDatum,
+    deserialize_datum_from, serialize_datum_into, DataType, DatumRef, Decimal, IntervalUnit,
+    NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarRef,
+    ToOwnedDatum,
 };
 use crate::util::hash_util::Crc32FastBuilder;
-use crate::util::value_encoding::{deserialize_datum, serialize_datum};
 
 /// A wrapper for u64 hash result.
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
@@ -179,10 +179,14 @@ pub struct FixedSizeKey<const N: usize> {
 
 /// Designed for hash keys which can't be represented by [`FixedSizeKey`].
 ///
+/// The key columns are encoded with memcomparable, which is also the encoding of the primary key
+/// of state tables. So two group keys are equal here iff they are equal in the storage, e.g.,
+/// `-0.0` and `0.0`, or `1.2` and `1.20`, are the same key.
+///
 /// See [`crate::hash::calc_hash_key_kind`]
 #[derive(Clone, Debug)]
 pub struct SerializedKey {
-    // Key encoding.
+    // Memcomparable key encoding.
     key: Vec<u8>,
     hash_code: u64,
     null_bitmap: FixedBitSet,
@@ -489,6 +493,20 @@ impl<'a> HashKeySerDe<'a> for ListRef<'a> {
     }
 }
 
+impl<'a> HashKeySerDe<'a> for JsonbRef<'a> {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        todo!()
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        todo!()
+    }
+}
+
 pub struct FixedSizeKeySerializer<const N: usize> {
     buffer: [u8; N],
     null_bitmap: FixedBitSet,
@@ -571,7 +589,7 @@ impl<const N: usize> HashKeyDeserializer for FixedSizeKeyDeserializer<N> {
 }
 
 pub struct SerializedKeySerializer {
-    buffer: Vec<u8>,
+    serializer: memcomparable::Serializer<Vec<u8>>,
     hash_code: u64,
     null_bitmap: FixedBitSet,
 }
@@ -581,7 +599,7 @@ impl HashKeySerializer for SerializedKeySerializer {
 
     fn from_hash_code(hash_code: HashCode) -> Self {
         Self {
-            buffer: Vec::new(),
+            serializer: memcomparable::Serializer::new(Vec::new()),
             hash_code: hash_code.0,
             null_bitmap: FixedBitSet::new(),
         }
@@ -590,20 +608,16 @@ impl HashKeySerializer for SerializedKeySerializer {
     fn append<'a, D: HashKeySerDe<'a>>(&mut self, data: Option<D>) {
         let len_bitmap = self.null_bitmap.len();
         self.null_bitmap.grow(len_bitmap + 1);
-        match data {
-            Some(v) => {
-                serialize_datum(&Some(v.to_owned_scalar().into()), &mut self.buffer);
-            }
-            None => {
-                serialize_datum(&None, &mut self.buffer);
-                self.null_bitmap.insert(len_bitmap);
-            }
+        if data.is_none() {
+            self.null_bitmap.insert(len_bitmap);
         }
+        let datum: DatumRef<'_> = data.map(Into::into);
+        serialize_datum_into(datum, &mut self.serializer).expect("Failed to serialize hash key!");
     }
 
     fn into_hash_key(self) -> SerializedKey {
         SerializedKey {
-            key: self.buffer,
+            key: self.serializer.into_inner(),
             hash_code: self.hash_code,
             null_bitmap: self.null_bitmap,
         }
@@ -691,10 +705,10 @@ impl HashKey for SerializedKey {
         array_builders: &mut [ArrayBuilderImpl],
         data_types: &[DataType],
     ) -> ArrayResult<()> {
-        let mut key_buffer = self.key.as_slice();
+        let mut deserializer = memcomparable::Deserializer::new(self.key.as_slice());
         for (datum_result, array_builder) in data_types
             .iter()
-            .map(|ty| deserialize_datum(&mut key_buffer, ty))
+            .map(|ty| deserialize_datum_from(ty, &mut deserializer))
             .zip_eq(array_builders.iter_mut())
         {
             array_builder.append_datum(&datum_result.map_err(ArrayError::internal)?);
@@ -916,6 +930,38 @@ mod tests {
         do_test::<Key128, _>(vec![0], generate_decimal_test_data);
     }
 
+    #[test]
+    fn test_serialized_key_memcomparable() {
+        let columns = vec![
+            array! { F64Array, [Some(0.0), Some(-0.0), Some(1.0), None] }.into(),
+            array! { DecimalArray, [
+                Some(Decimal::from_str("1.2").unwrap()),
+                Some(Decimal::from_str("1.200").unwrap()),
+                Some(Decimal::from_str("1.2").unwrap()),
+                None
+            ]}
+            .into(),
+        ];
+        let data = DataChunk::new(columns, 4);
+        let keys = KeySerialized::build(&[0, 1], &data).unwrap();
+
+        // `0.0` and `-0.0`, `1.2` and `1.200` are the same group.
+        assert_eq!(keys[0], keys[1]);
+        assert_ne!(keys[0], keys[2]);
+        assert!(!keys[0].has_null());
+        assert!(keys[3].has_null());
+
+        // The key is exactly the memcomparable encoding of the key columns.
+        for (row_idx, key) in keys.iter().enumerate() {
+            let mut serializer = memcomparable::Serializer::new(vec![]);
+            for column in data.columns() {
+                serialize_datum_into(column.array_ref().value_at(row_idx), &mut serializer)
+                    .unwrap();
+            }
+            assert_eq!(key.key, serializer.into_inner());
+        }
+    }
+
     // Simple test to ensure a row <None, Some(2)> will be serialized and restored
     // losslessly.
     #[test]
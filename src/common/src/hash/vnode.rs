@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use parse_display::Display;
 
+use crate::buffer::{Bitmap, BitmapBuilder};
 use crate::hash::HashCode;
 
 /// Parallel unit is the minimal scheduling unit.
@@ -21,6 +24,23 @@ use crate::hash::HashCode;
 pub type ParallelUnitId = u32;
 pub type VnodeMapping = Vec<ParallelUnitId>;
 
+/// Converts a vnode mapping into the bitmaps of vnodes owned by each parallel unit.
+pub fn vnode_mapping_to_bitmaps(
+    vnode_mapping: &[ParallelUnitId],
+) -> HashMap<ParallelUnitId, Bitmap> {
+    let mut builders: HashMap<ParallelUnitId, BitmapBuilder> = HashMap::new();
+    for (vnode, parallel_unit_id) in vnode_mapping.iter().enumerate() {
+        builders
+            .entry(*parallel_unit_id)
+            .or_insert_with(|| BitmapBuilder::zeroed(vnode_mapping.len()))
+            .set(vnode, true);
+    }
+    builders
+        .into_iter()
+        .map(|(id, builder)| (id, builder.finish()))
+        .collect()
+}
+
 /// `VirtualNode` (a.k.a. VNode) is a minimal partition that a set of keys belong to. It is used for
 /// consistent hashing.
 #[repr(transparent)]
@@ -21,7 +21,7 @@ use crate::collection::estimate_size::EstimateSize;
 use crate::types::{DataType, Datum, DatumRef, ToDatumRef};
 use crate::util::ordered::OrderedRowSerde;
 use crate::util::value_encoding;
-use crate::util::value_encoding::deserialize_datum;
+use crate::util::value_encoding::{deserialize_datum, skip_datum};
 
 /// TODO(row trait): rename to `OwnedRow`.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
@@ -149,6 +149,45 @@ impl RowDeserializer {
         Ok(Row(values))
     }
 
+    /// Deserialize only the columns at `indices` from value encoding bytes. The other columns are
+    /// skipped without being materialized, and decoding stops after the last requested column.
+    pub fn deserialize_projected(
+        &self,
+        mut data: impl bytes::Buf,
+        indices: &[usize],
+    ) -> value_encoding::Result<Row> {
+        let Some(&last) = indices.iter().max() else {
+            return Ok(Row::default());
+        };
+        // Number of references to each column in `indices`.
+        let mut refs = vec![0usize; last + 1];
+        for &i in indices {
+            refs[i] += 1;
+        }
+        let mut values = Vec::with_capacity(last + 1);
+        for (typ, &r) in self.data_types[..=last].iter().zip(&refs) {
+            if r > 0 {
+                values.push(deserialize_datum(&mut data, typ)?);
+            } else {
+                skip_datum(&mut data, typ)?;
+                values.push(None);
+            }
+        }
+        // Move out the datums referenced once and clone the others.
+        let projected = indices
+            .iter()
+            .map(|&i| {
+                refs[i] -= 1;
+                if refs[i] == 0 {
+                    values[i].take()
+                } else {
+                    values[i].clone()
+                }
+            })
+            .collect();
+        Ok(Row(projected))
+    }
+
     pub fn data_types(&self) -> &[DataType] {
         &self.data_types
     }
@@ -191,6 +230,12 @@ mod tests {
         ]);
         let row1 = de.deserialize(bytes.as_ref()).unwrap();
         assert_eq!(row, row1);
+
+        let indices = [8, 0, 3, 0];
+        let row2 = de.deserialize_projected(bytes.as_ref(), &indices).unwrap();
+        assert_eq!((&row).project(&indices).into_owned_row(), row2);
+        let row3 = de.deserialize_projected(bytes.as_ref(), &[]).unwrap();
+        assert_eq!(Row::default(), row3);
     }
 
     #[test]
@@ -14,6 +14,7 @@
 
 mod query_mode;
 mod search_path;
+mod timezone;
 mod transaction_isolation_level;
 
 use std::ops::Deref;
@@ -21,13 +22,14 @@ use std::ops::Deref;
 use itertools::Itertools;
 pub use query_mode::QueryMode;
 pub use search_path::{SearchPath, USER_NAME_WILD_CARD};
+pub use timezone::{Timezone, DEFAULT_TIMEZONE, TIMEZONE_KEY};
 
 use crate::error::{ErrorCode, RwError};
 use crate::session_config::transaction_isolation_level::IsolationLevel;
 
 // This is a hack, &'static str is not allowed as a const generics argument.
 // TODO: refine this using the adt_const_params feature.
-const CONFIG_KEYS: [&str; 10] = [
+const CONFIG_KEYS: [&str; 13] = [
     "RW_IMPLICIT_FLUSH",
     "CREATE_COMPACTION_GROUP_FOR_MV",
     "QUERY_MODE",
@@ -38,6 +40,9 @@ const CONFIG_KEYS: [&str; 10] = [
     "MAX_SPLIT_RANGE_GAP",
     "SEARCH_PATH",
     "TRANSACTION ISOLATION LEVEL",
+    "TIMEZONE",
+    "RW_RESOURCE_GROUP",
+    "RW_BATCH_BLOCKING_EXCHANGE",
 ];
 
 // MUST HAVE 1v1 relationship to CONFIG_KEYS. e.g. CONFIG_KEYS[IMPLICIT_FLUSH] =
@@ -52,6 +57,9 @@ const BATCH_ENABLE_LOOKUP_JOIN: usize = 6;
 const MAX_SPLIT_RANGE_GAP: usize = 7;
 const SEARCH_PATH: usize = 8;
 const TRANSACTION_ISOLATION_LEVEL: usize = 9;
+const TIMEZONE: usize = 10;
+const RESOURCE_GROUP: usize = 11;
+const BATCH_BLOCKING_EXCHANGE: usize = 12;
 
 trait ConfigEntry: Default + for<'a> TryFrom<&'a [&'a str], Error = RwError> {
     fn entry_name() -> &'static str;
@@ -198,6 +206,8 @@ type ExtraFloatDigit = ConfigI32<EXTRA_FLOAT_DIGITS, 1>;
 type DateStyle = ConfigString<DATE_STYLE>;
 type BatchEnableLookupJoin = ConfigBool<BATCH_ENABLE_LOOKUP_JOIN, false>;
 type MaxSplitRangeGap = ConfigI32<MAX_SPLIT_RANGE_GAP, 8>;
+type ResourceGroup = ConfigString<RESOURCE_GROUP>;
+type BatchBlockingExchange = ConfigBool<BATCH_BLOCKING_EXCHANGE, false>;
 
 #[derive(Default)]
 pub struct ConfigMap {
@@ -234,6 +244,18 @@ pub struct ConfigMap {
 
     /// see <https://www.postgresql.org/docs/current/transaction-iso.html>
     transaction_isolation_level: IsolationLevel,
+
+    /// see <https://www.postgresql.org/docs/current/runtime-config-client.html#GUC-TIMEZONE>
+    timezone: Timezone,
+
+    /// The resource group on compute nodes that the batch tasks of distributed queries are
+    /// admitted to. Empty for no resource group.
+    resource_group: ResourceGroup,
+
+    /// If `RW_BATCH_BLOCKING_EXCHANGE` is on, the exchanges between stages of distributed queries
+    /// retain their output until the query finishes, so that a failed fetch can be retried from
+    /// the beginning.
+    batch_blocking_exchange: BatchBlockingExchange,
 }
 
 impl ConfigMap {
@@ -257,6 +279,12 @@ impl ConfigMap {
             self.max_split_range_gap = val.as_slice().try_into()?;
         } else if key.eq_ignore_ascii_case(SearchPath::entry_name()) {
             self.search_path = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(Timezone::entry_name()) {
+            self.timezone = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(ResourceGroup::entry_name()) {
+            self.resource_group = val.as_slice().try_into()?;
+        } else if key.eq_ignore_ascii_case(BatchBlockingExchange::entry_name()) {
+            self.batch_blocking_exchange = val.as_slice().try_into()?;
         } else {
             return Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into());
         }
@@ -285,6 +313,12 @@ impl ConfigMap {
             Ok(self.search_path.to_string())
         } else if key.eq_ignore_ascii_case(IsolationLevel::entry_name()) {
             Ok(self.transaction_isolation_level.to_string())
+        } else if key.eq_ignore_ascii_case(Timezone::entry_name()) {
+            Ok(self.timezone.to_string())
+        } else if key.eq_ignore_ascii_case(ResourceGroup::entry_name()) {
+            Ok(self.resource_group.to_string())
+        } else if key.eq_ignore_ascii_case(BatchBlockingExchange::entry_name()) {
+            Ok(self.batch_blocking_exchange.to_string())
         } else {
             Err(ErrorCode::UnrecognizedConfigurationParameter(key.to_string()).into())
         }
@@ -336,6 +370,21 @@ impl ConfigMap {
                 name: SearchPath::entry_name().to_lowercase(),
                 setting : self.search_path.to_string(),
                 description : String::from("Sets the order in which schemas are searched when an object (table, data type, function, etc.) is referenced by a simple name with no schema specified")
+            },
+            VariableInfo {
+                name: Timezone::entry_name().to_lowercase(),
+                setting : self.timezone.to_string(),
+                description : String::from("Sets the time zone for displaying and interpreting time stamps.")
+            },
+            VariableInfo {
+                name: ResourceGroup::entry_name().to_lowercase(),
+                setting : self.resource_group.to_string(),
+                description : String::from("Sets the resource group on compute nodes that the batch tasks of distributed queries are admitted to.")
+            },
+            VariableInfo {
+                name: BatchBlockingExchange::entry_name().to_lowercase(),
+                setting : self.batch_blocking_exchange.to_string(),
+                description : String::from("To retain the output of exchanges between stages of distributed queries until the query finishes.")
             }
         ]
     }
@@ -379,4 +428,16 @@ impl ConfigMap {
     pub fn get_search_path(&self) -> SearchPath {
         self.search_path.clone()
     }
+
+    pub fn get_timezone(&self) -> &str {
+        self.timezone.as_str()
+    }
+
+    pub fn get_resource_group(&self) -> &str {
+        &self.resource_group
+    }
+
+    pub fn get_batch_blocking_exchange(&self) -> bool {
+        *self.batch_blocking_exchange
+    }
 }
@@ -0,0 +1,126 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Formatter;
+
+use chrono_tz::Tz;
+
+use super::{ConfigEntry, CONFIG_KEYS, TIMEZONE};
+use crate::error::{ErrorCode, RwError};
+
+pub const DEFAULT_TIMEZONE: &str = "UTC";
+/// The name of the session variable. Like all session variables, it is case-insensitive.
+pub const TIMEZONE_KEY: &str = CONFIG_KEYS[TIMEZONE];
+
+/// The time zone used for displaying and interpreting time stamps of the session.
+///
+/// see <https://www.postgresql.org/docs/current/runtime-config-client.html#GUC-TIMEZONE>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timezone(String);
+
+impl Default for Timezone {
+    fn default() -> Self {
+        Self(DEFAULT_TIMEZONE.to_string())
+    }
+}
+
+impl Timezone {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ConfigEntry for Timezone {
+    fn entry_name() -> &'static str {
+        TIMEZONE_KEY
+    }
+}
+
+impl TryFrom<&[&str]> for Timezone {
+    type Error = RwError;
+
+    fn try_from(value: &[&str]) -> Result<Self, Self::Error> {
+        if value.len() != 1 {
+            return Err(ErrorCode::InternalError(format!(
+                "SET {} takes only one argument",
+                Self::entry_name()
+            ))
+            .into());
+        }
+
+        // The value may be given as a quoted string literal, e.g. `SET TIMEZONE = 'UTC'`.
+        let s = value[0].trim().trim_matches('\'');
+        if s.is_empty() {
+            return Err(ErrorCode::InvalidConfigValue {
+                config_entry: Self::entry_name().to_string(),
+                config_value: s.to_string(),
+            }
+            .into());
+        }
+        if s.eq_ignore_ascii_case("default") || s.eq_ignore_ascii_case("local") {
+            return Ok(Self::default());
+        }
+        // Unknown time zones must be rejected here, as they are only looked up when displaying
+        // `timestamptz`.
+        if Tz::from_str_insensitive(s).is_err() {
+            return Err(ErrorCode::InvalidConfigValue {
+                config_entry: Self::entry_name().to_string(),
+                config_value: s.to_string(),
+            }
+            .into());
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for Timezone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_timezone() {
+        assert_eq!(Timezone::default().as_str(), DEFAULT_TIMEZONE);
+        assert_eq!(
+            Timezone::try_from(["Asia/Shanghai"].as_slice())
+                .unwrap()
+                .as_str(),
+            "Asia/Shanghai"
+        );
+        assert_eq!(
+            Timezone::try_from(["'Asia/Shanghai'"].as_slice())
+                .unwrap()
+                .as_str(),
+            "Asia/Shanghai"
+        );
+        assert_eq!(
+            Timezone::try_from(["DEFAULT"].as_slice()).unwrap(),
+            Timezone::default()
+        );
+        assert_eq!(
+            Timezone::try_from(["us/pacific"].as_slice())
+                .unwrap()
+                .as_str(),
+            "us/pacific"
+        );
+        assert!(Timezone::try_from([""].as_slice()).is_err());
+        assert!(Timezone::try_from(["Mars/Olympus"].as_slice()).is_err());
+        assert!(Timezone::try_from(["UTC", "UTC"].as_slice()).is_err());
+    }
+}
@@ -129,6 +129,44 @@ impl IntervalUnit {
         interval
     }
 
+    /// Converts each 30 days into a month, as `justify_days` in PostgreSQL. The months and the
+    /// remaining days end up with the same sign. Returns `None` on overflow.
+    pub fn justify_days(&self) -> Option<Self> {
+        let mut months = self.months.checked_add(self.days / 30)?;
+        let mut days = self.days % 30;
+        if months > 0 && days < 0 {
+            days += 30;
+            months -= 1;
+        } else if months < 0 && days > 0 {
+            days -= 30;
+            months += 1;
+        }
+        Some(Self {
+            months,
+            days,
+            ms: self.ms,
+        })
+    }
+
+    /// Converts each 24 hours into a day, as `justify_hours` in PostgreSQL. The days and the
+    /// remaining time end up with the same sign. Returns `None` on overflow.
+    pub fn justify_hours(&self) -> Option<Self> {
+        let mut days = self.days.checked_add((self.ms / DAY_MS).try_into().ok()?)?;
+        let mut ms = self.ms % DAY_MS;
+        if days > 0 && ms < 0 {
+            ms += DAY_MS;
+            days -= 1;
+        } else if days < 0 && ms > 0 {
+            ms -= DAY_MS;
+            days += 1;
+        }
+        Some(Self {
+            months: self.months,
+            days,
+            ms,
+        })
+    }
+
     #[must_use]
     pub fn negative(&self) -> Self {
         IntervalUnit {
@@ -1213,4 +1251,38 @@ mod tests {
             assert_eq!(lhs.cmp(&rhs), order)
         }
     }
+
+    #[test]
+    fn test_justify() {
+        let fields = |i: IntervalUnit| (i.get_months(), i.get_days(), i.get_ms());
+        let cases = [
+            ((0, 35, 0), (1, 5, 0), (0, 35, 0)),
+            ((0, -35, 0), (-1, -5, 0), (0, -35, 0)),
+            ((1, -1, 0), (0, 29, 0), (1, -1, 0)),
+            (
+                (0, 0, 27 * 3600 * 1000),
+                (0, 0, 27 * 3600 * 1000),
+                (0, 1, 3 * 3600 * 1000),
+            ),
+            (
+                (0, 1, -3600 * 1000),
+                (0, 1, -3600 * 1000),
+                (0, 0, 23 * 3600 * 1000),
+            ),
+            (
+                (0, -1, 3600 * 1000),
+                (0, -1, 3600 * 1000),
+                (0, 0, -23 * 3600 * 1000),
+            ),
+        ];
+        for ((months, days, ms), justified_days, justified_hours) in cases {
+            let interval = IntervalUnit::new(months, days, ms);
+            assert_eq!(fields(interval.justify_days().unwrap()), justified_days);
+            assert_eq!(fields(interval.justify_hours().unwrap()), justified_hours);
+        }
+        assert!(IntervalUnit::new(i32::MAX, 30, 0).justify_days().is_none());
+        assert!(IntervalUnit::new(0, i32::MAX, DAY_MS)
+            .justify_hours()
+            .is_none());
+    }
 }
@@ -64,8 +64,8 @@ use self::struct_type::StructType;
 use self::to_binary::ToBinary;
 use self::to_text::ToText;
 use crate::array::{
-    read_interval_unit, ArrayBuilderImpl, ListRef, ListValue, PrimitiveArrayItemType, StructRef,
-    StructValue,
+    read_interval_unit, ArrayBuilderImpl, JsonbRef, JsonbVal, ListRef, ListValue,
+    PrimitiveArrayItemType, StructRef, StructValue,
 };
 
 pub type OrderedF32 = ordered_float::OrderedFloat<f32>;
@@ -122,6 +122,9 @@ pub enum DataType {
     Struct(Arc<StructType>),
     #[display("{datatype}[]")]
     List { datatype: Box<DataType> },
+    #[display("jsonb")]
+    #[from_str(regex = "(?i)^jsonb$")]
+    Jsonb,
 }
 
 impl std::str::FromStr for Box<DataType> {
@@ -147,7 +150,8 @@ impl DataTypeName {
             | DataTypeName::Timestamp
             | DataTypeName::Timestampz
             | DataTypeName::Time
-            | DataTypeName::Interval => true,
+            | DataTypeName::Interval
+            | DataTypeName::Jsonb => true,
 
             DataTypeName::Struct | DataTypeName::List => false,
         }
@@ -168,6 +172,7 @@ impl DataTypeName {
             DataTypeName::Timestampz => DataType::Timestampz,
             DataTypeName::Time => DataType::Time,
             DataTypeName::Interval => DataType::Interval,
+            DataTypeName::Jsonb => DataType::Jsonb,
             DataTypeName::Struct | DataTypeName::List => {
                 return None;
             }
@@ -205,6 +210,7 @@ impl From<&ProstDataType> for DataType {
             TypeName::Timestampz => DataType::Timestampz,
             TypeName::Decimal => DataType::Decimal,
             TypeName::Interval => DataType::Interval,
+            TypeName::Jsonb => DataType::Jsonb,
             TypeName::Struct => {
                 let fields: Vec<DataType> = proto.field_type.iter().map(|f| f.into()).collect_vec();
                 let field_names: Vec<String> = proto.field_names.iter().cloned().collect_vec();
@@ -229,6 +235,7 @@ impl DataType {
     pub const INT32: DataType = DataType::Int32;
     pub const INT64: DataType = DataType::Int64;
     pub const INTERVAL: DataType = DataType::Interval;
+    pub const JSONB: DataType = DataType::Jsonb;
     pub const TIME: DataType = DataType::Time;
     pub const TIMESTAMP: DataType = DataType::Timestamp;
     pub const TIMESTAMPZ: DataType = DataType::Timestampz;
@@ -260,6 +267,7 @@ impl DataType {
                 },
             )
             .into(),
+            DataType::Jsonb => JsonbArrayBuilder::new(capacity).into(),
         }
     }
 
@@ -280,6 +288,7 @@ impl DataType {
             DataType::Interval => TypeName::Interval,
             DataType::Struct { .. } => TypeName::Struct,
             DataType::List { .. } => TypeName::List,
+            DataType::Jsonb => TypeName::Jsonb,
         }
     }
 
@@ -337,7 +346,7 @@ impl DataType {
         match self {
             Boolean | Int16 | Int32 | Int64 => true,
             Float32 | Float64 | Decimal | Date | Varchar | Time | Timestamp | Timestampz
-            | Interval => false,
+            | Interval | Jsonb => false,
             Struct(t) => t.fields.iter().all(|dt| dt.mem_cmp_eq_value_enc()),
             List { datatype } => datatype.mem_cmp_eq_value_enc(),
         }
@@ -381,6 +390,7 @@ impl DataType {
                     .collect_vec(),
             )),
             DataType::List { .. } => ScalarImpl::List(ListValue::new(vec![])),
+            DataType::Jsonb => ScalarImpl::Jsonb(JsonbVal::null()),
         }
     }
 }
@@ -469,7 +479,8 @@ macro_rules! for_all_scalar_variants {
             { NaiveDateTime, naivedatetime, NaiveDateTimeWrapper, NaiveDateTimeWrapper },
             { NaiveTime, naivetime, NaiveTimeWrapper, NaiveTimeWrapper },
             { Struct, struct, StructValue, StructRef<'scalar> },
-            { List, list, ListValue, ListRef<'scalar> }
+            { List, list, ListValue, ListRef<'scalar> },
+            { Jsonb, jsonb, JsonbVal, JsonbRef<'scalar> }
         }
     };
 }
@@ -830,6 +841,7 @@ impl ScalarRefImpl<'_> {
             }
             Self::Struct(v) => v.serialize(ser)?,
             Self::List(v) => v.serialize(ser)?,
+            Self::Jsonb(v) => v.memcmp_serialize(ser)?,
         };
         Ok(())
     }
@@ -877,6 +889,7 @@ impl ScalarImpl {
             }),
             Ty::Struct(t) => StructValue::deserialize(&t.fields, de)?.to_scalar_value(),
             Ty::List { datatype } => ListValue::deserialize(datatype, de)?.to_scalar_value(),
+            Ty::Jsonb => Self::Jsonb(JsonbVal::memcmp_deserialize(de)?),
         })
     }
 
@@ -914,7 +927,7 @@ impl ScalarImpl {
                     }
                     // these two types is var-length and should only be determine at runtime.
                     // TODO: need some test for this case (e.g. e2e test)
-                    DataType::List { .. } => deserializer.skip_bytes()?,
+                    DataType::List { .. } | DataType::Jsonb => deserializer.skip_bytes()?,
                     DataType::Struct(t) => t
                         .fields
                         .iter()
@@ -956,6 +969,7 @@ pub fn literal_type_match(data_type: &DataType, literal: Option<&ScalarImpl>) ->
                     | (DataType::Interval, ScalarImpl::Interval(_))
                     | (DataType::Struct { .. }, ScalarImpl::Struct(_))
                     | (DataType::List { .. }, ScalarImpl::List(_))
+                    | (DataType::Jsonb, ScalarImpl::Jsonb(_))
             )
         }
         None => true,
@@ -1156,6 +1170,10 @@ mod tests {
                         datatype: Box::new(DataType::Int64),
                     },
                 ),
+                DataTypeName::Jsonb => (
+                    ScalarImpl::Jsonb(r#"{"a": [233, "2333"]}"#.parse().unwrap()),
+                    DataType::Jsonb,
+                ),
             };
 
             test(Some(scalar), data_type.clone());
@@ -1262,6 +1280,9 @@ mod tests {
         assert_eq!(DataType::from_str("interval").unwrap(), DataType::Interval);
         assert_eq!(DataType::from_str("INTERVAL").unwrap(), DataType::Interval);
 
+        assert_eq!(DataType::from_str("jsonb").unwrap(), DataType::Jsonb);
+        assert_eq!(DataType::from_str("JSONB").unwrap(), DataType::Jsonb);
+
         assert_eq!(
             DataType::from_str("int2[]").unwrap(),
             DataType::List {
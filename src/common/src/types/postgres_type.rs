@@ -31,7 +31,8 @@ impl DataType {
             | DataType::Varchar
             | DataType::Interval
             | DataType::Struct(_)
-            | DataType::List { .. } => -1,
+            | DataType::List { .. }
+            | DataType::Jsonb => -1,
         }
     }
 
@@ -56,6 +57,7 @@ impl DataType {
             1114 => Ok(DataType::Timestamp),
             1184 => Ok(DataType::Timestampz),
             1186 => Ok(DataType::Interval),
+            3802 => Ok(DataType::Jsonb),
             1000 => Ok(DataType::List {
                 datatype: Box::new(DataType::Boolean),
             }),
@@ -95,6 +97,9 @@ impl DataType {
             1187 => Ok(DataType::List {
                 datatype: Box::new(DataType::Interval),
             }),
+            3807 => Ok(DataType::List {
+                datatype: Box::new(DataType::Jsonb),
+            }),
             _ => Err(ErrorCode::InternalError(format!("Unsupported oid {}", oid)).into()),
         }
     }
@@ -114,6 +119,7 @@ impl DataType {
             DataType::Timestamp => 1114,
             DataType::Timestampz => 1184,
             DataType::Interval => 1186,
+            DataType::Jsonb => 3802,
             // NOTE: Sturct type don't have oid in postgres, here we use varchar oid so that struct
             // will be considered as a varchar.
             DataType::Struct(_) => 1043,
@@ -131,6 +137,7 @@ impl DataType {
                 DataType::Timestamp => 1115,
                 DataType::Timestampz => 1185,
                 DataType::Interval => 1187,
+                DataType::Jsonb => 3807,
                 DataType::Struct(_) => 1015,
                 DataType::List { .. } => unreachable!("Never reach here!"),
             },
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{DatumRef, ScalarRefImpl};
+use std::fmt::{Display, LowerExp};
+
+use num_traits::Float;
+
+use super::{DatumRef, OrderedF32, OrderedF64, ScalarRefImpl};
 
 // Used to convert ScalarRef to text format
 pub trait ToText {
@@ -36,9 +40,44 @@ implement_using_to_string! {
     { i32 },
     { i64 },
     { String },
-    { &str },
-    { crate::types::OrderedF32 },
-    { crate::types::OrderedF64 }
+    { &str }
+}
+
+/// Formats a float the way Postgres does with the default `extra_float_digits`: the shortest
+/// representation that round-trips, switching to scientific notation (e.g. `1e+20`, `1.5e-05`)
+/// when the decimal exponent is below -4 or at least `max_fixed_exp`.
+fn float_to_text<T: Float + Display + LowerExp>(v: T, max_fixed_exp: i32) -> String {
+    if v.is_nan() {
+        return "NaN".to_string();
+    }
+    if v.is_infinite() {
+        return if v.is_sign_negative() {
+            "-Infinity".to_string()
+        } else {
+            "Infinity".to_string()
+        };
+    }
+    let scientific = format!("{:e}", v);
+    let (mantissa, exp) = scientific.split_once('e').unwrap();
+    let exp: i32 = exp.parse().unwrap();
+    if (-4..max_fixed_exp).contains(&exp) {
+        v.to_string()
+    } else {
+        let sign = if exp < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", mantissa, sign, exp.abs())
+    }
+}
+
+impl ToText for OrderedF32 {
+    fn to_text(&self) -> String {
+        float_to_text(self.0, 6)
+    }
+}
+
+impl ToText for OrderedF64 {
+    fn to_text(&self) -> String {
+        float_to_text(self.0, 15)
+    }
 }
 
 impl ToText for bool {
@@ -68,6 +107,7 @@ impl ToText for ScalarRefImpl<'_> {
             ScalarRefImpl::List(l) => l.to_text(),
             ScalarRefImpl::Struct(s) => s.to_text(),
             ScalarRefImpl::Utf8(v) => v.to_text(),
+            ScalarRefImpl::Jsonb(v) => v.to_text(),
         }
     }
 }
@@ -80,3 +120,32 @@ impl ToText for DatumRef<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_to_text() {
+        let f64_text = |v: f64| OrderedF64::from(v).to_text();
+        assert_eq!(f64_text(0.0), "0");
+        assert_eq!(f64_text(-0.0), "-0");
+        assert_eq!(f64_text(1.5), "1.5");
+        assert_eq!(f64_text(0.1 + 0.2), "0.30000000000000004");
+        assert_eq!(f64_text(0.0001), "0.0001");
+        assert_eq!(f64_text(0.00001), "1e-05");
+        assert_eq!(f64_text(-1.5e-7), "-1.5e-07");
+        assert_eq!(f64_text(123456789012345.0), "123456789012345");
+        assert_eq!(f64_text(1e15), "1e+15");
+        assert_eq!(f64_text(1.25e300), "1.25e+300");
+        assert_eq!(f64_text(f64::NAN), "NaN");
+        assert_eq!(f64_text(f64::NEG_INFINITY), "-Infinity");
+
+        let f32_text = |v: f32| OrderedF32::from(v).to_text();
+        assert_eq!(f32_text(123456.0), "123456");
+        assert_eq!(f32_text(1234567.0), "1.234567e+06");
+        assert_eq!(f32_text(6.01), "6.01");
+        assert_eq!(f32_text(0.00001), "1e-05");
+        assert_eq!(f32_text(f32::INFINITY), "Infinity");
+    }
+}
@@ -19,6 +19,7 @@ use itertools::Itertools;
 
 use crate::array::column::Column;
 use crate::array::{ArrayBuilderImpl, ArrayImpl, DataChunk};
+use crate::collection::estimate_size::EstimateSize;
 use crate::row::Row2;
 use crate::types::{DataType, ToDatumRef};
 
@@ -28,6 +29,41 @@ pub struct SlicedDataChunk {
     offset: usize,
 }
 
+/// Returns the number of rows per chunk so that a chunk of rows `avg_row_bytes` wide stays within
+/// `target_bytes`. The result is at least 1 and at most `max_chunk_size`. A `target_bytes` of 0
+/// disables the adaptation.
+pub fn adaptive_chunk_size(
+    avg_row_bytes: usize,
+    target_bytes: usize,
+    max_chunk_size: usize,
+) -> usize {
+    if target_bytes == 0 || avg_row_bytes == 0 {
+        return max_chunk_size;
+    }
+    (target_bytes / avg_row_bytes).clamp(1, max_chunk_size.max(1))
+}
+
+/// Splits `chunk` into compact chunks whose estimated size stays within `target_bytes`, based on
+/// the average row width of `chunk`. The chunk is returned as is if it fits, or if `target_bytes`
+/// is 0.
+pub fn split_chunk_by_target_bytes(chunk: DataChunk, target_bytes: usize) -> Vec<DataChunk> {
+    if target_bytes == 0 || chunk.cardinality() <= 1 || chunk.estimated_heap_size() <= target_bytes
+    {
+        return vec![chunk];
+    }
+    let chunk = chunk.compact();
+    let capacity = chunk.capacity();
+    let chunk_size = adaptive_chunk_size(
+        chunk.estimated_heap_size() / capacity,
+        target_bytes,
+        capacity,
+    );
+    (0..capacity)
+        .step_by(chunk_size)
+        .map(|offset| chunk.slice(offset, chunk_size.min(capacity - offset)))
+        .collect()
+}
+
 /// Used as a buffer for accumulating rows.
 pub struct DataChunkBuilder {
     /// Data types for build array
@@ -194,6 +230,31 @@ impl DataChunkBuilder {
         }
     }
 
+    /// Append one row by writing to the array builders directly with `f`, which must append
+    /// exactly one value to each of the builders. If `f` fails, the builder should not be used
+    /// anymore.
+    /// Return a data chunk if the buffer is full after append one row. Otherwise `None`.
+    pub fn append_one_row_with<E>(
+        &mut self,
+        f: impl FnOnce(&mut [ArrayBuilderImpl]) -> Result<(), E>,
+    ) -> Result<Option<DataChunk>, E> {
+        assert!(self.buffered_count < self.batch_size);
+        self.ensure_builders();
+
+        f(&mut self.array_builders)?;
+        self.buffered_count += 1;
+        debug_assert!(self
+            .array_builders
+            .iter()
+            .all(|builder| builder.len() == self.buffered_count));
+
+        if self.buffered_count == self.batch_size {
+            Ok(Some(self.build_data_chunk()))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn build_data_chunk(&mut self) -> DataChunk {
         let mut new_array_builders = vec![];
         swap(&mut new_array_builders, &mut self.array_builders);
@@ -261,10 +322,88 @@ impl SlicedDataChunk {
 
 #[cfg(test)]
 mod tests {
+    use itertools::Itertools;
+
     use crate::array::DataChunk;
+    use crate::collection::estimate_size::EstimateSize;
+    use crate::row::Row2;
     use crate::test_prelude::DataChunkTestExt;
     use crate::types::{DataType, ScalarImpl};
-    use crate::util::chunk_coalesce::{DataChunkBuilder, SlicedDataChunk};
+    use crate::util::chunk_coalesce::{
+        adaptive_chunk_size, split_chunk_by_target_bytes, DataChunkBuilder, SlicedDataChunk,
+    };
+
+    #[test]
+    fn test_adaptive_chunk_size() {
+        // Narrow rows use the maximum chunk size.
+        assert_eq!(adaptive_chunk_size(16, 4 << 20, 1024), 1024);
+        // Wide rows emit fewer rows per chunk.
+        assert_eq!(adaptive_chunk_size(64 << 10, 4 << 20, 1024), 64);
+        // A single huge row still makes progress.
+        assert_eq!(adaptive_chunk_size(8 << 20, 4 << 20, 1024), 1);
+        // Disabled.
+        assert_eq!(adaptive_chunk_size(64 << 10, 0, 1024), 1024);
+    }
+
+    #[test]
+    fn test_split_chunk_by_target_bytes() {
+        let chunk = DataChunk::from_pretty(
+            "i T
+             1 aaaaaaaaaaaaaaaa
+             2 bbbbbbbbbbbbbbbb
+             3 cccccccccccccccc
+             4 dddddddddddddddd
+             5 eeeeeeeeeeeeeeee",
+        );
+        let target_bytes = chunk.estimated_heap_size() / 2;
+        let chunks = split_chunk_by_target_bytes(chunk.clone(), target_bytes);
+        assert!(chunks.len() >= 2);
+        let rows = chunks
+            .iter()
+            .flat_map(|chunk| chunk.rows().map(|row| row.to_owned_row()))
+            .collect_vec();
+        assert_eq!(
+            rows,
+            chunk.rows().map(|row| row.to_owned_row()).collect_vec()
+        );
+
+        // Chunks within the target, or without a target, are not split.
+        assert_eq!(split_chunk_by_target_bytes(chunk.clone(), 1 << 20).len(), 1);
+        assert_eq!(split_chunk_by_target_bytes(chunk, 0).len(), 1);
+    }
+
+    #[test]
+    fn test_append_one_row_with() {
+        let mut builder = DataChunkBuilder::new(vec![DataType::Int32, DataType::Int64], 2);
+
+        let output = builder
+            .append_one_row_with(|builders| {
+                builders[0].append_datum(Some(ScalarImpl::Int32(1)));
+                builders[1].append_datum(None::<ScalarImpl>);
+                Ok::<_, ()>(())
+            })
+            .unwrap();
+        assert!(output.is_none());
+
+        let output = builder
+            .append_one_row_with(|builders| {
+                builders[0].append_datum(None::<ScalarImpl>);
+                builders[1].append_datum(Some(ScalarImpl::Int64(2)));
+                Ok::<_, ()>(())
+            })
+            .unwrap();
+        assert_eq!(
+            output,
+            Some(DataChunk::from_pretty(
+                "i I
+                 1 .
+                 . 2"
+            ))
+        );
+
+        let output = builder.append_one_row_with(|_| Err("failed"));
+        assert_eq!(output, Err("failed"));
+    }
 
     #[test]
     fn test_append_chunk() {
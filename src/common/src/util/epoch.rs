@@ -144,6 +144,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_epoch_generate_with_clock_behind() {
+        // If the previous epoch is ahead of the local clock (e.g. it was generated by another node
+        // or before the clock went backwards), the logical part keeps the epochs increasing.
+        let prev_epoch = Epoch::from_physical_time(Epoch::physical_now() + 60_000);
+        let epoch = prev_epoch.next();
+        assert!(epoch > prev_epoch);
+        assert_eq!(epoch.physical_time(), prev_epoch.physical_time());
+        assert_eq!(epoch.0, prev_epoch.0 + 1);
+    }
+
     #[test]
     fn test_subtract_ms() {
         {
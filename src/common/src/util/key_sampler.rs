@@ -0,0 +1,172 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::array::DataChunk;
+use crate::util::encoding_for_comparison::encode_chunk;
+use crate::util::sort_util::OrderPair;
+
+/// Collects a uniform sample of memcomparable-encoded keys with reservoir sampling, from which
+/// range boundaries can be derived to split the keys into partitions of similar sizes.
+pub struct KeySampler<R: Rng = StdRng> {
+    /// The maximum number of sampled keys.
+    capacity: usize,
+    /// The number of keys observed so far.
+    observed: usize,
+    samples: Vec<Vec<u8>>,
+    rng: R,
+}
+
+impl KeySampler<StdRng> {
+    pub fn new(capacity: usize) -> Self {
+        Self::with_rng(capacity, StdRng::from_entropy())
+    }
+}
+
+impl<R: Rng> KeySampler<R> {
+    pub fn with_rng(capacity: usize, rng: R) -> Self {
+        assert!(capacity > 0, "sample capacity must be positive");
+        Self {
+            capacity,
+            observed: 0,
+            samples: Vec::with_capacity(capacity),
+            rng,
+        }
+    }
+
+    /// Observes one key. Every observed key ends up in the sample with the same probability.
+    pub fn observe(&mut self, key: Vec<u8>) {
+        self.observed += 1;
+        if self.samples.len() < self.capacity {
+            self.samples.push(key);
+        } else {
+            let idx = self.rng.gen_range(0..self.observed);
+            if idx < self.capacity {
+                self.samples[idx] = key;
+            }
+        }
+    }
+
+    /// Observes the keys of the visible rows in `chunk`, ordered by `order_pairs`.
+    pub fn observe_chunk(&mut self, chunk: &DataChunk, order_pairs: &[OrderPair]) {
+        let keys = encode_chunk(chunk, order_pairs);
+        match chunk.visibility() {
+            Some(visibility) => keys
+                .into_iter()
+                .zip_eq(visibility.iter())
+                .filter(|(_, visible)| *visible)
+                .for_each(|(key, _)| self.observe(key)),
+            None => keys.into_iter().for_each(|key| self.observe(key)),
+        }
+    }
+
+    /// The number of keys observed so far.
+    pub fn observed(&self) -> usize {
+        self.observed
+    }
+
+    /// Returns the sorted boundaries that split the sampled keys into `partitions` ranges of
+    /// similar sizes, to be used as the boundaries of a range exchange and with
+    /// [`range_partition`]. Duplicated boundaries are removed, so fewer than `partitions - 1`
+    /// boundaries are returned if the keys are skewed or too few keys are sampled.
+    pub fn boundaries(&self, partitions: usize) -> Vec<Vec<u8>> {
+        assert!(partitions > 0, "the number of partitions must be positive");
+        let sorted = self.samples.iter().sorted().collect_vec();
+        (1..partitions)
+            .map(|i| i * sorted.len() / partitions)
+            .filter(|&idx| idx > 0)
+            .map(|idx| sorted[idx].clone())
+            .dedup()
+            .collect()
+    }
+}
+
+/// Returns the partition of `key` among the ranges split by the sorted `boundaries`. A key equal
+/// to a boundary belongs to the range starting at that boundary.
+pub fn range_partition(boundaries: &[Vec<u8>], key: &[u8]) -> usize {
+    boundaries.partition_point(|boundary| boundary.as_slice() <= key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::DataChunkTestExt;
+    use crate::util::sort_util::OrderType;
+
+    #[test]
+    fn test_reservoir_sampling() {
+        let mut sampler = KeySampler::with_rng(10, StdRng::seed_from_u64(0));
+        for i in 0..5u32 {
+            sampler.observe(i.to_be_bytes().to_vec());
+        }
+        assert_eq!(sampler.samples.len(), 5);
+
+        for i in 5..10000u32 {
+            sampler.observe(i.to_be_bytes().to_vec());
+        }
+        assert_eq!(sampler.observed(), 10000);
+        assert_eq!(sampler.samples.len(), 10);
+        assert!(sampler.samples.iter().all_unique());
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let mut sampler = KeySampler::new(1000);
+        for i in 0..100u32 {
+            sampler.observe(i.to_be_bytes().to_vec());
+        }
+        let boundaries = sampler.boundaries(4);
+        assert_eq!(
+            boundaries,
+            vec![
+                25u32.to_be_bytes().to_vec(),
+                50u32.to_be_bytes().to_vec(),
+                75u32.to_be_bytes().to_vec(),
+            ]
+        );
+        assert_eq!(range_partition(&boundaries, &0u32.to_be_bytes()), 0);
+        assert_eq!(range_partition(&boundaries, &25u32.to_be_bytes()), 1);
+        assert_eq!(range_partition(&boundaries, &74u32.to_be_bytes()), 2);
+        assert_eq!(range_partition(&boundaries, &99u32.to_be_bytes()), 3);
+
+        // Skewed keys produce fewer boundaries instead of empty ranges.
+        let mut sampler = KeySampler::new(1000);
+        (0..100).for_each(|_| sampler.observe(vec![1]));
+        assert_eq!(sampler.boundaries(4), vec![vec![1]]);
+        assert!(KeySampler::new(10).boundaries(4).is_empty());
+    }
+
+    #[test]
+    fn test_observe_chunk() {
+        let chunk = DataChunk::from_pretty(
+            "i
+             3 D
+             1
+             2",
+        );
+        let order_pairs = vec![OrderPair::new(0, OrderType::Descending)];
+        let mut sampler = KeySampler::new(10);
+        sampler.observe_chunk(&chunk, &order_pairs);
+        assert_eq!(sampler.observed(), 2);
+
+        // Descending order puts 2 before 1.
+        let boundaries = sampler.boundaries(2);
+        assert_eq!(
+            boundaries,
+            vec![encode_chunk(&chunk, &order_pairs)[1].clone()]
+        );
+    }
+}
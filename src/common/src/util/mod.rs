@@ -28,6 +28,7 @@ pub mod env_var;
 pub mod epoch;
 mod future_utils;
 pub mod hash_util;
+pub mod key_sampler;
 pub mod ordered;
 pub mod prost;
 pub mod scan_range;
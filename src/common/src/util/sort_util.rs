@@ -177,7 +177,8 @@ pub fn compare_rows(lhs: &Row, rhs: &Row, order_pairs: &[OrderPair]) -> Result<O
                 NaiveDateTime,
                 NaiveTime,
                 Struct,
-                List
+                List,
+                Jsonb
             ]
         );
 
@@ -242,7 +243,8 @@ pub fn compare_rows_in_chunk(
                 NaiveDateTime,
                 NaiveTime,
                 Struct,
-                List
+                List,
+                Jsonb
             ]
         );
         if res != Ordering::Equal {
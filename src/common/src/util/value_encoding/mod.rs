@@ -19,7 +19,7 @@ use bytes::{Buf, BufMut};
 use chrono::{Datelike, Timelike};
 use itertools::Itertools;
 
-use crate::array::{ListRef, ListValue, StructRef, StructValue};
+use crate::array::{JsonbVal, ListRef, ListValue, StructRef, StructValue};
 use crate::types::struct_type::StructType;
 use crate::types::{
     DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
@@ -53,6 +53,16 @@ pub fn deserialize_datum(mut data: impl Buf, ty: &DataType) -> Result<Datum> {
     inner_deserialize_datum(&mut data, ty)
 }
 
+/// Skip over a datum in the bytes without deserializing it.
+pub fn skip_datum(data: &mut impl Buf, ty: &DataType) -> Result<()> {
+    let null_tag = data.get_u8();
+    match null_tag {
+        0 => Ok(()),
+        1 => skip_value(ty, data),
+        _ => Err(ValueEncodingError::InvalidTagEncoding(null_tag)),
+    }
+}
+
 // prevent recursive use of &mut
 #[inline(always)]
 fn inner_deserialize_datum(data: &mut impl Buf, ty: &DataType) -> Result<Datum> {
@@ -84,6 +94,7 @@ fn serialize_value(value: ScalarRefImpl<'_>, buf: &mut impl BufMut) {
         }
         ScalarRefImpl::Struct(s) => serialize_struct(s, buf),
         ScalarRefImpl::List(list) => serialize_list(list, buf),
+        ScalarRefImpl::Jsonb(v) => serialize_str(&v.value_serialize(), buf),
     }
 }
 
@@ -157,9 +168,39 @@ fn deserialize_value(ty: &DataType, data: &mut impl Buf) -> Result<ScalarImpl> {
         DataType::List {
             datatype: item_type,
         } => deserialize_list(item_type, data)?,
+        DataType::Jsonb => ScalarImpl::Jsonb(deserialize_jsonb(data)?),
     })
 }
 
+fn skip_value(ty: &DataType, data: &mut impl Buf) -> Result<()> {
+    let len = match ty {
+        DataType::Boolean => 1,
+        DataType::Int16 => 2,
+        DataType::Int32 | DataType::Float32 | DataType::Date => 4,
+        DataType::Int64 | DataType::Float64 | DataType::Timestampz | DataType::Time => 8,
+        DataType::Timestamp => 12,
+        DataType::Decimal | DataType::Interval => 16,
+        DataType::Varchar | DataType::Jsonb => data.get_u32_le() as usize,
+        DataType::Struct(struct_def) => {
+            for field_type in &struct_def.fields {
+                skip_datum(data, field_type)?;
+            }
+            return Ok(());
+        }
+        DataType::List {
+            datatype: item_type,
+        } => {
+            let len = data.get_u32_le();
+            for _ in 0..len {
+                skip_datum(data, item_type)?;
+            }
+            return Ok(());
+        }
+    };
+    data.advance(len);
+    Ok(())
+}
+
 fn deserialize_struct(struct_def: &StructType, data: &mut impl Buf) -> Result<ScalarImpl> {
     let num_fields = struct_def.fields.len();
     let mut field_values = Vec::with_capacity(num_fields);
@@ -186,6 +227,13 @@ fn deserialize_str(data: &mut impl Buf) -> Result<String> {
     String::from_utf8(bytes).map_err(ValueEncodingError::InvalidUtf8)
 }
 
+fn deserialize_jsonb(data: &mut impl Buf) -> Result<JsonbVal> {
+    let len = data.get_u32_le();
+    let mut bytes = vec![0; len as usize];
+    data.copy_to_slice(&mut bytes);
+    JsonbVal::value_deserialize(&bytes).ok_or(ValueEncodingError::InvalidJsonbEncoding)
+}
+
 fn deserialize_bool(data: &mut impl Buf) -> Result<bool> {
     match data.get_u8() {
         1 => Ok(true),
@@ -16,7 +16,13 @@ use std::collections::HashMap;
 
 use risingwave_pb::common::WorkerNode;
 
-use crate::hash::ParallelUnitId;
+use crate::hash::{ParallelUnitId, VirtualNode};
+
+/// The number of row id generator slots that meta assigns to compute nodes.
+///
+/// A row id has 10 bits for the generator id. Generators of streaming tables take the vnode ids
+/// `[0, VirtualNode::COUNT)`, and the slots of compute nodes take the rest.
+pub const ROW_ID_GENERATOR_SLOT_COUNT: u32 = (1 << 10) - VirtualNode::COUNT as u32;
 
 pub fn get_pu_to_worker_mapping(nodes: &[WorkerNode]) -> HashMap<ParallelUnitId, WorkerNode> {
     let mut pu_to_worker = HashMap::new();
@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use prometheus::core::{AtomicU64, GenericCounterVec};
-use prometheus::{register_int_counter_vec_with_registry, Registry};
+use prometheus::core::{AtomicU64, GenericCounter, GenericCounterVec};
+use prometheus::{
+    register_int_counter_vec_with_registry, register_int_counter_with_registry, Registry,
+};
 
 pub struct ExchangeServiceMetrics {
     pub registry: Registry,
     pub stream_exchange_bytes: GenericCounterVec<AtomicU64>,
     pub stream_fragment_exchange_bytes: GenericCounterVec<AtomicU64>,
     pub actor_sampled_serialize_duration_ns: GenericCounterVec<AtomicU64>,
+    pub batch_exchange_bytes: GenericCounter<AtomicU64>,
 }
 
 impl ExchangeServiceMetrics {
@@ -48,11 +51,19 @@ impl ExchangeServiceMetrics {
         )
         .unwrap();
 
+        let batch_exchange_bytes = register_int_counter_with_registry!(
+            "batch_exchange_send_size",
+            "Total size of messages that have been send to downstream batch tasks",
+            registry
+        )
+        .unwrap();
+
         Self {
             registry,
             stream_exchange_bytes,
             stream_fragment_exchange_bytes,
             actor_sampled_serialize_duration_ns,
+            batch_exchange_bytes,
         }
     }
 
@@ -42,7 +42,7 @@ pub struct ExchangeServiceImpl {
     metrics: Arc<ExchangeServiceMetrics>,
 }
 
-type BatchDataStream = ReceiverStream<std::result::Result<GetDataResponse, Status>>;
+type BatchDataStream = impl Stream<Item = std::result::Result<GetDataResponse, Status>>;
 type StreamDataStream = impl Stream<Item = std::result::Result<GetStreamResponse, Status>>;
 
 #[async_trait::async_trait]
@@ -58,17 +58,23 @@ impl ExchangeService for ExchangeServiceImpl {
         let peer_addr = request
             .remote_addr()
             .ok_or_else(|| Status::unavailable("connection unestablished"))?;
+        let request = request.into_inner();
         let pb_task_output_id = request
-            .into_inner()
             .task_output_id
             .expect("Failed to get task output id.");
         let (tx, rx) = tokio::sync::mpsc::channel(BATCH_EXCHANGE_BUFFER_SIZE);
-        if let Err(e) = self.batch_mgr.get_data(tx, peer_addr, &pb_task_output_id) {
+        if let Err(e) = self.batch_mgr.get_data(
+            tx,
+            peer_addr,
+            &pb_task_output_id,
+            request.cursor,
+            request.max_chunks,
+        ) {
             error!("Failed to serve exchange RPC from {}: {}", peer_addr, e);
             return Err(e.into());
         }
 
-        Ok(Response::new(ReceiverStream::new(rx)))
+        Ok(Response::new(Self::get_data_impl(self.metrics.clone(), rx)))
     }
 
     async fn get_stream(
@@ -129,6 +135,17 @@ impl ExchangeServiceImpl {
         }
     }
 
+    fn get_data_impl(
+        metrics: Arc<ExchangeServiceMetrics>,
+        rx: tokio::sync::mpsc::Receiver<std::result::Result<GetDataResponse, Status>>,
+    ) -> BatchDataStream {
+        ReceiverStream::new(rx).inspect_ok(move |response| {
+            metrics
+                .batch_exchange_bytes
+                .inc_by(Message::get_encoded_len(response) as u64);
+        })
+    }
+
     #[try_stream(ok = GetStreamResponse, error = Status)]
     async fn get_stream_impl(
         metrics: Arc<ExchangeServiceMetrics>,
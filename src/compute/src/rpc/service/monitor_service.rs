@@ -15,25 +15,33 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use global_stats_alloc::task_local_alloc_installed;
+use risingwave_batch::task::BatchManager;
 use risingwave_pb::monitor_service::monitor_service_server::MonitorService;
 use risingwave_pb::monitor_service::{
-    ProfilingRequest, ProfilingResponse, StackTraceRequest, StackTraceResponse,
+    ActorMemoryUsage, BatchTaskMemoryUsage, MemoryUsageRequest, MemoryUsageResponse,
+    OperatorMemoryUsage, ProfilingRequest, ProfilingResponse, StackTraceRequest,
+    StackTraceResponse,
 };
 use risingwave_stream::task::LocalStreamManager;
+use tikv_jemalloc_ctl::{epoch as jemalloc_epoch, stats as jemalloc_stats};
 use tonic::{Request, Response, Status};
 
 #[derive(Clone)]
 pub struct MonitorServiceImpl {
+    batch_mgr: Arc<BatchManager>,
     stream_mgr: Arc<LocalStreamManager>,
     grpc_stack_trace_mgr: GrpcStackTraceManagerRef,
 }
 
 impl MonitorServiceImpl {
     pub fn new(
+        batch_mgr: Arc<BatchManager>,
         stream_mgr: Arc<LocalStreamManager>,
         grpc_stack_trace_mgr: GrpcStackTraceManagerRef,
     ) -> Self {
         Self {
+            batch_mgr,
             stream_mgr,
             grpc_stack_trace_mgr,
         }
@@ -101,6 +109,67 @@ impl MonitorService for MonitorServiceImpl {
             }
         }
     }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn memory_usage(
+        &self,
+        _request: Request<MemoryUsageRequest>,
+    ) -> Result<Response<MemoryUsageResponse>, Status> {
+        // The statistics of jemalloc are cached until the epoch is advanced.
+        jemalloc_epoch::advance().map_err(|e| Status::internal(e.to_string()))?;
+        let allocated_bytes =
+            jemalloc_stats::allocated::read().map_err(|e| Status::internal(e.to_string()))?;
+        let resident_bytes =
+            jemalloc_stats::resident::read().map_err(|e| Status::internal(e.to_string()))?;
+
+        // Without the task-local allocator, the counters of tasks and actors are never charged.
+        if !task_local_alloc_installed() {
+            return Ok(Response::new(MemoryUsageResponse {
+                allocated_bytes: allocated_bytes as u64,
+                resident_bytes: resident_bytes as u64,
+                ..Default::default()
+            }));
+        }
+
+        let to_operators = |operators: Vec<(String, usize)>| {
+            operators
+                .into_iter()
+                .map(|(identity, bytes)| OperatorMemoryUsage {
+                    identity,
+                    bytes: bytes as u64,
+                })
+                .collect()
+        };
+
+        let batch_tasks = self
+            .batch_mgr
+            .get_task_memory_usage()
+            .into_iter()
+            .map(|(task_id, bytes)| BatchTaskMemoryUsage {
+                task_id: Some(task_id.to_prost()),
+                bytes: bytes.val() as u64,
+                operators: to_operators(bytes.operators()),
+            })
+            .collect();
+
+        let actors = self
+            .stream_mgr
+            .get_actor_memory_usage()
+            .into_iter()
+            .map(|(actor_id, bytes)| ActorMemoryUsage {
+                actor_id,
+                bytes: bytes.val() as u64,
+                operators: to_operators(bytes.operators()),
+            })
+            .collect();
+
+        Ok(Response::new(MemoryUsageResponse {
+            allocated_bytes: allocated_bytes as u64,
+            resident_bytes: resident_bytes as u64,
+            batch_tasks,
+            actors,
+        }))
+    }
 }
 
 pub use grpc_middleware::*;
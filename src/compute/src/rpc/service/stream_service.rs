@@ -214,4 +214,23 @@ impl StreamService for StreamServiceImpl {
 
         Ok(Response::new(WaitEpochCommitResponse { status: None }))
     }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn get_actor_status(
+        &self,
+        _request: Request<GetActorStatusRequest>,
+    ) -> Result<Response<GetActorStatusResponse>, Status> {
+        let actors = self
+            .mgr
+            .get_actor_status()
+            .await
+            .into_iter()
+            .map(|status| get_actor_status_response::ActorStatus {
+                actor_id: status.actor_id,
+                fragment_id: status.fragment_id,
+                collected_epoch: status.collected_epoch.unwrap_or_default(),
+            })
+            .collect();
+        Ok(Response::new(GetActorStatusResponse { actors }))
+    }
 }
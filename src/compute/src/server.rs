@@ -31,7 +31,9 @@ use risingwave_pb::monitor_service::monitor_service_server::MonitorServiceServer
 use risingwave_pb::stream_service::stream_service_server::StreamServiceServer;
 use risingwave_pb::task_service::exchange_service_server::ExchangeServiceServer;
 use risingwave_pb::task_service::task_service_server::TaskServiceServer;
-use risingwave_rpc_client::{ComputeClientPool, ExtraInfoSourceRef, MetaClient};
+use risingwave_rpc_client::{
+    grpc_server_builder, init_client_tls, ComputeClientPool, ExtraInfoSourceRef, MetaClient,
+};
 use risingwave_source::dml_manager::DmlManager;
 use risingwave_source::monitor::SourceMetrics;
 use risingwave_source::TableSourceManager;
@@ -61,6 +63,9 @@ use crate::rpc::service::monitor_service::{
 use crate::rpc::service::stream_service::StreamServiceImpl;
 use crate::{AsyncStackTraceOption, ComputeNodeConfig, ComputeNodeOpts};
 
+/// How long running batch tasks may take to finish once the compute node starts shutting down.
+const BATCH_TASK_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Bootstraps the compute-node.
 pub async fn compute_node_serve(
     listen_addr: SocketAddr,
@@ -78,6 +83,8 @@ pub async fn compute_node_serve(
     let storage_config = Arc::new(config.storage.clone());
     let stream_config = Arc::new(config.streaming.clone());
     let batch_config = Arc::new(config.batch.clone());
+    init_client_tls(&config.server).unwrap();
+    let server_builder = grpc_server_builder(&config.server).unwrap();
 
     // Register to the cluster. We're not ready to serve until activate is called.
     let meta_client = MetaClient::register_new(
@@ -187,13 +194,6 @@ pub async fn compute_node_serve(
         monitor_cache(memory_collector, &registry).unwrap();
     }
 
-    sub_tasks.push(MetaClient::start_heartbeat_loop(
-        meta_client.clone(),
-        Duration::from_millis(config.server.heartbeat_interval_ms as u64),
-        Duration::from_secs(config.server.max_heartbeat_interval_secs as u64),
-        extra_info_sources,
-    ));
-
     let async_stack_trace_config = match opts.async_stack_trace {
         AsyncStackTraceOption::Off => None,
         c => Some(async_stack_trace::TraceConfig {
@@ -213,10 +213,23 @@ pub async fn compute_node_serve(
         async_stack_trace_config.clone(),
         config.streaming.developer.stream_enable_managed_cache,
     ));
-    let source_mgr = Arc::new(TableSourceManager::new(
-        source_metrics,
-        stream_config.developer.stream_connector_message_buffer_size,
+    extra_info_sources.push(stream_mgr.clone());
+
+    sub_tasks.push(MetaClient::start_heartbeat_loop(
+        meta_client.clone(),
+        Duration::from_millis(config.server.heartbeat_interval_ms as u64),
+        Duration::from_secs(config.server.max_heartbeat_interval_secs as u64),
+        extra_info_sources,
     ));
+
+    let source_mgr = Arc::new(
+        TableSourceManager::new(
+            source_metrics,
+            stream_config.developer.stream_connector_message_buffer_size,
+            meta_client.row_id_generator_slot(),
+        )
+        .unwrap(),
+    );
     let grpc_stack_trace_mgr = GrpcStackTraceManagerRef::default();
     let dml_mgr = Arc::new(DmlManager::default());
 
@@ -263,13 +276,17 @@ pub async fn compute_node_serve(
     let exchange_srv =
         ExchangeServiceImpl::new(batch_mgr.clone(), stream_mgr.clone(), exchange_srv_metrics);
     let stream_srv = StreamServiceImpl::new(stream_mgr.clone(), stream_env.clone());
-    let monitor_srv = MonitorServiceImpl::new(stream_mgr.clone(), grpc_stack_trace_mgr.clone());
-    let config_srv = ConfigServiceImpl::new(batch_mgr, stream_mgr);
+    let monitor_srv = MonitorServiceImpl::new(
+        batch_mgr.clone(),
+        stream_mgr.clone(),
+        grpc_stack_trace_mgr.clone(),
+    );
+    let config_srv = ConfigServiceImpl::new(batch_mgr.clone(), stream_mgr);
     let health_srv = HealthServiceImpl::new();
 
     let (shutdown_send, mut shutdown_recv) = tokio::sync::oneshot::channel::<()>();
     let join_handle = tokio::spawn(async move {
-        tonic::transport::Server::builder()
+        server_builder
             .initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE)
             .initial_stream_window_size(STREAM_WINDOW_SIZE)
             .tcp_nodelay(true)
@@ -285,17 +302,19 @@ pub async fn compute_node_serve(
             .serve_with_shutdown(listen_addr, async move {
                 tokio::select! {
                     _ = tokio::signal::ctrl_c() => {},
-                    _ = &mut shutdown_recv => {
-                        for (join_handle, shutdown_sender) in sub_tasks {
-                            if let Err(err) = shutdown_sender.send(()) {
-                                tracing::warn!("Failed to send shutdown: {:?}", err);
-                                continue;
-                            }
-                            if let Err(err) = join_handle.await {
-                                tracing::warn!("Failed to join shutdown: {:?}", err);
-                            }
-                        }
-                    },
+                    _ = &mut shutdown_recv => {},
+                }
+                // Stop accepting batch tasks and let the running ones drain before the sub-tasks
+                // (e.g. compactor) are stopped.
+                batch_mgr.shutdown(BATCH_TASK_DRAIN_TIMEOUT).await;
+                for (join_handle, shutdown_sender) in sub_tasks {
+                    if let Err(err) = shutdown_sender.send(()) {
+                        tracing::warn!("Failed to send shutdown: {:?}", err);
+                        continue;
+                    }
+                    if let Err(err) = join_handle.await {
+                        tracing::warn!("Failed to join shutdown: {:?}", err);
+                    }
                 }
             })
             .await
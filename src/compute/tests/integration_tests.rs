@@ -35,6 +35,7 @@ use risingwave_common::test_prelude::DataChunkTestExt;
 use risingwave_common::types::{DataType, IntoOrdered};
 use risingwave_common::util::epoch::EpochPair;
 use risingwave_common::util::sort_util::{OrderPair, OrderType};
+use risingwave_pb::stream_plan::SourceRateLimit;
 use risingwave_source::table_test_utils::create_table_source_desc_builder;
 use risingwave_source::{TableSourceManager, TableSourceManagerRef};
 use risingwave_storage::memory::MemoryStateStore;
@@ -152,6 +153,7 @@ async fn test_table_materialize() -> StreamResult<()> {
         "SourceExecutor".to_string(),
         Arc::new(StreamingMetrics::unused()),
         u64::MAX,
+        SourceRateLimit::default(),
     )?;
 
     // Create a `Materialize` to write the changes to storage
@@ -188,6 +190,7 @@ async fn test_table_materialize() -> StreamResult<()> {
         insert_inner,
         "InsertExecutor".to_string(),
         vec![], // ignore insertion order
+        vec![],
     ));
 
     tokio::spawn(async move {
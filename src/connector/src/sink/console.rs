@@ -120,6 +120,7 @@ fn parse_datum(datum: DatumRef<'_>) -> String {
                 join(v.values_ref().iter().map(|sub_v| parse_datum(*sub_v)), ", ")
             )
         }
+        Some(ScalarRefImpl::Jsonb(v)) => format!("Jsonb({})", v),
         _ => unimplemented!(),
     }
 }
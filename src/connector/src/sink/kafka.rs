@@ -35,6 +35,26 @@ use crate::sink::Result;
 
 pub const KAFKA_SINK: &str = "kafka";
 
+const KAFKA_SINK_RETRY_MAX: &str = "properties.retry.max";
+const KAFKA_SINK_RETRY_INTERVAL_MS: &str = "properties.retry.interval.ms";
+const KAFKA_SINK_TIMEOUT_MS: &str = "properties.timeout.ms";
+
+/// The upper bound of the backoff interval between two retries.
+const KAFKA_SINK_MAX_RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+fn parse_optional<T: std::str::FromStr>(
+    values: &HashMap<String, String>,
+    key: &str,
+) -> Result<Option<T>> {
+    values
+        .get(key)
+        .map(|v| {
+            v.parse::<T>()
+                .map_err(|_| SinkError::Config(format!("invalid value for {}: {}", key, v)))
+        })
+        .transpose()
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct KafkaConfig {
     #[serde(rename = "kafka.brokers")]
@@ -54,6 +74,7 @@ pub struct KafkaConfig {
 
     pub timeout: Duration,
     pub max_retry_num: u32,
+    /// The initial backoff interval, which is doubled after each failed attempt.
     pub retry_interval: Duration,
 }
 
@@ -74,14 +95,27 @@ impl KafkaConfig {
 
         let topic = values.get("kafka.topic").expect("kafka.topic must be set");
 
+        // default timeout is 5 seconds
+        let timeout = parse_optional(&values, KAFKA_SINK_TIMEOUT_MS)?.unwrap_or(5000);
+        // default max retry num is 3
+        let max_retry_num = parse_optional(&values, KAFKA_SINK_RETRY_MAX)?.unwrap_or(3);
+        if max_retry_num == 0 {
+            return Err(SinkError::Config(format!(
+                "{} must be at least 1",
+                KAFKA_SINK_RETRY_MAX
+            )));
+        }
+        // default retry interval is 100ms
+        let retry_interval = parse_optional(&values, KAFKA_SINK_RETRY_INTERVAL_MS)?.unwrap_or(100);
+
         Ok(KafkaConfig {
             brokers: brokers.to_string(),
             topic: topic.to_string(),
             identifier: identifier.to_owned(),
             partition: None,
-            timeout: Duration::from_secs(5), // default timeout is 5 seconds
-            max_retry_num: 3,                // default max retry num is 3
-            retry_interval: Duration::from_millis(100), // default retry interval is 100ms
+            timeout: Duration::from_millis(timeout),
+            max_retry_num,
+            retry_interval: Duration::from_millis(retry_interval),
             format: format.to_string(),
         })
     }
@@ -120,13 +154,15 @@ impl KafkaSink {
         FutKR: Future<Output = KafkaResult<T>> + 'a,
     {
         let mut err = KafkaError::Canceled;
+        let mut backoff = self.config.retry_interval;
         for _ in 0..self.config.max_retry_num {
             match f(&self.conductor).await {
                 Ok(res) => return Ok(res),
                 Err(e) => err = e,
             }
-            // a back off policy
-            tokio::time::sleep(self.config.retry_interval).await;
+            // exponential back off policy
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(KAFKA_SINK_MAX_RETRY_INTERVAL);
         }
         Err(err)
     }
@@ -137,6 +173,7 @@ impl KafkaSink {
         P: ToBytes + ?Sized,
     {
         let mut err = KafkaError::Canceled;
+        let mut backoff = self.config.retry_interval;
 
         for _ in 0..self.config.max_retry_num {
             match self.conductor.send(record).await {
@@ -148,7 +185,8 @@ impl KafkaSink {
             }
             if let KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull) = err {
                 // if the queue is full, we need to wait for some time and retry.
-                tokio::time::sleep(self.config.retry_interval).await;
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(KAFKA_SINK_MAX_RETRY_INTERVAL);
                 continue;
             } else {
                 return Err(err);
@@ -247,8 +285,6 @@ impl Sink for KafkaSink {
         // &self.in_transaction_epoch.unwrap()) && in_txn_epoch <= epoch {     return Ok(())
         // }
 
-        println!("sink chunk {:?}", chunk);
-
         match self.config.format.as_str() {
             "append_only" => self.append_only(chunk, &self.schema).await,
             "debezium" => {
@@ -371,6 +407,7 @@ fn datum_to_json_object(field: &Field, datum: DatumRef<'_>) -> ArrayResult<Value
             }
             json!(map)
         }
+        (DataType::Jsonb, ScalarRefImpl::Jsonb(v)) => v.to_serde_value(),
         _ => unimplemented!(),
     };
 
@@ -442,7 +479,7 @@ impl KafkaTransactionConductor {
     async fn new(config: KafkaConfig) -> Result<Self> {
         let inner: ThreadedProducer<DefaultProducerContext> = ClientConfig::new()
             .set("bootstrap.servers", &config.brokers)
-            .set("message.timeout.ms", "5000")
+            .set("message.timeout.ms", config.timeout.as_millis().to_string())
             .set("transactional.id", &config.identifier) // required by kafka transaction
             .create()
             .await?;
@@ -548,6 +585,39 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_kafka_config_retry() -> Result<()> {
+        let properties = hashmap! {
+            "kafka.brokers".to_string() => "localhost:29092".to_string(),
+            "identifier".to_string() => "test_sink_1".to_string(),
+            "format".to_string() => "append_only".to_string(),
+            "kafka.topic".to_string() => "test_topic".to_string(),
+        };
+        let config = KafkaConfig::from_hashmap(properties.clone())?;
+        assert_eq!(config.max_retry_num, 3);
+        assert_eq!(config.retry_interval, Duration::from_millis(100));
+        assert_eq!(config.timeout, Duration::from_secs(5));
+
+        let mut custom = properties.clone();
+        custom.insert(KAFKA_SINK_RETRY_MAX.to_string(), "10".to_string());
+        custom.insert(KAFKA_SINK_RETRY_INTERVAL_MS.to_string(), "500".to_string());
+        custom.insert(KAFKA_SINK_TIMEOUT_MS.to_string(), "1000".to_string());
+        let config = KafkaConfig::from_hashmap(custom)?;
+        assert_eq!(config.max_retry_num, 10);
+        assert_eq!(config.retry_interval, Duration::from_millis(500));
+        assert_eq!(config.timeout, Duration::from_secs(1));
+
+        let mut invalid = properties.clone();
+        invalid.insert(KAFKA_SINK_RETRY_MAX.to_string(), "-1".to_string());
+        assert!(KafkaConfig::from_hashmap(invalid).is_err());
+
+        let mut zero = properties;
+        zero.insert(KAFKA_SINK_RETRY_MAX.to_string(), "0".to_string());
+        assert!(KafkaConfig::from_hashmap(zero).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_chunk_to_json() -> Result<()> {
         let chunk = StreamChunk::from_pretty(
@@ -319,6 +319,7 @@ fn parse_datum(datum: DatumRef<'_>) -> Value {
                 .map(|v| parse_datum(*v))
                 .collect::<Vec<_>>(),
         ),
+        Some(ScalarRefImpl::Jsonb(v)) => v.to_serde_value(),
         _ => unimplemented!(),
     }
 }
@@ -0,0 +1,176 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+use anyhow::{anyhow, Result};
+use rdkafka::producer::{BaseRecord, DefaultProducerContext, ThreadedProducer};
+use rdkafka::ClientConfig;
+use serde::Serialize;
+
+pub const PARSE_ERROR_POLICY_KEY: &str = "parse.error.policy";
+pub const DEAD_LETTER_BROKERS_KEY: &str = "dead_letter.kafka.brokers";
+pub const DEAD_LETTER_TOPIC_KEY: &str = "dead_letter.kafka.topic";
+
+/// What a source does with a payload that its parser fails to decode, set with
+/// `parse.error.policy` in the `WITH` clause.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ParseErrorPolicy {
+    /// `skip`: logs the error and skips the payload.
+    #[default]
+    Skip,
+    /// `fail`: fails the source.
+    Fail,
+    /// `dead_letter`: sends the payload to a Kafka topic with the error, and skips it.
+    DeadLetter(DeadLetterConfig),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeadLetterConfig {
+    pub brokers: String,
+    pub topic: String,
+}
+
+impl ParseErrorPolicy {
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Self> {
+        let get = |key: &str| {
+            properties
+                .get(key)
+                .cloned()
+                .ok_or_else(|| anyhow!("'{}' must be set for the dead letter queue", key))
+        };
+
+        match properties.get(PARSE_ERROR_POLICY_KEY).map(String::as_str) {
+            None | Some("skip") => Ok(Self::Skip),
+            Some("fail") => Ok(Self::Fail),
+            Some("dead_letter") => Ok(Self::DeadLetter(DeadLetterConfig {
+                brokers: get(DEAD_LETTER_BROKERS_KEY)?,
+                topic: get(DEAD_LETTER_TOPIC_KEY)?,
+            })),
+            Some(policy) => Err(anyhow!(
+                "'{}' must be one of 'skip', 'fail' and 'dead_letter', got '{}'",
+                PARSE_ERROR_POLICY_KEY,
+                policy
+            )),
+        }
+    }
+}
+
+/// Where a payload failed to be parsed, and why. It is sent as the key of the dead letter, whose
+/// value is the raw payload, so that the payload can be replayed as it is.
+#[derive(Debug, Serialize)]
+pub struct DeadLetterKey<'a> {
+    pub source_id: u32,
+    pub split_id: &'a str,
+    pub offset: &'a str,
+    pub error: &'a str,
+}
+
+/// Produces the payloads that fail to be parsed to a Kafka topic.
+pub struct DeadLetterQueue {
+    topic: String,
+    producer: ThreadedProducer<DefaultProducerContext>,
+}
+
+impl DeadLetterQueue {
+    pub async fn new(config: &DeadLetterConfig) -> Result<Self> {
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", &config.brokers)
+            .create()
+            .await?;
+        Ok(Self {
+            topic: config.topic.clone(),
+            producer,
+        })
+    }
+
+    /// Enqueues `payload` to be sent in the background. Fails if the queue of the producer is full.
+    pub fn send(&self, key: &DeadLetterKey<'_>, payload: &[u8]) -> Result<()> {
+        let key = serde_json::to_vec(key)?;
+        self.producer
+            .send(BaseRecord::to(&self.topic).key(&key).payload(payload))
+            .map_err(|(e, _)| e)?;
+        Ok(())
+    }
+}
+
+impl Debug for DeadLetterQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeadLetterQueue")
+            .field("topic", &self.topic)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashmap;
+
+    use super::*;
+
+    fn policy(properties: HashMap<&str, &str>) -> Result<ParseErrorPolicy> {
+        let properties = properties
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        ParseErrorPolicy::from_properties(&properties)
+    }
+
+    #[test]
+    fn test_parse_error_policy() {
+        assert_eq!(policy(hashmap! {}).unwrap(), ParseErrorPolicy::Skip);
+        assert_eq!(
+            policy(hashmap! { "parse.error.policy" => "fail" }).unwrap(),
+            ParseErrorPolicy::Fail
+        );
+        assert_eq!(
+            policy(hashmap! {
+                "parse.error.policy" => "dead_letter",
+                "dead_letter.kafka.brokers" => "127.0.0.1:29092",
+                "dead_letter.kafka.topic" => "dlq",
+            })
+            .unwrap(),
+            ParseErrorPolicy::DeadLetter(DeadLetterConfig {
+                brokers: "127.0.0.1:29092".to_string(),
+                topic: "dlq".to_string(),
+            })
+        );
+
+        let err = policy(hashmap! { "parse.error.policy" => "dead_letter" }).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'dead_letter.kafka.brokers' must be set for the dead letter queue"
+        );
+        let err = policy(hashmap! { "parse.error.policy" => "ignore" }).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'parse.error.policy' must be one of 'skip', 'fail' and 'dead_letter', got 'ignore'"
+        );
+    }
+
+    #[test]
+    fn test_dead_letter_key() {
+        let key = DeadLetterKey {
+            source_id: 1,
+            split_id: "0",
+            offset: "42",
+            error: "invalid json",
+        };
+        assert_eq!(
+            serde_json::to_string(&key).unwrap(),
+            r#"{"source_id":1,"split_id":"0","offset":"42","error":"invalid json"}"#
+        );
+    }
+}
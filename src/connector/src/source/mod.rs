@@ -15,6 +15,7 @@
 pub mod base;
 pub mod cdc;
 pub mod datagen;
+pub mod dead_letter;
 pub mod dummy_connector;
 pub mod filesystem;
 pub mod google_pubsub;
@@ -15,7 +15,9 @@
 mod cluster_info;
 mod pause_resume;
 mod reschedule;
+mod source_rate_limit;
 
 pub use cluster_info::*;
 pub use pause_resume::*;
 pub use reschedule::*;
+pub use source_rate_limit::*;
@@ -0,0 +1,40 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::stream_plan::SourceRateLimit;
+
+use crate::common::MetaServiceOpts;
+
+pub async fn set_source_rate_limit(
+    source_id: u32,
+    rows_per_second: u64,
+    bytes_per_second: u64,
+    burst_ms: u64,
+) -> anyhow::Result<()> {
+    let meta_opts = MetaServiceOpts::from_env()?;
+    let meta_client = meta_opts.create_meta_client().await?;
+
+    let rate_limit = SourceRateLimit {
+        rows_per_second,
+        bytes_per_second,
+        burst_ms,
+    };
+    meta_client
+        .set_source_rate_limit(source_id, rate_limit)
+        .await?;
+
+    println!("Rate limit of source {} set", source_id);
+
+    Ok(())
+}
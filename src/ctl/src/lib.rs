@@ -190,6 +190,21 @@ enum MetaCommands {
         #[clap(long)]
         dry_run: bool,
     },
+    /// Set the rate limit of a source at runtime, applied to each of its splits
+    SetSourceRateLimit {
+        /// The id of the source
+        #[clap(long)]
+        source_id: u32,
+        /// The maximum rows per second read from each split, 0 for unlimited
+        #[clap(long, default_value_t = 0)]
+        rows_per_second: u64,
+        /// The maximum bytes per second read from each split, 0 for unlimited
+        #[clap(long, default_value_t = 0)]
+        bytes_per_second: u64,
+        /// The milliseconds of the rate that a source can burst after being idle
+        #[clap(long, default_value_t = 1000)]
+        burst_ms: u64,
+    },
 }
 
 pub async fn start(opts: CliOpts) -> Result<()> {
@@ -271,6 +286,20 @@ pub async fn start(opts: CliOpts) -> Result<()> {
         Commands::Meta(MetaCommands::Reschedule { plan, dry_run }) => {
             cmd_impl::meta::reschedule(plan, dry_run).await?
         }
+        Commands::Meta(MetaCommands::SetSourceRateLimit {
+            source_id,
+            rows_per_second,
+            bytes_per_second,
+            burst_ms,
+        }) => {
+            cmd_impl::meta::set_source_rate_limit(
+                source_id,
+                rows_per_second,
+                bytes_per_second,
+                burst_ms,
+            )
+            .await?
+        }
         Commands::Trace => cmd_impl::trace::trace().await?,
         Commands::Profile { sleep } => cmd_impl::profile::profile(sleep).await?,
     }
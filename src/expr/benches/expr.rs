@@ -0,0 +1,108 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{DataChunk, I64Array, Utf8Array};
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::expr_binary_nonnull::new_binary_expr;
+use risingwave_expr::expr::expr_unary::new_unary_expr;
+use risingwave_expr::expr::{BoxedExpression, Expression, InputRefExpression};
+use risingwave_pb::expr::expr_node::Type;
+
+const CHUNK_SIZE: usize = 1024;
+
+/// Creates a chunk with columns `(i64, i64, varchar, varchar)`, where every 10th row is null.
+fn create_input() -> DataChunk {
+    let ints = |offset: i64| {
+        (0..CHUNK_SIZE as i64)
+            .map(|i| (i % 10 != 0).then_some(i + offset))
+            .collect::<Vec<_>>()
+    };
+    let strs = ints(0)
+        .iter()
+        .map(|i| i.map(|i| format!("value_{}", i)))
+        .collect::<Vec<_>>();
+    let strs = strs.iter().map(|s| s.as_deref()).collect::<Vec<_>>();
+    let columns = vec![
+        Column::new(Arc::new(I64Array::from_slice(&ints(0)).into())),
+        Column::new(Arc::new(I64Array::from_slice(&ints(1)).into())),
+        Column::new(Arc::new(Utf8Array::from_slice(&strs).into())),
+        Column::new(Arc::new(Utf8Array::from_slice(&strs).into())),
+    ];
+    DataChunk::new(columns, CHUNK_SIZE)
+}
+
+fn input_ref(idx: usize, ty: DataType) -> BoxedExpression {
+    Box::new(InputRefExpression::new(ty, idx))
+}
+
+fn bench_expr(c: &mut Criterion) {
+    let input = create_input();
+    let exprs: Vec<(&str, BoxedExpression)> = vec![
+        (
+            "add(i64, i64)",
+            new_binary_expr(
+                Type::Add,
+                DataType::Int64,
+                input_ref(0, DataType::Int64),
+                input_ref(1, DataType::Int64),
+            )
+            .unwrap(),
+        ),
+        (
+            "less_than(i64, i64)",
+            new_binary_expr(
+                Type::LessThan,
+                DataType::Boolean,
+                input_ref(0, DataType::Int64),
+                input_ref(1, DataType::Int64),
+            )
+            .unwrap(),
+        ),
+        (
+            "equal(varchar, varchar)",
+            new_binary_expr(
+                Type::Equal,
+                DataType::Boolean,
+                input_ref(2, DataType::Varchar),
+                input_ref(3, DataType::Varchar),
+            )
+            .unwrap(),
+        ),
+        (
+            "cast(i64 as varchar)",
+            new_unary_expr(Type::Cast, DataType::Varchar, input_ref(0, DataType::Int64)).unwrap(),
+        ),
+        (
+            "upper(varchar)",
+            new_unary_expr(
+                Type::Upper,
+                DataType::Varchar,
+                input_ref(2, DataType::Varchar),
+            )
+            .unwrap(),
+        ),
+    ];
+    for (name, expr) in exprs {
+        c.bench_with_input(BenchmarkId::new("Expression", name), &input, |b, input| {
+            b.iter(|| black_box(expr.eval(input).unwrap()))
+        });
+    }
+}
+
+criterion_group!(benches, bench_expr);
+criterion_main!(benches);
@@ -47,6 +47,9 @@ pub enum ExprError {
     #[error("Array error: {0}")]
     Array(#[from] ArrayError),
 
+    #[error("Invalid input ref: column {idx} is out of range for input with {width} columns")]
+    InputRefOutOfRange { idx: usize, width: usize },
+
     #[error("More than one row returned by {0} used as an expression")]
     MaxOneRow(&'static str),
 
@@ -18,22 +18,27 @@ use risingwave_pb::expr::expr_node::RexNode;
 use risingwave_pb::expr::ExprNode;
 
 use crate::expr::expr_binary_bytes::{
-    new_ltrim_characters, new_repeat, new_rtrim_characters, new_substr_start, new_to_char,
-    new_trim_characters,
+    new_lpad, new_ltrim_characters, new_repeat, new_rpad, new_rtrim_characters, new_substr_start,
+    new_to_char, new_trim_characters,
+};
+use crate::expr::expr_binary_nonnull::{
+    new_binary_expr, new_date_trunc_expr, new_like_default, new_to_timestamp,
 };
-use crate::expr::expr_binary_nonnull::{new_binary_expr, new_date_trunc_expr, new_like_default};
 use crate::expr::expr_binary_nullable::new_nullable_binary_expr;
 use crate::expr::expr_quaternary_bytes::new_overlay_for_exp;
 use crate::expr::expr_ternary_bytes::{
-    new_overlay_exp, new_replace_expr, new_split_part_expr, new_substr_start_end,
-    new_translate_expr,
+    new_lpad_fill, new_overlay_exp, new_replace_expr, new_rpad_fill, new_split_part_expr,
+    new_substr_start_end, new_translate_expr,
 };
 use crate::expr::expr_to_char_const_tmpl::{ExprToCharConstTmpl, ExprToCharConstTmplContext};
+use crate::expr::expr_to_timestamp_const_tmpl::{
+    ExprToTimestampConstTmpl, ExprToTimestampConstTmplContext,
+};
 use crate::expr::expr_unary::{
     new_length_default, new_ltrim_expr, new_rtrim_expr, new_trim_expr, new_unary_expr,
 };
 use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
-use crate::vector_op::to_char::compile_pattern_to_chrono;
+use crate::vector_op::to_char::{compile_pattern_to_chrono, compile_pattern_to_chrono_parsing};
 use crate::{bail, ensure, Result};
 
 fn get_children_and_return_type(prost: &ExprNode) -> Result<(Vec<ExprNode>, DataType)> {
@@ -120,6 +125,32 @@ pub fn build_substr_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     }
 }
 
+pub fn build_lpad_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    let s = expr_build_from_prost(&children[0])?;
+    let length = expr_build_from_prost(&children[1])?;
+    if let Some(fill) = children.get(2) {
+        let fill = expr_build_from_prost(fill)?;
+        Ok(new_lpad_fill(s, length, fill, ret_type))
+    } else {
+        Ok(new_lpad(s, length, ret_type))
+    }
+}
+
+pub fn build_rpad_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    let s = expr_build_from_prost(&children[0])?;
+    let length = expr_build_from_prost(&children[1])?;
+    if let Some(fill) = children.get(2) {
+        let fill = expr_build_from_prost(fill)?;
+        Ok(new_rpad_fill(s, length, fill, ret_type))
+    } else {
+        Ok(new_rpad(s, length, ret_type))
+    }
+}
+
 pub fn build_trim_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_children_and_return_type(prost)?;
     ensure!(!children.is_empty() && children.len() <= 2);
@@ -246,6 +277,33 @@ pub fn build_to_char_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     }
 }
 
+pub fn build_to_timestamp_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    // `to_timestamp(double precision)` converts UNIX epoch seconds.
+    if children.len() == 1 {
+        return build_unary_expr_prost(prost);
+    }
+    ensure!(children.len() == 2);
+    let data_expr = expr_build_from_prost(&children[0])?;
+    let tmpl_node = &children[1];
+    if let RexNode::Constant(tmpl_value) = tmpl_node.get_rex_node().unwrap()
+        && let Ok(Some(tmpl)) = deserialize_datum(tmpl_value.get_body().as_slice(), &DataType::from(tmpl_node.get_return_type().unwrap()))
+    {
+        let tmpl = tmpl.as_utf8();
+        let pattern = compile_pattern_to_chrono_parsing(tmpl);
+
+        Ok(ExprToTimestampConstTmpl {
+            ctx: ExprToTimestampConstTmplContext {
+                chrono_tmpl: pattern,
+            },
+            child: data_expr,
+        }.boxed())
+    } else {
+        let tmpl_expr = expr_build_from_prost(&children[1])?;
+        Ok(new_to_timestamp(data_expr, tmpl_expr, ret_type))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
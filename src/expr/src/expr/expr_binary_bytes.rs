@@ -21,6 +21,7 @@ use super::Expression;
 use crate::expr::template::BinaryBytesExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::concat_op::concat_op;
+use crate::vector_op::pad::{lpad, rpad};
 use crate::vector_op::repeat::repeat;
 use crate::vector_op::substr::*;
 use crate::vector_op::to_char::to_char_timestamp;
@@ -79,6 +80,24 @@ pub fn new_repeat(
         .boxed()
 }
 
+pub fn new_lpad(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    BinaryBytesExpression::<Utf8Array, I32Array, _>::new(expr_ia1, expr_ia2, return_type, lpad)
+        .boxed()
+}
+
+pub fn new_rpad(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    BinaryBytesExpression::<Utf8Array, I32Array, _>::new(expr_ia1, expr_ia2, return_type, rpad)
+        .boxed()
+}
+
 macro_rules! impl_utf8_utf8 {
     ($({ $func_name:ident, $method:ident }),*) => {
         $(pub fn $func_name(
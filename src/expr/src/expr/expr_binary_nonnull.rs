@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use risingwave_common::array::{
-    Array, BoolArray, DecimalArray, I32Array, I64Array, IntervalArray, ListArray, NaiveDateArray,
-    NaiveDateTimeArray, StructArray, Utf8Array,
+    Array, BoolArray, DecimalArray, I32Array, I64Array, IntervalArray, JsonbArray, ListArray,
+    NaiveDateArray, NaiveDateTimeArray, StructArray, Utf8Array,
 };
 use risingwave_common::types::*;
 use risingwave_pb::expr::expr_node::Type;
@@ -34,6 +34,7 @@ use crate::vector_op::like::like_default;
 use crate::vector_op::position::position;
 use crate::vector_op::round::round_digits;
 use crate::vector_op::timestampz::{timestamp_at_time_zone, timestampz_at_time_zone};
+use crate::vector_op::to_char::to_timestamp;
 use crate::vector_op::tumble::{
     tumble_start_date, tumble_start_date_time, tumble_start_timestampz,
 };
@@ -189,6 +190,14 @@ macro_rules! gen_binary_expr_cmp {
                     gen_list_cmp($op),
                 ))
             }
+            (DataType::Jsonb, DataType::Jsonb) => Box::new(BinaryExpression::<
+                JsonbArray,
+                JsonbArray,
+                BoolArray,
+                _,
+            >::new(
+                $l, $r, $ret, gen_jsonb_cmp($op)
+            )),
             _ => {
                 for_all_cmp_variants! {$macro, $l, $r, $ret, $general_f}
             }
@@ -679,6 +688,19 @@ pub fn new_like_default(
     ))
 }
 
+pub fn new_to_timestamp(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryExpression::<Utf8Array, Utf8Array, I64Array, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        to_timestamp,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::interval_array::IntervalArray;
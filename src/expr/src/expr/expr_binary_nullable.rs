@@ -24,11 +24,16 @@ use risingwave_pb::expr::expr_node::Type;
 use super::{BoxedExpression, Expression};
 use crate::expr::template::BinaryNullableExpression;
 use crate::vector_op::array_access::array_access;
+use crate::vector_op::array_length::array_length;
 use crate::vector_op::cmp::{
-    general_is_distinct_from, general_is_not_distinct_from, str_is_distinct_from,
-    str_is_not_distinct_from,
+    general_is_distinct_from, general_is_not_distinct_from, jsonb_is_distinct_from,
+    jsonb_is_not_distinct_from, str_is_distinct_from, str_is_not_distinct_from,
 };
 use crate::vector_op::conjunction::{and, or};
+use crate::vector_op::jsonb_access::{
+    jsonb_array_element, jsonb_array_element_str, jsonb_extract_path, jsonb_object_field,
+    jsonb_object_field_str,
+};
 use crate::{for_all_cmp_variants, ExprError, Result};
 
 macro_rules! gen_nullable_cmp_impl {
@@ -194,21 +199,31 @@ pub fn new_nullable_binary_expr(
     l: BoxedExpression,
     r: BoxedExpression,
 ) -> Result<BoxedExpression> {
-    let expr = match expr_type {
-        Type::ArrayAccess => build_array_access_expr(ret, l, r),
-        Type::And => Box::new(BinaryShortCircuitExpression::new(l, r, expr_type)),
-        Type::Or => Box::new(BinaryShortCircuitExpression::new(l, r, expr_type)),
-        Type::IsDistinctFrom => new_distinct_from_expr(l, r, ret)?,
-        Type::IsNotDistinctFrom => new_not_distinct_from_expr(l, r, ret)?,
-        tp => {
-            return Err(ExprError::UnsupportedFunction(format!(
-                "{:?}({:?}, {:?})",
-                tp,
-                l.return_type(),
-                r.return_type(),
-            )));
-        }
-    };
+    let expr =
+        match expr_type {
+            Type::ArrayAccess => build_array_access_expr(ret, l, r),
+            Type::ArrayLength => Box::new(BinaryNullableExpression::<
+                ListArray,
+                I32Array,
+                I32Array,
+                _,
+            >::new(l, r, ret, array_length)),
+            Type::And => Box::new(BinaryShortCircuitExpression::new(l, r, expr_type)),
+            Type::Or => Box::new(BinaryShortCircuitExpression::new(l, r, expr_type)),
+            Type::IsDistinctFrom => new_distinct_from_expr(l, r, ret)?,
+            Type::IsNotDistinctFrom => new_not_distinct_from_expr(l, r, ret)?,
+            Type::JsonbAccessInner | Type::JsonbAccessStr | Type::JsonbAccessPath => {
+                build_jsonb_access_expr(expr_type, ret, l, r)?
+            }
+            tp => {
+                return Err(ExprError::UnsupportedFunction(format!(
+                    "{:?}({:?}, {:?})",
+                    tp,
+                    l.return_type(),
+                    r.return_type(),
+                )));
+            }
+        };
     Ok(expr)
 }
 
@@ -246,9 +261,69 @@ fn build_array_access_expr(
         DataType::Interval => array_access_expression!(IntervalArray),
         DataType::Struct { .. } => array_access_expression!(StructArray),
         DataType::List { .. } => array_access_expression!(ListArray),
+        DataType::Jsonb => array_access_expression!(JsonbArray),
     }
 }
 
+fn build_jsonb_access_expr(
+    expr_type: Type,
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> Result<BoxedExpression> {
+    let expr: BoxedExpression = match (expr_type, r.return_type()) {
+        (Type::JsonbAccessInner, DataType::Varchar) => {
+            Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                Utf8Array,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_object_field))
+        }
+        (Type::JsonbAccessInner, DataType::Int32) => {
+            Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                I32Array,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_array_element))
+        }
+        (Type::JsonbAccessStr, DataType::Varchar) => {
+            Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                Utf8Array,
+                Utf8Array,
+                _,
+            >::new(l, r, ret, jsonb_object_field_str))
+        }
+        (Type::JsonbAccessStr, DataType::Int32) => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            I32Array,
+            Utf8Array,
+            _,
+        >::new(
+            l, r, ret, jsonb_array_element_str
+        )),
+        (Type::JsonbAccessPath, DataType::List { .. }) => {
+            Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                ListArray,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_extract_path))
+        }
+        (tp, rt) => {
+            return Err(ExprError::UnsupportedFunction(format!(
+                "{:?}({:?}, {:?})",
+                tp,
+                l.return_type(),
+                rt,
+            )));
+        }
+    };
+    Ok(expr)
+}
+
 pub fn new_distinct_from_expr(
     l: BoxedExpression,
     r: BoxedExpression,
@@ -265,6 +340,14 @@ pub fn new_distinct_from_expr(
         >::new(
             l, r, ret, str_is_distinct_from
         )),
+        (DataType::Jsonb, DataType::Jsonb) => {
+            Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                JsonbArray,
+                BoolArray,
+                _,
+            >::new(l, r, ret, jsonb_is_distinct_from))
+        }
         _ => {
             for_all_cmp_variants! {gen_nullable_cmp_impl, l, r, ret, general_is_distinct_from}
         }
@@ -288,6 +371,14 @@ pub fn new_not_distinct_from_expr(
         >::new(
             l, r, ret, str_is_not_distinct_from
         )),
+        (DataType::Jsonb, DataType::Jsonb) => {
+            Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                JsonbArray,
+                BoolArray,
+                _,
+            >::new(l, r, ret, jsonb_is_not_distinct_from))
+        }
         _ => {
             for_all_cmp_variants! {gen_nullable_cmp_impl, l, r, ret, general_is_not_distinct_from}
         }
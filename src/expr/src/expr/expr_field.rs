@@ -17,7 +17,7 @@ use std::convert::TryFrom;
 use anyhow::anyhow;
 use risingwave_common::array::{ArrayImpl, ArrayRef, DataChunk};
 use risingwave_common::row::Row;
-use risingwave_common::types::{DataType, Datum};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
 use risingwave_common::util::value_encoding::deserialize_datum;
 use risingwave_pb::expr::expr_node::{RexNode, Type};
 use risingwave_pb::expr::ExprNode;
@@ -47,8 +47,12 @@ impl Expression for FieldExpression {
         }
     }
 
-    fn eval_row(&self, _input: &Row) -> Result<Datum> {
-        Err(anyhow!("expects a struct array ref").into())
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        match self.input.eval_row(input)? {
+            Some(ScalarImpl::Struct(value)) => Ok(value.fields()[self.index].clone()),
+            Some(_) => Err(anyhow!("expects a struct value").into()),
+            None => Ok(None),
+        }
     }
 }
 
@@ -95,7 +99,8 @@ impl<'a> TryFrom<&'a ExprNode> for FieldExpression {
 mod tests {
 
     use risingwave_common::array;
-    use risingwave_common::array::{DataChunk, F32Array, I32Array, StructArray};
+    use risingwave_common::array::{DataChunk, F32Array, I32Array, StructArray, StructValue};
+    use risingwave_common::row::Row;
     use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_pb::data::data_type::TypeName;
 
@@ -167,4 +172,24 @@ mod tests {
         assert_eq!(res.datum_at(3), Some(ScalarImpl::Float32(4.0.into())));
         assert_eq!(res.datum_at(4), Some(ScalarImpl::Float32(5.0.into())));
     }
+
+    #[test]
+    fn test_field_expr_eval_row() {
+        let field_expr = FieldExpression::try_from(&make_field_function(
+            vec![make_input_ref(0, TypeName::Struct), make_i32_literal(1)],
+            TypeName::Float32,
+        ))
+        .unwrap();
+
+        let value = StructValue::new(vec![
+            Some(ScalarImpl::Int32(1)),
+            Some(ScalarImpl::Float32(2.0.into())),
+        ]);
+        let row = Row::new(vec![Some(ScalarImpl::Struct(value))]);
+        assert_eq!(
+            field_expr.eval_row(&row).unwrap(),
+            Some(ScalarImpl::Float32(2.0.into()))
+        );
+        assert_eq!(field_expr.eval_row(&Row::new(vec![None])).unwrap(), None);
+    }
 }
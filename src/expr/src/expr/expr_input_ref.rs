@@ -13,10 +13,9 @@
 // limitations under the License.
 
 use std::convert::TryFrom;
-use std::ops::Index;
 
 use risingwave_common::array::{ArrayRef, DataChunk};
-use risingwave_common::row::Row;
+use risingwave_common::row::{Row, Row2};
 use risingwave_common::types::{DataType, Datum};
 use risingwave_pb::expr::expr_node::{RexNode, Type};
 use risingwave_pb::expr::ExprNode;
@@ -37,12 +36,12 @@ impl Expression for InputRefExpression {
     }
 
     fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
-        Ok(input.column_at(self.idx).array())
+        self.eval_immut(input)
     }
 
     fn eval_row(&self, input: &Row) -> Result<Datum> {
-        let cell = input.index(self.idx).as_ref().cloned();
-        Ok(cell)
+        self.check_bound(input.len())?;
+        Ok(input.datum_at(self.idx).map(|s| s.into_scalar_impl()))
     }
 }
 
@@ -56,8 +55,20 @@ impl InputRefExpression {
     }
 
     pub fn eval_immut(&self, input: &DataChunk) -> Result<ArrayRef> {
+        self.check_bound(input.dimension())?;
         Ok(input.column_at(self.idx).array())
     }
+
+    /// Returns an error instead of panicking if the plan references a column beyond the input.
+    fn check_bound(&self, width: usize) -> Result<()> {
+        if self.idx >= width {
+            return Err(ExprError::InputRefOutOfRange {
+                idx: self.idx,
+                width,
+            });
+        }
+        Ok(())
+    }
 }
 
 impl<'a> TryFrom<&'a ExprNode> for InputRefExpression {
@@ -80,10 +91,16 @@ impl<'a> TryFrom<&'a ExprNode> for InputRefExpression {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::DataChunk;
     use risingwave_common::row::Row;
+    use risingwave_common::test_prelude::DataChunkTestExt;
+    use risingwave_common::types::struct_type::StructType;
     use risingwave_common::types::{DataType, Datum};
 
     use crate::expr::{Expression, InputRefExpression};
+    use crate::ExprError;
 
     #[test]
     fn test_eval_row_input_ref() {
@@ -96,4 +113,43 @@ mod tests {
             assert_eq!(*expected, result);
         }
     }
+
+    #[test]
+    fn test_eval_input_ref_all_types() {
+        let chunk = DataChunk::from_pretty(
+            "TS                  T   {i,F}
+             2022-01-01T10:00:00 abc {1,1.5}
+             .                   .   .",
+        );
+        let types = [
+            DataType::Timestamp,
+            DataType::Varchar,
+            DataType::Struct(Arc::new(StructType {
+                fields: vec![DataType::Int32, DataType::Float64],
+                field_names: vec![],
+            })),
+        ];
+        for (i, ty) in types.into_iter().enumerate() {
+            let expr = InputRefExpression::new(ty, i);
+            let result = expr.eval(&chunk).unwrap();
+            assert_eq!(*result, *chunk.column_at(i).array());
+        }
+    }
+
+    #[test]
+    fn test_input_ref_out_of_range() {
+        let chunk = DataChunk::from_pretty(
+            "i i
+             1 2",
+        );
+        let expr = InputRefExpression::new(DataType::Int32, 2);
+        assert!(matches!(
+            expr.eval(&chunk),
+            Err(ExprError::InputRefOutOfRange { idx: 2, width: 2 })
+        ));
+        assert!(matches!(
+            expr.eval_row(&Row::new(vec![Some(1.into())])),
+            Err(ExprError::InputRefOutOfRange { idx: 2, width: 1 })
+        ));
+    }
 }
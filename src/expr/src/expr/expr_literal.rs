@@ -55,7 +55,9 @@ impl Expression for LiteralExpression {
                         }
                     )*
                     (_, _) => $crate::bail!(
-                        "Do not support values in insert values executor".to_string()
+                        "literal {:?} does not match type {:?}",
+                        literal,
+                        self.return_type
                     ),
                 }
             };
@@ -114,11 +116,14 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
 
         if let RexNode::Constant(prost_value) = prost.get_rex_node().unwrap() {
             // TODO: We need to unify these
-            let value = deserialize_datum(
-                prost_value.get_body().as_slice(),
-                &DataType::from(prost.get_return_type().unwrap()),
-            )
-            .map_err(|e| ExprError::Internal(e.into()))?;
+            let value = deserialize_datum(prost_value.get_body().as_slice(), &ret_type)
+                .map_err(|e| ExprError::Internal(e.into()))?;
+            ensure!(
+                literal_type_match(&ret_type, value.as_ref()),
+                "literal {:?} does not match type {:?}",
+                value,
+                ret_type
+            );
             Ok(Self {
                 return_type: ret_type,
                 literal: value,
@@ -133,7 +138,9 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
 mod tests {
     use risingwave_common::array::{I32Array, StructValue};
     use risingwave_common::array_nonnull;
-    use risingwave_common::types::{Decimal, IntervalUnit, IntoOrdered};
+    use risingwave_common::types::{
+        Decimal, IntervalUnit, IntoOrdered, NaiveDateTimeWrapper, NaiveDateWrapper, ToOwnedDatum,
+    };
     use risingwave_common::util::value_encoding::serialize_datum_to_bytes;
     use risingwave_pb::data::data_type::{IntervalType, TypeName};
     use risingwave_pb::data::{DataType as ProstDataType, Datum as ProstDatum};
@@ -257,6 +264,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_temporal_expr_literal_from() {
+        let v = NaiveDateWrapper::from_ymd_uncheck(1994, 1, 1);
+        let bytes = serialize_datum_to_bytes(Some(v.to_scalar_value()).as_ref());
+        let expr =
+            LiteralExpression::try_from(&make_expression(Some(bytes), TypeName::Date)).unwrap();
+        assert_eq!(v.to_scalar_value(), expr.literal().unwrap());
+
+        let v = NaiveDateTimeWrapper::from_protobuf(757_386_123_000_000).unwrap();
+        let bytes = serialize_datum_to_bytes(Some(v.to_scalar_value()).as_ref());
+        let expr = LiteralExpression::try_from(&make_expression(Some(bytes), TypeName::Timestamp))
+            .unwrap();
+        assert_eq!(v.to_scalar_value(), expr.literal().unwrap());
+
+        // The result column has one row per row of the input chunk.
+        let result = expr.eval(&DataChunk::new_dummy(3)).unwrap();
+        assert_eq!(result.len(), 3);
+        assert!(result
+            .iter()
+            .all(|d| d.to_owned_datum() == Some(v.to_scalar_value())));
+    }
+
     #[test]
     fn test_literal_eval_dummy_chunk() {
         let literal = LiteralExpression::new(DataType::Int32, Some(1.into()));
@@ -23,11 +23,14 @@ use crate::expr::expr_is_null::{IsNotNullExpression, IsNullExpression};
 use crate::expr::template::UnaryNullableExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::{decimal_abs, general_abs, general_neg};
+use crate::vector_op::array_length::cardinality;
 use crate::vector_op::ascii::ascii;
 use crate::vector_op::bitwise_op::general_bitnot;
 use crate::vector_op::cast::*;
 use crate::vector_op::cmp::{is_false, is_not_false, is_not_true, is_true};
 use crate::vector_op::conjunction;
+use crate::vector_op::jsonb_access::{jsonb_array_length, jsonb_typeof};
+use crate::vector_op::justify::{justify_days, justify_hours};
 use crate::vector_op::length::{bit_length, length_default, octet_length};
 use crate::vector_op::lower::lower;
 use crate::vector_op::ltrim::ltrim;
@@ -240,6 +243,35 @@ pub fn new_unary_expr(
             return_type,
             bit_length,
         )),
+        (ProstType::JustifyDays, _, _) => {
+            Box::new(UnaryExpression::<IntervalArray, IntervalArray, _>::new(
+                child_expr,
+                return_type,
+                justify_days,
+            ))
+        }
+        (ProstType::JustifyHours, _, _) => {
+            Box::new(UnaryExpression::<IntervalArray, IntervalArray, _>::new(
+                child_expr,
+                return_type,
+                justify_hours,
+            ))
+        }
+        (ProstType::JsonbTypeof, _, _) => Box::new(
+            UnaryExpression::<JsonbArray, Utf8Array, _>::new(child_expr, return_type, jsonb_typeof),
+        ),
+        (ProstType::JsonbArrayLength, _, _) => {
+            Box::new(UnaryExpression::<JsonbArray, I32Array, _>::new(
+                child_expr,
+                return_type,
+                jsonb_array_length,
+            ))
+        }
+        (ProstType::Cardinality, _, _) => Box::new(UnaryExpression::<ListArray, I32Array, _>::new(
+            child_expr,
+            return_type,
+            cardinality,
+        )),
         (ProstType::Neg, _, _) => {
             gen_unary_atm_expr! { "Neg", child_expr, return_type, general_neg,
                 {
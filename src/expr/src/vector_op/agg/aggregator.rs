@@ -26,6 +26,7 @@ use crate::expr::{build_from_prost, AggKind};
 use crate::vector_op::agg::approx_count_distinct::ApproxCountDistinct;
 use crate::vector_op::agg::array_agg::create_array_agg_state;
 use crate::vector_op::agg::count_star::CountStar;
+use crate::vector_op::agg::decimal_sum::DecimalSum;
 use crate::vector_op::agg::filter::*;
 use crate::vector_op::agg::functions::*;
 use crate::vector_op::agg::general_agg::*;
@@ -155,6 +156,18 @@ pub fn create_agg_state_unary(
 ) -> Result<BoxedAggState> {
     use crate::expr::data_types::*;
 
+    // Keeps a wider accumulator so that only the final sum has to fit into a decimal.
+    if agg_kind == AggKind::Sum
+        && return_type == DataType::Decimal
+        && matches!(input_type, DataType::Int64 | DataType::Decimal)
+    {
+        return Ok(Box::new(DecimalSum::new(
+            input_type,
+            input_col_idx,
+            distinct,
+        )));
+    }
+
     macro_rules! gen_arms {
         [$(($agg:ident, $fn:expr, $in:tt, $ret:tt, $init_result:expr)),* $(,)?] => {
             match (
@@ -208,10 +221,8 @@ pub fn create_agg_state_unary(
         (Sum0, sum, int64, int64, Some(0)),
         (Sum, sum, int16, int64, None),
         (Sum, sum, int32, int64, None),
-        (Sum, sum, int64, decimal, None),
         (Sum, sum, float32, float32, None),
         (Sum, sum, float64, float64, None),
-        (Sum, sum, decimal, decimal, None),
         (Sum, sum, interval, interval, None),
         (Min, min, int16, int16, None),
         (Min, min, int32, int32, None),
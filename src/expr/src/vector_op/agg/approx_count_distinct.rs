@@ -108,7 +108,7 @@ impl ApproxCountDistinct {
             if zero_registers == 0.0 {
                 raw_estimate
             } else {
-                m * (m.log2() - (zero_registers.log2()))
+                m * (m.ln() - (zero_registers.ln()))
             }
         } else {
             raw_estimate
@@ -159,7 +159,7 @@ impl Aggregator for ApproxCountDistinct {
 mod tests {
 
     use risingwave_common::array::{
-        ArrayBuilder, ArrayBuilderImpl, DataChunk, I32Array, I64ArrayBuilder,
+        Array, ArrayBuilder, ArrayBuilderImpl, DataChunk, I32Array, I64ArrayBuilder,
     };
     use risingwave_common::types::DataType;
 
@@ -214,4 +214,21 @@ mod tests {
         let array = builder.finish();
         assert_eq!(array.len(), 3);
     }
+
+    #[test]
+    fn test_small_cardinality() {
+        let mut agg = ApproxCountDistinct::new(DataType::Int64, 0);
+        let mut builder = ArrayBuilderImpl::Int64(I64ArrayBuilder::new(1));
+
+        // Linear counting is used for small cardinalities, which is exact without collisions.
+        let data_chunk = generate_data_chunk(3, 0);
+        agg.update_multi(&data_chunk, 0, data_chunk.cardinality())
+            .unwrap();
+        agg.update_multi(&data_chunk, 0, data_chunk.cardinality())
+            .unwrap();
+        agg.output(&mut builder).unwrap();
+
+        let array = builder.finish();
+        assert_eq!(array.as_int64().value_at(0), Some(3));
+    }
 }
@@ -0,0 +1,166 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+use risingwave_common::array::*;
+use risingwave_common::bail;
+use risingwave_common::types::*;
+
+use crate::vector_op::agg::aggregator::Aggregator;
+use crate::{ExprError, Result};
+
+/// Largest mantissa a [`Decimal`] can hold (96 bits).
+const MAX_DECIMAL_MANTISSA_BITS: u64 = 96;
+
+/// `sum` over `int64` or `decimal` returning `decimal`.
+///
+/// The running sum is kept as an arbitrary precision mantissa with the largest scale seen so far,
+/// so intermediate values may exceed the range of [`Decimal`]. Only the final result has to fit:
+/// it is rounded to fewer fractional digits if needed, and fails with
+/// [`ExprError::NumericOutOfRange`] if even its integral part is too large.
+#[derive(Clone)]
+pub struct DecimalSum {
+    input_type: DataType,
+    input_col_idx: usize,
+    /// Sum of the finite values, scaled by `10^scale`.
+    mantissa: BigInt,
+    scale: u32,
+    /// `NaN` or an infinity, once one has been seen. It absorbs all finite values.
+    special: Option<Decimal>,
+    has_value: bool,
+    /// Values seen so far, for `sum(distinct ..)`.
+    exists: Option<HashSet<Datum>>,
+}
+
+impl DecimalSum {
+    pub fn new(input_type: DataType, input_col_idx: usize, distinct: bool) -> Self {
+        Self {
+            input_type,
+            input_col_idx,
+            mantissa: BigInt::zero(),
+            scale: 0,
+            special: None,
+            has_value: false,
+            exists: distinct.then(HashSet::new),
+        }
+    }
+
+    fn accumulate(&mut self, datum: DatumRef<'_>) -> Result<()> {
+        let Some(scalar) = datum else {
+            return Ok(());
+        };
+        if let Some(exists) = &mut self.exists {
+            if !exists.insert(Some(scalar.into_scalar_impl())) {
+                return Ok(());
+            }
+        }
+        self.has_value = true;
+        match scalar {
+            ScalarRefImpl::Int64(v) => self.add_finite(BigInt::from(v), 0),
+            ScalarRefImpl::Decimal(
+                v @ (Decimal::NaN | Decimal::PositiveInf | Decimal::NegativeInf),
+            ) => {
+                self.special = Some(match self.special {
+                    Some(special) => special + v,
+                    None => v,
+                });
+            }
+            ScalarRefImpl::Decimal(v) => {
+                self.add_finite(BigInt::from(v.mantissa()), v.scale() as u32)
+            }
+            _ => bail!("Input fail to match {:?}.", self.input_type),
+        }
+        Ok(())
+    }
+
+    fn add_finite(&mut self, mut mantissa: BigInt, scale: u32) {
+        if scale > self.scale {
+            self.mantissa *= BigInt::from(10).pow(scale - self.scale);
+            self.scale = scale;
+        } else if scale < self.scale {
+            mantissa *= BigInt::from(10).pow(self.scale - scale);
+        }
+        self.mantissa += mantissa;
+    }
+
+    fn finish(&mut self) -> Result<Option<Decimal>> {
+        let mantissa = std::mem::take(&mut self.mantissa);
+        let scale = std::mem::take(&mut self.scale);
+        let special = self.special.take();
+        if !std::mem::take(&mut self.has_value) {
+            return Ok(None);
+        }
+        if let Some(special) = special {
+            return Ok(Some(special));
+        }
+        // Drop as few fractional digits as needed for the mantissa to fit, rounding half away
+        // from zero like `Decimal::round_dp`.
+        for dropped in 0..=scale {
+            let divisor = BigInt::from(10).pow(dropped);
+            let mut rounded = &mantissa / &divisor;
+            let remainder = &mantissa % &divisor;
+            if remainder.abs() * 2 >= divisor {
+                rounded += mantissa.signum();
+            }
+            if rounded.bits() <= MAX_DECIMAL_MANTISSA_BITS {
+                let rounded = i128::try_from(&rounded).unwrap();
+                return Ok(Some(Decimal::from_i128_with_scale(
+                    rounded,
+                    scale - dropped,
+                )));
+            }
+        }
+        Err(ExprError::NumericOutOfRange)
+    }
+}
+
+impl Aggregator for DecimalSum {
+    fn return_type(&self) -> DataType {
+        DataType::Decimal
+    }
+
+    fn update_single(&mut self, input: &DataChunk, row_id: usize) -> Result<()> {
+        let array = input.column_at(self.input_col_idx).array_ref();
+        self.accumulate(array.value_at(row_id))
+    }
+
+    fn update_multi(
+        &mut self,
+        input: &DataChunk,
+        start_row_id: usize,
+        end_row_id: usize,
+    ) -> Result<()> {
+        let array = input.column_at(self.input_col_idx).array_ref();
+        for row_id in start_row_id..end_row_id {
+            self.accumulate(array.value_at(row_id))?;
+        }
+        Ok(())
+    }
+
+    fn output(&mut self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        if let Some(exists) = &mut self.exists {
+            exists.clear();
+        }
+        let res = self.finish()?;
+        if let ArrayBuilderImpl::Decimal(b) = builder {
+            b.append(res);
+            Ok(())
+        } else {
+            bail!("Builder fail to match Decimal.")
+        }
+    }
+}
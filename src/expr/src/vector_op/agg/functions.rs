@@ -55,18 +55,25 @@ where
 }
 
 use std::convert::From;
-use std::ops::Add;
 
+use num_traits::CheckedAdd;
 use risingwave_common::types::ScalarRef;
 
+use crate::ExprError;
+
+/// Returns [`ExprError::NumericOutOfRange`] instead of panicking or wrapping when the sum
+/// overflows. Sums returning decimal are computed by `DecimalSum` with a wider accumulator.
 pub fn sum<R, T>(result: Option<R>, input: Option<T>) -> Result<Option<R>>
 where
-    R: From<T> + Add<Output = R> + Copy,
+    R: From<T> + CheckedAdd + Copy,
 {
     let res = match (result, input) {
         (_, None) => result,
         (None, Some(i)) => Some(R::from(i)),
-        (Some(r), Some(i)) => Some(r + R::from(i)),
+        (Some(r), Some(i)) => Some(
+            r.checked_add(&R::from(i))
+                .ok_or(ExprError::NumericOutOfRange)?,
+        ),
     };
     Ok(res)
 }
@@ -148,7 +148,7 @@ impl_aggregator! { I32Array, Int32, I32Array, Int32 }
 impl_aggregator! { I64Array, Int64, I64Array, Int64 }
 impl_aggregator! { F32Array, Float32, F32Array, Float32 } // sum
 impl_aggregator! { F64Array, Float64, F64Array, Float64 } // sum
-impl_aggregator! { DecimalArray, Decimal, DecimalArray, Decimal } // sum
+impl_aggregator! { DecimalArray, Decimal, DecimalArray, Decimal }
 impl_aggregator! { Utf8Array, Utf8, Utf8Array, Utf8 }
 impl_aggregator! { BoolArray, Bool, BoolArray, Bool } // TODO(#359): remove once unnecessary
 impl_aggregator! { StructArray, Struct, StructArray, Struct }
@@ -173,11 +173,9 @@ impl_aggregator! { NaiveTimeArray, NaiveTime, I64Array, Int64 }
 impl_aggregator! { NaiveDateArray, NaiveDate, I64Array, Int64 }
 impl_aggregator! { NaiveDateTimeArray, NaiveDateTime, I64Array, Int64 }
 
-// sum
-impl_aggregator! { I64Array, Int64, DecimalArray, Decimal }
-
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
     use std::sync::Arc;
 
     use risingwave_common::array::column::Column;
@@ -186,6 +184,7 @@ mod tests {
     use super::*;
     use crate::expr::AggKind;
     use crate::vector_op::agg::aggregator::create_agg_state_unary;
+    use crate::ExprError;
 
     fn eval_agg(
         input_type: DataType,
@@ -240,6 +239,96 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn vec_sum_decimal() -> Result<()> {
+        let max = Decimal::from_str("79228162514264337593543950335").unwrap();
+        let sum_decimal = |input: &[Option<Decimal>]| -> Result<Vec<Option<Decimal>>> {
+            let actual = eval_agg(
+                DataType::Decimal,
+                Arc::new(DecimalArray::from_slice(input).into()),
+                AggKind::Sum,
+                DataType::Decimal,
+                DecimalArrayBuilder::new(0).into(),
+            )?;
+            let actual: DecimalArray = actual.into();
+            Ok(actual.iter().collect())
+        };
+
+        assert_eq!(
+            sum_decimal(&[Some(max), None, Some(-max)])?,
+            vec![Some(Decimal::from(0))]
+        );
+        assert_eq!(
+            sum_decimal(&[
+                Some(Decimal::from_str("0.1").unwrap()),
+                Some(Decimal::from(1))
+            ])?,
+            vec![Some(Decimal::from_str("1.1").unwrap())]
+        );
+        // Only the final sum has to fit into a decimal.
+        assert_eq!(
+            sum_decimal(&[Some(max), Some(Decimal::from(1)), Some(Decimal::from(-1))])?,
+            vec![Some(max)]
+        );
+        assert!(matches!(
+            sum_decimal(&[Some(max), Some(Decimal::from(1))]),
+            Err(ExprError::NumericOutOfRange)
+        ));
+        // Fractional digits are rounded away if the integral part needs the room.
+        let tenth_of_max = Decimal::from_str("7922816251426433759354395033.5").unwrap();
+        assert_eq!(
+            sum_decimal(&[Some(tenth_of_max), Some(tenth_of_max)])?,
+            vec![Some(
+                Decimal::from_str("15845632502852867518708790067").unwrap()
+            )]
+        );
+        // Infinities absorb finite values, and opposite infinities or NaN give NaN.
+        assert_eq!(
+            sum_decimal(&[Some(max), Some(Decimal::PositiveInf), Some(max)])?,
+            vec![Some(Decimal::PositiveInf)]
+        );
+        assert_eq!(
+            sum_decimal(&[Some(Decimal::PositiveInf), Some(Decimal::NegativeInf)])?,
+            vec![Some(Decimal::NaN)]
+        );
+        assert_eq!(
+            sum_decimal(&[Some(Decimal::NaN), Some(Decimal::from(1))])?,
+            vec![Some(Decimal::NaN)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn vec_sum_int64_to_decimal() -> Result<()> {
+        let input = I64Array::from_slice(&[Some(i64::MAX), Some(i64::MAX)]);
+        let actual = eval_agg(
+            DataType::Int64,
+            Arc::new(input.into()),
+            AggKind::Sum,
+            DataType::Decimal,
+            DecimalArrayBuilder::new(0).into(),
+        )?;
+        let actual: DecimalArray = actual.into();
+        assert_eq!(
+            actual.iter().collect::<Vec<_>>(),
+            vec![Some(Decimal::from_str("18446744073709551614").unwrap())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn vec_sum_int64_overflow() {
+        let input = I64Array::from_slice(&[Some(i64::MAX), Some(1)]);
+        let actual = eval_agg(
+            DataType::Int64,
+            Arc::new(input.into()),
+            AggKind::Sum0,
+            DataType::Int64,
+            ArrayBuilderImpl::Int64(I64ArrayBuilder::new(0)),
+        );
+        assert!(matches!(actual, Err(ExprError::NumericOutOfRange)));
+    }
+
     #[test]
     fn vec_min_float32() -> Result<()> {
         let input = F32Array::from_slice(&[Some(1.0.into()), Some(2.0.into()), Some(3.0.into())]);
@@ -159,7 +159,7 @@ impl_aggregator! { I32Array, Int32, I32Array, Int32 }
 impl_aggregator! { I64Array, Int64, I64Array, Int64 }
 impl_aggregator! { F32Array, Float32, F32Array, Float32 } // sum
 impl_aggregator! { F64Array, Float64, F64Array, Float64 } // sum
-impl_aggregator! { DecimalArray, Decimal, DecimalArray, Decimal } // sum
+impl_aggregator! { DecimalArray, Decimal, DecimalArray, Decimal }
 impl_aggregator! { Utf8Array, Utf8, Utf8Array, Utf8 }
 impl_aggregator! { BoolArray, Bool, BoolArray, Bool } // TODO(#359): remove once unnecessary
 impl_aggregator! { StructArray, Struct, StructArray, Struct }
@@ -184,9 +184,6 @@ impl_aggregator! { NaiveTimeArray, NaiveTime, I64Array, Int64 }
 impl_aggregator! { NaiveDateArray, NaiveDate, I64Array, Int64 }
 impl_aggregator! { NaiveDateTimeArray, NaiveDateTime, I64Array, Int64 }
 
-// sum
-impl_aggregator! { I64Array, Int64, DecimalArray, Decimal }
-
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
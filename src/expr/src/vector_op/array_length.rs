@@ -0,0 +1,85 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::ListRef;
+use risingwave_common::types::ScalarRefImpl;
+
+use crate::Result;
+
+/// Returns the length of the `dimension` of `list`, or null if the dimension does not exist or is
+/// empty. The lengths of the inner dimensions are those of the first nested list.
+pub fn array_length(list: Option<ListRef<'_>>, dimension: Option<i32>) -> Result<Option<i32>> {
+    match (list, dimension) {
+        (Some(mut list), Some(dimension)) if dimension > 0 => {
+            for _ in 1..dimension {
+                match list.value_at(1)? {
+                    Some(ScalarRefImpl::List(inner)) => list = inner,
+                    _ => return Ok(None),
+                }
+            }
+            Ok(if list.is_empty() {
+                None
+            } else {
+                Some(list.len() as i32)
+            })
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Returns the total number of elements in `list`, including those of nested lists.
+pub fn cardinality(list: ListRef<'_>) -> Result<i32> {
+    Ok(list.flatten().len() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::ListValue;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+
+    #[test]
+    fn test_array_length() {
+        let flat = ListValue::new(vec![Some(ScalarImpl::Int32(1)), None]);
+        let flat = ListRef::ValueRef { val: &flat };
+        assert_eq!(array_length(Some(flat), Some(1)).unwrap(), Some(2));
+        assert_eq!(array_length(Some(flat), Some(2)).unwrap(), None);
+        assert_eq!(array_length(Some(flat), Some(0)).unwrap(), None);
+        assert_eq!(array_length(Some(flat), None).unwrap(), None);
+        assert_eq!(cardinality(flat).unwrap(), 2);
+
+        let empty = ListValue::new(vec![]);
+        let empty = ListRef::ValueRef { val: &empty };
+        assert_eq!(array_length(Some(empty), Some(1)).unwrap(), None);
+        assert_eq!(cardinality(empty).unwrap(), 0);
+
+        let nested = ListValue::new(vec![
+            Some(ScalarImpl::List(ListValue::new(vec![
+                Some(ScalarImpl::Int32(1)),
+                Some(ScalarImpl::Int32(2)),
+                Some(ScalarImpl::Int32(3)),
+            ]))),
+            Some(ScalarImpl::List(ListValue::new(vec![
+                Some(ScalarImpl::Int32(4)),
+                Some(ScalarImpl::Int32(5)),
+                Some(ScalarImpl::Int32(6)),
+            ]))),
+        ]);
+        let nested = ListRef::ValueRef { val: &nested };
+        assert_eq!(array_length(Some(nested), Some(1)).unwrap(), Some(2));
+        assert_eq!(array_length(Some(nested), Some(2)).unwrap(), Some(3));
+        assert_eq!(cardinality(nested).unwrap(), 6);
+    }
+}
@@ -364,6 +364,7 @@ macro_rules! for_all_cast_variants {
             { varchar, float64, str_parse },
             { varchar, decimal, str_parse },
             { varchar, boolean, str_to_bool },
+            { varchar, jsonb, str_parse },
             // `str_to_list` requires `target_elem_type` and is handled elsewhere
 
             { boolean, varchar, bool_to_varchar },
@@ -379,6 +380,7 @@ macro_rules! for_all_cast_variants {
             { timestamp, varchar, general_to_text },
             { timestampz, varchar, |x| Ok(timestampz_to_utc_string(x)) },
             { list, varchar, |x| general_to_text(x) },
+            { jsonb, varchar, |x| general_to_text(x) },
 
             { boolean, int32, general_cast },
             { int32, boolean, int32_to_bool },
@@ -15,7 +15,7 @@
 use std::any::type_name;
 use std::fmt::Debug;
 
-use risingwave_common::array::{ListRef, StructRef};
+use risingwave_common::array::{JsonbRef, ListRef, StructRef};
 
 use crate::{ExprError, Result};
 
@@ -156,6 +156,12 @@ pub fn gen_list_cmp(op: Comparison) -> fn(ListRef<'_>, ListRef<'_>) -> Result<bo
     gen_cmp!(op)
 }
 
+#[inline(always)]
+pub fn gen_jsonb_cmp(op: Comparison) -> fn(JsonbRef<'_>, JsonbRef<'_>) -> Result<bool> {
+    use crate::gen_cmp;
+    gen_cmp!(op)
+}
+
 #[inline(always)]
 pub fn gen_str_cmp(op: Comparison) -> fn(&str, &str) -> Result<bool> {
     use crate::gen_cmp;
@@ -194,6 +200,30 @@ pub fn str_is_not_distinct_from(l: Option<&str>, r: Option<&str>) -> Result<Opti
     }
 }
 
+pub fn jsonb_is_distinct_from(
+    l: Option<JsonbRef<'_>>,
+    r: Option<JsonbRef<'_>>,
+) -> Result<Option<bool>> {
+    match (l, r) {
+        (Some(lv), Some(rv)) => Ok(Some(lv != rv)),
+        (Some(_), None) => Ok(Some(true)),
+        (None, Some(_)) => Ok(Some(true)),
+        (None, None) => Ok(Some(false)),
+    }
+}
+
+pub fn jsonb_is_not_distinct_from(
+    l: Option<JsonbRef<'_>>,
+    r: Option<JsonbRef<'_>>,
+) -> Result<Option<bool>> {
+    match (l, r) {
+        (Some(lv), Some(rv)) => Ok(Some(lv == rv)),
+        (Some(_), None) => Ok(Some(false)),
+        (None, Some(_)) => Ok(Some(false)),
+        (None, None) => Ok(Some(true)),
+    }
+}
+
 #[inline(always)]
 pub fn is_true(v: Option<bool>) -> Result<Option<bool>> {
     Ok(Some(v == Some(true)))
@@ -0,0 +1,163 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{JsonbRef, JsonbVal, ListRef};
+use risingwave_common::types::{ScalarRef, ScalarRefImpl};
+
+use crate::{ExprError, Result};
+
+/// `jsonb -> text`: extracts the field of an object with the given key.
+#[inline(always)]
+pub fn jsonb_object_field(v: Option<JsonbRef<'_>>, key: Option<&str>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(key)
+        .and_then(|(v, key)| v.access_object_field(key))
+        .map(|v| v.to_owned_scalar()))
+}
+
+/// `jsonb -> int`: extracts the element of an array at the given zero-based index.
+#[inline(always)]
+pub fn jsonb_array_element(v: Option<JsonbRef<'_>>, idx: Option<i32>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(idx)
+        .and_then(|(v, idx)| v.access_array_element(idx))
+        .map(|v| v.to_owned_scalar()))
+}
+
+/// `jsonb ->> text`: extracts the field of an object with the given key as text.
+#[inline(always)]
+pub fn jsonb_object_field_str(
+    v: Option<JsonbRef<'_>>,
+    key: Option<&str>,
+) -> Result<Option<String>> {
+    Ok(v.zip(key)
+        .and_then(|(v, key)| v.access_object_field(key))
+        .and_then(|v| v.as_text()))
+}
+
+/// `jsonb ->> int`: extracts the element of an array at the given zero-based index as text.
+#[inline(always)]
+pub fn jsonb_array_element_str(
+    v: Option<JsonbRef<'_>>,
+    idx: Option<i32>,
+) -> Result<Option<String>> {
+    Ok(v.zip(idx)
+        .and_then(|(v, idx)| v.access_array_element(idx))
+        .and_then(|v| v.as_text()))
+}
+
+/// `jsonb #> text[]`: extracts the value at the given path. A `NULL` element in the path yields
+/// `NULL`.
+#[inline(always)]
+pub fn jsonb_extract_path(
+    v: Option<JsonbRef<'_>>,
+    path: Option<ListRef<'_>>,
+) -> Result<Option<JsonbVal>> {
+    let (Some(v), Some(path)) = (v, path) else {
+        return Ok(None);
+    };
+    let path = path.values_ref();
+    let keys = path
+        .iter()
+        .map(|key| match key {
+            Some(ScalarRefImpl::Utf8(key)) => Some(*key),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>();
+    Ok(keys
+        .and_then(|keys| v.access_path(keys))
+        .map(|v| v.to_owned_scalar()))
+}
+
+#[inline(always)]
+pub fn jsonb_typeof(v: JsonbRef<'_>) -> Result<String> {
+    Ok(v.type_name().to_owned())
+}
+
+#[inline(always)]
+pub fn jsonb_array_length(v: JsonbRef<'_>) -> Result<i32> {
+    let len = v.array_len().map_err(|reason| ExprError::InvalidParam {
+        name: "jsonb",
+        reason,
+    })?;
+    len.try_into().map_err(|_| ExprError::NumericOutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::ListValue;
+    use risingwave_common::types::{Scalar, ScalarImpl};
+
+    use super::*;
+
+    fn jsonb(s: &str) -> JsonbVal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_jsonb_access() {
+        let v = jsonb(r#"{"a": [1, "two", null], "b": {"c": "d"}}"#);
+        let v = v.as_scalar_ref();
+
+        assert_eq!(
+            jsonb_object_field(Some(v), Some("b")).unwrap(),
+            Some(jsonb(r#"{"c": "d"}"#))
+        );
+        assert_eq!(jsonb_object_field(Some(v), Some("x")).unwrap(), None);
+        assert_eq!(jsonb_array_element(Some(v), Some(0)).unwrap(), None);
+
+        let a = jsonb(r#"[1, "two", null]"#);
+        let a = a.as_scalar_ref();
+        assert_eq!(
+            jsonb_array_element(Some(a), Some(0)).unwrap(),
+            Some(jsonb("1"))
+        );
+        assert_eq!(
+            jsonb_array_element(Some(a), Some(-3)).unwrap(),
+            Some(jsonb("1"))
+        );
+        assert_eq!(jsonb_array_element(Some(a), Some(3)).unwrap(), None);
+        assert_eq!(
+            jsonb_array_element_str(Some(a), Some(1)).unwrap(),
+            Some("two".to_owned())
+        );
+        assert_eq!(jsonb_array_element_str(Some(a), Some(2)).unwrap(), None);
+        assert_eq!(
+            jsonb_object_field_str(Some(v), Some("a")).unwrap(),
+            Some(r#"[1, "two", null]"#.to_owned())
+        );
+
+        let path = ListValue::new(vec![
+            Some(ScalarImpl::Utf8("a".into())),
+            Some(ScalarImpl::Utf8("1".into())),
+        ]);
+        assert_eq!(
+            jsonb_extract_path(Some(v), Some(ListRef::ValueRef { val: &path })).unwrap(),
+            Some(jsonb(r#""two""#))
+        );
+        let path = ListValue::new(vec![Some(ScalarImpl::Utf8("a".into())), None]);
+        assert_eq!(
+            jsonb_extract_path(Some(v), Some(ListRef::ValueRef { val: &path })).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_jsonb_info() {
+        let v = jsonb(r#"[1, {"a": 2}]"#);
+        assert_eq!(jsonb_typeof(v.as_scalar_ref()).unwrap(), "array");
+        assert_eq!(jsonb_array_length(v.as_scalar_ref()).unwrap(), 2);
+        let v = jsonb(r#"{"a": 2}"#);
+        assert_eq!(jsonb_typeof(v.as_scalar_ref()).unwrap(), "object");
+        assert!(jsonb_array_length(v.as_scalar_ref()).is_err());
+    }
+}
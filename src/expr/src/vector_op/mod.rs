@@ -15,6 +15,7 @@
 pub mod agg;
 pub mod arithmetic_op;
 pub mod array_access;
+pub mod array_length;
 pub mod ascii;
 pub mod bitwise_op;
 pub mod cast;
@@ -23,12 +24,15 @@ pub mod concat_op;
 pub mod conjunction;
 pub mod date_trunc;
 pub mod extract;
+pub mod jsonb_access;
+pub mod justify;
 pub mod length;
 pub mod like;
 pub mod lower;
 pub mod ltrim;
 pub mod md5;
 pub mod overlay;
+pub mod pad;
 pub mod position;
 pub mod repeat;
 pub mod replace;
@@ -0,0 +1,133 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{StringWriter, WrittenGuard};
+
+use crate::{ExprError, Result};
+
+/// The maximum `length` to pad to. Like PostgreSQL, the result must fit in 1 GB even if all of its
+/// characters take 4 bytes in UTF-8.
+const MAX_PAD_LENGTH: usize = (0x3fff_ffff - 4) / 4;
+
+/// Pads `s` on the left to `length` characters with spaces, or truncates it on the right if it is
+/// longer than that.
+#[inline(always)]
+pub fn lpad(s: &str, length: i32, writer: StringWriter<'_>) -> Result<WrittenGuard> {
+    pad(s, length, " ", true, writer)
+}
+
+/// Pads `s` on the left to `length` characters by repeating `fill`, or truncates it on the right
+/// if it is longer than that.
+#[inline(always)]
+pub fn lpad_fill(
+    s: &str,
+    length: i32,
+    fill: &str,
+    writer: StringWriter<'_>,
+) -> Result<WrittenGuard> {
+    pad(s, length, fill, true, writer)
+}
+
+/// Pads `s` on the right to `length` characters with spaces, or truncates it on the right if it is
+/// longer than that.
+#[inline(always)]
+pub fn rpad(s: &str, length: i32, writer: StringWriter<'_>) -> Result<WrittenGuard> {
+    pad(s, length, " ", false, writer)
+}
+
+/// Pads `s` on the right to `length` characters by repeating `fill`, or truncates it on the right
+/// if it is longer than that.
+#[inline(always)]
+pub fn rpad_fill(
+    s: &str,
+    length: i32,
+    fill: &str,
+    writer: StringWriter<'_>,
+) -> Result<WrittenGuard> {
+    pad(s, length, fill, false, writer)
+}
+
+fn pad(
+    s: &str,
+    length: i32,
+    fill: &str,
+    left: bool,
+    writer: StringWriter<'_>,
+) -> Result<WrittenGuard> {
+    let length = length.max(0) as usize;
+    if length > MAX_PAD_LENGTH {
+        return Err(ExprError::InvalidParam {
+            name: "length",
+            reason: "requested length too large".to_string(),
+        });
+    }
+    let s_len = s.chars().count();
+    // Like PostgreSQL, an empty `fill` leaves a shorter `s` as it is.
+    if s_len >= length || fill.is_empty() {
+        let end = s.char_indices().nth(length).map_or(s.len(), |(i, _)| i);
+        return Ok(writer.write_ref(&s[..end]));
+    }
+
+    let padding: String = fill.chars().cycle().take(length - s_len).collect();
+    let mut writer = writer.begin();
+    if left {
+        writer.write_ref(&padding);
+        writer.write_ref(s);
+    } else {
+        writer.write_ref(s);
+        writer.write_ref(&padding);
+    }
+    Ok(writer.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_pad() -> Result<()> {
+        let cases = [
+            ("hi", 5, "xy", "xyxhi", "hixyx"),
+            ("hi", 1, "xy", "h", "h"),
+            ("hi", 2, "xy", "hi", "hi"),
+            ("hi", 5, "", "hi", "hi"),
+            ("hi", -1, "xy", "", ""),
+            ("荷兰", 4, "来自", "来自荷兰", "荷兰来自"),
+            ("荷兰", 1, "x", "荷", "荷"),
+        ];
+
+        for (s, length, fill, expected_lpad, expected_rpad) in cases {
+            let mut builder = Utf8ArrayBuilder::new(2);
+            let _guard = lpad_fill(s, length, fill, builder.writer())?;
+            let _guard = rpad_fill(s, length, fill, builder.writer())?;
+            let array = builder.finish();
+            assert_eq!(array.value_at(0).unwrap(), expected_lpad);
+            assert_eq!(array.value_at(1).unwrap(), expected_rpad);
+        }
+
+        let mut builder = Utf8ArrayBuilder::new(2);
+        let _guard = lpad("hi", 4, builder.writer())?;
+        let _guard = rpad("hi", 4, builder.writer())?;
+        let array = builder.finish();
+        assert_eq!(array.value_at(0).unwrap(), "  hi");
+        assert_eq!(array.value_at(1).unwrap(), "hi  ");
+
+        let mut builder = Utf8ArrayBuilder::new(2);
+        assert!(lpad("hi", i32::MAX, builder.writer()).is_err());
+        assert!(rpad_fill("hi", MAX_PAD_LENGTH as i32 + 1, "xy", builder.writer()).is_err());
+        Ok(())
+    }
+}
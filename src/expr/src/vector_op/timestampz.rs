@@ -73,6 +73,19 @@ pub fn timestampz_at_time_zone(input: i64, time_zone: &str) -> Result<NaiveDateT
     Ok(NaiveDateTimeWrapper(naive))
 }
 
+/// Formats a `timestamptz` as seen in `time_zone`, which is how it is displayed to a session.
+pub fn timestampz_to_string(input: i64, time_zone: &str) -> Result<String> {
+    let time_zone = lookup_time_zone(time_zone)?;
+    let secs = input.div_euclid(1_000_000);
+    let nsecs = input.rem_euclid(1_000_000) * 1000;
+    let instant_local = Utc
+        .timestamp_opt(secs, nsecs as u32)
+        .unwrap()
+        .with_timezone(&time_zone);
+    // PostgreSQL uses a space rather than `T` to separate the date and time.
+    Ok(instant_local.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use std::assert_matches::assert_matches;
@@ -159,4 +172,17 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_timestampz_to_string() {
+        let usecs = str_to_timestampz("2022-07-01 00:00:00.5Z").unwrap();
+        for (zone, expected) in [
+            ("UTC", "2022-07-01 00:00:00.500+00:00"),
+            ("Asia/Singapore", "2022-07-01 08:00:00.500+08:00"),
+            ("us/pacific", "2022-06-30 17:00:00.500-07:00"),
+        ] {
+            assert_eq!(timestampz_to_string(usecs, zone).unwrap(), expected);
+        }
+        assert_matches!(timestampz_to_string(usecs, "Mars/Olympus"), Err(_));
+    }
 }
@@ -13,33 +13,51 @@
 // limitations under the License.
 
 use aho_corasick::AhoCorasickBuilder;
+use chrono::format::{parse, Parsed, StrftimeItems};
 use risingwave_common::array::{StringWriter, WrittenGuard};
 use risingwave_common::types::NaiveDateTimeWrapper;
 
-use crate::Result;
+use crate::{ExprError, Result};
 
 /// Compile the pg pattern to chrono pattern.
 // TODO: Chrono can not fully support the pg format, so consider using other implementations later.
 pub fn compile_pattern_to_chrono(tmpl: &str) -> String {
+    // A `timestamp` has no time zone, for which pg prints nothing.
+    compile_pattern(tmpl, "")
+}
+
+/// Compile the pg pattern to chrono pattern for parsing, see [`to_timestamp`].
+pub fn compile_pattern_to_chrono_parsing(tmpl: &str) -> String {
+    // Accepts a numeric UTC offset like `+08`, `+0800` or `+08:00`.
+    compile_pattern(tmpl, "%#z")
+}
+
+fn compile_pattern(tmpl: &str, chrono_tz: &str) -> String {
     // https://www.postgresql.org/docs/current/functions-formatting.html
     static PG_PATTERNS: &[&str] = &[
-        "HH24", "hh24", "HH12", "hh12", "HH", "hh", "MI", "mi", "SS", "ss", "YYYY", "yyyy", "YY",
-        "yy", "IYYY", "iyyy", "IY", "iy", "MM", "mm", "DD", "dd",
+        "HH24", "hh24", "HH12", "hh12", "HH", "hh", "MI", "mi", "SS", "ss", "MS", "ms", "YYYY",
+        "yyyy", "YY", "yy", "IYYY", "iyyy", "IY", "iy", "MM", "mm", "DD", "dd", "%",
     ];
     // https://docs.rs/chrono/latest/chrono/format/strftime/index.html
     static CHRONO_PATTERNS: &[&str] = &[
-        "%H", "%H", "%I", "%I", "%I", "%I", "%M", "%M", "%S", "%S", "%Y", "%Y", "%y", "%y", "%G",
-        "%G", "%g", "%g", "%m", "%m", "%d", "%d",
+        "%H", "%H", "%I", "%I", "%I", "%I", "%M", "%M", "%S", "%S", "%3f", "%3f", "%Y", "%Y", "%y",
+        "%y", "%G", "%G", "%g", "%g", "%m", "%m", "%d", "%d", "%%",
     ];
+    static PG_TZ_PATTERNS: &[&str] = &["TZ", "tz"];
 
     let ac = AhoCorasickBuilder::new()
         .ascii_case_insensitive(false)
         .match_kind(aho_corasick::MatchKind::LeftmostLongest)
-        .build(PG_PATTERNS);
+        .build(PG_PATTERNS.iter().chain(PG_TZ_PATTERNS));
 
     let mut chrono_tmpl = String::new();
     ac.replace_all_with(tmpl, &mut chrono_tmpl, |mat, _, dst| {
-        dst.push_str(CHRONO_PATTERNS[mat.pattern()]);
+        dst.push_str(
+            CHRONO_PATTERNS
+                .get(mat.pattern())
+                .copied()
+                .unwrap_or(chrono_tz),
+        );
         true
     });
 
@@ -55,3 +73,110 @@ pub fn to_char_timestamp(
     let res = data.0.format(&chrono_tmpl).to_string();
     Ok(writer.write_ref(&res))
 }
+
+/// Parses `s` with the pg pattern `tmpl` to a `timestamptz` in microseconds.
+pub fn to_timestamp(s: &str, tmpl: &str) -> Result<i64> {
+    to_timestamp_const_tmpl(s, &compile_pattern_to_chrono_parsing(tmpl))
+}
+
+/// Parses `s` with the chrono pattern `chrono_tmpl` compiled by
+/// [`compile_pattern_to_chrono_parsing`].
+///
+/// Like pg, the fields missing from the pattern take their lowest value, e.g. the date defaults to
+/// `0001-01-01`. The input is taken as UTC unless the pattern has a `TZ`.
+pub fn to_timestamp_const_tmpl(s: &str, chrono_tmpl: &str) -> Result<i64> {
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, s, StrftimeItems::new(chrono_tmpl)).map_err(|e| {
+        ExprError::InvalidParam {
+            name: "to_timestamp",
+            reason: format!("fail to parse \"{}\": {}", s, e),
+        }
+    })?;
+
+    // The `set_*` methods only fail on values out of range, which the defaults are not.
+    if parsed.year.is_none()
+        && parsed.year_div_100.is_none()
+        && parsed.year_mod_100.is_none()
+        && parsed.isoyear.is_none()
+    {
+        parsed.set_year(1).unwrap();
+    }
+    if parsed.isoyear.is_none() {
+        if parsed.month.is_none() {
+            parsed.set_month(1).unwrap();
+        }
+        if parsed.day.is_none() {
+            parsed.set_day(1).unwrap();
+        }
+    }
+    if parsed.hour_mod_12.is_none() {
+        parsed.set_hour(0).unwrap();
+    } else if parsed.hour_div_12.is_none() {
+        // `HH12` without a meridiem indicator is taken as AM.
+        parsed.set_ampm(false).unwrap();
+    }
+    if parsed.minute.is_none() {
+        parsed.set_minute(0).unwrap();
+    }
+    if parsed.offset.is_none() {
+        parsed.set_offset(0).unwrap();
+    }
+
+    parsed
+        .to_datetime()
+        .map(|datetime| datetime.timestamp_micros())
+        .map_err(|e| ExprError::InvalidParam {
+            name: "to_timestamp",
+            reason: format!("fail to parse \"{}\": {}", s, e),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_op::cast::str_to_timestampz;
+
+    #[test]
+    fn test_compile_pattern() {
+        assert_eq!(
+            compile_pattern_to_chrono("YYYY-MM-DD HH24:MI:SS.MS TZ"),
+            "%Y-%m-%d %H:%M:%S.%3f "
+        );
+        assert_eq!(
+            compile_pattern_to_chrono_parsing("YYYY-MM-DD HH24:MI:SS.MS TZ"),
+            "%Y-%m-%d %H:%M:%S.%3f %#z"
+        );
+        assert_eq!(compile_pattern_to_chrono("HH12 100%"), "%I 100%%");
+    }
+
+    #[test]
+    fn test_to_timestamp() {
+        let cases = [
+            (
+                "2022-10-16 13:04:05.123",
+                "YYYY-MM-DD HH24:MI:SS.MS",
+                "2022-10-16 13:04:05.123Z",
+            ),
+            (
+                "2022-10-16 13:04:05 +08:00",
+                "YYYY-MM-DD HH24:MI:SS TZ",
+                "2022-10-16 05:04:05Z",
+            ),
+            ("16/10/2022", "DD/MM/YYYY", "2022-10-16 00:00:00Z"),
+            ("2022", "YYYY", "2022-01-01 00:00:00Z"),
+            ("01:04", "HH12:MI", "0001-01-01 01:04:00Z"),
+        ];
+        for (s, tmpl, expected) in cases {
+            assert_eq!(
+                to_timestamp(s, tmpl).unwrap(),
+                str_to_timestampz(expected).unwrap(),
+                "{} {}",
+                s,
+                tmpl
+            );
+        }
+
+        assert!(to_timestamp("2022-13-01", "YYYY-MM-DD").is_err());
+        assert!(to_timestamp("2022/10/01", "YYYY-MM-DD").is_err());
+    }
+}
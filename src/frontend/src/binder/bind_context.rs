@@ -50,6 +50,8 @@ pub enum Clause {
     GroupBy,
     Having,
     Filter,
+    ColumnDefault,
+    GeneratedColumn,
 }
 
 /// A `BindContext` that is only visible if the `LATERAL` keyword
@@ -0,0 +1,66 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::catalog::{ColumnDesc, Field};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_sqlparser::ast::Expr;
+
+use super::bind_context::Clause;
+use super::{BindContext, Binder};
+use crate::expr::ExprImpl;
+
+impl Binder {
+    /// Binds the DEFAULT expression of a table column of `data_type`. It can not refer to any
+    /// column.
+    pub fn bind_column_default(&mut self, expr: Expr, data_type: DataType) -> Result<ExprImpl> {
+        self.context = BindContext::new();
+        self.bind_column_expr(expr, data_type, Clause::ColumnDefault)
+    }
+
+    /// Binds the expression of a stored generated column of `data_type` on the `columns` of table
+    /// `table_name`. The bound expression refers to the columns by their indices in `columns`.
+    pub fn bind_generated_column(
+        &mut self,
+        table_name: String,
+        columns: &[ColumnDesc],
+        expr: Expr,
+        data_type: DataType,
+    ) -> Result<ExprImpl> {
+        self.context = BindContext::new();
+        let fields = columns.iter().map(|c| (false, Field::from(c)));
+        self.bind_table_to_context(fields, table_name, None)?;
+        self.bind_column_expr(expr, data_type, Clause::GeneratedColumn)
+    }
+
+    fn bind_column_expr(
+        &mut self,
+        expr: Expr,
+        data_type: DataType,
+        clause: Clause,
+    ) -> Result<ExprImpl> {
+        self.context.clause = Some(clause);
+        let bound = self.bind_expr(expr);
+        self.context.clause = None;
+        let bound = bound?.cast_assign(data_type)?;
+        if bound.has_subquery() {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "subqueries are not allowed in {}",
+                clause
+            ))
+            .into());
+        }
+        Ok(bound)
+    }
+}
@@ -54,6 +54,9 @@ impl Binder {
             BinaryOperator::PGRegexNotMatch => {
                 return self.bind_regex_not_match(bound_left, bound_right)
             }
+            BinaryOperator::Arrow => ExprType::JsonbAccessInner,
+            BinaryOperator::LongArrow => ExprType::JsonbAccessStr,
+            BinaryOperator::HashArrow => ExprType::JsonbAccessPath,
 
             _ => {
                 return Err(
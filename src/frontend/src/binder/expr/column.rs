@@ -72,26 +72,56 @@ impl Binder {
             }
         }
 
-        // Try to find a correlated column in `upper_contexts`, starting from the innermost context.
         let mut err = ErrorCode::ItemNotFound(format!("Invalid column: {}", column_name));
-        for (i, (context, _)) in self.upper_subquery_contexts.iter().rev().enumerate() {
-            // `depth` starts from 1.
-            let depth = i + 1;
+
+        // Try to find the column in the visible lateral contexts, i.e. a table function in `FROM`
+        // referring to the `FROM` items on its left.
+        for lateral_context in self.lateral_contexts.iter().rev() {
+            if !lateral_context.is_visible {
+                continue;
+            }
+            let context = &lateral_context.context;
             match context.get_column_binding_index(&table_name, &column_name) {
                 Ok(index) => {
                     let column = &context.columns[index];
-                    return Ok(CorrelatedInputRef::new(
-                        column.index,
-                        column.field.data_type.clone(),
-                        depth,
-                    )
-                    .into());
+                    return Ok(InputRef::new(column.index, column.field.data_type.clone()).into());
                 }
                 Err(e) => {
                     err = e;
                 }
             }
         }
+
+        // Try to find a correlated column in `upper_contexts`, starting from the innermost context.
+        // The visible lateral contexts of a level are searched after the level itself, so that a
+        // `LATERAL` subquery can refer to the `FROM` items on its left.
+        for (i, (context, lateral_contexts)) in
+            self.upper_subquery_contexts.iter().rev().enumerate()
+        {
+            // `depth` starts from 1.
+            let depth = i + 1;
+            let visible_lateral_contexts = lateral_contexts
+                .iter()
+                .rev()
+                .filter(|lateral_context| lateral_context.is_visible)
+                .map(|lateral_context| &lateral_context.context);
+            for context in std::iter::once(context).chain(visible_lateral_contexts) {
+                match context.get_column_binding_index(&table_name, &column_name) {
+                    Ok(index) => {
+                        let column = &context.columns[index];
+                        return Ok(CorrelatedInputRef::new(
+                            column.index,
+                            column.field.data_type.clone(),
+                            depth,
+                        )
+                        .into());
+                    }
+                    Err(e) => {
+                        err = e;
+                    }
+                }
+            }
+        }
         Err(err.into())
     }
 }
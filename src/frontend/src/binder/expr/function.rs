@@ -128,6 +128,8 @@ impl Binder {
             // temporal/chrono
             "to_timestamp" => ExprType::ToTimestamp,
             "date_trunc" => ExprType::DateTrunc,
+            "justify_days" => ExprType::JustifyDays,
+            "justify_hours" => ExprType::JustifyHours,
             // string
             "substr" => ExprType::Substr,
             "length" => ExprType::Length,
@@ -141,6 +143,8 @@ impl Binder {
             "rtrim" => ExprType::Rtrim,
             "md5" => ExprType::Md5,
             "to_char" => ExprType::ToChar,
+            "lpad" => ExprType::Lpad,
+            "rpad" => ExprType::Rpad,
             "concat" => {
                 inputs = Self::rewrite_concat_to_concat_ws(inputs)?;
                 ExprType::ConcatWs
@@ -158,6 +162,11 @@ impl Binder {
             "array_cat" => ExprType::ArrayCat,
             "array_append" => ExprType::ArrayAppend,
             "array_prepend" => ExprType::ArrayPrepend,
+            "array_length" => ExprType::ArrayLength,
+            "cardinality" => ExprType::Cardinality,
+            // jsonb
+            "jsonb_typeof" => ExprType::JsonbTypeof,
+            "jsonb_array_length" => ExprType::JsonbArrayLength,
             // System information operations.
             "pg_typeof" if inputs.len() == 1 => {
                 let input = &inputs[0];
@@ -450,7 +459,9 @@ impl Binder {
                 | Clause::Values
                 | Clause::GroupBy
                 | Clause::Having
-                | Clause::Filter => {
+                | Clause::Filter
+                | Clause::ColumnDefault
+                | Clause::GeneratedColumn => {
                     return Err(ErrorCode::InvalidInputSyntax(format!(
                         "window functions are not allowed in {}",
                         clause
@@ -465,7 +476,10 @@ impl Binder {
     fn ensure_aggregate_allowed(&self) -> Result<()> {
         if let Some(clause) = self.context.clause {
             match clause {
-                Clause::Where | Clause::Values => {
+                Clause::Where
+                | Clause::Values
+                | Clause::ColumnDefault
+                | Clause::GeneratedColumn => {
                     return Err(ErrorCode::InvalidInputSyntax(format!(
                         "aggregate functions are not allowed in {}",
                         clause
@@ -481,7 +495,10 @@ impl Binder {
     fn ensure_table_function_allowed(&self) -> Result<()> {
         if let Some(clause) = self.context.clause {
             match clause {
-                Clause::Where | Clause::Values => {
+                Clause::Where
+                | Clause::Values
+                | Clause::ColumnDefault
+                | Clause::GeneratedColumn => {
                     return Err(ErrorCode::InvalidInputSyntax(format!(
                         "table functions are not allowed in {}",
                         clause
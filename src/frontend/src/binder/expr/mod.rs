@@ -472,6 +472,7 @@ pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
                 "float4" => DataType::Float32,
                 "float8" => DataType::Float64,
                 "timestamptz" => DataType::Timestampz,
+                "jsonb" => DataType::Jsonb,
                 _ => return Err(new_err().into()),
             }
         }
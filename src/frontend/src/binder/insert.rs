@@ -15,11 +15,12 @@
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
+use risingwave_pb::expr::ExprNode;
 use risingwave_sqlparser::ast::{Ident, ObjectName, Query, SetExpr};
 
 use super::{BoundQuery, BoundSetExpr};
 use crate::binder::{Binder, BoundTableSource};
-use crate::expr::{ExprImpl, InputRef};
+use crate::expr::{Expr, ExprImpl, InputRef};
 
 #[derive(Debug)]
 pub struct BoundInsert {
@@ -37,6 +38,10 @@ pub struct BoundInsert {
     /// Used as part of an extra `Project` when the column types of `source` query does not match
     /// `table_source`. This does not include a simple `VALUE`. See comments in code for details.
     pub cast_exprs: Vec<ExprImpl>,
+
+    /// The expressions to fill the columns not in `column_idxs` with, i.e. their DEFAULT values,
+    /// or NULL for the columns without one. Empty if `column_idxs` is empty.
+    pub default_columns: Vec<(usize, ExprNode)>,
 }
 
 impl Binder {
@@ -50,11 +55,94 @@ impl Binder {
             Self::resolve_schema_qualified_name(&self.db_name, source_name)?;
         let table_source = self.bind_table_source(schema_name.as_deref(), &source_name)?;
 
-        let expected_types: Vec<DataType> = table_source
-            .columns
-            .iter()
-            .map(|c| c.data_type.clone())
-            .collect();
+        let mut target_table_col_idxs: Vec<usize> = vec![];
+        'outer: for query_column in &columns {
+            let column_name = query_column.real_value();
+            for (col_idx, table_column) in table_source.columns.iter().enumerate() {
+                if column_name == table_column.name {
+                    if table_source.generated_columns.contains(&col_idx) {
+                        return Err(RwError::from(ErrorCode::BindError(format!(
+                            "cannot insert into generated column \"{}\"",
+                            column_name
+                        ))));
+                    }
+                    target_table_col_idxs.push(col_idx);
+                    continue 'outer;
+                }
+            }
+            // Invalid column name found
+            return Err(RwError::from(ErrorCode::BindError(format!(
+                "Column {} not found in table {}",
+                column_name, table_source.name
+            ))));
+        }
+
+        // validate that the table has a column for each target column, e.g.
+        // create table t1 (v1 int, v2 int);
+        // insert into t1 (v1, v2, v2) values (5, 6); // ...more target columns than values
+        // Target columns can be less than the columns of the table, in which case the rest are
+        // filled with their DEFAULT values.
+        if target_table_col_idxs.len() > table_source.columns.len() {
+            return Err(RwError::from(ErrorCode::BindError(
+                "INSERT has more target columns than values".to_string(),
+            )));
+        }
+
+        // Check if column was used multiple times in query e.g.
+        // insert into t1 (v1, v1) values (1, 5);
+        let mut uniq_cols = target_table_col_idxs.clone();
+        uniq_cols.sort_unstable();
+        uniq_cols.dedup();
+        if target_table_col_idxs.len() != uniq_cols.len() {
+            return Err(RwError::from(ErrorCode::BindError(
+                "Column specified more than once".to_string(),
+            )));
+        }
+
+        // Without target columns, all the columns except the generated ones are written in order.
+        if target_table_col_idxs.is_empty() && !table_source.generated_columns.is_empty() {
+            target_table_col_idxs = (0..table_source.columns.len())
+                .filter(|idx| !table_source.generated_columns.contains(idx))
+                .collect();
+        }
+
+        // The columns not written are filled with their DEFAULT values, or NULL without one. The
+        // generated columns are left NULL here, and computed before the rows are materialized.
+        let default_columns = if target_table_col_idxs.is_empty() {
+            vec![]
+        } else {
+            (0..table_source.columns.len())
+                .filter(|idx| !target_table_col_idxs.contains(idx))
+                .map(|idx| {
+                    let expr = table_source
+                        .default_columns
+                        .get(&(idx as u32))
+                        .cloned()
+                        .unwrap_or_else(|| {
+                            let data_type = table_source.columns[idx].data_type.clone();
+                            ExprImpl::literal_null(data_type).to_expr_proto()
+                        });
+                    (idx, expr)
+                })
+                .collect()
+        };
+
+        // The types expected by the `source` query follow the order of the target columns if they
+        // are specified, e.g.
+        // create table t1 (v1 int, v2 varchar);
+        // insert into t1 (v2, v1) values ('a', 1); // expects (varchar, int)
+        let expected_types: Vec<DataType> = if target_table_col_idxs.is_empty() {
+            table_source
+                .columns
+                .iter()
+                .map(|c| c.data_type.clone())
+                .collect()
+        } else {
+            target_table_col_idxs
+                .iter()
+                .map(|idx| table_source.columns[*idx].data_type.clone())
+                .collect()
+        };
 
         // When the column types of `source` query do not match `expected_types`, casting is
         // needed.
@@ -119,51 +207,12 @@ impl Binder {
             }
         };
 
-        let mut target_table_col_idxs: Vec<usize> = vec![];
-        'outer: for query_column in &columns {
-            let column_name = query_column.real_value();
-            for (col_idx, table_column) in table_source.columns.iter().enumerate() {
-                if column_name == table_column.name {
-                    target_table_col_idxs.push(col_idx);
-                    continue 'outer;
-                }
-            }
-            // Invalid column name found
-            return Err(RwError::from(ErrorCode::BindError(format!(
-                "Column {} not found in table {}",
-                column_name, table_source.name
-            ))));
-        }
-
-        // validate that query has a value for each target column, if target columns are used
-        // create table t1 (v1 int, v2 int);
-        // insert into t1 (v1, v2, v2) values (5, 6); // ...more target columns than values
-        // insert into t1 (v1) values (5, 6);         // ...less target columns than values
-        let (eq_len, msg) = match target_table_col_idxs.len().cmp(&expected_types.len()) {
-            std::cmp::Ordering::Equal => (true, ""),
-            std::cmp::Ordering::Greater => (false, "INSERT has more target columns than values"),
-            std::cmp::Ordering::Less => (false, "INSERT has less target columns than values"),
-        };
-        if !eq_len && !target_table_col_idxs.is_empty() {
-            return Err(RwError::from(ErrorCode::BindError(msg.to_string())));
-        }
-
-        // Check if column was used multiple times in query e.g.
-        // insert into t1 (v1, v1) values (1, 5);
-        let mut uniq_cols = target_table_col_idxs.clone();
-        uniq_cols.sort_unstable();
-        uniq_cols.dedup();
-        if target_table_col_idxs.len() != uniq_cols.len() {
-            return Err(RwError::from(ErrorCode::BindError(
-                "Column specified more than once".to_string(),
-            )));
-        }
-
         let insert = BoundInsert {
             table_source,
             source,
             cast_exprs,
             column_idxs: target_table_col_idxs,
+            default_columns,
         };
 
         Ok(insert)
@@ -20,6 +20,7 @@ use risingwave_common::session_config::SearchPath;
 use risingwave_sqlparser::ast::{Statement, TableAlias};
 
 mod bind_context;
+mod column_expr;
 mod delete;
 mod expr;
 mod insert;
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
@@ -145,6 +145,9 @@ impl Binder {
             .transpose()?
             .map(|v| v as u64);
 
+        // CTEs declared by this query are only visible within it, so remember the outer ones and
+        // restore them once the query is bound.
+        let outer_ctes = with.as_ref().map(|_| self.cte_to_relation.clone());
         if let Some(with) = with {
             self.bind_with(with)?;
         }
@@ -176,6 +179,9 @@ impl Binder {
                 )
             })
             .collect::<Result<_>>()?;
+        if let Some(outer_ctes) = outer_ctes {
+            self.cte_to_relation = outer_ctes;
+        }
         Ok(BoundQuery {
             body,
             order,
@@ -245,9 +251,17 @@ impl Binder {
         if with.recursive {
             Err(ErrorCode::NotImplemented("recursive cte".into(), None.into()).into())
         } else {
+            let mut declared = HashSet::new();
             for cte_table in with.cte_tables {
                 let Cte { alias, query, .. } = cte_table;
                 let table_name = alias.name.real_value();
+                if !declared.insert(table_name.clone()) {
+                    return Err(ErrorCode::BindError(format!(
+                        "WITH query name \"{}\" specified more than once",
+                        table_name
+                    ))
+                    .into());
+                }
                 let bound_query = self.bind_query(query)?;
                 self.cte_to_relation
                     .insert(table_name, (bound_query, alias));
@@ -45,15 +45,21 @@ impl Binder {
         let mut root = self.bind_table_with_joins(first)?;
         self.pop_and_merge_lateral_context()?;
         for t in from_iter {
+            let is_lateral = Self::is_lateral_subquery(&t.relation);
             self.push_lateral_context();
             let right = self.bind_table_with_joins(t.clone())?;
             self.pop_and_merge_lateral_context()?;
-            root = Relation::Join(Box::new(BoundJoin {
+            let join = Box::new(BoundJoin {
                 join_type: JoinType::Inner,
                 left: root,
                 right,
                 cond: ExprImpl::literal_bool(true),
-            }));
+            });
+            root = if is_lateral {
+                Relation::Apply(join)
+            } else {
+                Relation::Join(join)
+            };
         }
         Ok(Some(root))
     }
@@ -69,6 +75,14 @@ impl Binder {
                 // Cross join equals to inner join with with no constraint.
                 JoinOperator::CrossJoin => (JoinConstraint::None, JoinType::Inner),
             };
+            let is_lateral = Self::is_lateral_subquery(&join.relation);
+            if is_lateral && !matches!(join_type, JoinType::Inner | JoinType::LeftOuter) {
+                return Err(ErrorCode::BindError(
+                    "The combining JOIN type must be INNER or LEFT for a LATERAL reference."
+                        .to_string(),
+                )
+                .into());
+            }
             let right: Relation;
             let cond: ExprImpl;
             if matches!(
@@ -83,18 +97,26 @@ impl Binder {
                 right = self.bind_table_factor(join.relation.clone())?;
                 (cond, _) = self.bind_join_constraint(constraint, None, join_type)?;
             }
-            let join = BoundJoin {
+            let join = Box::new(BoundJoin {
                 join_type,
                 left: root,
                 right,
                 cond,
+            });
+            root = if is_lateral {
+                Relation::Apply(join)
+            } else {
+                Relation::Join(join)
             };
-            root = Relation::Join(Box::new(join));
         }
 
         Ok(root)
     }
 
+    fn is_lateral_subquery(table_factor: &TableFactor) -> bool {
+        matches!(table_factor, TableFactor::Derived { lateral: true, .. })
+    }
+
     fn bind_join_constraint(
         &mut self,
         constraint: JoinConstraint,
@@ -20,6 +20,7 @@ use risingwave_common::catalog::{
     Field, TableId, DEFAULT_SCHEMA_NAME, RW_INTERNAL_TABLE_FUNCTION_NAME,
 };
 use risingwave_common::error::{internal_error, ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{FunctionArg, Ident, ObjectName, TableAlias, TableFactor};
 
 use super::bind_context::ColumnBinding;
@@ -47,8 +48,15 @@ pub enum Relation {
     SystemTable(Box<BoundSystemTable>),
     Subquery(Box<BoundSubquery>),
     Join(Box<BoundJoin>),
+    /// A join whose right side is a `LATERAL` subquery, which may refer to the columns of the left
+    /// side.
+    Apply(Box<BoundJoin>),
     WindowTableFunction(Box<BoundWindowTableFunction>),
-    TableFunction(Box<TableFunction>),
+    TableFunction {
+        expr: Box<TableFunction>,
+        /// Whether an `ordinality` column numbering the output rows is appended.
+        with_ordinality: bool,
+    },
 }
 
 impl Relation {
@@ -63,7 +71,7 @@ impl Relation {
                     false
                 }
             },
-            Relation::Join(j) => {
+            Relation::Join(j) | Relation::Apply(j) => {
                 j.left.contains_sys_table() || j.right.contains_sys_table()
             },
             _ => false,
@@ -78,6 +86,10 @@ impl Relation {
                     || join.left.is_correlated()
                     || join.right.is_correlated()
             }
+            // The correlated input refs in the right side refer to the left side.
+            Relation::Apply(join) => {
+                join.cond.has_correlated_input_ref_by_depth() || join.left.is_correlated()
+            }
             _ => false,
         }
     }
@@ -91,7 +103,7 @@ impl Relation {
             Relation::Subquery(subquery) => subquery
                 .query
                 .collect_correlated_indices_by_depth_and_assign_id(depth + 1, correlated_id),
-            Relation::Join(join) => {
+            Relation::Join(join) | Relation::Apply(join) => {
                 let mut correlated_indices = vec![];
                 correlated_indices.extend(
                     join.cond
@@ -317,19 +329,42 @@ impl Binder {
     pub(super) fn bind_table_factor(&mut self, table_factor: TableFactor) -> Result<Relation> {
         match table_factor {
             TableFactor::Table { name, alias } => self.bind_relation_by_name(name, alias),
-            TableFactor::TableFunction { name, alias, args } => {
+            TableFactor::TableFunction {
+                name,
+                alias,
+                args,
+                with_ordinality,
+            } => {
                 let func_name = &name.0[0].real_value();
+                let table_function_type = TableFunctionType::from_str(func_name);
+                if with_ordinality && table_function_type.is_err() {
+                    return Err(ErrorCode::BindError(format!(
+                        "WITH ORDINALITY is not supported for {}",
+                        func_name
+                    ))
+                    .into());
+                }
                 if func_name.eq_ignore_ascii_case(RW_INTERNAL_TABLE_FUNCTION_NAME) {
                     return self.bind_internal_table(args, alias);
                 }
-                if let Ok(table_function_type) = TableFunctionType::from_str(func_name) {
-                    let args: Vec<ExprImpl> = args
+                if let Ok(table_function_type) = table_function_type {
+                    // Like in Postgres, a table function in `FROM` is implicitly lateral, so its
+                    // arguments may refer to the `FROM` items on its left.
+                    let mark_visible = self.context.columns.is_empty();
+                    if mark_visible {
+                        self.try_mark_lateral_as_visible();
+                    }
+                    let args: Result<Vec<ExprImpl>> = args
                         .into_iter()
                         .map(|arg| self.bind_function_arg(arg))
                         .flatten_ok()
-                        .try_collect()?;
+                        .try_collect();
+                    if mark_visible {
+                        self.try_mark_lateral_as_invisible();
+                    }
+                    let args = args?;
                     let tf = TableFunction::new(table_function_type, args)?;
-                    let columns = [(
+                    let mut columns = vec![(
                         false,
                         Field {
                             data_type: tf.return_type(),
@@ -337,8 +372,10 @@ impl Binder {
                             sub_fields: vec![],
                             type_name: "".to_string(),
                         },
-                    )]
-                    .into_iter();
+                    )];
+                    if with_ordinality {
+                        columns.push((false, Field::with_name(DataType::Int64, "ordinality")));
+                    }
 
                     self.bind_table_to_context(
                         columns,
@@ -346,7 +383,10 @@ impl Binder {
                         alias,
                     )?;
 
-                    return Ok(Relation::TableFunction(Box::new(tf)));
+                    return Ok(Relation::TableFunction {
+                        expr: Box::new(tf),
+                        with_ordinality,
+                    });
                 }
                 let kind = WindowTableFunctionKind::from_str(func_name).map_err(|_| {
                     ErrorCode::NotImplemented(
@@ -364,18 +404,21 @@ impl Binder {
                 alias,
             } => {
                 if lateral {
-                    // If we detect a lateral, we mark the lateral context as visible.
-                    self.try_mark_lateral_as_visible();
+                    // A lateral subquery on the right side of a `JOIN` sees the left side in the
+                    // current context. Otherwise, the left side is the lateral context on top of
+                    // the stack, and we mark it as visible.
+                    let mark_visible = self.context.columns.is_empty();
+                    if mark_visible {
+                        self.try_mark_lateral_as_visible();
+                    }
 
-                    // Bind lateral subquery here.
+                    let bound_subquery = self.bind_subquery_relation(*subquery, alias);
 
                     // Mark the lateral context as invisible once again.
-                    self.try_mark_lateral_as_invisible();
-                    Err(ErrorCode::NotImplemented(
-                        "lateral subqueries are not yet supported".into(),
-                        Some(3815).into(),
-                    )
-                    .into())
+                    if mark_visible {
+                        self.try_mark_lateral_as_invisible();
+                    }
+                    Ok(Relation::Subquery(Box::new(bound_subquery?)))
                 } else {
                     // Non-lateral subqueries to not have access to the join-tree context.
                     self.push_lateral_context();
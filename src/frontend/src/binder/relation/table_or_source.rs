@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -21,6 +22,8 @@ use risingwave_common::catalog::{
 };
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::session_config::USER_NAME_WILD_CARD;
+use risingwave_pb::catalog::source_info::SourceInfo;
+use risingwave_pb::expr::ExprNode;
 use risingwave_sqlparser::ast::{Statement, TableAlias};
 use risingwave_sqlparser::parser::Parser;
 
@@ -50,6 +53,10 @@ pub struct BoundTableSource {
     pub columns: Vec<ColumnDesc>,
     pub append_only: bool,
     pub owner: UserId,
+    /// The DEFAULT expressions of the columns, keyed by the column indices.
+    pub default_columns: HashMap<u32, ExprNode>,
+    /// The indices of the stored generated columns, which can not be written directly.
+    pub generated_columns: HashSet<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -345,6 +352,17 @@ impl Binder {
 
         let owner = source.owner;
 
+        let (default_columns, generated_columns) = match &source.info {
+            SourceInfo::TableSource(info) => (
+                info.default_columns.clone(),
+                info.generated_columns
+                    .keys()
+                    .map(|&index| index as usize)
+                    .collect(),
+            ),
+            SourceInfo::StreamSource(_) => Default::default(),
+        };
+
         // Note(bugen): do not bind context here.
 
         Ok(BoundTableSource {
@@ -354,6 +372,8 @@ impl Binder {
             columns,
             append_only,
             owner,
+            default_columns,
+            generated_columns,
         })
     }
 }
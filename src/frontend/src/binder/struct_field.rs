@@ -22,9 +22,9 @@ use crate::binder::Binder;
 use crate::expr::{Expr as RwExpr, ExprImpl, ExprType, FunctionCall, InputRef, Literal};
 
 impl Binder {
-    /// This function will accept three expr type: `CompoundIdentifier`,`Identifier`,`Cast(Todo)`
-    /// We will extract ident from `expr` to get the `column_binding`.
-    /// Will return `column_binding` and field `idents`.
+    /// For `CompoundIdentifier` and `Identifier`, we will extract ident from `expr` to get the
+    /// `column_binding`. Other expressions, e.g. `Cast` or `Row`, are bound as they are.
+    /// Will return the bound expression and field `idents`.
     fn bind_field_access(&mut self, expr: Expr, ids: Vec<Ident>) -> Result<(ExprImpl, Vec<Ident>)> {
         let (column_ident, ids) = match &expr {
             // For CompoundIdentifier, we will use the first ident as table name and the second
@@ -73,13 +73,7 @@ impl Binder {
             let expr = InputRef::new(binding.index, binding.field.data_type.clone()).into();
             Ok((expr, ids))
         } else {
-            match expr {
-                Expr::Cast { expr, data_type } => {
-                    let cast = self.bind_cast(*expr, data_type)?;
-                    Ok((cast, ids))
-                }
-                _ => unreachable!(),
-            }
+            Ok((self.bind_expr(expr)?, ids))
         }
     }
 
@@ -163,7 +157,7 @@ impl Binder {
                             f.clone(),
                         )
                         .into(),
-                        t.field_names[i].clone(),
+                        field_name_at(&t.field_names, i),
                     )
                 })
                 .collect_vec())
@@ -173,9 +167,20 @@ impl Binder {
     }
 }
 
+/// Returns the name of the `index`-th field. Like pg, the fields of a struct without names, e.g.
+/// constructed by `ROW(...)`, are named `f1`, `f2`, etc.
+fn field_name_at(field_names: &[String], index: usize) -> String {
+    field_names
+        .get(index)
+        .cloned()
+        .unwrap_or_else(|| format!("f{}", index + 1))
+}
+
 fn find_field(input: DataType, field_name: String) -> Result<(DataType, usize)> {
     if let DataType::Struct(t) = input {
-        if let Some((pos, _)) = t.field_names.iter().find_position(|s| **s == field_name) {
+        if let Some(pos) =
+            (0..t.fields.len()).find(|i| field_name_at(&t.field_names, *i) == field_name)
+        {
             Ok((t.fields[pos].clone(), pos))
         } else {
             Err(ErrorCode::BindError(format!(
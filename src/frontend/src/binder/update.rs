@@ -91,6 +91,16 @@ impl Binder {
 
             for (id, value) in assignments {
                 let id_expr = self.bind_expr(Expr::Identifier(id.clone()))?;
+                // Generated columns are computed from the other columns once the rows are updated.
+                if let ExprImpl::InputRef(input_ref) = &id_expr {
+                    if table_source.generated_columns.contains(&input_ref.index()) {
+                        return Err(ErrorCode::BindError(format!(
+                            "cannot update generated column \"{}\"",
+                            id.real_value()
+                        ))
+                        .into());
+                    }
+                }
                 let value_expr = self.bind_expr(value)?.cast_assign(id_expr.return_type())?;
 
                 match assignment_exprs.entry(id_expr) {
@@ -193,4 +193,5 @@ prepare_sys_catalog! {
     { PG_CATALOG, PG_ATTRIBUTE, vec![0, 4], read_pg_attribute },
     { INFORMATION_SCHEMA, COLUMNS, vec![], read_columns_info },
     { INFORMATION_SCHEMA, TABLES, vec![], read_tables_info },
+    { PG_CATALOG, PG_TABLES, vec![], read_pg_tables_info },
 }
@@ -22,6 +22,7 @@ pub mod pg_matviews;
 pub mod pg_namespace;
 pub mod pg_opclass;
 pub mod pg_operator;
+pub mod pg_tables;
 pub mod pg_type;
 pub mod pg_user;
 pub mod pg_views;
@@ -39,6 +40,7 @@ pub use pg_matviews::*;
 pub use pg_namespace::*;
 pub use pg_opclass::*;
 pub use pg_operator::*;
+pub use pg_tables::*;
 pub use pg_type::*;
 pub use pg_user::*;
 pub use pg_views::*;
@@ -379,6 +381,46 @@ impl SysCatalogReaderImpl {
             .collect_vec())
     }
 
+    pub(super) fn read_pg_tables_info(&self) -> Result<Vec<Row>> {
+        // Get two read locks. The order must be the same as
+        // `FrontendObserverNode::handle_initialization_notification`.
+        let catalog_reader = self.catalog_reader.read_guard();
+        let user_info_reader = self.user_info_reader.read_guard();
+        let schemas = catalog_reader.iter_schemas(&self.auth_context.database)?;
+
+        Ok(schemas
+            .flat_map(|schema| {
+                let table_rows = schema.iter_table().map(|table| {
+                    let has_indexes = schema
+                        .iter_index()
+                        .any(|index| index.primary_table.id == table.id);
+                    Row::new(vec![
+                        Some(ScalarImpl::Utf8(schema.name())),
+                        Some(ScalarImpl::Utf8(table.name().to_string())),
+                        Some(ScalarImpl::Utf8(
+                            user_info_reader.get_user_name_by_id(table.owner).unwrap(),
+                        )),
+                        None,
+                        Some(ScalarImpl::Bool(has_indexes)),
+                    ])
+                });
+                let sys_table_rows = schema.iter_system_tables().map(|table| {
+                    Row::new(vec![
+                        Some(ScalarImpl::Utf8(schema.name())),
+                        Some(ScalarImpl::Utf8(table.name().to_string())),
+                        Some(ScalarImpl::Utf8(
+                            user_info_reader.get_user_name_by_id(table.owner).unwrap(),
+                        )),
+                        None,
+                        Some(ScalarImpl::Bool(false)),
+                    ])
+                });
+
+                table_rows.chain(sys_table_rows)
+            })
+            .collect_vec())
+    }
+
     pub(super) fn read_pg_attribute(&self) -> Result<Vec<Row>> {
         let reader = self.catalog_reader.read_guard();
         let schemas = reader.iter_schemas(&self.auth_context.database)?;
@@ -18,7 +18,9 @@ use itertools::Itertools;
 use risingwave_common::catalog::{TableDesc, TableId};
 use risingwave_common::config::constant::hummock::TABLE_OPTION_DUMMY_RETENTION_SECOND;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
-use risingwave_pb::catalog::{ColumnIndex as ProstColumnIndex, Table as ProstTable};
+use risingwave_pb::catalog::{
+    ColumnIndex as ProstColumnIndex, Table as ProstTable, TableStatistics as ProstTableStatistics,
+};
 
 use super::column_catalog::ColumnCatalog;
 use super::{DatabaseId, FragmentId, SchemaId};
@@ -105,6 +107,9 @@ pub struct TableCatalog {
     pub definition: String,
 
     pub handle_pk_conflict: bool,
+
+    /// Statistics collected by `ANALYZE`, if the table has been analyzed.
+    pub statistics: Option<ProstTableStatistics>,
 }
 
 pub enum TableKind {
@@ -222,6 +227,7 @@ impl TableCatalog {
             value_indices: self.value_indices.iter().map(|x| *x as _).collect(),
             definition: self.definition.clone(),
             handle_pk_conflict: self.handle_pk_conflict,
+            statistics: self.statistics.clone(),
         }
     }
 }
@@ -269,6 +275,7 @@ impl From<ProstTable> for TableCatalog {
             value_indices: tb.value_indices.iter().map(|x| *x as _).collect(),
             definition: tb.definition.clone(),
             handle_pk_conflict: tb.handle_pk_conflict,
+            statistics: tb.statistics,
         }
     }
 }
@@ -354,6 +361,7 @@ mod tests {
             value_indices: vec![0],
             definition: "".into(),
             handle_pk_conflict: false,
+            statistics: None,
         }
         .into();
 
@@ -411,7 +419,8 @@ mod tests {
                 vnode_col_idx: None,
                 value_indices: vec![0],
                 definition: "".into(),
-                handle_pk_conflict: false
+                handle_pk_conflict: false,
+                statistics: None,
             }
         );
         assert_eq!(table, TableCatalog::from(table.to_prost(0, 0)));
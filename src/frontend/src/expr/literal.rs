@@ -39,6 +39,7 @@ impl std::fmt::Debug for Literal {
                 // Add single quotation marks for string and interval literals
                 Some(ScalarImpl::Utf8(v)) => write!(f, "'{}'", v),
                 Some(ScalarImpl::Interval(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::Jsonb(v)) => write!(f, "'{}'", v),
                 Some(ScalarImpl::Bool(v)) => write!(f, "{}", v),
                 Some(ScalarImpl::List(v)) => write!(f, "{}", display_for_explain(v)),
                 Some(v) => write!(f, "{}", v.as_scalar_ref_impl().to_text()),
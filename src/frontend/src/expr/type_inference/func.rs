@@ -290,6 +290,37 @@ fn infer_type_for_special(
                 .into()),
             }
         }
+        ExprType::ArrayLength => {
+            ensure_arity!("array_length", | inputs | == 2);
+            match inputs[0].return_type() {
+                DataType::List { .. } => {
+                    let owned = std::mem::replace(&mut inputs[1], ExprImpl::literal_bool(true));
+                    inputs[1] = owned.cast_implicit(DataType::Int32)?;
+                    Ok(Some(DataType::Int32))
+                }
+                _ => Ok(None),
+            }
+        }
+        ExprType::Cardinality => {
+            ensure_arity!("cardinality", | inputs | == 1);
+            match inputs[0].return_type() {
+                DataType::List { .. } => Ok(Some(DataType::Int32)),
+                _ => Ok(None),
+            }
+        }
+        ExprType::JsonbAccessPath => {
+            ensure_arity!("#>", | inputs | == 2);
+            let inputs_owned = std::mem::take(inputs);
+            let [json, path]: [_; 2] = inputs_owned.try_into().unwrap();
+            let path_type = DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            };
+            *inputs = vec![
+                json.cast_implicit(DataType::Jsonb)?,
+                path.cast_implicit(path_type)?,
+            ];
+            Ok(Some(DataType::Jsonb))
+        }
         ExprType::Vnode => {
             ensure_arity!("vnode", 1 <= | inputs |);
             Ok(Some(DataType::Int16))
@@ -685,6 +716,7 @@ fn build_type_derive_map() -> FuncSigMap {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
     ];
     let num_types = [
         T::Int16,
@@ -725,7 +757,7 @@ fn build_type_derive_map() -> FuncSigMap {
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Date, T::Timestamp, T::Timestampz]);
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Time, T::Interval]);
     for e in cmp_exprs {
-        for t in [T::Boolean, T::Varchar] {
+        for t in [T::Boolean, T::Varchar, T::Jsonb] {
             map.insert(*e, vec![t, t], T::Boolean);
         }
     }
@@ -814,6 +846,7 @@ fn build_type_derive_map() -> FuncSigMap {
         T::Timestampz,
     );
     map.insert(E::ToTimestamp, vec![T::Float64], T::Timestampz);
+    map.insert(E::ToTimestamp, vec![T::Varchar, T::Varchar], T::Timestampz);
     map.insert(E::AtTimeZone, vec![T::Timestamp, T::Varchar], T::Timestampz);
     map.insert(E::AtTimeZone, vec![T::Timestampz, T::Varchar], T::Timestamp);
     map.insert(E::DateTrunc, vec![T::Varchar, T::Timestamp], T::Timestamp);
@@ -823,6 +856,9 @@ fn build_type_derive_map() -> FuncSigMap {
         T::Timestampz,
     );
     map.insert(E::DateTrunc, vec![T::Varchar, T::Interval], T::Interval);
+    for e in [E::JustifyDays, E::JustifyHours] {
+        map.insert(e, vec![T::Interval], T::Interval);
+    }
 
     // string expressions
     for e in [E::Trim, E::Ltrim, E::Rtrim, E::Lower, E::Upper, E::Md5] {
@@ -835,6 +871,10 @@ fn build_type_derive_map() -> FuncSigMap {
         map.insert(e, vec![T::Varchar, T::Int32], T::Varchar);
     }
     map.insert(E::Substr, vec![T::Varchar, T::Int32, T::Int32], T::Varchar);
+    for e in [E::Lpad, E::Rpad] {
+        map.insert(e, vec![T::Varchar, T::Int32], T::Varchar);
+        map.insert(e, vec![T::Varchar, T::Int32, T::Varchar], T::Varchar);
+    }
     for e in [E::Replace, E::Translate] {
         map.insert(e, vec![T::Varchar, T::Varchar, T::Varchar], T::Varchar);
     }
@@ -867,6 +907,14 @@ fn build_type_derive_map() -> FuncSigMap {
     // TODO: Support more `to_char` types.
     map.insert(E::ToChar, vec![T::Timestamp, T::Varchar], T::Varchar);
 
+    // jsonb functions and operators
+    for t in [T::Varchar, T::Int32] {
+        map.insert(E::JsonbAccessInner, vec![T::Jsonb, t], T::Jsonb);
+        map.insert(E::JsonbAccessStr, vec![T::Jsonb, t], T::Varchar);
+    }
+    map.insert(E::JsonbTypeof, vec![T::Jsonb], T::Varchar);
+    map.insert(E::JsonbArrayLength, vec![T::Jsonb], T::Int32);
+
     map
 }
 
@@ -0,0 +1,337 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use futures::StreamExt;
+use itertools::Itertools;
+use pgwire::pg_field_descriptor::PgFieldDescriptor;
+use pgwire::pg_response::{PgResponse, StatementType};
+use pgwire::types::Row;
+use risingwave_common::catalog::ColumnId;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_pb::catalog::table_statistics::column_statistics::{
+    OptionalMaxValue, OptionalMinValue,
+};
+use risingwave_pb::catalog::table_statistics::ColumnStatistics as ProstColumnStatistics;
+use risingwave_pb::catalog::TableStatistics as ProstTableStatistics;
+use risingwave_sqlparser::ast::ObjectName;
+use risingwave_sqlparser::parser::Parser;
+
+use super::{query, RwPgResponse};
+use crate::binder::{Binder, Relation};
+use crate::catalog::CatalogError;
+use crate::session::OptimizerContext;
+
+/// Handles `ANALYZE <table>`, which scans the table once and reports the row count, the fraction
+/// of nulls, the estimated number of distinct values and the min/max value of each column. The
+/// statistics are also persisted in the catalog of the table.
+///
+/// A table without visible columns only gets its row count persisted, and no row is reported.
+pub async fn handle_analyze(
+    context: OptimizerContext,
+    table_name: ObjectName,
+) -> Result<RwPgResponse> {
+    let session = context.session_ctx.clone();
+    let (table_id, columns) = {
+        let mut binder = Binder::new(&session);
+        match binder.bind_relation_by_name(table_name.clone(), None)? {
+            Relation::BaseTable(t) => {
+                let columns = t
+                    .table_catalog
+                    .columns
+                    .iter()
+                    .filter(|c| !c.is_hidden())
+                    .map(|c| (c.name().to_owned(), c.data_type().clone(), c.column_id()))
+                    .collect_vec();
+                (t.table_id, columns)
+            }
+            _ => return Err(CatalogError::NotFound("table", table_name.to_string()).into()),
+        }
+    };
+
+    let sql = gen_analyze_sql(&table_name, &columns);
+    let stmt = Parser::parse_sql(&sql)
+        .map_err(|e| ErrorCode::InternalError(format!("failed to parse `{}`: {}", sql, e)))?
+        .into_iter()
+        .exactly_one()
+        .map_err(|_| ErrorCode::InternalError(format!("expect one statement: `{}`", sql)))?;
+
+    // The rows are always in text format, so that they can be parsed into the statistics.
+    let mut response = query::handle_query(context, stmt, vec![]).await?;
+    let mut rows = vec![];
+    while let Some(row_set) = response.values_stream().next().await {
+        rows.extend(row_set.map_err(|e| RwError::from(ErrorCode::InternalError(e.to_string())))?);
+    }
+    let row = rows.into_iter().exactly_one().map_err(|_| {
+        ErrorCode::InternalError("expect exactly one row in the analyze result".to_owned())
+    })?;
+
+    let (statistics, rows) = collect_statistics(&row, &columns)?;
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .update_table_statistics(table_id, statistics)
+        .await?;
+
+    Ok(PgResponse::new_for_stream(
+        StatementType::SELECT,
+        Some(rows.len() as i32),
+        rows.into(),
+        analyze_row_desc(),
+    ))
+}
+
+/// The columns reported by `ANALYZE`, one row per analyzed column.
+fn analyze_row_desc() -> Vec<PgFieldDescriptor> {
+    [
+        ("column_name", DataType::VARCHAR),
+        ("row_count", DataType::INT64),
+        ("null_frac", DataType::FLOAT64),
+        ("n_distinct", DataType::INT64),
+        ("min_value", DataType::VARCHAR),
+        ("max_value", DataType::VARCHAR),
+    ]
+    .into_iter()
+    .map(|(name, data_type)| {
+        PgFieldDescriptor::new(name.to_owned(), data_type.to_oid(), data_type.type_len())
+    })
+    .collect()
+}
+
+/// Collects the statistics of the table from the single row returned by the query generated by
+/// [`gen_analyze_sql`]. Also returns the rows reported to the client, one per column.
+fn collect_statistics(
+    row: &Row,
+    columns: &[(String, DataType, ColumnId)],
+) -> Result<(ProstTableStatistics, Vec<Row>)> {
+    let mut values = row
+        .values()
+        .iter()
+        .map(|v| v.as_ref().map(|v| String::from_utf8_lossy(v).into_owned()));
+    let mut next_value = || {
+        values.next().ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(
+                "too few columns in the analyze result".to_owned(),
+            ))
+        })
+    };
+
+    let row_count: u64 = parse_value(next_value()?)?.unwrap_or_default();
+    let mut statistics = ProstTableStatistics {
+        row_count,
+        columns: vec![],
+    };
+    let mut rows = vec![];
+    for (name, data_type, column_id) in columns {
+        let non_null_count: u64 = parse_value(next_value()?)?.unwrap_or_default();
+        let n_distinct: i64 = parse_value(next_value()?)?.unwrap_or_default();
+        let (min_value, max_value) = if is_orderable(data_type) {
+            (next_value()?, next_value()?)
+        } else {
+            (None, None)
+        };
+        // The null fraction of an empty table is NULL.
+        let null_frac = (row_count > 0).then(|| 1.0 - non_null_count as f64 / row_count as f64);
+
+        rows.push(Row::new(vec![
+            Some(name.clone().into()),
+            Some(row_count.to_string().into()),
+            null_frac.map(|v| v.to_string().into()),
+            Some(n_distinct.to_string().into()),
+            min_value.clone().map(Into::into),
+            max_value.clone().map(Into::into),
+        ]));
+        statistics.columns.push(ProstColumnStatistics {
+            column_id: column_id.get_id(),
+            null_frac: null_frac.unwrap_or_default(),
+            n_distinct,
+            optional_min_value: min_value.map(OptionalMinValue::MinValue),
+            optional_max_value: max_value.map(OptionalMaxValue::MaxValue),
+        });
+    }
+
+    if values.next().is_some() {
+        return Err(
+            ErrorCode::InternalError("too many columns in the analyze result".to_owned()).into(),
+        );
+    }
+    Ok((statistics, rows))
+}
+
+fn parse_value<T: FromStr>(value: Option<String>) -> Result<Option<T>> {
+    value
+        .map(|v| {
+            v.parse().map_err(|_| {
+                ErrorCode::InternalError(format!("invalid value `{}` in the analyze result", v))
+                    .into()
+            })
+        })
+        .transpose()
+}
+
+/// Generates a query computing the statistics of all columns in a single scan of the table. It
+/// returns one row with the row count, followed by the non-null count, the estimated number of
+/// distinct values and, if the type is orderable, the min/max value of each column.
+fn gen_analyze_sql(table_name: &ObjectName, columns: &[(String, DataType, ColumnId)]) -> String {
+    let mut select_items = vec!["count(*)".to_owned()];
+    for (name, data_type, _) in columns {
+        let column = format!("\"{}\"", name.replace('"', "\"\""));
+        select_items.push(format!("count({})", column));
+        select_items.push(format!("approx_count_distinct({})", column));
+        if is_orderable(data_type) {
+            select_items.push(format!("min({})::VARCHAR", column));
+            select_items.push(format!("max({})::VARCHAR", column));
+        }
+    }
+    format!("SELECT {} FROM {}", select_items.join(", "), table_name)
+}
+
+/// Whether min/max are supported and meaningful for the type.
+fn is_orderable(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal
+            | DataType::Varchar
+            | DataType::Date
+            | DataType::Time
+            | DataType::Timestamp
+            | DataType::Interval
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_sqlparser::ast::Ident;
+
+    use super::*;
+
+    fn text_row(values: &[Option<&str>]) -> Row {
+        Row::new(
+            values
+                .iter()
+                .map(|v| v.map(|v| v.as_bytes().to_vec().into()))
+                .collect(),
+        )
+    }
+
+    fn columns() -> Vec<(String, DataType, ColumnId)> {
+        vec![
+            ("v1".to_owned(), DataType::Int32, ColumnId::new(1)),
+            (
+                "v2".to_owned(),
+                DataType::List {
+                    datatype: Box::new(DataType::Int32),
+                },
+                ColumnId::new(2),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_gen_analyze_sql() {
+        let table_name = ObjectName(vec![Ident::new("t")]);
+        assert_eq!(
+            gen_analyze_sql(&table_name, &columns()),
+            "SELECT count(*), \
+             count(\"v1\"), approx_count_distinct(\"v1\"), \
+             min(\"v1\")::VARCHAR, max(\"v1\")::VARCHAR, \
+             count(\"v2\"), approx_count_distinct(\"v2\") \
+             FROM t"
+        );
+        assert_eq!(gen_analyze_sql(&table_name, &[]), "SELECT count(*) FROM t");
+    }
+
+    #[test]
+    fn test_collect_statistics() {
+        let row = text_row(&[
+            Some("4"),
+            Some("3"),
+            Some("2"),
+            Some("1"),
+            Some("2"),
+            Some("2"),
+            Some("1"),
+        ]);
+        let (statistics, rows) = collect_statistics(&row, &columns()).unwrap();
+        assert_eq!(
+            statistics,
+            ProstTableStatistics {
+                row_count: 4,
+                columns: vec![
+                    ProstColumnStatistics {
+                        column_id: 1,
+                        null_frac: 0.25,
+                        n_distinct: 2,
+                        optional_min_value: Some(OptionalMinValue::MinValue("1".to_owned())),
+                        optional_max_value: Some(OptionalMaxValue::MaxValue("2".to_owned())),
+                    },
+                    ProstColumnStatistics {
+                        column_id: 2,
+                        null_frac: 0.5,
+                        n_distinct: 1,
+                        optional_min_value: None,
+                        optional_max_value: None,
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            rows.iter().map(|row| row.values().to_vec()).collect_vec(),
+            [
+                text_row(&[
+                    Some("v1"),
+                    Some("4"),
+                    Some("0.25"),
+                    Some("2"),
+                    Some("1"),
+                    Some("2"),
+                ]),
+                text_row(&[Some("v2"), Some("4"), Some("0.5"), Some("1"), None, None]),
+            ]
+            .map(|row| row.values().to_vec())
+        );
+
+        // The null fraction of an empty table is NULL.
+        let row = text_row(&[
+            Some("0"),
+            Some("0"),
+            Some("0"),
+            None,
+            None,
+            Some("0"),
+            Some("0"),
+        ]);
+        let (statistics, rows) = collect_statistics(&row, &columns()).unwrap();
+        assert_eq!(statistics.row_count, 0);
+        assert_eq!(statistics.columns[0].null_frac, 0.0);
+        assert_eq!(rows[0].values()[2], None);
+
+        // A table without visible columns only has its row count collected.
+        let (statistics, rows) = collect_statistics(&text_row(&[Some("5")]), &[]).unwrap();
+        assert_eq!(statistics.row_count, 5);
+        assert!(statistics.columns.is_empty());
+        assert!(rows.is_empty());
+
+        let row = text_row(&[Some("4"), Some("3")]);
+        assert!(collect_statistics(&row, &columns()).is_err());
+        let row = text_row(&[Some("4"), Some("3")]);
+        assert!(collect_statistics(&row, &[]).is_err());
+    }
+}
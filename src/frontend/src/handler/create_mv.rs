@@ -19,7 +19,7 @@ use risingwave_pb::catalog::Table as ProstTable;
 use risingwave_pb::user::grant_privilege::Action;
 use risingwave_sqlparser::ast::{Ident, ObjectName, Query};
 
-use super::privilege::{check_privileges, resolve_relation_privileges};
+use super::privilege::{check_privileges, resolve_set_expr_privileges};
 use super::RwPgResponse;
 use crate::binder::{Binder, BoundSetExpr};
 use crate::optimizer::PlanRef;
@@ -68,13 +68,12 @@ pub fn gen_create_mv_plan(
             )
             .into());
         }
-        if let Some(relation) = &select.from {
-            let mut check_items = Vec::new();
-            resolve_relation_privileges(relation, Action::Select, &mut check_items);
-            check_privileges(session, &check_items)?;
-        }
     }
 
+    let mut check_items = Vec::new();
+    resolve_set_expr_privileges(&bound.body, Action::Select, &mut check_items);
+    check_privileges(session, &check_items)?;
+
     let mut plan_root = Planner::new(context).plan_query(bound)?;
     // Check the col_names match number of columns in the query.
     if let Some(col_names) = &col_names {
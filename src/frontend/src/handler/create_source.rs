@@ -18,6 +18,7 @@ use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::ErrorCode::{self, ProtocolError};
 use risingwave_common::error::{Result, RwError};
+use risingwave_connector::source::dead_letter::ParseErrorPolicy;
 use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::{
     ColumnIndex as ProstColumnIndex, Source as ProstSource, StreamSourceInfo,
@@ -147,6 +148,9 @@ pub async fn handle_create_source(
             UPSTREAM_SOURCE_KEY
         ))));
     }
+    // Reject an invalid parse error policy here rather than when the source is read.
+    ParseErrorPolicy::from_properties(&with_properties)
+        .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
     let (columns, source_info) = match &stmt.source_schema {
         SourceSchema::Protobuf(protobuf_schema) => {
             assert_eq!(columns.len(), 1);
@@ -258,8 +262,12 @@ pub async fn handle_create_source(
     let catalog_writer = session.env().catalog_writer();
     if is_materialized {
         let (graph, table) = {
-            let (plan, table) =
-                gen_materialized_source_plan(context.into(), source.clone(), session.user_id())?;
+            let (plan, table) = gen_materialized_source_plan(
+                context.into(),
+                source.clone(),
+                session.user_id(),
+                HashMap::new(),
+            )?;
             let graph = build_graph(plan);
 
             (graph, table)
@@ -330,4 +338,18 @@ pub mod tests {
         };
         assert_eq!(columns, expected_columns);
     }
+
+    #[tokio::test]
+    async fn test_create_source_with_invalid_parse_error_policy() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let sql = r#"CREATE SOURCE t (v1 int)
+    WITH (kafka.topic = 'abc', kafka.servers = 'localhost:1001', parse.error.policy = 'ignore')
+    ROW FORMAT JSON"#;
+        assert!(frontend.run_sql(sql).await.is_err());
+
+        let sql = r#"CREATE SOURCE t (v1 int)
+    WITH (kafka.topic = 'abc', kafka.servers = 'localhost:1001', parse.error.policy = 'dead_letter')
+    ROW FORMAT JSON"#;
+        assert!(frontend.run_sql(sql).await.is_err());
+    }
 }
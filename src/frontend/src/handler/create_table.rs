@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 use fixedbitset::FixedBitSet;
@@ -31,10 +31,11 @@ use risingwave_sqlparser::ast::{
 
 use super::create_source::make_prost_source;
 use super::RwPgResponse;
-use crate::binder::{bind_data_type, bind_struct_field};
+use crate::binder::{bind_data_type, bind_struct_field, Binder};
 use crate::catalog::column_catalog::ColumnCatalog;
 use crate::catalog::{check_valid_column_name, ColumnId};
-use crate::optimizer::plan_node::LogicalSource;
+use crate::expr::{Expr, ExprImpl, InputRef};
+use crate::optimizer::plan_node::{LogicalProject, LogicalSource};
 use crate::optimizer::property::{Order, RequiredDist};
 use crate::optimizer::{PlanRef, PlanRoot};
 use crate::session::{OptimizerContext, OptimizerContextRef, SessionImpl};
@@ -46,6 +47,7 @@ use crate::stream_fragmenter::build_graph;
 pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<(Vec<ColumnDesc>, Option<ColumnId>)> {
     // In `ColumnDef`, pk can contain only one column. So we use `Option` rather than `Vec`.
     let mut pk_column_id = None;
+    let mut column_names = HashSet::with_capacity(columns.len());
 
     let column_descs = {
         let mut column_descs = Vec::with_capacity(columns.len());
@@ -88,6 +90,13 @@ pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<(Vec<ColumnDesc>, Opt
                 }
             }
             check_valid_column_name(&name.real_value())?;
+            if !column_names.insert(name.real_value()) {
+                return Err(ErrorCode::BindError(format!(
+                    "column \"{}\" specified more than once",
+                    name.real_value()
+                ))
+                .into());
+            }
             let field_descs = if let AstDataType::Struct(fields) = &data_type {
                 fields
                     .iter()
@@ -110,6 +119,104 @@ pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<(Vec<ColumnDesc>, Opt
     Ok((column_descs, pk_column_id))
 }
 
+/// The DEFAULT and generated expressions bound from the column options of a table.
+#[derive(Default)]
+pub struct BoundColumnExprs {
+    /// The DEFAULT expressions, keyed by the column indices.
+    pub default_columns: HashMap<usize, ExprImpl>,
+    /// The expressions of the stored generated columns, keyed by the column indices.
+    pub generated_columns: HashMap<usize, ExprImpl>,
+}
+
+impl BoundColumnExprs {
+    pub fn to_protobuf(&self) -> TableSourceInfo {
+        let to_prost = |exprs: &HashMap<usize, ExprImpl>| {
+            exprs
+                .iter()
+                .map(|(&index, expr)| (index as u32, expr.to_expr_proto()))
+                .collect()
+        };
+        TableSourceInfo {
+            default_columns: to_prost(&self.default_columns),
+            generated_columns: to_prost(&self.generated_columns),
+        }
+    }
+}
+
+/// Binds the `DEFAULT` and `GENERATED ALWAYS AS (...) STORED` options of the columns of a table,
+/// and takes them out of `columns`, so that the rest can be bound by [`bind_sql_columns`].
+pub fn bind_sql_column_exprs(
+    session: &SessionImpl,
+    table_name: &ObjectName,
+    columns: &mut [ColumnDef],
+) -> Result<BoundColumnExprs> {
+    let mut options = vec![];
+    for (i, column) in columns.iter_mut().enumerate() {
+        column
+            .options
+            .retain(|option_def| match &option_def.option {
+                ColumnOption::Default(_) | ColumnOption::Generated(_) => {
+                    options.push((i, option_def.option.clone()));
+                    false
+                }
+                _ => true,
+            });
+    }
+    let mut exprs = BoundColumnExprs::default();
+    if options.is_empty() {
+        return Ok(exprs);
+    }
+
+    let (column_descs, _) = bind_sql_columns(columns.to_vec())?;
+    let table_name = table_name.0.last().unwrap().real_value();
+    let mut binder = Binder::new(session);
+    for (i, option) in options {
+        let column = &column_descs[i];
+        let data_type = column.data_type.clone();
+        let (bound_exprs, expr, msg) = match option {
+            ColumnOption::Default(expr) => (
+                &mut exprs.default_columns,
+                binder.bind_column_default(expr, data_type)?,
+                "multiple default values specified",
+            ),
+            ColumnOption::Generated(expr) => (
+                &mut exprs.generated_columns,
+                binder.bind_generated_column(table_name.clone(), &column_descs, expr, data_type)?,
+                "multiple generation clauses specified",
+            ),
+            _ => unreachable!(),
+        };
+        if bound_exprs.insert(i, expr).is_some() {
+            return Err(
+                ErrorCode::BindError(format!("{} for column \"{}\"", msg, column.name)).into(),
+            );
+        }
+    }
+
+    for (&i, expr) in &exprs.generated_columns {
+        if exprs.default_columns.contains_key(&i) {
+            return Err(ErrorCode::BindError(format!(
+                "both default and generation expression specified for column \"{}\"",
+                column_descs[i].name
+            ))
+            .into());
+        }
+        // A generated column can only be computed from the columns written directly.
+        if let Some(j) = expr
+            .collect_input_refs(column_descs.len())
+            .ones()
+            .find(|j| exprs.generated_columns.contains_key(j))
+        {
+            return Err(ErrorCode::BindError(format!(
+                "cannot use generated column \"{}\" in column generation expression",
+                column_descs[j].name
+            ))
+            .into());
+        }
+    }
+    Ok(exprs)
+}
+
 /// Binds table constraints given the binding results from column definitions.
 /// It returns the columns together with `pk_column_ids`, and an optional row id column index if
 /// added.
@@ -199,12 +306,27 @@ pub(crate) fn gen_create_table_plan(
     session: &SessionImpl,
     context: OptimizerContextRef,
     table_name: ObjectName,
-    columns: Vec<ColumnDef>,
+    mut columns: Vec<ColumnDef>,
     constraints: Vec<TableConstraint>,
 ) -> Result<(PlanRef, ProstSource, ProstTable)> {
+    let column_exprs = bind_sql_column_exprs(session, &table_name, &mut columns)?;
     let (column_descs, pk_column_id_from_columns) = bind_sql_columns(columns)?;
     let (columns, pk_column_ids, row_id_index) =
         bind_sql_table_constraints(column_descs, pk_column_id_from_columns, constraints)?;
+    if let Some(index) = pk_column_ids
+        .iter()
+        .map(|id| id.get_id() as usize)
+        .find(|index| column_exprs.generated_columns.contains_key(index))
+    {
+        return Err(ErrorCode::NotImplemented(
+            format!(
+                "generated column \"{}\" in primary key",
+                columns[index].get_column_desc()?.name
+            ),
+            None.into(),
+        )
+        .into());
+    }
     let row_id_index = row_id_index.map(|index| ProstColumnIndex { index: index as _ });
     let pk_column_ids = pk_column_ids.into_iter().map(Into::into).collect();
     let properties = context.inner().with_options.inner().clone();
@@ -215,22 +337,30 @@ pub(crate) fn gen_create_table_plan(
         columns,
         pk_column_ids,
         properties,
-        Info::TableSource(TableSourceInfo {}),
+        Info::TableSource(column_exprs.to_protobuf()),
+    )?;
+    let (plan, table) = gen_materialized_source_plan(
+        context,
+        source.clone(),
+        session.user_id(),
+        column_exprs.generated_columns,
     )?;
-    let (plan, table) = gen_materialized_source_plan(context, source.clone(), session.user_id())?;
     Ok((plan, source, table))
 }
 
 /// Generate a stream plan with `StreamSource` + `StreamMaterialize`, it resembles a
-/// `CREATE MATERIALIZED VIEW AS SELECT * FROM <source>`.
+/// `CREATE MATERIALIZED VIEW AS SELECT * FROM <source>`. The `generated_columns` are computed by
+/// a `StreamProject` in between.
 pub(crate) fn gen_materialized_source_plan(
     context: OptimizerContextRef,
     source: ProstSource,
     owner: u32,
+    mut generated_columns: HashMap<usize, ExprImpl>,
 ) -> Result<(PlanRef, ProstTable)> {
     let materialize = {
         // Manually assemble the materialization plan for the table.
-        let source_node: PlanRef = LogicalSource::new(Rc::new((&source).into()), context).into();
+        let mut source_node: PlanRef =
+            LogicalSource::new(Rc::new((&source).into()), context).into();
         let row_id_index = source.row_id_index.as_ref().map(|index| index.index as _);
         // row_id_index is Some means that the user has not specified pk, then we will add a hidden
         // column to store pk, and materialize executor do not need to handle pk conflict.
@@ -242,6 +372,20 @@ pub(crate) fn gen_materialized_source_plan(
             required_cols.toggle(row_id_index);
             out_names.remove(row_id_index);
         }
+        if !generated_columns.is_empty() {
+            let exprs = source_node
+                .schema()
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    generated_columns
+                        .remove(&i)
+                        .unwrap_or_else(|| InputRef::new(i, field.data_type()).into())
+                })
+                .collect();
+            source_node = LogicalProject::create(source_node, exprs);
+        }
 
         PlanRoot::new(
             source_node,
@@ -312,6 +456,7 @@ mod tests {
 
     use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
     use risingwave_common::types::DataType;
+    use risingwave_pb::catalog::source_info::SourceInfo;
 
     use super::*;
     use crate::catalog::root_catalog::SchemaPath;
@@ -360,6 +505,60 @@ mod tests {
         assert_eq!(columns, expected_columns);
     }
 
+    #[tokio::test]
+    async fn test_bind_column_exprs() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let sql = "create table t (v1 int, v2 int default 1, v3 int generated always as (v1 + v2) stored)";
+        frontend.run_sql(sql).await.unwrap();
+
+        {
+            let session = frontend.session_ref();
+            let catalog_reader = session.env().catalog_reader().read_guard();
+            let schema_path = SchemaPath::Name(DEFAULT_SCHEMA_NAME);
+            let (source, _) = catalog_reader
+                .get_source_by_name(DEFAULT_DATABASE_NAME, schema_path, "t")
+                .unwrap();
+            let SourceInfo::TableSource(info) = &source.info else {
+                panic!("t should be a table source")
+            };
+            assert_eq!(info.default_columns.keys().collect_vec(), vec![&1]);
+            assert_eq!(info.generated_columns.keys().collect_vec(), vec![&2]);
+        }
+
+        for (sql, expected) in [
+            (
+                "create table t1 (v1 int, v2 int default v1)",
+                "Invalid column: v1",
+            ),
+            (
+                "create table t1 (v1 int, v2 int default 1 default 2)",
+                "multiple default values specified for column \"v2\"",
+            ),
+            (
+                "create table t1 (v1 int, v2 int default 1 generated always as (v1) stored)",
+                "both default and generation expression specified for column \"v2\"",
+            ),
+            (
+                "create table t1 (v1 int generated always as (v2) stored, v2 int generated always as (1) stored)",
+                "cannot use generated column \"v2\" in column generation expression",
+            ),
+            (
+                "create table t1 (v1 int, v2 int generated always as (sum(v1)) stored)",
+                "aggregate functions are not allowed in GENERATED COLUMN",
+            ),
+            (
+                "create table t1 (v1 int, v2 int primary key generated always as (v1) stored)",
+                "generated column \"v2\" in primary key",
+            ),
+        ] {
+            let actual = frontend.run_sql(sql).await.err().unwrap();
+            assert!(
+                actual.to_string().contains(expected),
+                "sql: {sql}\nexpected: {expected:?}\nactual: {actual:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_bind_primary_key() {
         for (sql, expected) in [
@@ -398,6 +597,10 @@ mod tests {
                 "create table t (v1 int, primary key (V3), v2 int)",
                 Err("column \"v3\" named in key does not exist"),
             ),
+            (
+                "create table t (v1 int, V1 int)",
+                Err("column \"v1\" specified more than once"),
+            ),
         ] {
             let mut ast = risingwave_sqlparser::parser::Parser::parse_sql(sql).unwrap();
             let risingwave_sqlparser::ast::Statement::CreateTable {
@@ -25,7 +25,7 @@ use super::create_index::gen_create_index_plan;
 use super::create_mv::gen_create_mv_plan;
 use super::create_sink::gen_sink_plan;
 use super::create_table::gen_create_table_plan;
-use super::query::gen_batch_query_plan;
+use super::query::{gen_batch_query_plan, gen_logical_query_plan};
 use super::RwPgResponse;
 use crate::optimizer::plan_node::Convention;
 use crate::scheduler::BatchPlanFragmenter;
@@ -42,8 +42,20 @@ pub(super) fn handle_explain(
     if analyze {
         return Err(ErrorCode::NotImplemented("explain analyze".to_string(), 4856.into()).into());
     }
-    if options.explain_type == ExplainType::Logical {
-        return Err(ErrorCode::NotImplemented("explain logical".to_string(), 4856.into()).into());
+    if options.explain_type == ExplainType::Logical
+        && !matches!(
+            stmt,
+            Statement::Query(_)
+                | Statement::Insert { .. }
+                | Statement::Delete { .. }
+                | Statement::Update { .. }
+        )
+    {
+        return Err(ErrorCode::NotImplemented(
+            "explain logical of non-query statement".to_string(),
+            4856.into(),
+        )
+        .into());
     }
 
     let session = context.session_ctx.clone();
@@ -101,6 +113,10 @@ pub(super) fn handle_explain(
             .into());
         }
 
+        stmt if options.explain_type == ExplainType::Logical => {
+            gen_logical_query_plan(&session, context.into(), stmt)?
+        }
+
         stmt => gen_batch_query_plan(&session, context.into(), stmt)?.0,
     };
 
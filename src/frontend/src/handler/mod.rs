@@ -31,6 +31,7 @@ use crate::session::{OptimizerContext, SessionImpl};
 use crate::utils::WithOptions;
 
 pub mod alter_user;
+mod analyze;
 mod create_database;
 pub mod create_index;
 pub mod create_mv;
@@ -90,7 +91,7 @@ pub async fn handle(
     session: Arc<SessionImpl>,
     stmt: Statement,
     sql: &str,
-    format: bool,
+    formats: Vec<bool>,
 ) -> Result<RwPgResponse> {
     let context = OptimizerContext::new(
         session.clone(),
@@ -153,6 +154,7 @@ pub async fn handle(
         Statement::Grant { .. } => handle_privilege::handle_grant_privilege(context, stmt).await,
         Statement::Revoke { .. } => handle_privilege::handle_revoke_privilege(context, stmt).await,
         Statement::Describe { name } => describe::handle_describe(context, name),
+        Statement::Analyze { table_name } => analyze::handle_analyze(context, table_name).await,
         Statement::ShowObjects(show_object) => show::handle_show_object(context, show_object),
         Statement::Drop(DropStatement {
             object_type,
@@ -198,7 +200,7 @@ pub async fn handle(
         Statement::Query(_)
         | Statement::Insert { .. }
         | Statement::Delete { .. }
-        | Statement::Update { .. } => query::handle_query(context, stmt, format).await,
+        | Statement::Update { .. } => query::handle_query(context, stmt, formats).await,
         Statement::CreateView {
             materialized,
             name,
@@ -16,7 +16,8 @@ use risingwave_common::error::ErrorCode::PermissionDenied;
 use risingwave_common::error::Result;
 use risingwave_pb::user::grant_privilege::{Action as ProstAction, Object as ProstObject};
 
-use crate::binder::{BoundStatement, Relation};
+use crate::binder::{BoundSetExpr, BoundStatement, Relation};
+use crate::expr::{ExprImpl, ExprVisitor, Subquery};
 use crate::session::SessionImpl;
 use crate::user::UserId;
 
@@ -60,15 +61,12 @@ pub(crate) fn resolve_relation_privileges(
             objects.push(item);
         }
         Relation::Subquery(query) => {
-            if let crate::binder::BoundSetExpr::Select(select) = &query.query.body {
-                if let Some(sub_relation) = &select.from {
-                    resolve_relation_privileges(sub_relation, action, objects);
-                }
-            }
+            resolve_set_expr_privileges(&query.query.body, action, objects)
         }
-        Relation::Join(join) => {
+        Relation::Join(join) | Relation::Apply(join) => {
             resolve_relation_privileges(&join.left, action, objects);
             resolve_relation_privileges(&join.right, action, objects);
+            resolve_expr_privileges(&join.cond, objects);
         }
         Relation::WindowTableFunction(table) => {
             resolve_relation_privileges(&table.input, action, objects)
@@ -77,6 +75,56 @@ pub(crate) fn resolve_relation_privileges(
     };
 }
 
+/// resolve privileges in `set_expr`, including every branch of set operations and the subqueries
+/// in its expressions
+pub(crate) fn resolve_set_expr_privileges(
+    set_expr: &BoundSetExpr,
+    action: ProstAction,
+    objects: &mut Vec<ObjectCheckItem>,
+) {
+    match set_expr {
+        BoundSetExpr::Select(select) => {
+            if let Some(sub_relation) = &select.from {
+                resolve_relation_privileges(sub_relation, action, objects);
+            }
+            select
+                .select_items
+                .iter()
+                .chain(&select.where_clause)
+                .chain(&select.group_by)
+                .chain(&select.having)
+                .for_each(|expr| resolve_expr_privileges(expr, objects));
+        }
+        BoundSetExpr::Query(query) => resolve_set_expr_privileges(&query.body, action, objects),
+        BoundSetExpr::Values(values) => values
+            .rows
+            .iter()
+            .flatten()
+            .for_each(|expr| resolve_expr_privileges(expr, objects)),
+        BoundSetExpr::SetOperation { left, right, .. } => {
+            resolve_set_expr_privileges(left, action, objects);
+            resolve_set_expr_privileges(right, action, objects);
+        }
+    }
+}
+
+/// resolve `SELECT` privileges of the relations read by subqueries in `expr`
+fn resolve_expr_privileges(expr: &ExprImpl, objects: &mut Vec<ObjectCheckItem>) {
+    struct SubqueryPrivilegeResolver<'a> {
+        objects: &'a mut Vec<ObjectCheckItem>,
+    }
+
+    impl ExprVisitor<()> for SubqueryPrivilegeResolver<'_> {
+        fn merge(_: (), _: ()) {}
+
+        fn visit_subquery(&mut self, subquery: &Subquery) {
+            resolve_set_expr_privileges(&subquery.query.body, ProstAction::Select, self.objects);
+        }
+    }
+
+    SubqueryPrivilegeResolver { objects }.visit_expr(expr);
+}
+
 /// resolve privileges in `stmt`
 pub(crate) fn resolve_privileges(stmt: &BoundStatement) -> Vec<ObjectCheckItem> {
     let mut objects = Vec::new();
@@ -88,11 +136,7 @@ pub(crate) fn resolve_privileges(stmt: &BoundStatement) -> Vec<ObjectCheckItem>
                 object: ProstObject::TableId(insert.table_source.source_id.table_id),
             };
             objects.push(object);
-            if let crate::binder::BoundSetExpr::Select(select) = &insert.source.body {
-                if let Some(sub_relation) = &select.from {
-                    resolve_relation_privileges(sub_relation, ProstAction::Select, &mut objects);
-                }
-            }
+            resolve_set_expr_privileges(&insert.source.body, ProstAction::Select, &mut objects);
         }
         BoundStatement::Delete(ref delete) => {
             let object = ObjectCheckItem {
@@ -101,6 +145,9 @@ pub(crate) fn resolve_privileges(stmt: &BoundStatement) -> Vec<ObjectCheckItem>
                 object: ProstObject::TableId(delete.table_source.source_id.table_id),
             };
             objects.push(object);
+            if let Some(selection) = &delete.selection {
+                resolve_expr_privileges(selection, &mut objects);
+            }
         }
         BoundStatement::Update(ref update) => {
             let object = ObjectCheckItem {
@@ -109,13 +156,14 @@ pub(crate) fn resolve_privileges(stmt: &BoundStatement) -> Vec<ObjectCheckItem>
                 object: ProstObject::TableId(update.table_source.source_id.table_id),
             };
             objects.push(object);
+            update
+                .selection
+                .iter()
+                .chain(&update.exprs)
+                .for_each(|expr| resolve_expr_privileges(expr, &mut objects));
         }
         BoundStatement::Query(ref query) => {
-            if let crate::binder::BoundSetExpr::Select(select) = &query.body {
-                if let Some(sub_relation) = &select.from {
-                    resolve_relation_privileges(sub_relation, ProstAction::Select, &mut objects);
-                }
-            }
+            resolve_set_expr_privileges(&query.body, ProstAction::Select, &mut objects);
         }
     };
     objects
@@ -165,9 +213,14 @@ pub(crate) fn check_privileges(session: &SessionImpl, items: &Vec<ObjectCheckIte
 
 #[cfg(test)]
 mod tests {
-    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SUPER_USER_ID};
+    use risingwave_common::catalog::{
+        DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, DEFAULT_SUPER_USER_ID,
+    };
+    use risingwave_sqlparser::parser::Parser;
 
     use super::*;
+    use crate::binder::Binder;
+    use crate::catalog::root_catalog::SchemaPath;
     use crate::test_utils::LocalFrontend;
 
     #[tokio::test]
@@ -214,4 +267,40 @@ mod tests {
             .unwrap();
         assert!(check_privileges(&session, &check_items).is_ok());
     }
+
+    #[tokio::test]
+    async fn test_resolve_privileges() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend.run_sql("create table t (v1 int)").await.unwrap();
+        let session = frontend.session_ref();
+        let table_id = {
+            let catalog_reader = session.env().catalog_reader().read_guard();
+            let schema_path = SchemaPath::Name(DEFAULT_SCHEMA_NAME);
+            let (table, _) = catalog_reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, schema_path, "t")
+                .unwrap();
+            table.id().table_id
+        };
+
+        for sql in [
+            "select v1 from t",
+            "select 1 union all select v1 from t",
+            "select 1 where exists (select * from t)",
+            "select (select max(v1) from t)",
+            "values ((select max(v1) from t))",
+            "select * from (select 1) as s join (select v1 from t) as u on true",
+        ] {
+            let stmt = Parser::parse_sql(sql).unwrap().remove(0);
+            let bound = Binder::new(&session).bind(stmt).unwrap();
+            let check_items = resolve_privileges(&bound);
+            assert!(
+                check_items
+                    .iter()
+                    .any(|item| item.action == ProstAction::Select
+                        && item.object == ProstObject::TableId(table_id)),
+                "privileges on t are not resolved for {}",
+                sql
+            );
+        }
+    }
 }
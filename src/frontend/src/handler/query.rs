@@ -87,10 +87,30 @@ pub fn gen_batch_query_plan(
     Ok((physical, query_mode, schema))
 }
 
+/// Generates the optimized logical plan of a query or DML statement, used by `EXPLAIN (LOGICAL)`.
+pub fn gen_logical_query_plan(
+    session: &SessionImpl,
+    context: OptimizerContextRef,
+    stmt: Statement,
+) -> Result<PlanRef> {
+    to_statement_type(&stmt)?;
+
+    let bound = {
+        let mut binder = Binder::new(session);
+        binder.bind(stmt)?
+    };
+
+    let check_items = resolve_privileges(&bound);
+    check_privileges(session, &check_items)?;
+
+    let mut planner = Planner::new(context);
+    planner.plan(bound)?.gen_optimized_logical_plan()
+}
+
 pub async fn handle_query(
     context: OptimizerContext,
     stmt: Statement,
-    format: bool,
+    formats: Vec<bool>,
 ) -> Result<RwPgResponse> {
     let stmt_type = to_statement_type(&stmt)?;
     let session = context.session_ctx.clone();
@@ -123,6 +143,9 @@ pub async fn handle_query(
         .iter()
         .map(|f| f.data_type())
         .collect_vec();
+    let time_zone = session.config().get_timezone().to_owned();
+    // The affected rows of DML are returned in the first column.
+    let first_column_binary = formats.first().copied().unwrap_or(false);
 
     let mut row_stream = {
         // Acquire hummock snapshot for execution.
@@ -135,14 +158,16 @@ pub async fn handle_query(
             QueryMode::Local => PgResponseStream::LocalQuery(DataChunkToRowSetAdapter::new(
                 local_execute(session.clone(), query, pinned_snapshot).await?,
                 column_types,
-                format,
+                formats,
+                time_zone,
             )),
             // Local mode do not support cancel tasks.
             QueryMode::Distributed => {
                 PgResponseStream::DistributedQuery(DataChunkToRowSetAdapter::new(
                     distribute_execute(session.clone(), query, pinned_snapshot).await?,
                     column_types,
-                    format,
+                    formats,
+                    time_zone,
                 ))
             }
         }
@@ -160,7 +185,7 @@ pub async fn handle_query(
             let affected_rows_str = first_row_set[0].values()[0]
                 .as_ref()
                 .expect("compute node should return affected rows in output");
-            if format {
+            if first_column_binary {
                 Some(
                     i64::from_sql(&postgres_types::Type::INT8, affected_rows_str)
                         .unwrap()
@@ -26,15 +26,16 @@ use pin_project_lite::pin_project;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::{ColumnDesc, Field};
 use risingwave_common::types::{DataType, ScalarRefImpl};
-use risingwave_expr::vector_op::cast::{timestampz_to_utc_binary, timestampz_to_utc_string};
+use risingwave_expr::vector_op::cast::timestampz_to_utc_binary;
+use risingwave_expr::vector_op::timestampz::timestampz_to_string;
 
 pin_project! {
     /// Wrapper struct that converts a stream of DataChunk to a stream of RowSet based on formatting
     /// parameters.
     ///
     /// This is essentially `StreamExt::map(self, move |res| res.map(|chunk| to_pg_rows(chunk,
-    /// format)))` but we need a nameable type as part of [`super::PgResponseStream`], but we cannot
-    /// name the type of a closure.
+    /// formats)))` but we need a nameable type as part of [`super::PgResponseStream`], but we
+    /// cannot name the type of a closure.
     pub struct DataChunkToRowSetAdapter<VS>
     where
         VS: Stream<Item = Result<DataChunk, BoxedError>>,
@@ -42,18 +43,25 @@ pin_project! {
         #[pin]
         chunk_stream: VS,
         column_types: Vec<DataType>,
-        format: bool,
+        formats: Vec<bool>,
+        time_zone: String,
     }
 }
 impl<VS> DataChunkToRowSetAdapter<VS>
 where
     VS: Stream<Item = Result<DataChunk, BoxedError>>,
 {
-    pub fn new(chunk_stream: VS, column_types: Vec<DataType>, format: bool) -> Self {
+    pub fn new(
+        chunk_stream: VS,
+        column_types: Vec<DataType>,
+        formats: Vec<bool>,
+        time_zone: String,
+    ) -> Self {
         Self {
             chunk_stream,
             column_types,
-            format,
+            formats,
+            time_zone,
         }
     }
 }
@@ -70,9 +78,12 @@ where
             Poll::Pending => Poll::Pending,
             Poll::Ready(chunk) => match chunk {
                 Some(chunk_result) => match chunk_result {
-                    Ok(chunk) => {
-                        Poll::Ready(Some(Ok(to_pg_rows(this.column_types, chunk, *this.format))))
-                    }
+                    Ok(chunk) => Poll::Ready(Some(to_pg_rows(
+                        this.column_types,
+                        chunk,
+                        this.formats,
+                        this.time_zone,
+                    ))),
                     Err(err) => Poll::Ready(Some(Err(err))),
                 },
                 None => Poll::Ready(None),
@@ -82,12 +93,21 @@ where
 }
 
 /// Format scalars according to postgres convention.
-fn pg_value_format(data_type: &DataType, d: ScalarRefImpl<'_>, format: bool) -> Bytes {
+///
+/// `timestamptz` values are displayed in `time_zone`, the `TIMEZONE` of the session.
+fn pg_value_format(
+    data_type: &DataType,
+    d: ScalarRefImpl<'_>,
+    format: bool,
+    time_zone: &str,
+) -> Result<Bytes, BoxedError> {
     // format == false means TEXT format
     // format == true means BINARY format
-    if !format {
+    let value = if !format {
         match (data_type, d) {
-            (DataType::Timestampz, ScalarRefImpl::Int64(us)) => timestampz_to_utc_string(us).into(),
+            (DataType::Timestampz, ScalarRefImpl::Int64(us)) => {
+                timestampz_to_string(us, time_zone)?.into()
+            }
             _ => d.text_format().into(),
         }
     } else {
@@ -95,21 +115,34 @@ fn pg_value_format(data_type: &DataType, d: ScalarRefImpl<'_>, format: bool) ->
             (DataType::Timestampz, ScalarRefImpl::Int64(us)) => timestampz_to_utc_binary(us),
             _ => d.binary_format(),
         }
-    }
+    };
+    Ok(value)
 }
 
-fn to_pg_rows(column_types: &[DataType], chunk: DataChunk, format: bool) -> Vec<Row> {
+/// Convert the rows of `chunk` to pg rows. `formats` holds the format of each column, and columns
+/// without a format are in TEXT format.
+fn to_pg_rows(
+    column_types: &[DataType],
+    chunk: DataChunk,
+    formats: &[bool],
+    time_zone: &str,
+) -> RowSetResult {
     chunk
         .rows()
         .map(|r| {
-            Row::new(
-                r.values()
-                    .zip_eq(column_types)
-                    .map(|(data, t)| data.map(|data| pg_value_format(t, data, format)))
-                    .collect_vec(),
-            )
+            let values = r
+                .values()
+                .zip_eq(column_types)
+                .enumerate()
+                .map(|(i, (data, t))| {
+                    let format = formats.get(i).copied().unwrap_or(false);
+                    data.map(|data| pg_value_format(t, data, format, time_zone))
+                        .transpose()
+                })
+                .try_collect()?;
+            Ok(Row::new(values))
         })
-        .collect_vec()
+        .try_collect()
 }
 
 /// Convert column descs to rows which conclude name and type
@@ -172,8 +205,10 @@ mod tests {
                 DataType::Varchar,
             ],
             chunk,
-            false,
-        );
+            &[],
+            "UTC",
+        )
+        .unwrap();
         let expected: Vec<Vec<Option<Bytes>>> = vec![
             vec![
                 Some("1".into()),
@@ -198,6 +233,32 @@ mod tests {
         assert_eq!(vec, expected);
     }
 
+    #[test]
+    fn test_to_pg_rows_mixed_formats() {
+        let chunk = DataChunk::from_pretty(
+            "i I
+             1 6
+             2 .",
+        );
+        let rows = to_pg_rows(
+            &[DataType::Int32, DataType::Int64],
+            chunk,
+            &[false, true],
+            "UTC",
+        )
+        .unwrap();
+        let expected: Vec<Vec<Option<Bytes>>> = vec![
+            vec![Some("1".into()), Some(6_i64.to_be_bytes().to_vec().into())],
+            vec![Some("2".into()), None],
+        ];
+        let vec = rows
+            .into_iter()
+            .map(|r| r.values().iter().cloned().collect_vec())
+            .collect_vec();
+
+        assert_eq!(vec, expected);
+    }
+
     #[test]
     fn test_value_format() {
         use {DataType as T, ScalarRefImpl as S};
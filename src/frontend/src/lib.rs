@@ -98,6 +98,15 @@ pub struct FrontendOpts {
     /// >0 = open metrics
     #[clap(long, default_value = "0")]
     pub metrics_level: u32,
+
+    /// The path of the TLS certificate for client connections. The demo certificate is used if not
+    /// specified.
+    #[clap(long, requires = "ssl_key")]
+    pub ssl_cert: Option<String>,
+
+    /// The path of the TLS private key for client connections.
+    #[clap(long, requires = "ssl_cert")]
+    pub ssl_key: Option<String>,
 }
 
 impl Default for FrontendOpts {
@@ -110,7 +119,7 @@ use std::future::Future;
 use std::pin::Pin;
 
 use pgwire::pg_protocol::TlsConfig;
-use risingwave_common::config::ServerConfig;
+use risingwave_common::config::{BatchConfig, ServerConfig};
 
 /// Start frontend
 pub fn start(opts: FrontendOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
@@ -118,7 +127,11 @@ pub fn start(opts: FrontendOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
     // slow compile in release mode.
     Box::pin(async move {
         let session_mgr = Arc::new(SessionManagerImpl::new(&opts).await.unwrap());
-        pg_serve(&opts.host, session_mgr, Some(TlsConfig::new_default()))
+        let tls_config = match (&opts.ssl_cert, &opts.ssl_key) {
+            (Some(cert), Some(key)) => TlsConfig::new(cert, key),
+            _ => TlsConfig::new_default(),
+        };
+        pg_serve(&opts.host, session_mgr, Some(tls_config))
             .await
             .unwrap();
     })
@@ -129,4 +142,8 @@ pub struct FrontendConfig {
     // For connection
     #[serde(default)]
     pub server: ServerConfig,
+
+    // For batch query.
+    #[serde(default)]
+    pub batch: BatchConfig,
 }
@@ -77,10 +77,17 @@ impl ToBatchProst for BatchInsert {
             .iter()
             .map(|&i| i as u32)
             .collect();
+        let default_columns = self
+            .logical
+            .default_columns()
+            .iter()
+            .map(|(i, expr)| (*i as u32, expr.clone()))
+            .collect();
         NodeBody::Insert(InsertNode {
             table_source_id: self.logical.source_id().table_id(),
             associated_mview_id: self.logical.associated_mview_id().table_id(),
             column_idxs: c_idxs,
+            default_columns,
         })
     }
 }
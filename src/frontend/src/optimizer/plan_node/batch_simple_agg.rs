@@ -65,36 +65,46 @@ impl PlanTreeNodeUnary for BatchSimpleAgg {
 }
 impl_plan_tree_node_for_unary! { BatchSimpleAgg }
 
+impl BatchSimpleAgg {
+    /// Computes the partial aggregation on each shard of `input` and only ships the partial
+    /// results to a single node to compute the total, if possible.
+    fn try_two_phase(&self, input: PlanRef) -> Option<PlanRef> {
+        // TODO: distinct agg cannot use 2-phase agg yet.
+        if !(input.distribution().satisfies(&RequiredDist::AnyShard)
+            && self.logical.can_agg_two_phase())
+        {
+            return None;
+        }
+        // partial agg
+        let partial_agg = self.clone_with_input(input).into();
+
+        // insert exchange
+        let exchange = BatchExchange::new(partial_agg, Order::any(), Distribution::Single).into();
+
+        // insert total agg
+        let total_agg_types = self
+            .logical
+            .agg_calls()
+            .iter()
+            .enumerate()
+            .map(|(partial_output_idx, agg_call)| {
+                agg_call.partial_to_total_agg_call(partial_output_idx)
+            })
+            .collect();
+        let total_agg_logical =
+            LogicalAgg::new(total_agg_types, self.logical.group_key().to_vec(), exchange);
+        Some(BatchSimpleAgg::new(total_agg_logical).into())
+    }
+}
+
 impl ToDistributedBatch for BatchSimpleAgg {
     fn to_distributed(&self) -> Result<PlanRef> {
         // Ensure input is distributed, batch phase might not distribute it
         // (e.g. see distribution of BatchSeqScan::new vs BatchSeqScan::to_distributed)
         let dist_input = self.input().to_distributed()?;
 
-        // TODO: distinct agg cannot use 2-phase agg yet.
-        if dist_input.distribution().satisfies(&RequiredDist::AnyShard)
-            && self.logical.can_agg_two_phase()
-        {
-            // partial agg
-            let partial_agg = self.clone_with_input(dist_input).into();
-
-            // insert exchange
-            let exchange =
-                BatchExchange::new(partial_agg, Order::any(), Distribution::Single).into();
-
-            // insert total agg
-            let total_agg_types = self
-                .logical
-                .agg_calls()
-                .iter()
-                .enumerate()
-                .map(|(partial_output_idx, agg_call)| {
-                    agg_call.partial_to_total_agg_call(partial_output_idx)
-                })
-                .collect();
-            let total_agg_logical =
-                LogicalAgg::new(total_agg_types, self.logical.group_key().to_vec(), exchange);
-            Ok(BatchSimpleAgg::new(total_agg_logical).into())
+        if let Some(two_phase_agg) = self.try_two_phase(dist_input) {
+            Ok(two_phase_agg)
         } else {
             let new_input = self
                 .input()
@@ -122,6 +132,10 @@ impl ToLocalBatch for BatchSimpleAgg {
     fn to_local(&self) -> Result<PlanRef> {
         let new_input = self.input().to_local()?;
 
+        if let Some(two_phase_agg) = self.try_two_phase(new_input.clone()) {
+            return Ok(two_phase_agg);
+        }
+
         let new_input =
             RequiredDist::single().enforce_if_not_satisfies(new_input, &Order::any())?;
 
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_common::catalog::FieldDisplay;
 use risingwave_common::error::Result;
@@ -156,12 +157,20 @@ impl ColPrunable for LogicalExpand {
 
 impl PredicatePushdown for LogicalExpand {
     fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
-        // TODO: how to do predicate pushdown for Expand?
-        //
-        // let new_input = self.input.predicate_pushdown(predicate);
-        // self.clone_with_input(new_input).into()
-
-        gen_filter_and_pushdown(self, predicate, Condition::true_cond())
+        // The original columns of input are never set to null, so the conditions which only refer
+        // to them can be pushed down to the input. Others referring to expanded columns or `flag`
+        // are kept above.
+        let input_len = self.input().schema().len();
+        let mut expanded_cols = FixedBitSet::with_capacity(self.schema().len());
+        expanded_cols.insert_range(..input_len);
+        expanded_cols.insert(2 * input_len);
+        let (filter_predicate, pushed_predicate) = predicate.split_disjoint(&expanded_cols);
+        let mut mapping = ColIndexMapping::with_remaining_columns(
+            &(input_len..2 * input_len).collect_vec(),
+            self.schema().len(),
+        );
+        let pushed_predicate = pushed_predicate.rewrite_expr(&mut mapping);
+        gen_filter_and_pushdown(self, filter_predicate, pushed_predicate)
     }
 }
 
@@ -192,9 +201,14 @@ mod tests {
     use itertools::Itertools;
     use risingwave_common::catalog::{Field, Schema};
     use risingwave_common::types::DataType;
+    use risingwave_pb::expr::expr_node::Type;
 
-    use crate::optimizer::plan_node::{LogicalExpand, LogicalValues};
+    use crate::expr::{ExprImpl, FunctionCall, InputRef};
+    use crate::optimizer::plan_node::{
+        LogicalExpand, LogicalValues, PlanTreeNodeUnary, PredicatePushdown,
+    };
     use crate::session::OptimizerContext;
+    use crate::utils::Condition;
 
     // TODO(Wenzhuo): change this test according to expand's new definition.
     #[tokio::test]
@@ -222,4 +236,44 @@ mod tests {
         assert_eq!(fd[0].from().ones().collect_vec(), &[0, 6]);
         assert_eq!(fd[0].to().ones().collect_vec(), &[1, 2]);
     }
+
+    #[tokio::test]
+    async fn predicate_pushdown_expand() {
+        // input: [v1, v2, v3]
+        // output: [v1, v2, v3, v1, v2, v3, flag]
+        // predicate: ($0 = $1) AND ($3 = $4)
+        // Only ($3 = $4) refers to the original columns, so it can be pushed down as ($0 = $1).
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![
+            Field::with_name(DataType::Int32, "v1"),
+            Field::with_name(DataType::Int32, "v2"),
+            Field::with_name(DataType::Int32, "v3"),
+        ];
+        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let expand = LogicalExpand::new(values.into(), vec![vec![0, 1], vec![2]]);
+
+        fn eq(l: usize, r: usize) -> ExprImpl {
+            FunctionCall::new(
+                Type::Equal,
+                vec![
+                    InputRef::new(l, DataType::Int32).into(),
+                    InputRef::new(r, DataType::Int32).into(),
+                ],
+            )
+            .unwrap()
+            .into()
+        }
+        let predicate = Condition {
+            conjunctions: vec![eq(0, 1), eq(3, 4)],
+        };
+        let plan = expand.predicate_pushdown(predicate);
+
+        let filter = plan.as_logical_filter().unwrap();
+        assert_eq!(filter.predicate().conjunctions, vec![eq(0, 1)]);
+        let input = filter.input();
+        let expand = input.as_logical_expand().unwrap();
+        let input = expand.input();
+        let pushed_filter = input.as_logical_filter().unwrap();
+        assert_eq!(pushed_filter.predicate().conjunctions, vec![eq(0, 1)]);
+    }
 }
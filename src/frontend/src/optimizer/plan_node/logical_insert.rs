@@ -17,6 +17,7 @@ use std::fmt;
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::Result;
 use risingwave_common::types::DataType;
+use risingwave_pb::expr::ExprNode;
 
 use super::{
     gen_filter_and_pushdown, BatchInsert, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary,
@@ -37,7 +38,8 @@ pub struct LogicalInsert {
     source_id: TableId,        // TODO: use SourceId
     associated_mview_id: TableId,
     input: PlanRef,
-    column_idxs: Vec<usize>, // columns in which to insert
+    column_idxs: Vec<usize>,                 // columns in which to insert
+    default_columns: Vec<(usize, ExprNode)>, // expressions to fill the other columns with
 }
 
 impl LogicalInsert {
@@ -48,6 +50,7 @@ impl LogicalInsert {
         source_id: TableId,
         associated_mview_id: TableId,
         column_idxs: Vec<usize>,
+        default_columns: Vec<(usize, ExprNode)>,
     ) -> Self {
         let ctx = input.ctx();
         let schema = Schema::new(vec![Field::unnamed(DataType::Int64)]);
@@ -60,6 +63,7 @@ impl LogicalInsert {
             associated_mview_id,
             input,
             column_idxs,
+            default_columns,
         }
     }
 
@@ -70,6 +74,7 @@ impl LogicalInsert {
         source_id: TableId,
         table_id: TableId,
         column_idxs: Vec<usize>,
+        default_columns: Vec<(usize, ExprNode)>,
     ) -> Result<Self> {
         Ok(Self::new(
             input,
@@ -77,6 +82,7 @@ impl LogicalInsert {
             source_id,
             table_id,
             column_idxs,
+            default_columns,
         ))
     }
 
@@ -96,6 +102,12 @@ impl LogicalInsert {
         self.column_idxs.clone()
     }
 
+    // Get the expressions to fill the columns not in `column_idxs` with
+    #[must_use]
+    pub fn default_columns(&self) -> &[(usize, ExprNode)] {
+        &self.default_columns
+    }
+
     #[must_use]
     pub fn associated_mview_id(&self) -> TableId {
         self.associated_mview_id
@@ -114,6 +126,7 @@ impl PlanTreeNodeUnary for LogicalInsert {
             self.source_id,
             self.associated_mview_id,
             self.column_idxs.clone(),
+            self.default_columns.clone(),
         )
     }
 }
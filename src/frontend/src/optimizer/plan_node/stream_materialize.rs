@@ -186,6 +186,7 @@ impl StreamMaterialize {
             value_indices,
             definition,
             handle_pk_conflict,
+            statistics: None,
         };
 
         Ok(Self { base, input, table })
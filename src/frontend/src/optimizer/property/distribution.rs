@@ -52,7 +52,7 @@ use risingwave_common::catalog::{FieldDisplay, Schema, TableId};
 use risingwave_common::error::Result;
 use risingwave_common::hash::{ParallelUnitId, VnodeMapping};
 use risingwave_pb::batch_plan::exchange_info::{
-    ConsistentHashInfo, Distribution as DistributionProst, DistributionMode, HashInfo,
+    ConsistentHashInfo, Distribution as DistributionProst, DistributionMode, ExchangeMode, HashInfo,
 };
 use risingwave_pb::batch_plan::ExchangeInfo;
 
@@ -156,6 +156,7 @@ impl Distribution {
                     }))
                 }
             },
+            exchange_mode: ExchangeMode::Pipelined as i32,
         }
     }
 
@@ -16,11 +16,11 @@
 //!
 //! |`column_idx`| 0   |  1 | 2  | 3  | 4  | remark |
 //! |-----------|-----|----|----|----|----|---|
-//! |Equal      | 1   | 1  | 1  | 1  | 1  | |
+//! |Equal      | 1   | 1  | 1  | 1  | 1  | `IS NULL` is also regarded as Equal |
 //! |In         | 10  | 8  | 5  | 5  | 5  | take the minimum value with actual in number |
 //! |Range(Two) | 600 | 50 | 20 | 10 | 10 | `RangeTwoSideBound` like a between 1 and 2 |
 //! |Range(One) | 1400| 70 | 25 | 15 | 10 | `RangeOneSideBound` like a > 1, a >= 1, a < 1|
-//! |All        | 4000| 100| 30 | 20 | 10 | |
+//! |All        | 4000| 100| 30 | 20 | 20 | |
 //!
 //! ```text
 //! index cost = cost(match type of 0 idx)
@@ -34,11 +34,12 @@
 //!
 //! - For `a = 1 and b = 1 and c = 1`, its cost is 1 = Equal0 * Equal1 * Equal2 = 1
 //! - For `a in (xxx) and b = 1 and c = 1`, its cost is In0 * Equal1 * Equal2 = 10
-//! - For `a = 1 and b in (xxx)`, its cost is Equal0 * In1 * All2 = 1 * 8 * 50 = 400
+//! - For `a = 1 and b in (xxx)`, its cost is Equal0 * In1 * All2 = 1 * 8 * 30 = 240
 //! - For `a between xxx and yyy`, its cost is Range(Two)0 = 600
 //! - For `a = 1 and b between xxx and yyy`, its cost is Equal0 * Range(Two)1 = 50
 //! - For `a = 1 and b > 1`, its cost is Equal0 * Range(One)1 = 70
 //! - For `a = 1`, its cost is 100 = Equal0 * All1 = 100
+//! - For `a is null`, its cost is 100 = Equal0 * All1 = 100
 //! - For no condition, its cost is All0 = 4000
 //!
 //! With the assumption that the most effective part of a index is its prefix,
@@ -701,6 +702,7 @@ impl<'a> TableScanIoEstimator<'a> {
             DataType::Varchar => 20,
             DataType::Struct { .. } => 20,
             DataType::List { .. } => 20,
+            DataType::Jsonb => 20,
         }
     }
 
@@ -766,6 +768,15 @@ impl<'a> TableScanIoEstimator<'a> {
             }
         }
 
+        // IsNull, which is scanned as an equal condition on null
+        for (i, expr) in conjunctions.iter().enumerate() {
+            if let Some(input_ref) = expr.as_is_null()
+                && input_ref.index == column_idx {
+                    conjunctions.remove(i);
+                    return MatchItem::Equal;
+            }
+        }
+
         // In
         for (i, expr) in conjunctions.iter().enumerate() {
             if let Some((input_ref, in_const_list)) = expr.as_in_const_list()
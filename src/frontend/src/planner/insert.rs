@@ -33,6 +33,7 @@ impl Planner {
             insert.table_source.source_id,
             insert.table_source.associated_mview_id,
             insert.column_idxs,
+            insert.default_columns,
         )?
         .into();
         // For insert, frontend will only schedule one task so do not need this to be single.
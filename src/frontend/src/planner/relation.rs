@@ -15,19 +15,22 @@
 use std::rc::Rc;
 
 use itertools::Itertools;
+use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::ScalarImpl;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_pb::plan_common::JoinType;
 
 use crate::binder::{
     BoundBaseTable, BoundJoin, BoundSource, BoundSystemTable, BoundWindowTableFunction, Relation,
     WindowTableFunctionKind,
 };
-use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, TableFunction};
+use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, Literal, TableFunction};
 use crate::optimizer::plan_node::{
-    LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalSource,
-    LogicalTableFunction, PlanRef,
+    LogicalApply, LogicalFilter, LogicalHopWindow, LogicalJoin, LogicalProject, LogicalProjectSet,
+    LogicalScan, LogicalSource, LogicalTableFunction, LogicalValues, PlanRef,
 };
 use crate::planner::Planner;
+use crate::utils::Condition;
 
 impl Planner {
     pub fn plan_relation(&mut self, relation: Relation) -> Result<PlanRef> {
@@ -37,9 +40,13 @@ impl Planner {
             // TODO: order is ignored in the subquery
             Relation::Subquery(q) => Ok(self.plan_query(q.query)?.into_subplan()),
             Relation::Join(join) => self.plan_join(*join),
+            Relation::Apply(join) => self.plan_apply(*join),
             Relation::WindowTableFunction(tf) => self.plan_window_table_function(*tf),
             Relation::Source(s) => self.plan_source(*s),
-            Relation::TableFunction(tf) => self.plan_table_function(*tf),
+            Relation::TableFunction {
+                expr,
+                with_ordinality,
+            } => self.plan_table_function(*expr, with_ordinality),
         }
     }
 
@@ -75,10 +82,99 @@ impl Planner {
 
     pub(super) fn plan_join(&mut self, join: BoundJoin) -> Result<PlanRef> {
         let left = self.plan_relation(join.left)?;
-        let right = self.plan_relation(join.right)?;
         let join_type = join.join_type;
         let on_clause = join.cond;
-        Ok(LogicalJoin::create(left, right, join_type, on_clause))
+        match join.right {
+            Relation::TableFunction {
+                expr,
+                with_ordinality,
+            } if expr.args.iter().any(ExprImpl::has_input_ref) => {
+                self.plan_lateral_table_function(left, *expr, with_ordinality, join_type, on_clause)
+            }
+            right => {
+                let right = self.plan_relation(right)?;
+                Ok(LogicalJoin::create(left, right, join_type, on_clause))
+            }
+        }
+    }
+
+    /// Plans a join whose right side is a `LATERAL` subquery as a [`LogicalApply`], which is then
+    /// unnested by the optimizer.
+    pub(super) fn plan_apply(&mut self, mut join: BoundJoin) -> Result<PlanRef> {
+        if join.cond.has_subquery() {
+            return Err(ErrorCode::NotImplemented(
+                "Subquery in the ON clause of a LATERAL join".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        let correlated_id = self.ctx.next_correlated_id();
+        let correlated_indices = join
+            .right
+            .collect_correlated_indices_by_depth_and_assign_id(0, correlated_id);
+        let left = self.plan_relation(join.left)?;
+        let right = self.plan_relation(join.right)?;
+        Ok(LogicalApply::create(
+            left,
+            right,
+            join.join_type,
+            Condition::with_expr(join.cond),
+            correlated_id,
+            correlated_indices,
+            false,
+        ))
+    }
+
+    /// Plans a table function whose arguments refer to the left side of the join, e.g.
+    /// `select * from t, unnest(t.arr)`. The table function is evaluated once per left row by a
+    /// [`LogicalProjectSet`], whose `projected_row_id` also gives the ordinality.
+    fn plan_lateral_table_function(
+        &mut self,
+        left: PlanRef,
+        table_function: TableFunction,
+        with_ordinality: bool,
+        join_type: JoinType,
+        on_clause: ExprImpl,
+    ) -> Result<PlanRef> {
+        if join_type != JoinType::Inner {
+            return Err(ErrorCode::NotImplemented(
+                format!(
+                    "{:?} join with a table function referring to the left side",
+                    join_type
+                ),
+                None.into(),
+            )
+            .into());
+        }
+        let mut select_list: Vec<ExprImpl> = left
+            .schema()
+            .data_types()
+            .into_iter()
+            .enumerate()
+            .map(|(i, data_type)| InputRef::new(i, data_type).into())
+            .collect_vec();
+        select_list.push(table_function.into());
+        let project_set = LogicalProjectSet::create(left, select_list);
+
+        // Remove the hidden `projected_row_id` column, which counts from 0.
+        let mut exprs: Vec<ExprImpl> = project_set
+            .schema()
+            .data_types()
+            .into_iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, data_type)| InputRef::new(i, data_type).into())
+            .collect();
+        if with_ordinality {
+            let projected_row_id = InputRef::new(0, DataType::Int64).into();
+            let one = Literal::new(Some(ScalarImpl::Int64(1)), DataType::Int64).into();
+            exprs.push(FunctionCall::new(ExprType::Add, vec![projected_row_id, one])?.into());
+        }
+        let project = LogicalProject::create(project_set, exprs);
+        Ok(LogicalFilter::create(
+            project,
+            Condition::with_expr(on_clause),
+        ))
     }
 
     pub(super) fn plan_window_table_function(
@@ -100,8 +196,24 @@ impl Planner {
         }
     }
 
-    pub(super) fn plan_table_function(&mut self, table_function: TableFunction) -> Result<PlanRef> {
-        Ok(LogicalTableFunction::new(table_function, self.ctx()).into())
+    pub(super) fn plan_table_function(
+        &mut self,
+        table_function: TableFunction,
+        with_ordinality: bool,
+    ) -> Result<PlanRef> {
+        if with_ordinality {
+            // Evaluate the table function over a dummy row to number its output.
+            let values = LogicalValues::create(vec![vec![]], Schema::default(), self.ctx());
+            self.plan_lateral_table_function(
+                values,
+                table_function,
+                true,
+                JoinType::Inner,
+                ExprImpl::literal_bool(true),
+            )
+        } else {
+            Ok(LogicalTableFunction::new(table_function, self.ctx()).into())
+        }
     }
 
     fn plan_tumble_window(
@@ -46,6 +46,9 @@ pub enum QueryMessage {
     /// Events passed running execution.
     Stage(StageEvent),
     CancelQuery,
+    /// The result of the query has been consumed or dropped. Tasks that are kept alive only for
+    /// their outputs can be released.
+    CleanUp,
 }
 
 enum QueryState {
@@ -187,6 +190,16 @@ impl QueryExecution {
         };
     }
 
+    /// Release the resources still held by the tasks of this query once its result is no longer
+    /// needed.
+    pub async fn clean_up(self: Arc<Self>) {
+        // The runner is gone if the query has failed or been cancelled, in which case all its
+        // tasks have been aborted already.
+        if self.shutdown_tx.send(QueryMessage::CleanUp).await.is_err() {
+            debug!("Query-{:?} has already been stopped", self.query.query_id);
+        }
+    }
+
     fn gen_stage_executions(
         &self,
         pinned_snapshot: &PinnedHummockSnapshot,
@@ -289,6 +302,12 @@ impl QueryRunner {
                     // One stage failed, not necessary to execute schedule stages.
                     break;
                 }
+                QueryMessage::CleanUp => {
+                    for stage_execution in self.stage_executions.values() {
+                        stage_execution.release_blocking_outputs().await;
+                    }
+                    break;
+                }
                 rest => {
                     unimplemented!("unsupported message \"{:?}\" for QueryRunner.run", rest);
                 }
@@ -557,6 +576,7 @@ pub(crate) mod tests {
             }),
             state: risingwave_pb::common::worker_node::State::Running as i32,
             parallel_units: generate_parallel_units(0, 0),
+            row_id_generator_slot: 0,
         };
         let worker2 = WorkerNode {
             id: 1,
@@ -567,6 +587,7 @@ pub(crate) mod tests {
             }),
             state: risingwave_pb::common::worker_node::State::Running as i32,
             parallel_units: generate_parallel_units(8, 1),
+            row_id_generator_slot: 1,
         };
         let worker3 = WorkerNode {
             id: 2,
@@ -577,6 +598,7 @@ pub(crate) mod tests {
             }),
             state: risingwave_pb::common::worker_node::State::Running as i32,
             parallel_units: generate_parallel_units(16, 2),
+            row_id_generator_slot: 2,
         };
         let workers = vec![worker1, worker2, worker3];
         let worker_node_manager = Arc::new(WorkerNodeManager::mock(workers));
@@ -113,7 +113,10 @@ impl QueryExecutionInfo {
     }
 
     pub fn delete_query(&mut self, query_id: &QueryId) {
-        self.query_execution_map.remove(query_id);
+        if let Some(query) = self.query_execution_map.remove(query_id) {
+            // Spawn a task to clean up. Avoid await point in this function.
+            tokio::spawn(async move { query.clean_up().await });
+        }
     }
 
     pub fn abort_queries(&self, session_id: SessionId) {
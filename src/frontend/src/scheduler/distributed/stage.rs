@@ -24,13 +24,14 @@ use futures::{stream, StreamExt};
 use futures_async_stream::for_await;
 use itertools::Itertools;
 use rand::seq::SliceRandom;
-use risingwave_batch::executor::ExecutorBuilder;
+use risingwave_batch::executor::{validate_plan_fragment, ExecutorBuilder};
 use risingwave_batch::task::TaskId as TaskIdBatch;
 use risingwave_common::array::DataChunk;
 use risingwave_common::hash::VnodeMapping;
 use risingwave_common::util::addr::HostAddr;
 use risingwave_common::util::select_all;
 use risingwave_connector::source::SplitMetaData;
+use risingwave_pb::batch_plan::exchange_info::ExchangeMode;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::plan_node::NodeBody::{Delete, Insert, Update};
 use risingwave_pb::batch_plan::{
@@ -138,6 +139,46 @@ struct StageRunner {
     ctx: ExecutionContextRef,
 }
 
+/// Sends the abort RPCs of all the tasks of `stage` without waiting for the responses.
+async fn abort_tasks(
+    stage: &QueryStageRef,
+    tasks: &HashMap<TaskId, TaskStatusHolder>,
+    compute_client_pool: &ComputeClientPoolRef,
+) -> SchedulerResult<()> {
+    for (task, task_status) in tasks.iter() {
+        // 1. Collect task info and client.
+        let loc = &task_status.get_status().location;
+        let addr = loc.as_ref().expect("Get address should not fail");
+        let client = compute_client_pool
+            .get_by_addr(HostAddr::from(addr))
+            .await
+            .map_err(|e| anyhow!(e))?;
+
+        // 2. Send RPC to each compute node for each task asynchronously.
+        let query_id = stage.query_id.id.clone();
+        let stage_id = stage.id;
+        let task_id = *task;
+        tokio::spawn(async move {
+            if let Err(e) = client
+                .abort(AbortTaskRequest {
+                    task_id: Some(risingwave_pb::batch_plan::TaskId {
+                        query_id: query_id.clone(),
+                        stage_id,
+                        task_id,
+                    }),
+                })
+                .await
+            {
+                error!(
+                    "Abort task failed, task_id: {}, stage_id: {}, query_id: {}, reason: {}",
+                    task_id, stage_id, query_id, e
+                );
+            };
+        });
+    }
+    Ok(())
+}
+
 impl TaskStatusHolder {
     fn new(task_id: TaskId) -> Self {
         let task_status = TaskStatus {
@@ -232,6 +273,25 @@ impl StageExecution {
         }
     }
 
+    /// Aborts the tasks of the stage if it outputs to a blocking exchange, so that they release
+    /// the chunks retained for re-reads. Called once the query ends, as the tasks keep them even
+    /// after they are finished.
+    pub async fn release_blocking_outputs(&self) {
+        if !matches!(
+            self.stage.exchange_info.get_exchange_mode(),
+            Ok(ExchangeMode::Blocking)
+        ) || !self.is_scheduled().await
+        {
+            return;
+        }
+        if let Err(e) = abort_tasks(&self.stage, &self.tasks, &self.compute_client_pool).await {
+            warn!(
+                "Failed to release the outputs of stage {:?}-{:?}: {:?}",
+                self.stage.query_id, self.stage.id, e
+            );
+        }
+    }
+
     pub async fn is_scheduled(&self) -> bool {
         let s = self.state.read().await;
         matches!(*s, StageState::Running { .. })
@@ -449,6 +509,7 @@ impl StageRunner {
         // Currently, the dml or table scan should never be root fragment, so the partition is None.
         // And root fragment only contain one task.
         let plan_fragment = self.create_plan_fragment(ROOT_TASK_ID, None);
+        validate_plan_fragment(&plan_fragment)?;
         let plan_node = plan_fragment.root.unwrap();
         let task_id = TaskIdBatch {
             query_id: self.stage.query_id.id.clone(),
@@ -642,39 +703,7 @@ impl StageRunner {
             *state = StageState::Failed
         }
 
-        for (task, task_status) in self.tasks.iter() {
-            // 1. Collect task info and client.
-            let loc = &task_status.get_status().location;
-            let addr = loc.as_ref().expect("Get address should not fail");
-            let client = self
-                .compute_client_pool
-                .get_by_addr(HostAddr::from(addr))
-                .await
-                .map_err(|e| anyhow!(e))?;
-
-            // 2. Send RPC to each compute node for each task asynchronously.
-            let query_id = self.stage.query_id.id.clone();
-            let stage_id = self.stage.id;
-            let task_id = *task;
-            tokio::spawn(async move {
-                if let Err(e) = client
-                    .abort(AbortTaskRequest {
-                        task_id: Some(risingwave_pb::batch_plan::TaskId {
-                            query_id: query_id.clone(),
-                            stage_id,
-                            task_id,
-                        }),
-                    })
-                    .await
-                {
-                    error!(
-                        "Abort task failed, task_id: {}, stage_id: {}, query_id: {}, reason: {}",
-                        task_id, stage_id, query_id, e
-                    );
-                };
-            });
-        }
-        Ok(())
+        abort_tasks(&self.stage, &self.tasks, &self.compute_client_pool).await
     }
 
     async fn schedule_task(
@@ -683,10 +712,12 @@ impl StageRunner {
         plan_fragment: PlanFragment,
         worker: Option<WorkerNode>,
     ) -> SchedulerResult<Streaming<TaskInfoResponse>> {
-        let worker_node_addr = worker
-            .unwrap_or(self.worker_node_manager.next_random()?)
-            .host
-            .unwrap();
+        let worker_node_addr = match worker {
+            Some(worker) => worker,
+            None => self.worker_node_manager.next_random()?,
+        }
+        .host
+        .unwrap();
 
         let compute_client = self
             .compute_client_pool
@@ -695,8 +726,9 @@ impl StageRunner {
             .map_err(|e| anyhow!(e))?;
 
         let t_id = task_id.task_id;
+        let resource_group = self.ctx.session().config().get_resource_group().to_string();
         let stream_status = compute_client
-            .create_task(task_id, plan_fragment, self.epoch)
+            .create_task(task_id, plan_fragment, self.epoch, resource_group)
             .await
             .map_err(|e| anyhow!(e))?;
 
@@ -22,7 +22,7 @@ use futures::Stream;
 use futures_async_stream::try_stream;
 use itertools::Itertools;
 use pgwire::pg_server::BoxedError;
-use risingwave_batch::executor::{BoxedDataChunkStream, ExecutorBuilder};
+use risingwave_batch::executor::{validate_plan_fragment, BoxedDataChunkStream, ExecutorBuilder};
 use risingwave_batch::task::TaskId;
 use risingwave_common::array::DataChunk;
 use risingwave_common::bail;
@@ -110,6 +110,7 @@ impl LocalQueryExecution {
         };
 
         let plan_fragment = self.create_plan_fragment()?;
+        validate_plan_fragment(&plan_fragment)?;
         let plan_node = plan_fragment.root.unwrap();
         let executor = ExecutorBuilder::new(
             &plan_node,
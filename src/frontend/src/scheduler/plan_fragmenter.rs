@@ -23,9 +23,12 @@ use itertools::Itertools;
 use risingwave_common::buffer::{Bitmap, BitmapBuilder};
 use risingwave_common::catalog::TableDesc;
 use risingwave_common::error::RwError;
-use risingwave_common::hash::{ParallelUnitId, VirtualNode, VnodeMapping};
+use risingwave_common::hash::{
+    vnode_mapping_to_bitmaps, ParallelUnitId, VirtualNode, VnodeMapping,
+};
 use risingwave_common::util::scan_range::ScanRange;
 use risingwave_connector::source::{ConnectorProperties, SplitEnumeratorImpl, SplitImpl};
+use risingwave_pb::batch_plan::exchange_info::ExchangeMode;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::{ExchangeInfo, ScanRange as ScanRangeProto};
 use risingwave_pb::common::Buffer;
@@ -571,7 +574,11 @@ impl BatchPlanFragmenter {
         parent_exec_node: Option<&mut ExecutionPlanNode>,
     ) -> SchedulerResult<()> {
         let mut execution_plan_node = ExecutionPlanNode::from(node.clone());
-        let child_exchange_info = node.distribution().to_prost(builder.parallelism, self);
+        let mut child_exchange_info = node.distribution().to_prost(builder.parallelism, self);
+        let session = node.ctx().inner().session_ctx.clone();
+        if session.config().get_batch_blocking_exchange() {
+            child_exchange_info.exchange_mode = ExchangeMode::Blocking as i32;
+        }
         let child_stage = self.new_stage(node.inputs()[0].clone(), child_exchange_info)?;
         execution_plan_node.source_stage_id = Some(child_stage.id);
 
@@ -697,19 +704,6 @@ impl BatchPlanFragmenter {
     }
 }
 
-// TODO: let frontend store owner_mapping directly?
-fn vnode_mapping_to_owner_mapping(vnode_mapping: VnodeMapping) -> HashMap<ParallelUnitId, Bitmap> {
-    let mut m: HashMap<ParallelUnitId, BitmapBuilder> = HashMap::new();
-    let num_vnodes = vnode_mapping.len();
-    for (i, parallel_unit_id) in vnode_mapping.into_iter().enumerate() {
-        let bitmap = m
-            .entry(parallel_unit_id)
-            .or_insert_with(|| BitmapBuilder::zeroed(num_vnodes));
-        bitmap.set(i, true);
-    }
-    m.into_iter().map(|(k, v)| (k, v.finish())).collect()
-}
-
 /// Try to derive the partition to read from the scan range.
 /// It can be derived if the value of the distribution key is already known.
 fn derive_partitions(
@@ -721,7 +715,7 @@ fn derive_partitions(
     let mut partitions: HashMap<ParallelUnitId, (BitmapBuilder, Vec<_>)> = HashMap::new();
 
     if scan_ranges.is_empty() {
-        return vnode_mapping_to_owner_mapping(vnode_mapping.clone())
+        return vnode_mapping_to_bitmaps(vnode_mapping)
             .into_iter()
             .map(|(k, vnode_bitmap)| {
                 (
@@ -743,7 +737,7 @@ fn derive_partitions(
         match vnode {
             None => {
                 // put this scan_range to all partitions
-                vnode_mapping_to_owner_mapping(vnode_mapping.clone())
+                vnode_mapping_to_bitmaps(vnode_mapping)
                     .into_iter()
                     .for_each(|(parallel_unit_id, vnode_bitmap)| {
                         let (bitmap, scan_ranges) = partitions
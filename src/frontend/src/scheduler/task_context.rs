@@ -15,7 +15,7 @@
 use std::sync::Arc;
 
 use risingwave_batch::executor::BatchTaskMetricsWithTaskLabels;
-use risingwave_batch::task::{BatchTaskContext, TaskOutput, TaskOutputId};
+use risingwave_batch::task::{BatchResultCache, BatchTaskContext, TaskOutput, TaskOutputId};
 use risingwave_common::catalog::SysCatalogReaderRef;
 use risingwave_common::config::BatchConfig;
 use risingwave_common::error::Result;
@@ -77,4 +77,8 @@ impl BatchTaskContext for FrontendBatchTaskContext {
     fn get_config(&self) -> &BatchConfig {
         self.env.batch_config()
     }
+
+    fn result_cache(&self) -> Option<Arc<BatchResultCache>> {
+        None
+    }
 }
@@ -184,6 +184,7 @@ mod tests {
                 host: Some(HostAddr::try_from("127.0.0.1:1234").unwrap().to_protobuf()),
                 state: worker_node::State::Running as i32,
                 parallel_units: vec![],
+                row_id_generator_slot: 0,
             },
             WorkerNode {
                 id: 2,
@@ -191,6 +192,7 @@ mod tests {
                 host: Some(HostAddr::try_from("127.0.0.1:1235").unwrap().to_protobuf()),
                 state: worker_node::State::Running as i32,
                 parallel_units: vec![],
+                row_id_generator_slot: 1,
             },
         ];
         worker_nodes
@@ -43,7 +43,7 @@ use risingwave_pb::common::WorkerType;
 use risingwave_pb::health::health_server::HealthServer;
 use risingwave_pb::user::auth_info::EncryptionType;
 use risingwave_pb::user::grant_privilege::{Action, Object};
-use risingwave_rpc_client::{ComputeClientPool, ComputeClientPoolRef, MetaClient};
+use risingwave_rpc_client::{init_client_tls, ComputeClientPool, ComputeClientPoolRef, MetaClient};
 use risingwave_sqlparser::ast::{ObjectName, ShowObject, Statement};
 use risingwave_sqlparser::parser::Parser;
 use tokio::sync::oneshot::Sender;
@@ -259,12 +259,12 @@ impl FrontendEnv {
         opts: &FrontendOpts,
     ) -> Result<(Self, JoinHandle<()>, JoinHandle<()>, Sender<()>)> {
         let frontend_config: FrontendConfig = load_config(&opts.config_path).unwrap();
-        let batch_config: BatchConfig = load_config(&opts.config_path).unwrap();
         tracing::info!(
-            "Starting frontend node with\nfrontend config {:?}\nbatch config {:?}",
-            frontend_config,
-            batch_config
+            "Starting frontend node with\nfrontend config {:?}",
+            frontend_config
         );
+        let batch_config = frontend_config.batch.clone();
+        init_client_tls(&frontend_config.server)?;
 
         let frontend_address: HostAddr = opts
             .client_address
@@ -763,10 +763,11 @@ impl Session<PgResponseStream> for SessionImpl {
         self: Arc<Self>,
         sql: &str,
 
-        // format: indicate the query PgResponse format (Only meaningful for SELECT queries).
+        // formats: indicate the query PgResponse format of each column (Only meaningful for SELECT
+        // queries). Columns without a format are in TEXT.
         // false: TEXT
         // true: BINARY
-        format: bool,
+        formats: Vec<bool>,
     ) -> std::result::Result<PgResponse<PgResponseStream>, BoxedError> {
         // Parse sql.
         let mut stmts = Parser::parse_sql(sql).map_err(|e| {
@@ -785,7 +786,7 @@ impl Session<PgResponseStream> for SessionImpl {
             ));
         }
         let stmt = stmts.swap_remove(0);
-        let rsp = handle(self, stmt, sql, format).await.map_err(|e| {
+        let rsp = handle(self, stmt, sql, formats).await.map_err(|e| {
             tracing::error!("failed to handle sql:\n{}:\n{}", sql, e);
             e
         })?;
@@ -30,7 +30,8 @@ use risingwave_common::error::Result;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Index as ProstIndex, Schema as ProstSchema, Sink as ProstSink,
-    Source as ProstSource, Table as ProstTable, View as ProstView,
+    Source as ProstSource, Table as ProstTable, TableStatistics as ProstTableStatistics,
+    View as ProstView,
 };
 use risingwave_pb::hummock::HummockSnapshot;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
@@ -89,7 +90,7 @@ impl LocalFrontend {
         sql: impl Into<String>,
     ) -> std::result::Result<RwPgResponse, Box<dyn std::error::Error + Send + Sync>> {
         let sql = sql.into();
-        self.session_ref().run_statement(sql.as_str(), false).await
+        self.session_ref().run_statement(sql.as_str(), vec![]).await
     }
 
     pub async fn run_user_sql(
@@ -101,7 +102,7 @@ impl LocalFrontend {
     ) -> std::result::Result<RwPgResponse, Box<dyn std::error::Error + Send + Sync>> {
         let sql = sql.into();
         self.session_user_ref(database, user_name, user_id)
-            .run_statement(sql.as_str(), false)
+            .run_statement(sql.as_str(), vec![])
             .await
     }
 
@@ -158,7 +159,7 @@ impl LocalFrontend {
 }
 
 pub async fn get_explain_output(sql: &str, session: Arc<SessionImpl>) -> String {
-    let mut rsp = session.run_statement(sql, false).await.unwrap();
+    let mut rsp = session.run_statement(sql, vec![]).await.unwrap();
     assert_eq!(rsp.get_stmt_type(), StatementType::EXPLAIN);
     let mut res = String::new();
     #[for_await]
@@ -355,6 +356,27 @@ impl CatalogWriter for MockCatalogWriter {
         Ok(())
     }
 
+    async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: ProstTableStatistics,
+    ) -> Result<()> {
+        let &schema_id = self
+            .table_id_to_schema_id
+            .read()
+            .get(&table_id.table_id)
+            .unwrap();
+        let database_id = self.get_database_id_by_schema(schema_id);
+        let mut table = self
+            .catalog
+            .read()
+            .get_table_by_id(&table_id)?
+            .to_prost(schema_id, database_id);
+        table.statistics = Some(statistics);
+        self.catalog.write().update_table(&table);
+        Ok(())
+    }
+
     async fn drop_schema(&self, schema_id: u32) -> Result<()> {
         let database_id = self.drop_schema_id(schema_id);
         self.catalog.write().drop_schema(database_id, schema_id);
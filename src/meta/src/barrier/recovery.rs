@@ -17,6 +17,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use futures::future::try_join_all;
+use futures::TryFutureExt;
 use itertools::Itertools;
 use risingwave_common::util::epoch::Epoch;
 use risingwave_pb::common::worker_node::State;
@@ -123,72 +124,77 @@ where
         self.clean_dirty_fragments()
             .await
             .expect("clean dirty fragments");
+        let recovery_timer = self.metrics.recovery_latency.start_timer();
         let retry_strategy = Self::get_retry_strategy();
-        let (new_epoch, _responses) = tokio_retry::Retry::spawn(retry_strategy, || async {
-            let mut info = self.resolve_actor_info_for_recovery().await;
-            let mut new_epoch = prev_epoch.next();
-
-            // Migrate expired actors to newly joined node by changing actor_map
-            let migrated = self.migrate_actors(&info).await?;
-            if migrated {
-                info = self.resolve_actor_info_for_recovery().await;
-            }
+        let (new_epoch, _responses) = tokio_retry::Retry::spawn(retry_strategy, || {
+            async {
+                let mut info = self.resolve_actor_info_for_recovery().await;
+                let mut new_epoch = prev_epoch.next();
+
+                // Migrate expired actors to newly joined node by changing actor_map
+                let migrated = self.migrate_actors(&info).await?;
+                if migrated {
+                    info = self.resolve_actor_info_for_recovery().await;
+                }
 
-            // Reset all compute nodes, stop and drop existing actors.
-            self.reset_compute_nodes(&info).await.inspect_err(|e| {
-                error!("reset compute nodes failed: {}", e);
-            })?;
-
-            // update and build all actors.
-            self.update_actors(&info).await.inspect_err(|e| {
-                error!("update actors failed: {}", e);
-            })?;
-            self.build_actors(&info).await.inspect_err(|e| {
-                error!("build_actors failed: {}", e);
-            })?;
-
-            // get split assignments for all actors
-            let source_split_assignments = self.source_manager.list_assignments().await;
-            let command = Command::Plain(Some(Mutation::Add(AddMutation {
-                actor_dispatchers: Default::default(),
-                actor_splits: build_actor_connector_splits(&source_split_assignments),
-            })));
-
-            let prev_epoch = new_epoch;
-            new_epoch = prev_epoch.next();
-            // checkpoint, used as init barrier to initialize all executors.
-            let command_ctx = Arc::new(CommandContext::new(
-                self.fragment_manager.clone(),
-                self.snapshot_manager.clone(),
-                self.env.stream_client_pool_ref(),
-                info,
-                prev_epoch,
-                new_epoch,
-                command,
-                true,
-                self.source_manager.clone(),
-            ));
-
-            let (barrier_complete_tx, mut barrier_complete_rx) =
-                tokio::sync::mpsc::unbounded_channel();
-            self.inject_barrier(command_ctx.clone(), barrier_complete_tx)
-                .await;
-            match barrier_complete_rx.recv().await.unwrap() {
-                (_, Ok(response)) => {
-                    if let Err(err) = command_ctx.post_collect().await {
-                        error!("post_collect failed: {}", err);
-                        return Err(err);
+                // Reset all compute nodes, stop and drop existing actors.
+                self.reset_compute_nodes(&info).await.inspect_err(|e| {
+                    error!("reset compute nodes failed: {}", e);
+                })?;
+
+                // update and build all actors.
+                self.update_actors(&info).await.inspect_err(|e| {
+                    error!("update actors failed: {}", e);
+                })?;
+                self.build_actors(&info).await.inspect_err(|e| {
+                    error!("build_actors failed: {}", e);
+                })?;
+
+                // get split assignments for all actors
+                let source_split_assignments = self.source_manager.list_assignments().await;
+                let command = Command::Plain(Some(Mutation::Add(AddMutation {
+                    actor_dispatchers: Default::default(),
+                    actor_splits: build_actor_connector_splits(&source_split_assignments),
+                })));
+
+                let prev_epoch = new_epoch;
+                new_epoch = prev_epoch.next();
+                // checkpoint, used as init barrier to initialize all executors.
+                let command_ctx = Arc::new(CommandContext::new(
+                    self.fragment_manager.clone(),
+                    self.snapshot_manager.clone(),
+                    self.env.stream_client_pool_ref(),
+                    info,
+                    prev_epoch,
+                    new_epoch,
+                    command,
+                    true,
+                    self.source_manager.clone(),
+                ));
+
+                let (barrier_complete_tx, mut barrier_complete_rx) =
+                    tokio::sync::mpsc::unbounded_channel();
+                self.inject_barrier(command_ctx.clone(), barrier_complete_tx)
+                    .await;
+                match barrier_complete_rx.recv().await.unwrap() {
+                    (_, Ok(response)) => {
+                        if let Err(err) = command_ctx.post_collect().await {
+                            error!("post_collect failed: {}", err);
+                            return Err(err);
+                        }
+                        Ok((new_epoch, response))
+                    }
+                    (_, Err(err)) => {
+                        error!("inject_barrier failed: {}", err);
+                        Err(err)
                     }
-                    Ok((new_epoch, response))
-                }
-                (_, Err(err)) => {
-                    error!("inject_barrier failed: {}", err);
-                    Err(err)
                 }
             }
+            .inspect_err(|_| self.metrics.recovery_failure_cnt.inc())
         })
         .await
         .expect("Retry until recovery success.");
+        recovery_timer.observe_duration();
         debug!("recovery success");
 
         new_epoch
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::config::StreamingConfig;
+use risingwave_common::config::{ServerConfig, StreamingConfig};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct MetaNodeConfig {
+    #[serde(default)]
+    pub server: ServerConfig,
+
     #[serde(default)]
     pub meta: MetaConfig,
     // Below for streaming.
@@ -154,6 +154,7 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
         let meta_config: MetaNodeConfig = load_config(&opts.config_path).unwrap();
         tracing::info!("Starting meta node with config {:?}", meta_config);
         tracing::info!("Starting meta node with options {:?}", opts);
+        risingwave_rpc_client::init_client_tls(&meta_config.server).unwrap();
         let meta_addr = opts.host.unwrap_or_else(|| opts.listen_addr.clone());
         let listen_addr = opts.listen_addr.parse().unwrap();
         let dashboard_addr = opts.dashboard_host.map(|x| x.parse().unwrap());
@@ -210,6 +211,7 @@ pub fn start(opts: MetaNodeOpts) -> Pin<Box<dyn Future<Output = ()> + Send>> {
                 periodic_compaction_interval_sec: meta_config.meta.periodic_compaction_interval_sec,
                 node_num_monitor_interval_sec: opts.node_num_monitor_interval_sec,
                 prometheus_endpoint: opts.prometheus_endpoint,
+                server: meta_config.server,
             },
         )
         .await
@@ -31,7 +31,7 @@ use risingwave_common::catalog::{
 };
 use risingwave_common::{bail, ensure};
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
-use risingwave_pb::catalog::{Database, Index, Schema, Sink, Source, Table, View};
+use risingwave_pb::catalog::{Database, Index, Schema, Sink, Source, Table, TableStatistics, View};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::user::grant_privilege::{ActionWithGrantOption, Object};
 use risingwave_pb::user::update_user_request::UpdateField;
@@ -1329,6 +1329,28 @@ where
         }
     }
 
+    /// Replaces the statistics of a table with the ones collected by `ANALYZE`.
+    pub async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: TableStatistics,
+    ) -> MetaResult<NotificationVersion> {
+        let core = &mut self.core.lock().await.database;
+        let mut tables = BTreeMapTransaction::new(&mut core.tables);
+        let mut table = tables
+            .get(&table_id)
+            .cloned()
+            .ok_or_else(|| MetaError::catalog_id_not_found("table", table_id))?;
+        table.statistics = Some(statistics);
+        tables.insert(table_id, table.clone());
+        commit_meta!(self, tables)?;
+
+        let version = self
+            .notify_frontend(Operation::Update, Info::Table(table))
+            .await;
+        Ok(version)
+    }
+
     pub async fn list_tables(&self) -> Vec<Table> {
         self.core.lock().await.database.list_tables()
     }
@@ -19,6 +19,7 @@ use std::time::{Duration, SystemTime};
 
 use itertools::Itertools;
 use risingwave_common::hash::ParallelUnitId;
+use risingwave_common::util::worker_util::ROW_ID_GENERATOR_SLOT_COUNT;
 use risingwave_pb::common::worker_node::State;
 use risingwave_pb::common::{HostAddress, ParallelUnit, WorkerNode, WorkerType};
 use risingwave_pb::meta::heartbeat_request;
@@ -96,6 +97,8 @@ where
         let join_handle = tokio::spawn(async move {
             let mut monitor_interval = tokio::time::interval(interval);
             monitor_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The workers with an actor count in the metrics, to remove it once they are gone.
+            let mut reported_workers = HashSet::new();
             loop {
                 tokio::select! {
                     // Wait for interval
@@ -114,6 +117,26 @@ where
                         .with_label_values(&[(worker_type.as_str_name())])
                         .set(worker_num as i64);
                 }
+
+                let running_actor_count =
+                    cluster_manager.core.read().await.list_running_actor_count();
+                let workers: HashSet<_> = running_actor_count
+                    .iter()
+                    .map(|(worker_id, _)| *worker_id)
+                    .collect();
+                for worker_id in reported_workers.difference(&workers) {
+                    meta_metrics
+                        .worker_actor_count
+                        .remove_label_values(&[&worker_id.to_string()])
+                        .ok();
+                }
+                for (worker_id, actor_count) in running_actor_count {
+                    meta_metrics
+                        .worker_actor_count
+                        .with_label_values(&[&worker_id.to_string()])
+                        .set(actor_count as i64);
+                }
+                reported_workers = workers;
             }
         });
 
@@ -122,6 +145,7 @@ where
 
     /// A worker node will immediately register itself to meta when it bootstraps.
     /// The meta will assign it with a unique ID and set its state as `Starting`.
+    /// A compute node also gets a row id generator slot that no other live compute node holds.
     /// When the worker node is fully ready to serve, it will request meta again
     /// (via `activate_worker_node`) to set its state to `Running`.
     pub async fn add_worker_node(
@@ -135,6 +159,13 @@ where
             // TODO(zehua): update parallelism when the worker exists.
             Some(worker) => Ok(worker.to_protobuf()),
             None => {
+                // Pick the slot before allocating any ids, as all the slots may be taken.
+                let row_id_generator_slot = if r#type == WorkerType::ComputeNode {
+                    core.free_row_id_generator_slot()?
+                } else {
+                    0
+                };
+
                 // Generate worker id.
                 let worker_id = self
                     .env
@@ -154,6 +185,7 @@ where
                     host: Some(host_address.clone()),
                     state: State::Starting as i32,
                     parallel_units,
+                    row_id_generator_slot,
                 };
 
                 let worker = Worker::from_protobuf(worker_node.clone());
@@ -414,6 +446,26 @@ impl ClusterManagerCore {
             .map(|(_, worker)| worker.clone())
     }
 
+    /// Returns the smallest row id generator slot not held by any compute node, so the slots of
+    /// deleted workers are reused.
+    fn free_row_id_generator_slot(&self) -> MetaResult<u32> {
+        let taken: HashSet<_> = self
+            .workers
+            .values()
+            .filter(|worker| worker.worker_type() == WorkerType::ComputeNode)
+            .map(|worker| worker.worker_node.row_id_generator_slot)
+            .collect();
+        (0..ROW_ID_GENERATOR_SLOT_COUNT)
+            .find(|slot| !taken.contains(slot))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "all {} row id generator slots are taken by compute nodes",
+                    ROW_ID_GENERATOR_SLOT_COUNT
+                )
+                .into()
+            })
+    }
+
     fn add_worker_node(&mut self, worker: Worker) {
         self.parallel_units
             .extend(worker.worker_node.parallel_units.clone());
@@ -490,6 +542,18 @@ impl ClusterManagerCore {
         ret
     }
 
+    /// Lists the number of running actors last reported by each worker via heartbeat.
+    fn list_running_actor_count(&self) -> Vec<(WorkerId, u32)> {
+        self.workers
+            .values()
+            .filter_map(|worker| {
+                worker
+                    .running_actor_count()
+                    .map(|count| (worker.worker_id(), count))
+            })
+            .collect()
+    }
+
     fn get_active_parallel_unit_count(&self) -> usize {
         self.list_active_parallel_units().len()
     }
@@ -557,6 +621,56 @@ mod tests {
         }
         assert_cluster_manager(&cluster_manager, fake_parallelism).await;
 
+        // The slots of the deleted workers are reused.
+        let worker_node = cluster_manager
+            .add_worker_node(
+                WorkerType::ComputeNode,
+                HostAddress {
+                    host: "localhost".to_string(),
+                    port: 6000,
+                },
+                fake_parallelism,
+            )
+            .await
+            .unwrap();
+        assert_eq!(worker_node.row_id_generator_slot, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_row_id_generator_slot() -> MetaResult<()> {
+        let env = MetaSrvEnv::for_test().await;
+        let cluster_manager = ClusterManager::new(env, Duration::new(0, 0)).await?;
+        let fake_host_address = |port| HostAddress {
+            host: "localhost".to_string(),
+            port,
+        };
+
+        for slot in 0..ROW_ID_GENERATOR_SLOT_COUNT {
+            let worker_node = cluster_manager
+                .add_worker_node(WorkerType::ComputeNode, fake_host_address(slot as i32), 1)
+                .await?;
+            assert_eq!(worker_node.row_id_generator_slot, slot);
+        }
+        // Frontends don't take slots.
+        cluster_manager
+            .add_worker_node(WorkerType::Frontend, fake_host_address(-1), 0)
+            .await?;
+        // No slot is left for another compute node.
+        assert!(cluster_manager
+            .add_worker_node(WorkerType::ComputeNode, fake_host_address(-2), 1)
+            .await
+            .is_err());
+
+        cluster_manager
+            .delete_worker_node(fake_host_address(42))
+            .await?;
+        let worker_node = cluster_manager
+            .add_worker_node(WorkerType::ComputeNode, fake_host_address(-2), 1)
+            .await?;
+        assert_eq!(worker_node.row_id_generator_slot, 42);
+
         Ok(())
     }
 
@@ -18,6 +18,7 @@ use std::time::Duration;
 
 #[cfg(any(test, feature = "test"))]
 use prost::Message;
+use risingwave_common::config::ServerConfig;
 use risingwave_pb::meta::MetaLeaderInfo;
 #[cfg(any(test, feature = "test"))]
 use risingwave_pb::meta::MetaLeaseInfo;
@@ -98,6 +99,9 @@ pub struct MetaOpts {
 
     /// The prometheus endpoint for dashboard service.
     pub prometheus_endpoint: Option<String>,
+
+    /// The config of the gRPC server, including the TLS of the channels between nodes.
+    pub server: ServerConfig,
 }
 
 impl MetaOpts {
@@ -118,6 +122,7 @@ impl MetaOpts {
             periodic_compaction_interval_sec: 60,
             node_num_monitor_interval_sec: 10,
             prometheus_endpoint: None,
+            server: ServerConfig::default(),
         }
     }
 }
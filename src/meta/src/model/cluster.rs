@@ -42,6 +42,8 @@ pub struct Worker {
     info_version_id: u64,
     // GC watermark.
     hummock_gc_watermark: Option<HummockSstableId>,
+    // Number of running actors, only reported by compute nodes.
+    running_actor_count: Option<u32>,
 }
 
 impl MetadataModel for Worker {
@@ -62,6 +64,7 @@ impl MetadataModel for Worker {
             expire_at: INVALID_EXPIRE_AT,
             info_version_id: 0,
             hummock_gc_watermark: Default::default(),
+            running_actor_count: Default::default(),
         }
     }
 
@@ -102,6 +105,9 @@ impl Worker {
                 Info::HummockGcWatermark(info) => {
                     self.hummock_gc_watermark = Some(info);
                 }
+                Info::RunningActorCount(info) => {
+                    self.running_actor_count = Some(info);
+                }
             }
         }
     }
@@ -110,6 +116,10 @@ impl Worker {
         self.hummock_gc_watermark
     }
 
+    pub fn running_actor_count(&self) -> Option<u32> {
+        self.running_actor_count
+    }
+
     pub fn info_version_id(&self) -> u64 {
         self.info_version_id
     }
@@ -17,8 +17,9 @@ use std::sync::atomic::AtomicU64;
 use prometheus::{
     exponential_buckets, histogram_opts, register_histogram_vec_with_registry,
     register_histogram_with_registry, register_int_counter_vec_with_registry,
-    register_int_gauge_vec_with_registry, register_int_gauge_with_registry, Histogram,
-    HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Registry,
+    register_int_counter_with_registry, register_int_gauge_vec_with_registry,
+    register_int_gauge_with_registry, Histogram, HistogramVec, IntCounter, IntCounterVec, IntGauge,
+    IntGaugeVec, Registry,
 };
 
 pub struct MetaMetrics {
@@ -75,6 +76,13 @@ pub struct MetaMetrics {
 
     /// The number of workers in the cluster.
     pub worker_num: IntGaugeVec,
+    /// The number of running actors on each compute node, reported via heartbeat.
+    pub worker_actor_count: IntGaugeVec,
+
+    /// Latency of a whole recovery, from start to the completion of the initial barrier
+    pub recovery_latency: Histogram,
+    /// The number of failed recovery attempts
+    pub recovery_failure_cnt: IntCounter,
 }
 
 impl MetaMetrics {
@@ -230,6 +238,28 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let worker_actor_count = register_int_gauge_vec_with_registry!(
+            "worker_actor_count",
+            "number of running actors on each compute node",
+            &["worker_id"],
+            registry,
+        )
+        .unwrap();
+
+        let opts = histogram_opts!(
+            "recovery_latency",
+            "latency of the whole recovery process",
+            exponential_buckets(0.1, 1.5, 20).unwrap() // max 221s
+        );
+        let recovery_latency = register_histogram_with_registry!(opts, registry).unwrap();
+
+        let recovery_failure_cnt = register_int_counter_with_registry!(
+            "recovery_failure_cnt",
+            "number of failed recovery attempts",
+            registry
+        )
+        .unwrap();
+
         Self {
             registry,
 
@@ -257,6 +287,10 @@ impl MetaMetrics {
             time_after_last_observation: AtomicU64::new(0),
 
             worker_num,
+            worker_actor_count,
+
+            recovery_latency,
+            recovery_failure_cnt,
         }
     }
 
@@ -31,6 +31,7 @@ use risingwave_pb::meta::scale_service_server::ScaleServiceServer;
 use risingwave_pb::meta::stream_manager_service_server::StreamManagerServiceServer;
 use risingwave_pb::meta::{MetaLeaderInfo, MetaLeaseInfo};
 use risingwave_pb::user::user_service_server::UserServiceServer;
+use risingwave_rpc_client::grpc_server_builder;
 use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
 
@@ -308,6 +309,7 @@ pub async fn rpc_serve_with_store<S: MetaStore>(
     )
     .await?;
     let prometheus_endpoint = opts.prometheus_endpoint.clone();
+    let server_builder = grpc_server_builder(&opts.server).map_err(anyhow::Error::from)?;
     let env = MetaSrvEnv::<S>::new(opts, meta_store.clone(), info).await;
     let fragment_manager = Arc::new(FragmentManager::new(env.clone()).await.unwrap());
     let meta_metrics = Arc::new(MetaMetrics::new());
@@ -516,7 +518,7 @@ pub async fn rpc_serve_with_store<S: MetaStore>(
 
     // Start services.
     tokio::spawn(async move {
-        tonic::transport::Server::builder()
+        server_builder
             .layer(MetricsMiddlewareLayer::new(meta_metrics.clone()))
             .add_service(HeartbeatServiceServer::new(heartbeat_srv))
             .add_service(ClusterServiceServer::new(cluster_srv))
@@ -405,6 +405,21 @@ where
         }))
     }
 
+    async fn update_table_statistics(
+        &self,
+        request: Request<UpdateTableStatisticsRequest>,
+    ) -> Result<Response<UpdateTableStatisticsResponse>, Status> {
+        let req = request.into_inner();
+        let version = self
+            .catalog_manager
+            .update_table_statistics(req.table_id, req.get_statistics()?.clone())
+            .await?;
+        Ok(Response::new(UpdateTableStatisticsResponse {
+            status: None,
+            version,
+        }))
+    }
+
     async fn risectl_list_state_tables(
         &self,
         _request: Request<RisectlListStateTablesRequest>,
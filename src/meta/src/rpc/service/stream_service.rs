@@ -20,9 +20,11 @@ use risingwave_pb::meta::list_table_fragments_response::{
 };
 use risingwave_pb::meta::stream_manager_service_server::StreamManagerService;
 use risingwave_pb::meta::*;
+use risingwave_pb::stream_plan::barrier::Mutation;
+use risingwave_pb::stream_plan::SourceRateLimitMutation;
 use tonic::{Request, Response, Status};
 
-use crate::barrier::BarrierScheduler;
+use crate::barrier::{BarrierScheduler, Command};
 use crate::manager::{FragmentManagerRef, MetaSrvEnv};
 use crate::storage::MetaStore;
 
@@ -112,4 +114,22 @@ where
             table_fragments: info,
         }))
     }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn set_source_rate_limit(
+        &self,
+        request: Request<SetSourceRateLimitRequest>,
+    ) -> TonicResponse<SetSourceRateLimitResponse> {
+        let req = request.into_inner();
+        let rate_limit = req.get_rate_limit()?.clone();
+
+        // The source executors of the source pick up the new limit on the barrier.
+        let mutation = Mutation::SourceRateLimit(SourceRateLimitMutation {
+            source_rate_limits: HashMap::from([(req.source_id, rate_limit)]),
+        });
+        self.barrier_scheduler
+            .run_command(Command::Plain(Some(mutation)))
+            .await?;
+        Ok(Response::new(SetSourceRateLimitResponse {}))
+    }
 }
@@ -15,7 +15,7 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
-use risingwave_common::buffer::{Bitmap, BitmapBuilder};
+use risingwave_common::buffer::Bitmap;
 use risingwave_common::hash::{ParallelUnitId, VirtualNode, VnodeMapping};
 use risingwave_common::util::compress::compress_data;
 use risingwave_pb::common::{ParallelUnit, ParallelUnitMapping};
@@ -48,25 +48,6 @@ pub(crate) fn build_vnode_mapping(parallel_units: &[ParallelUnit]) -> VnodeMappi
     vnode_mapping
 }
 
-pub(crate) fn vnode_mapping_to_bitmaps(
-    vnode_mapping: VnodeMapping,
-) -> HashMap<ParallelUnitId, Bitmap> {
-    let mut vnode_bitmaps = HashMap::new();
-    vnode_mapping
-        .iter()
-        .enumerate()
-        .for_each(|(vnode, parallel_unit)| {
-            vnode_bitmaps
-                .entry(*parallel_unit)
-                .or_insert_with(|| BitmapBuilder::zeroed(VirtualNode::COUNT))
-                .set(vnode, true);
-        });
-    vnode_bitmaps
-        .into_iter()
-        .map(|(u, b)| (u, b.finish()))
-        .collect()
-}
-
 pub(crate) fn actor_mapping_from_bitmaps(bitmaps: &HashMap<ActorId, Bitmap>) -> ActorMapping {
     let mut raw = vec![0 as ActorId; VirtualNode::COUNT];
 
@@ -506,6 +506,14 @@ where
                     );
                 }
             }
+            if current_parallel_units.len() + added_parallel_units.len()
+                <= removed_parallel_units.len()
+            {
+                bail!(
+                    "removing all parallel units of fragment {} is not allowed",
+                    fragment_id
+                );
+            }
 
             match fragment.distribution_type() {
                 FragmentDistributionType::Hash => {}
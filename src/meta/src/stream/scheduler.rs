@@ -19,7 +19,7 @@ use anyhow::{anyhow, Context};
 use itertools::Itertools;
 use rand::prelude::SliceRandom;
 use risingwave_common::bail;
-use risingwave_common::hash::VnodeMapping;
+use risingwave_common::hash::{vnode_mapping_to_bitmaps, VnodeMapping};
 use risingwave_common::util::compress::compress_data;
 use risingwave_pb::common::{ActorInfo, ParallelUnit, ParallelUnitMapping, WorkerNode};
 use risingwave_pb::meta::table_fragments::fragment::FragmentDistributionType;
@@ -27,7 +27,7 @@ use risingwave_pb::meta::table_fragments::Fragment;
 
 use crate::manager::{WorkerId, WorkerLocations};
 use crate::model::ActorId;
-use crate::stream::{build_vnode_mapping, vnode_mapping_to_bitmaps};
+use crate::stream::build_vnode_mapping;
 use crate::MetaResult;
 
 /// [`Scheduler`] defines schedule logic for mv actors.
@@ -218,7 +218,7 @@ impl Scheduler {
 
             // Build vnode mapping according to the parallel units.
             let vnode_mapping = self.set_fragment_vnode_mapping(fragment, &parallel_units)?;
-            let vnode_bitmaps = vnode_mapping_to_bitmaps(vnode_mapping);
+            let vnode_bitmaps = vnode_mapping_to_bitmaps(&vnode_mapping);
 
             // Record actor locations and set vnodes into the actors.
             for (actor, parallel_unit) in fragment.actors.iter_mut().zip_eq(parallel_units) {
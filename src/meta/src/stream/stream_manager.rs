@@ -876,6 +876,13 @@ mod tests {
         ) -> std::result::Result<Response<WaitEpochCommitResponse>, Status> {
             unimplemented!()
         }
+
+        async fn get_actor_status(
+            &self,
+            _request: Request<GetActorStatusRequest>,
+        ) -> std::result::Result<Response<GetActorStatusResponse>, Status> {
+            unimplemented!()
+        }
     }
 
     struct MockServices {
@@ -19,15 +19,13 @@ mod tests {
     use itertools::Itertools;
     use maplit::btreeset;
     use risingwave_common::buffer::Bitmap;
-    use risingwave_common::hash::{ParallelUnitId, VirtualNode};
+    use risingwave_common::hash::{vnode_mapping_to_bitmaps, ParallelUnitId, VirtualNode};
     use risingwave_common::util::compress::decompress_data;
     use risingwave_pb::common::ParallelUnit;
     use risingwave_pb::stream_plan::{ActorMapping, StreamActor};
 
     use crate::model::ActorId;
-    use crate::stream::mapping::{
-        actor_mapping_from_bitmaps, build_vnode_mapping, vnode_mapping_to_bitmaps,
-    };
+    use crate::stream::mapping::{actor_mapping_from_bitmaps, build_vnode_mapping};
     use crate::stream::scale::rebalance_actor_vnode;
     use crate::stream::{
         actor_mapping_to_parallel_unit_mapping, parallel_unit_mapping_to_actor_mapping,
@@ -49,7 +47,7 @@ mod tests {
     fn build_fake_actors(info: &[(ActorId, ParallelUnitId)]) -> Vec<StreamActor> {
         let parallel_units = generate_parallel_units(info);
 
-        let vnode_bitmaps = vnode_mapping_to_bitmaps(build_vnode_mapping(&parallel_units));
+        let vnode_bitmaps = vnode_mapping_to_bitmaps(&build_vnode_mapping(&parallel_units));
 
         info.iter()
             .map(|(actor_id, parallel_unit_id)| StreamActor {
@@ -140,7 +138,7 @@ mod tests {
                 .map(|i| (i as ActorId, i as ParallelUnitId))
                 .collect_vec();
             let parallel_units = generate_parallel_units(&info);
-            let bitmaps = vnode_mapping_to_bitmaps(build_vnode_mapping(&parallel_units));
+            let bitmaps = vnode_mapping_to_bitmaps(&build_vnode_mapping(&parallel_units));
             check_bitmaps(&bitmaps);
         }
     }
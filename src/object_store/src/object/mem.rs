@@ -138,7 +138,8 @@ impl ObjectStore for InMemObjectStore {
                     obj,
                     BlockLocation {
                         offset: pos,
-                        size: obj.len() - pos,
+                        // Out-of-range positions are rejected by `find_block`.
+                        size: obj.len().saturating_sub(pos),
                     },
                 )
             })
@@ -261,6 +262,7 @@ fn get_obj_meta(path: &str, obj: &Bytes) -> ObjectResult<ObjectMetadata> {
 mod tests {
     use bytes::Bytes;
     use itertools::enumerate;
+    use tokio::io::AsyncReadExt;
 
     use super::*;
 
@@ -323,6 +325,20 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_streaming_read() {
+        let store = InMemObjectStore::new();
+        store.upload("/abc", Bytes::from("123456")).await.unwrap();
+
+        let mut buf = String::new();
+        let mut reader = store.streaming_read("/abc", Some(4)).await.unwrap();
+        reader.read_to_string(&mut buf).await.unwrap();
+        assert_eq!(buf, "56");
+
+        // Start position out of range.
+        assert!(store.streaming_read("/abc", Some(7)).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_metadata() {
         let block = Bytes::from("123456");
@@ -24,7 +24,8 @@ use risingwave_pb::compute::config_service_client::ConfigServiceClient;
 use risingwave_pb::compute::{ShowConfigRequest, ShowConfigResponse};
 use risingwave_pb::monitor_service::monitor_service_client::MonitorServiceClient;
 use risingwave_pb::monitor_service::{
-    ProfilingRequest, ProfilingResponse, StackTraceRequest, StackTraceResponse,
+    MemoryUsageRequest, MemoryUsageResponse, ProfilingRequest, ProfilingResponse,
+    StackTraceRequest, StackTraceResponse,
 };
 use risingwave_pb::task_service::exchange_service_client::ExchangeServiceClient;
 use risingwave_pb::task_service::task_service_client::TaskServiceClient;
@@ -34,11 +35,11 @@ use risingwave_pb::task_service::{
 };
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
-use tonic::transport::{Channel, Endpoint};
+use tonic::transport::Channel;
 use tonic::Streaming;
 
 use crate::error::Result;
-use crate::{RpcClient, RpcClientPool};
+use crate::{tls, RpcClient, RpcClientPool};
 
 #[derive(Clone)]
 pub struct ComputeClient {
@@ -51,7 +52,7 @@ pub struct ComputeClient {
 
 impl ComputeClient {
     pub async fn new(addr: HostAddr) -> Result<Self> {
-        let channel = Endpoint::from_shared(format!("http://{}", &addr))?
+        let channel = tls::endpoint(&addr)?
             .initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE)
             .initial_stream_window_size(STREAM_WINDOW_SIZE)
             .tcp_nodelay(true)
@@ -81,6 +82,27 @@ impl ComputeClient {
             .to_owned()
             .get_data(GetDataRequest {
                 task_output_id: Some(output_id),
+                ..Default::default()
+            })
+            .await?
+            .into_inner())
+    }
+
+    /// Fetches at most `max_chunks` chunks of the task output starting from `cursor`, i.e. the
+    /// number of chunks fetched before.
+    pub async fn get_data_from_cursor(
+        &self,
+        output_id: TaskOutputId,
+        cursor: u64,
+        max_chunks: u32,
+    ) -> Result<Streaming<GetDataResponse>> {
+        Ok(self
+            .exchange_client
+            .to_owned()
+            .get_data(GetDataRequest {
+                task_output_id: Some(output_id),
+                cursor,
+                max_chunks,
             })
             .await?
             .into_inner())
@@ -139,6 +161,7 @@ impl ComputeClient {
         task_id: TaskId,
         plan: PlanFragment,
         epoch: u64,
+        resource_group: String,
     ) -> Result<Streaming<TaskInfoResponse>> {
         Ok(self
             .task_client
@@ -147,6 +170,7 @@ impl ComputeClient {
                 task_id: Some(task_id),
                 plan: Some(plan),
                 epoch,
+                resource_group,
             })
             .await?
             .into_inner())
@@ -183,6 +207,15 @@ impl ComputeClient {
             .into_inner())
     }
 
+    pub async fn memory_usage(&self) -> Result<MemoryUsageResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .memory_usage(MemoryUsageRequest {})
+            .await?
+            .into_inner())
+    }
+
     pub async fn show_config(&self) -> Result<ShowConfigResponse> {
         Ok(self
             .config_client
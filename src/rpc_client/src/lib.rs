@@ -21,6 +21,7 @@
 #![feature(type_alias_impl_trait)]
 #![feature(associated_type_defaults)]
 #![feature(generators)]
+#![feature(once_cell)]
 
 #[cfg(madsim)]
 use std::collections::HashMap;
@@ -48,12 +49,14 @@ mod hummock_meta_client;
 mod meta_client;
 // mod sink_client;
 mod stream_client;
+mod tls;
 
 pub use cdc_client::CdcClient;
 pub use compute_client::{ComputeClient, ComputeClientPool, ComputeClientPoolRef};
 pub use hummock_meta_client::HummockMetaClient;
 pub use meta_client::MetaClient;
 pub use stream_client::{StreamClient, StreamClientPool, StreamClientPoolRef};
+pub use tls::{grpc_server_builder, init_client_tls};
 
 #[async_trait]
 pub trait RpcClient: Send + Sync + 'static + Clone {
@@ -28,7 +28,8 @@ use risingwave_hummock_sdk::{
 };
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Index as ProstIndex, Schema as ProstSchema, Sink as ProstSink,
-    Source as ProstSource, Table as ProstTable, View as ProstView,
+    Source as ProstSource, Table as ProstTable, TableStatistics as ProstTableStatistics,
+    View as ProstView,
 };
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::ddl_service::ddl_service_client::DdlServiceClient;
@@ -45,19 +46,19 @@ use risingwave_pb::meta::reschedule_request::Reschedule as ProstReschedule;
 use risingwave_pb::meta::scale_service_client::ScaleServiceClient;
 use risingwave_pb::meta::stream_manager_service_client::StreamManagerServiceClient;
 use risingwave_pb::meta::*;
-use risingwave_pb::stream_plan::StreamFragmentGraph;
+use risingwave_pb::stream_plan::{SourceRateLimit, StreamFragmentGraph};
 use risingwave_pb::user::update_user_request::UpdateField;
 use risingwave_pb::user::user_service_client::UserServiceClient;
 use risingwave_pb::user::*;
 use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
 use tokio_retry::strategy::{jitter, ExponentialBackoff};
-use tonic::transport::{Channel, Endpoint};
+use tonic::transport::Channel;
 use tonic::Streaming;
 
 use crate::error::Result;
 use crate::hummock_meta_client::HummockMetaClient;
-use crate::{rpc_client_method_impl, ExtraInfoSourceRef};
+use crate::{rpc_client_method_impl, tls, ExtraInfoSourceRef};
 
 type DatabaseId = u32;
 type SchemaId = u32;
@@ -66,6 +67,7 @@ type SchemaId = u32;
 #[derive(Clone, Debug)]
 pub struct MetaClient {
     worker_id: u32,
+    row_id_generator_slot: u32,
     worker_type: WorkerType,
     host_addr: HostAddr,
     inner: GrpcMetaClient,
@@ -76,6 +78,10 @@ impl MetaClient {
         self.worker_id
     }
 
+    pub fn row_id_generator_slot(&self) -> u32 {
+        self.row_id_generator_slot
+    }
+
     pub fn host_addr(&self) -> &HostAddr {
         &self.host_addr
     }
@@ -114,6 +120,7 @@ impl MetaClient {
         let worker_node = resp.node.expect("AddWorkerNodeResponse::node is empty");
         Ok(Self {
             worker_id: worker_node.id,
+            row_id_generator_slot: worker_node.row_id_generator_slot,
             worker_type,
             host_addr: addr.clone(),
             inner: grpc_meta_client,
@@ -269,6 +276,19 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn update_table_statistics(
+        &self,
+        table_id: u32,
+        statistics: ProstTableStatistics,
+    ) -> Result<CatalogVersion> {
+        let request = UpdateTableStatisticsRequest {
+            table_id,
+            statistics: Some(statistics),
+        };
+        let resp = self.inner.update_table_statistics(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn drop_source(&self, source_id: u32) -> Result<CatalogVersion> {
         let request = DropSourceRequest { source_id };
         let resp = self.inner.drop_source(request).await?;
@@ -463,6 +483,19 @@ impl MetaClient {
         Ok(resp.table_fragments)
     }
 
+    pub async fn set_source_rate_limit(
+        &self,
+        source_id: u32,
+        rate_limit: SourceRateLimit,
+    ) -> Result<()> {
+        let request = SetSourceRateLimitRequest {
+            source_id,
+            rate_limit: Some(rate_limit),
+        };
+        let _resp = self.inner.set_source_rate_limit(request).await?;
+        Ok(())
+    }
+
     pub async fn pause(&self) -> Result<()> {
         let request = PauseRequest {};
         let _resp = self.inner.pause(request).await?;
@@ -817,8 +850,13 @@ impl GrpcMetaClient {
 
     /// Connect to the meta server `addr`.
     pub async fn new(addr: &str) -> Result<Self> {
-        let endpoint = Endpoint::from_shared(addr.to_string())?
-            .initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE);
+        // The scheme depends on whether TLS is enabled.
+        let host_addr = ["http://", "https://"]
+            .iter()
+            .find_map(|scheme| addr.strip_prefix(scheme))
+            .unwrap_or(addr);
+        let endpoint =
+            tls::endpoint(host_addr)?.initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE);
         let retry_strategy = ExponentialBackoff::from_millis(Self::CONN_RETRY_BASE_INTERVAL_MS)
             .max_delay(Duration::from_millis(Self::CONN_RETRY_MAX_INTERVAL_MS))
             .map(jitter);
@@ -873,6 +911,7 @@ macro_rules! for_all_meta_rpc {
             ,{ heartbeat_client, heartbeat, HeartbeatRequest, HeartbeatResponse }
             ,{ stream_client, flush, FlushRequest, FlushResponse }
             ,{ stream_client, list_table_fragments, ListTableFragmentsRequest, ListTableFragmentsResponse }
+            ,{ stream_client, set_source_rate_limit, SetSourceRateLimitRequest, SetSourceRateLimitResponse }
             ,{ ddl_client, create_materialized_source, CreateMaterializedSourceRequest, CreateMaterializedSourceResponse }
             ,{ ddl_client, create_materialized_view, CreateMaterializedViewRequest, CreateMaterializedViewResponse }
             ,{ ddl_client, create_view, CreateViewRequest, CreateViewResponse }
@@ -889,6 +928,7 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, drop_database, DropDatabaseRequest, DropDatabaseResponse }
             ,{ ddl_client, drop_schema, DropSchemaRequest, DropSchemaResponse }
             ,{ ddl_client, drop_index, DropIndexRequest, DropIndexResponse }
+            ,{ ddl_client, update_table_statistics, UpdateTableStatisticsRequest, UpdateTableStatisticsResponse }
             ,{ ddl_client, risectl_list_state_tables, RisectlListStateTablesRequest, RisectlListStateTablesResponse }
             ,{ hummock_client, unpin_version_before, UnpinVersionBeforeRequest, UnpinVersionBeforeResponse }
             ,{ hummock_client, get_current_version, GetCurrentVersionRequest, GetCurrentVersionResponse }
@@ -0,0 +1,143 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TLS of the gRPC channels between nodes. The clients are created on demand all over the place,
+//! so their TLS config is set once for the whole process at startup.
+
+use std::fmt::Display;
+#[cfg(not(madsim))]
+use std::sync::OnceLock;
+
+#[cfg(not(madsim))]
+use risingwave_common::config::GrpcTlsConfig;
+use risingwave_common::config::ServerConfig;
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::Result;
+#[cfg(not(madsim))]
+use tonic::transport::{Certificate, ClientTlsConfig, Identity, ServerTlsConfig};
+use tonic::transport::{Endpoint, Server};
+
+#[cfg(not(madsim))]
+static CLIENT_TLS_CONFIG: OnceLock<ClientTlsConfig> = OnceLock::new();
+
+/// Enables TLS for all the gRPC channels created afterwards in this process, if configured in
+/// `config`.
+#[cfg(not(madsim))]
+pub fn init_client_tls(config: &ServerConfig) -> Result<()> {
+    if let Some(tls) = config.grpc_tls()? {
+        let mut client_config = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(read_pem(&tls.ca_cert)?))
+            .identity(identity(tls)?);
+        if let Some(domain_name) = &tls.domain_name {
+            client_config = client_config.domain_name(domain_name);
+        }
+        // The nodes running in the same process, e.g., in the playground, share the config of the
+        // first one.
+        let _ = CLIENT_TLS_CONFIG.set(client_config);
+    }
+    Ok(())
+}
+
+#[cfg(madsim)]
+pub fn init_client_tls(config: &ServerConfig) -> Result<()> {
+    if config.grpc_tls()?.is_some() {
+        return Err(InternalError("TLS is not supported in simulation".to_owned()).into());
+    }
+    Ok(())
+}
+
+/// Creates the builder of a gRPC server, which only accepts TLS connections from the other nodes
+/// if configured in `config`.
+#[cfg(not(madsim))]
+pub fn grpc_server_builder(config: &ServerConfig) -> Result<Server> {
+    let builder = Server::builder();
+    match config.grpc_tls()? {
+        Some(tls) => {
+            let server_config = ServerTlsConfig::new()
+                .identity(identity(tls)?)
+                .client_ca_root(Certificate::from_pem(read_pem(&tls.ca_cert)?));
+            builder
+                .tls_config(server_config)
+                .map_err(|e| InternalError(format!("invalid TLS config: {}", e)).into())
+        }
+        None => Ok(builder),
+    }
+}
+
+#[cfg(madsim)]
+pub fn grpc_server_builder(config: &ServerConfig) -> Result<Server> {
+    if config.grpc_tls()?.is_some() {
+        return Err(InternalError("TLS is not supported in simulation".to_owned()).into());
+    }
+    Ok(Server::builder())
+}
+
+/// Creates the endpoint of the gRPC server at `addr`, which is in the form of `host:port`.
+pub(crate) fn endpoint(addr: impl Display) -> crate::error::Result<Endpoint> {
+    #[cfg(not(madsim))]
+    if let Some(client_config) = CLIENT_TLS_CONFIG.get() {
+        return Ok(Endpoint::from_shared(format!("https://{}", addr))?
+            .tls_config(client_config.clone())?);
+    }
+    Ok(Endpoint::from_shared(format!("http://{}", addr))?)
+}
+
+#[cfg(not(madsim))]
+fn identity(tls: &GrpcTlsConfig) -> Result<Identity> {
+    Ok(Identity::from_pem(
+        read_pem(&tls.cert)?,
+        read_pem(&tls.key)?,
+    ))
+}
+
+#[cfg(not(madsim))]
+fn read_pem(path: &str) -> Result<Vec<u8>> {
+    std::fs::read(path)
+        .map_err(|e| InternalError(format!("failed to read `{}`: {}", path, e)).into())
+}
+
+#[cfg(all(test, not(madsim)))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_require_tls() {
+        let config = ServerConfig {
+            require_tls: true,
+            ..Default::default()
+        };
+        assert!(init_client_tls(&config).is_err());
+        assert!(grpc_server_builder(&config).is_err());
+
+        let config = ServerConfig {
+            tls: Some(GrpcTlsConfig {
+                cert: "not_exist.crt".to_owned(),
+                key: "not_exist.key".to_owned(),
+                ca_cert: "not_exist.crt".to_owned(),
+                domain_name: None,
+            }),
+            require_tls: true,
+            ..Default::default()
+        };
+        assert!(grpc_server_builder(&config).is_err());
+
+        let config = ServerConfig::default();
+        assert!(grpc_server_builder(&config).is_ok());
+        assert!(endpoint("127.0.0.1:5688")
+            .unwrap()
+            .uri()
+            .to_string()
+            .starts_with("http://"));
+    }
+}
@@ -24,6 +24,7 @@ use risingwave_common::catalog::{ColumnDesc, ColumnId, TableId};
 use risingwave_common::error::ErrorCode::{ConnectorError, ProtocolError};
 use risingwave_common::error::{internal_error, Result, RwError, ToRwResult};
 use risingwave_common::util::select_all;
+use risingwave_connector::source::dead_letter::{DeadLetterKey, DeadLetterQueue, ParseErrorPolicy};
 use risingwave_connector::source::{
     Column, ConnectorProperties, ConnectorState, SourceMessage, SplitId, SplitMetaData,
     SplitReaderImpl,
@@ -80,6 +81,7 @@ struct InnerConnectorSourceReader {
 pub struct ConnectorSourceReader {
     parser: Arc<SourceParserImpl>,
     columns: Vec<SourceColumnDesc>,
+    parse_error_handler: ParseErrorHandler,
 
     // merge all streams of inner reader into one
     // TODO: make this static dispatch instead of box
@@ -146,6 +148,55 @@ impl InnerConnectorSourceReader {
     }
 }
 
+/// Handles the payloads that fail to be parsed according to the [`ParseErrorPolicy`] of the source.
+struct ParseErrorHandler {
+    policy: ParseErrorPolicy,
+    /// Set if the policy is [`ParseErrorPolicy::DeadLetter`].
+    dead_letter_queue: Option<DeadLetterQueue>,
+    metrics: Arc<SourceMetrics>,
+    context: SourceContext,
+}
+
+impl ParseErrorHandler {
+    /// Returns an error if the source should fail, or `Ok` if the payload should be skipped.
+    fn handle(&self, split_id: &str, offset: &str, payload: &[u8], error: RwError) -> Result<()> {
+        self.metrics
+            .parse_error_count
+            .with_label_values(&[
+                &self.context.actor_id.to_string(),
+                &self.context.source_id.to_string(),
+                split_id,
+            ])
+            .inc();
+
+        match &self.policy {
+            ParseErrorPolicy::Skip => {
+                tracing::warn!("message parsing failed {}, skipping", error.to_string());
+                Ok(())
+            }
+            ParseErrorPolicy::Fail => Err(error),
+            ParseErrorPolicy::DeadLetter(_) => {
+                let key = DeadLetterKey {
+                    source_id: self.context.source_id.table_id(),
+                    split_id,
+                    offset,
+                    error: &error.to_string(),
+                };
+                // Losing a dead letter is not worth failing the source for.
+                let dead_letter_queue = self.dead_letter_queue.as_ref().unwrap();
+                if let Err(e) = dead_letter_queue.send(&key, payload) {
+                    tracing::error!(
+                        "failed to send message to dead letter queue {}, skipping: {:?}",
+                        e,
+                        key
+                    );
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 impl ConnectorSourceReader {
     #[try_stream(boxed, ok = StreamChunkWithState, error = RwError)]
     pub async fn into_stream(self) {
@@ -155,23 +206,26 @@ impl ConnectorSourceReader {
             let mut builder =
                 SourceStreamChunkBuilder::with_capacity(self.columns.clone(), batch.len());
             let mut split_offset_mapping: HashMap<SplitId, String> = HashMap::new();
+            let mut payload_size = 0;
 
             for msg in batch {
                 if let Some(content) = msg.payload {
-                    split_offset_mapping.insert(msg.split_id, msg.offset);
+                    payload_size += content.len();
                     if let Err(e) = self
                         .parser
                         .parse(content.as_ref(), builder.row_writer())
                         .await
                     {
-                        tracing::warn!("message parsing failed {}, skipping", e.to_string());
-                        continue;
+                        self.parse_error_handler
+                            .handle(&msg.split_id, &msg.offset, &content, e)?;
                     }
+                    split_offset_mapping.insert(msg.split_id, msg.offset);
                 }
             }
             yield StreamChunkWithState {
                 chunk: builder.finish(),
                 split_offset_mapping: Some(split_offset_mapping),
+                payload_size,
             };
         }
     }
@@ -182,6 +236,7 @@ pub struct ConnectorSource {
     pub config: ConnectorProperties,
     pub columns: Vec<SourceColumnDesc>,
     pub parser: Arc<SourceParserImpl>,
+    pub parse_error_policy: ParseErrorPolicy,
     pub connector_message_buffer_size: usize,
 }
 
@@ -204,6 +259,8 @@ impl ConnectorSource {
             .map(|addr| source_props.insert("connector_node_addr".to_string(), addr));
         let config =
             ConnectorProperties::extract(source_props).map_err(|e| ConnectorError(e.into()))?;
+        let parse_error_policy =
+            ParseErrorPolicy::from_properties(&properties).map_err(|e| ConnectorError(e.into()))?;
         let parser = SourceParserImpl::create(
             &format,
             &properties,
@@ -216,6 +273,7 @@ impl ConnectorSource {
             config,
             columns,
             parser,
+            parse_error_policy,
             connector_message_buffer_size,
         })
     }
@@ -271,9 +329,25 @@ impl ConnectorSource {
 
         let stream = select_all(readers.into_iter().map(|r| r.into_stream())).boxed();
 
+        let dead_letter_queue = match &self.parse_error_policy {
+            ParseErrorPolicy::DeadLetter(config) => Some(
+                DeadLetterQueue::new(config)
+                    .await
+                    .map_err(|e| ConnectorError(e.into()))?,
+            ),
+            _ => None,
+        };
+        let parse_error_handler = ParseErrorHandler {
+            policy: self.parse_error_policy.clone(),
+            dead_letter_queue,
+            metrics,
+            context,
+        };
+
         Ok(ConnectorSourceReader {
             parser: self.parser.clone(),
             columns,
+            parse_error_handler,
             stream,
         })
     }
@@ -30,6 +30,7 @@ use futures::stream::BoxStream;
 pub use manager::*;
 pub use parser::*;
 use risingwave_common::array::StreamChunk;
+use risingwave_common::collection::estimate_size::EstimateSize;
 use risingwave_common::error::RwError;
 use risingwave_connector::source::SplitId;
 pub use table::*;
@@ -76,14 +77,19 @@ pub type BoxSourceWithStateStream = BoxStream<'static, Result<StreamChunkWithSta
 pub struct StreamChunkWithState {
     pub chunk: StreamChunk,
     pub split_offset_mapping: Option<HashMap<SplitId, String>>,
+    /// The size in bytes of the messages the chunk is parsed from.
+    pub payload_size: usize,
 }
 
 /// The `split_offset_mapping` field is unused for the table source, so we implement `From` for it.
+/// The table source has no messages, so the size of the chunk is taken as the payload size.
 impl From<StreamChunk> for StreamChunkWithState {
     fn from(chunk: StreamChunk) -> Self {
+        let payload_size = chunk.data_chunk().estimated_heap_size();
         Self {
             chunk,
             split_offset_mapping: None,
+            payload_size,
         }
     }
 }
@@ -23,6 +23,7 @@ use risingwave_common::error::ErrorCode::{ConnectorError, InternalError, Protoco
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::try_match_expand;
 use risingwave_common::types::DataType;
+use risingwave_connector::source::dead_letter::ParseErrorPolicy;
 use risingwave_connector::source::ConnectorProperties;
 use risingwave_connector::ConnectorParams;
 use risingwave_pb::catalog::source_info::SourceInfo as ProstSourceInfo;
@@ -31,6 +32,7 @@ use risingwave_pb::plan_common::{ColumnCatalog as ProstColumnCatalog, RowFormatT
 
 use crate::connector_source::DEFAULT_CONNECTOR_MESSAGE_BUFFER_SIZE;
 use crate::monitor::SourceMetrics;
+use crate::row_id::{RowIdGenerator, WorkerRowIdGeneratorRef};
 use crate::table::TableSource;
 use crate::{ConnectorSource, SourceFormat, SourceImpl, SourceParserImpl};
 
@@ -114,6 +116,8 @@ pub struct TableSourceManager {
     /// The capacity of the chunks in the channel that connects between `ConnectorSource` and
     /// `SourceExecutor`.
     connector_message_buffer_size: usize,
+    /// Row id generator shared by `INSERT`s on this worker.
+    row_id_generator: WorkerRowIdGeneratorRef,
 }
 
 impl TableSourceManager {
@@ -174,6 +178,10 @@ impl TableSourceManager {
     fn msg_buf_size(&self) -> usize {
         self.connector_message_buffer_size
     }
+
+    pub fn row_id_generator(&self) -> WorkerRowIdGeneratorRef {
+        self.row_id_generator.clone()
+    }
 }
 
 impl Default for TableSourceManager {
@@ -182,17 +190,27 @@ impl Default for TableSourceManager {
             sources: Default::default(),
             metrics: Default::default(),
             connector_message_buffer_size: DEFAULT_CONNECTOR_MESSAGE_BUFFER_SIZE,
+            row_id_generator: Arc::new(tokio::sync::Mutex::new(
+                RowIdGenerator::for_worker(0).unwrap(),
+            )),
         }
     }
 }
 
 impl TableSourceManager {
-    pub fn new(metrics: Arc<SourceMetrics>, connector_message_buffer_size: usize) -> Self {
-        TableSourceManager {
+    pub fn new(
+        metrics: Arc<SourceMetrics>,
+        connector_message_buffer_size: usize,
+        row_id_generator_slot: u32,
+    ) -> Result<Self> {
+        Ok(TableSourceManager {
             sources: Mutex::new(HashMap::new()),
             metrics,
             connector_message_buffer_size,
-        }
+            row_id_generator: Arc::new(tokio::sync::Mutex::new(RowIdGenerator::for_worker(
+                row_id_generator_slot,
+            )?)),
+        })
     }
 }
 
@@ -302,11 +320,14 @@ impl SourceDescBuilder {
             .map(|addr| source_props.insert("connector_node_addr".to_string(), addr.clone()));
         let config = ConnectorProperties::extract(source_props)
             .map_err(|e| RwError::from(ConnectorError(e.into())))?;
+        let parse_error_policy = ParseErrorPolicy::from_properties(&self.properties)
+            .map_err(|e| RwError::from(ConnectorError(e.into())))?;
 
         let source = SourceImpl::Connector(ConnectorSource {
             config,
             columns: columns.clone(),
             parser,
+            parse_error_policy,
             connector_message_buffer_size: self.source_manager.msg_buf_size(),
         });
 
@@ -356,7 +377,7 @@ pub mod test_utils {
                 is_hidden: false,
             })
             .collect();
-        let info = ProstSourceInfo::TableSource(TableSourceInfo {});
+        let info = ProstSourceInfo::TableSource(TableSourceInfo::default());
         SourceDescBuilder {
             source_id,
             row_id_index,
@@ -457,7 +478,7 @@ mod tests {
             })
             .collect();
         let pk_column_ids = vec![1];
-        let info = TableSourceInfo {};
+        let info = TableSourceInfo::default();
 
         let mem_source_manager: TableSourceManagerRef = Arc::new(TableSourceManager::default());
         let mut source_builder = SourceDescBuilder::new(
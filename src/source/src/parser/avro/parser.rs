@@ -59,9 +59,12 @@ impl AvroParser {
         let (schema, schema_resolver) = if use_schema_registry {
             let kafka_topic = get_kafka_topic(&props)?;
             let client = Client::new(url)?;
-            let (schema, resolver) =
-                ConfluentSchemaResolver::new(format!("{}-value", kafka_topic).as_str(), client)
-                    .await?;
+            let (schema, resolver) = ConfluentSchemaResolver::new(
+                format!("{}-value", kafka_topic).as_str(),
+                client,
+                NewFieldPolicy::from_properties(&props)?,
+            )
+            .await?;
             (schema, Some(resolver))
         } else {
             let schema_content = match url.scheme() {
@@ -108,6 +111,9 @@ impl AvroParser {
         schema: &Schema,
         index: &mut i32,
     ) -> Result<ColumnDesc> {
+        if let Some(inner) = nullable_inner(schema) {
+            return Self::avro_field_to_column_desc(name, inner, index);
+        }
         let data_type = Self::avro_type_mapping(schema)?;
         match schema {
             Schema::Record {
@@ -141,6 +147,9 @@ impl AvroParser {
     }
 
     fn avro_type_mapping(schema: &Schema) -> Result<DataType> {
+        if let Some(inner) = nullable_inner(schema) {
+            return Self::avro_type_mapping(inner);
+        }
         let data_type = match schema {
             Schema::String => DataType::Varchar,
             Schema::Int => DataType::Int32,
@@ -188,7 +197,7 @@ impl AvroParser {
         let avro_value = if let Some(resolver) = &self.schema_resolver {
             let (schema_id, mut raw_payload) = extract_schema_id(payload)?;
             let writer_schema = resolver.get(schema_id).await?;
-            from_avro_datum(writer_schema.as_ref(), &mut raw_payload, Some(&self.schema))
+            from_avro_datum(&writer_schema.schema, &mut raw_payload, Some(&self.schema))
                 .map_err(|e| RwError::from(ProtocolError(e.to_string())))?
         } else {
             let mut reader = Reader::with_schema(&self.schema, payload)
@@ -224,6 +233,16 @@ impl AvroParser {
     }
 }
 
+/// Returns `T` if `schema` is a nullable union, i.e. `["null", T]` or `[T, "null"]`.
+fn nullable_inner(schema: &Schema) -> Option<&Schema> {
+    if let Schema::Union(union) = schema {
+        if let [Schema::Null, inner] | [inner, Schema::Null] = union.variants() {
+            return Some(inner);
+        }
+    }
+    None
+}
+
 /// Convert Avro value to datum.For now, support the following [Avro type](https://avro.apache.org/docs/current/spec.html).
 ///  - boolean
 ///  - int : i32
@@ -233,9 +252,12 @@ impl AvroParser {
 ///  - string: String
 ///  - Date (the number of days from the unix epoch, 1970-1-1 UTC)
 ///  - Timestamp (the number of milliseconds from the unix epoch,  1970-1-1 00:00:00.000 UTC)
+///  - Nullable union (`["null", T]`)
 #[inline]
 fn from_avro_value(value: Value) -> Result<Datum> {
     let v = match value {
+        Value::Null => return Ok(None),
+        Value::Union(_, value) => return from_avro_value(*value),
         Value::Boolean(b) => ScalarImpl::Bool(b),
         Value::String(s) => ScalarImpl::Utf8(s),
         Value::Int(i) => ScalarImpl::Int32(i),
@@ -338,8 +360,8 @@ mod test {
     use url::Url;
 
     use super::{
-        read_schema_from_http, read_schema_from_local, read_schema_from_s3, unix_epoch_days,
-        AvroParser,
+        from_avro_value, read_schema_from_http, read_schema_from_local, read_schema_from_s3,
+        unix_epoch_days, AvroParser,
     };
     use crate::{SourceColumnDesc, SourceParser, SourceStreamChunkBuilder};
 
@@ -640,4 +662,37 @@ mod test {
         let avro_parser = avro_parser_rs.unwrap();
         println!("avro_parser = {:?}", avro_parser);
     }
+    #[test]
+    fn test_nullable_union() {
+        let schema = Schema::parse_str(
+            r#"{"type": "record", "name": "user", "fields": [
+                {"name": "id", "type": ["null", "int"]},
+                {"name": "tags", "type": [{"type": "array", "items": "string"}, "null"]},
+                {"name": "ip", "type": ["null", "int", "string"]}
+            ]}"#,
+        )
+        .unwrap();
+        let Schema::Record { fields, .. } = schema else { unreachable!() };
+
+        assert_eq!(
+            AvroParser::avro_type_mapping(&fields[0].schema).unwrap(),
+            DataType::Int32
+        );
+        assert_eq!(
+            AvroParser::avro_type_mapping(&fields[1].schema).unwrap(),
+            DataType::List {
+                datatype: Box::new(DataType::Varchar)
+            }
+        );
+        assert!(AvroParser::avro_type_mapping(&fields[2].schema).is_err());
+
+        assert_eq!(
+            from_avro_value(Value::Union(1, Box::new(Value::Int(1)))).unwrap(),
+            Some(ScalarImpl::Int32(1))
+        );
+        assert_eq!(
+            from_avro_value(Value::Union(0, Box::new(Value::Null))).unwrap(),
+            None
+        );
+    }
 }
@@ -82,44 +82,198 @@ pub(super) async fn read_schema_from_http(location: &Url) -> Result<String> {
     })
 }
 
+/// What to do with the messages whose writer schema has fields that the reader schema, i.e. the
+/// columns of the source, does not have. These fields are always dropped when the message is
+/// resolved against the reader schema.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NewFieldPolicy {
+    /// `ignore`: drops the new fields silently.
+    #[default]
+    Ignore,
+    /// `warn`: drops the new fields, and logs them once for every writer schema.
+    Warn,
+    /// `fail`: fails to parse the messages.
+    Fail,
+}
+
+impl NewFieldPolicy {
+    const KEY: &'static str = "schema.registry.new_fields";
+
+    pub fn from_properties(properties: &HashMap<String, String>) -> Result<Self> {
+        match properties.get(Self::KEY).map(String::as_str) {
+            None | Some("ignore") => Ok(Self::Ignore),
+            Some("warn") => Ok(Self::Warn),
+            Some("fail") => Ok(Self::Fail),
+            Some(policy) => Err(RwError::from(InvalidConfigValue {
+                config_entry: Self::KEY.to_string(),
+                config_value: policy.to_string(),
+            })),
+        }
+    }
+}
+
+/// A writer schema fetched from the schema registry.
+#[derive(Debug)]
+pub struct WriterSchema {
+    pub schema: Schema,
+    /// The paths of the fields that the reader schema does not have.
+    pub new_fields: Vec<String>,
+}
+
+/// Returns the paths of the fields of `writer` that `reader` does not have, including the fields
+/// of nested records, e.g. `address.zip_code`.
+pub(super) fn new_fields(writer: &Schema, reader: &Schema) -> Vec<String> {
+    fn collect(writer: &Schema, reader: &Schema, prefix: &str, new_fields: &mut Vec<String>) {
+        if let (
+            Schema::Record {
+                fields: writer_fields,
+                ..
+            },
+            Schema::Record {
+                fields: reader_fields,
+                ..
+            },
+        ) = (writer, reader)
+        {
+            for field in writer_fields {
+                let path = format!("{}{}", prefix, field.name);
+                match reader_fields.iter().find(|f| f.name == field.name) {
+                    Some(reader_field) => collect(
+                        &field.schema,
+                        &reader_field.schema,
+                        &format!("{}.", path),
+                        new_fields,
+                    ),
+                    None => new_fields.push(path),
+                }
+            }
+        }
+    }
+
+    let mut fields = vec![];
+    collect(writer, reader, "", &mut fields);
+    fields
+}
+
 #[derive(Debug)]
 pub struct ConfluentSchemaResolver {
-    writer_schemas: Cache<i32, Arc<Schema>>,
+    reader_schema: Schema,
+    writer_schemas: Cache<i32, Arc<WriterSchema>>,
     confluent_client: Client,
+    new_field_policy: NewFieldPolicy,
 }
 
 impl ConfluentSchemaResolver {
     // return the reader schema and a new `SchemaResolver`
-    pub async fn new(subject_name: &str, client: Client) -> Result<(Schema, Self)> {
+    pub async fn new(
+        subject_name: &str,
+        client: Client,
+        new_field_policy: NewFieldPolicy,
+    ) -> Result<(Schema, Self)> {
         let cf_schema = client.get_schema_by_subject(subject_name).await?;
         let schema = Schema::parse_str(&cf_schema.raw)
             .map_err(|e| RwError::from(ProtocolError(format!("Avro schema parse error {}", e))))?;
         let resolver = ConfluentSchemaResolver {
+            reader_schema: schema.clone(),
             writer_schemas: Cache::new(u64::MAX),
             confluent_client: client,
+            new_field_policy,
+        };
+        let writer_schema = WriterSchema {
+            schema: schema.clone(),
+            new_fields: vec![],
         };
         resolver
             .writer_schemas
-            .insert(cf_schema.id, Arc::new(schema.clone()))
+            .insert(cf_schema.id, Arc::new(writer_schema))
             .await;
         Ok((schema, resolver))
     }
 
-    // get the writer schema by id
-    pub async fn get(&self, schema_id: i32) -> Result<Arc<Schema>> {
-        if let Some(schema) = self.writer_schemas.get(&schema_id) {
-            Ok(schema)
+    // get the writer schema by id, checked against the `NewFieldPolicy`
+    pub async fn get(&self, schema_id: i32) -> Result<Arc<WriterSchema>> {
+        let writer_schema = if let Some(schema) = self.writer_schemas.get(&schema_id) {
+            schema
         } else {
             let cf_schema = self.confluent_client.get_schema_by_id(schema_id).await?;
 
             let schema = Schema::parse_str(&cf_schema.raw).map_err(|e| {
                 RwError::from(ProtocolError(format!("Avro schema parse error {}", e)))
             })?;
-            let schema = Arc::new(schema);
+            let new_fields = new_fields(&schema, &self.reader_schema);
+            if !new_fields.is_empty() && self.new_field_policy == NewFieldPolicy::Warn {
+                tracing::warn!(
+                    "Avro writer schema {} has new fields {:?}, which are dropped",
+                    schema_id,
+                    new_fields
+                );
+            }
+            let schema = Arc::new(WriterSchema { schema, new_fields });
             self.writer_schemas
                 .insert(schema_id, Arc::clone(&schema))
                 .await;
-            Ok(schema)
+            schema
+        };
+
+        if !writer_schema.new_fields.is_empty() && self.new_field_policy == NewFieldPolicy::Fail {
+            return Err(RwError::from(ProtocolError(format!(
+                "Avro writer schema {} has new fields {:?}",
+                schema_id, writer_schema.new_fields
+            ))));
         }
+        Ok(writer_schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_fields() {
+        let reader = Schema::parse_str(
+            r#"{"type": "record", "name": "user", "fields": [
+                {"name": "id", "type": "int"},
+                {"name": "address", "type": {"type": "record", "name": "address", "fields": [
+                    {"name": "city", "type": "string"}
+                ]}}
+            ]}"#,
+        )
+        .unwrap();
+        let writer = Schema::parse_str(
+            r#"{"type": "record", "name": "user", "fields": [
+                {"name": "id", "type": "int"},
+                {"name": "name", "type": "string", "default": ""},
+                {"name": "address", "type": {"type": "record", "name": "address", "fields": [
+                    {"name": "city", "type": "string"},
+                    {"name": "zip_code", "type": "string", "default": ""}
+                ]}}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            new_fields(&writer, &reader),
+            vec!["name", "address.zip_code"]
+        );
+        assert!(new_fields(&reader, &writer).is_empty());
+        assert!(new_fields(&reader, &reader).is_empty());
+    }
+
+    #[test]
+    fn test_new_field_policy() {
+        let policy = |value: &str| {
+            NewFieldPolicy::from_properties(&HashMap::from([(
+                "schema.registry.new_fields".to_string(),
+                value.to_string(),
+            )]))
+        };
+        assert_eq!(
+            NewFieldPolicy::from_properties(&HashMap::new()).unwrap(),
+            NewFieldPolicy::Ignore
+        );
+        assert_eq!(policy("warn").unwrap(), NewFieldPolicy::Warn);
+        assert_eq!(policy("fail").unwrap(), NewFieldPolicy::Fail);
+        assert!(policy("drop").is_err());
     }
 }
@@ -15,7 +15,7 @@
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use num_traits::FromPrimitive;
-use risingwave_common::array::{ListValue, StructValue};
+use risingwave_common::array::{JsonbVal, ListValue, StructValue};
 use risingwave_common::types::{DataType, Datum, Decimal, ScalarImpl};
 use risingwave_expr::vector_op::cast::{
     i64_to_timestamp, i64_to_timestampz, str_to_date, str_to_time, str_to_timestamp,
@@ -105,6 +105,7 @@ fn do_parse_json_value(dtype: &DataType, v: &Value) -> Result<ScalarImpl> {
             }
         }
         DataType::Interval => unimplemented!(),
+        DataType::Jsonb => ScalarImpl::Jsonb(JsonbVal::from(v.clone())),
     };
     Ok(v)
 }
@@ -193,6 +194,7 @@ fn do_parse_simd_json_value(dtype: &DataType, v: &BorrowedValue<'_>) -> Result<S
             }
         }
         DataType::Interval => unimplemented!(),
+        DataType::Jsonb => ScalarImpl::Jsonb(JsonbVal::from(serde_json::to_value(v)?)),
     };
     Ok(v)
 }
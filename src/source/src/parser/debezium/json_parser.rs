@@ -25,11 +25,6 @@ use super::operators::*;
 use crate::parser::common::json_parse_value;
 use crate::{ParseFuture, SourceParser, SourceStreamChunkRowWriter, WriteGuard};
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DebeziumEvent {
-    pub payload: Payload,
-}
-
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Payload {
     pub before: Option<BTreeMap<String, Value>>,
@@ -46,10 +41,17 @@ impl DebeziumJsonParser {
         payload: &[u8],
         mut writer: SourceStreamChunkRowWriter<'_>,
     ) -> Result<WriteGuard> {
-        let event: DebeziumEvent = serde_json::from_slice(payload)
+        let mut event: Value = serde_json::from_slice(payload)
             .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
 
-        let mut payload = event.payload;
+        // The envelope is wrapped in `payload` only if the Kafka Connect converter has schemas
+        // enabled. Otherwise, `before`, `after` and `op` are at the top level.
+        let envelope = match event.as_object_mut().and_then(|obj| obj.remove("payload")) {
+            Some(envelope) => envelope,
+            None => event,
+        };
+        let mut payload: Payload = serde_json::from_value(envelope)
+            .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
 
         match payload.op.as_str() {
             DEBEZIUM_UPDATE_OP => {
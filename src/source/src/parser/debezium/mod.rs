@@ -207,6 +207,26 @@ mod test {
         assert!(row2[3].eq(&Some(ScalarImpl::Float64(9.1.into()))));
     }
 
+    #[tokio::test]
+    async fn test_debezium_json_parser_without_schema() {
+        // the envelope is not wrapped in `payload` when `value.converter.schemas.enable` is false
+        let data = br#"{"before":{"id":102,"name":"car battery","description":"12V car battery","weight":8.1},"after":{"id":102,"name":"car battery","description":"24V car battery","weight":9.1},"source":{"version":"1.7.1.Final","connector":"mysql","name":"dbserver1","ts_ms":1639551901000,"snapshot":"false","db":"inventory","sequence":null,"table":"products","server_id":223344,"gtid":null,"file":"mysql-bin.000003","pos":1382,"row":0,"thread":null,"query":null},"op":"u","ts_ms":1639551901165,"transaction":null}"#;
+        let parser = DebeziumJsonParser;
+        let columns = get_test_columns();
+
+        let [(op1, row1), (op2, row2)]: [_; 2] =
+            parse_one(parser, columns, data).await.try_into().unwrap();
+
+        assert_eq!(op1, Op::UpdateDelete);
+        assert_eq!(op2, Op::UpdateInsert);
+
+        assert!(row1[0].eq(&Some(ScalarImpl::Int32(102))));
+        assert!(row1[2].eq(&Some(ScalarImpl::Utf8("12V car battery".to_string()))));
+        assert!(row2[0].eq(&Some(ScalarImpl::Int32(102))));
+        assert!(row2[2].eq(&Some(ScalarImpl::Utf8("24V car battery".to_string()))));
+        assert!(row2[3].eq(&Some(ScalarImpl::Float64(9.1.into()))));
+    }
+
     #[tokio::test]
     async fn test_update_with_before_null() {
         // the test case it identical with test_debezium_json_parser_insert but op is 'u'
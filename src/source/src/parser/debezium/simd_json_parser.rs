@@ -23,6 +23,7 @@ use super::operators::*;
 use crate::parser::common::simd_json_parse_value;
 use crate::{ParseFuture, SourceParser, SourceStreamChunkRowWriter, WriteGuard};
 
+const PAYLOAD: &str = "payload";
 const BEFORE: &str = "before";
 const AFTER: &str = "after";
 const OP: &str = "op";
@@ -49,16 +50,18 @@ impl DebeziumJsonParser {
         let event: BorrowedValue<'_> = simd_json::to_borrowed_value(&mut payload_mut)
             .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
 
-        let payload = event
-            .get("payload")
-            .and_then(ensure_not_null)
-            .ok_or_else(|| {
+        // The envelope is wrapped in `payload` only if the Kafka Connect converter has schemas
+        // enabled. Otherwise, `before`, `after` and `op` are at the top level.
+        let payload = match event.get(PAYLOAD) {
+            Some(payload) => ensure_not_null(payload).ok_or_else(|| {
                 RwError::from(ProtocolError("no payload in debezium event".to_owned()))
-            })?;
+            })?,
+            None => &event,
+        };
 
         let op = payload.get(OP).and_then(|v| v.as_str()).ok_or_else(|| {
             RwError::from(ProtocolError(
-                "op field not found in maxwell json".to_owned(),
+                "op field not found in debezium json".to_owned(),
             ))
         })?;
 
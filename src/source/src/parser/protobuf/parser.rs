@@ -288,20 +288,42 @@ fn protobuf_type_mapping(field_descriptor: &FieldDescriptor) -> Result<DataType>
     Ok(t)
 }
 
+/// Reads a zigzag-encoded varint from the front of `buf`, and advances `buf` past it.
+fn read_zigzag_varint(buf: &mut &[u8]) -> Result<i64> {
+    let value = prost::encoding::decode_varint(buf).map_err(|e| {
+        RwError::from(ProtocolError(format!(
+            "failed to decode the message indexes of the payload: {}",
+            e
+        )))
+    })?;
+    Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
 pub(crate) fn resolve_pb_header(payload: &[u8]) -> Result<&[u8]> {
-    // there's a message index array at the front of payload
-    // if it is the first message in proto def, the array is just and `0`
-    // TODO: support parsing more complex indec array
-    let (_, remained) = extract_schema_id(payload)?;
-    match remained.first() {
-        Some(0) => Ok(&remained[1..]),
-        Some(i) => {
-            Err(RwError::from(ProtocolError(format!("The payload message must be the first message in protobuf schema def, but the message index is {}", i))))
-        }
-        None => {
-            Err(RwError::from(ProtocolError("The proto payload is empty".to_owned())))
-        }
+    // There's a message index array at the front of payload, which locates the message type in the
+    // proto def. It is encoded as the number of indexes followed by the indexes, or a single `0`
+    // for the first message, i.e. `[0]`.
+    // TODO: support messages other than the first one
+    let (_, mut remained) = extract_schema_id(payload)?;
+    if remained.is_empty() {
+        return Err(RwError::from(ProtocolError(
+            "The proto payload is empty".to_owned(),
+        )));
     }
+    let indexes = match read_zigzag_varint(&mut remained)? {
+        0 => vec![0],
+        len => (0..len)
+            .map(|_| read_zigzag_varint(&mut remained))
+            .collect::<Result<Vec<_>>>()?,
+    };
+    if indexes != [0] {
+        return Err(RwError::from(ProtocolError(format!(
+            "The payload message must be the first message in protobuf schema def, but the message \
+             indexes are {:?}",
+            indexes
+        ))));
+    }
+    Ok(remained)
 }
 
 impl SourceParser for ProtobufParser {
@@ -426,4 +448,26 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_resolve_pb_header() {
+        // Magic byte, schema id 1, message indexes `[0]` and the message.
+        assert_eq!(
+            resolve_pb_header(b"\x00\x00\x00\x00\x01\x00\x08").unwrap(),
+            b"\x08"
+        );
+        // The explicit form of message indexes `[0]`.
+        assert_eq!(
+            resolve_pb_header(b"\x00\x00\x00\x00\x01\x02\x00\x08").unwrap(),
+            b"\x08"
+        );
+
+        // Message indexes `[1]` and `[0, 1]`.
+        let err = resolve_pb_header(b"\x00\x00\x00\x00\x01\x02\x02\x08").unwrap_err();
+        assert!(err.to_string().contains("message indexes are [1]"));
+        let err = resolve_pb_header(b"\x00\x00\x00\x00\x01\x04\x00\x02\x08").unwrap_err();
+        assert!(err.to_string().contains("message indexes are [0, 1]"));
+
+        assert!(resolve_pb_header(b"\x00\x00\x00\x00\x01").is_err());
+    }
 }
@@ -12,18 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::Result;
+use risingwave_common::hash::VirtualNode;
+use risingwave_common::util::epoch::UNIX_SINGULARITY_DATE_EPOCH;
+use risingwave_common::util::worker_util::ROW_ID_GENERATOR_SLOT_COUNT;
+
 const TIMESTAMP_SHIFT_BITS: u8 = 22;
 const VNODE_ID_SHIFT_BITS: u8 = 12;
 const SEQUENCE_UPPER_BOUND: u16 = 1 << 12;
 const VNODE_ID_UPPER_BOUND: u32 = 1 << 10;
 
+static_assertions::const_assert_eq!(
+    VirtualNode::COUNT as u32 + ROW_ID_GENERATOR_SLOT_COUNT,
+    VNODE_ID_UPPER_BOUND
+);
+
 /// `RowIdGenerator` generates unique row ids using snowflake algorithm as following format:
 ///
 /// | timestamp | vnode id | sequence |
 /// |-----------|-----------|----------|
 /// |  41 bits  | 10 bits   | 12 bits  |
+///
+/// The vnode id part is the vnode of a streaming table in `[0, VirtualNode::COUNT)`, or
+/// `VirtualNode::COUNT` plus the row id generator slot of a worker, see [`Self::for_worker`].
 #[derive(Debug)]
 pub struct RowIdGenerator {
     /// Specific epoch using for generating row ids.
@@ -40,6 +55,11 @@ pub struct RowIdGenerator {
 
 pub type RowId = i64;
 
+/// A [`RowIdGenerator`] shared by all executors on a worker node. The row id generator slot of the
+/// worker takes the place of the vnode id, so row ids generated on a worker are unique and
+/// monotonically increasing.
+pub type WorkerRowIdGeneratorRef = Arc<tokio::sync::Mutex<RowIdGenerator>>;
+
 impl RowIdGenerator {
     pub fn new(vnode_id: u32) -> Self {
         assert!(vnode_id < VNODE_ID_UPPER_BOUND);
@@ -55,6 +75,24 @@ impl RowIdGenerator {
         }
     }
 
+    /// Create a generator for the worker with the row id generator `slot` assigned by meta.
+    ///
+    /// Slots are placed after the vnode ids, so the row ids never collide with the ones generated
+    /// per vnode by streaming.
+    pub fn for_worker(slot: u32) -> Result<Self> {
+        if slot >= ROW_ID_GENERATOR_SLOT_COUNT {
+            return Err(InternalError(format!(
+                "row id generator slot {} is out of range [0, {})",
+                slot, ROW_ID_GENERATOR_SLOT_COUNT
+            ))
+            .into());
+        }
+        Ok(Self::with_epoch(
+            VirtualNode::COUNT as u32 + slot,
+            *UNIX_SINGULARITY_DATE_EPOCH,
+        ))
+    }
+
     fn row_id(&self) -> RowId {
         self.last_duration_ms << TIMESTAMP_SHIFT_BITS
             | (self.vnode_id << VNODE_ID_SHIFT_BITS) as i64
@@ -145,6 +183,11 @@ mod tests {
         );
         assert_eq!(row_id & (SEQUENCE_UPPER_BOUND as i64 - 1), 0);
 
+        let mut generator = RowIdGenerator::for_worker(2).unwrap();
+        assert_eq!(generator.vnode_id, VirtualNode::COUNT as u32 + 2);
+        let row_ids = generator.next_batch(10).await;
+        assert!(row_ids.windows(2).all(|w| w[0] < w[1]));
+
         let mut generator = RowIdGenerator::new(1);
         let row_ids = generator
             .next_batch((SEQUENCE_UPPER_BOUND + 10) as usize)
@@ -159,4 +202,10 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn test_worker_slot_out_of_range() {
+        assert!(RowIdGenerator::for_worker(ROW_ID_GENERATOR_SLOT_COUNT - 1).is_ok());
+        assert!(RowIdGenerator::for_worker(ROW_ID_GENERATOR_SLOT_COUNT).is_err());
+    }
 }
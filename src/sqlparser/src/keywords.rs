@@ -74,6 +74,7 @@ define_keywords!(
     ALL,
     ALLOCATE,
     ALTER,
+    ALWAYS,
     ANALYSE,
     ANALYZE,
     AND,
@@ -234,6 +235,7 @@ define_keywords!(
     FULL,
     FUNCTION,
     FUSION,
+    GENERATED,
     GET,
     GLOBAL,
     GRANT,
@@ -337,6 +339,7 @@ define_keywords!(
     OPTION,
     OR,
     ORDER,
+    ORDINALITY,
     OUT,
     OUTER,
     OUTPUTFORMAT,
@@ -628,6 +628,27 @@ fn parse_pg_bitwise_binary_ops() {
     }
 }
 
+#[test]
+fn parse_pg_json_binary_ops() {
+    let json_ops = &[
+        ("->", BinaryOperator::Arrow),
+        ("->>", BinaryOperator::LongArrow),
+        ("#>", BinaryOperator::HashArrow),
+    ];
+
+    for (str_op, op) in json_ops {
+        let select = verified_only_select(&format!("SELECT a {} b", &str_op));
+        assert_eq!(
+            SelectItem::UnnamedExpr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: op.clone(),
+                right: Box::new(Expr::Identifier(Ident::new("b"))),
+            }),
+            select.projection[0]
+        );
+    }
+}
+
 #[test]
 fn parse_pg_unary_ops() {
     let pg_unary_ops = &[
@@ -26,7 +26,7 @@ use risingwave_hummock_sdk::filter_key_extractor::FilterKeyExtractorManager;
 use risingwave_object_store::object::parse_remote_object_store;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::compactor::compactor_service_server::CompactorServiceServer;
-use risingwave_rpc_client::MetaClient;
+use risingwave_rpc_client::{grpc_server_builder, init_client_tls, MetaClient};
 use risingwave_storage::hummock::compactor::{CompactionExecutor, CompactorContext, Context};
 use risingwave_storage::hummock::hummock_meta_client::MonitoredHummockMetaClient;
 use risingwave_storage::hummock::{
@@ -54,6 +54,8 @@ pub async fn compactor_serve(
         config,
         opts
     );
+    init_client_tls(&config.server).unwrap();
+    let server_builder = grpc_server_builder(&config.server).unwrap();
 
     // Register to the cluster.
     let meta_client =
@@ -152,7 +154,7 @@ pub async fn compactor_serve(
 
     let (shutdown_send, mut shutdown_recv) = tokio::sync::oneshot::channel();
     let join_handle = tokio::spawn(async move {
-        tonic::transport::Server::builder()
+        server_builder
             .add_service(CompactorServiceServer::new(CompactorServiceImpl::new(
                 compactor_context,
                 meta_client.clone(),
@@ -478,6 +478,7 @@ mod tests {
             value_indices: vec![0],
             definition: "".into(),
             handle_pk_conflict: false,
+            statistics: None,
         }
     }
 
@@ -265,6 +265,20 @@ pub fn prev_full_key(full_key: &[u8]) -> Vec<u8> {
 }
 
 /// Get the end bound of the given `prefix` when transforming it to a key range.
+///
+/// The end bound is the exclusive upper bound of all keys starting with `prefix`, or `Unbounded`
+/// if there's no such bound (e.g. the prefix consists of `\xff`s only).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::Bound::*;
+///
+/// use risingwave_hummock_sdk::key::end_bound_of_prefix;
+/// assert_eq!(end_bound_of_prefix(b"123"), Excluded(b"124".to_vec()));
+/// assert_eq!(end_bound_of_prefix(b"12\xff"), Excluded(b"13".to_vec()));
+/// assert_eq!(end_bound_of_prefix(b"\xff\xff"), Unbounded);
+/// ```
 pub fn end_bound_of_prefix(prefix: &[u8]) -> Bound<Vec<u8>> {
     if let Some((s, e)) = next_key_no_alloc(prefix) {
         let mut res = Vec::with_capacity(s.len() + 1);
@@ -289,6 +303,23 @@ pub fn start_bound_of_excluded_prefix(prefix: &[u8]) -> Bound<Vec<u8>> {
 }
 
 /// Transform the given `prefix` to a key range.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::Bound::*;
+///
+/// use risingwave_hummock_sdk::key::range_of_prefix;
+/// assert_eq!(
+///     range_of_prefix(b"12\xff"),
+///     (Included(b"12\xff".to_vec()), Excluded(b"13".to_vec()))
+/// );
+/// assert_eq!(
+///     range_of_prefix(b"\xff"),
+///     (Included(b"\xff".to_vec()), Unbounded)
+/// );
+/// assert_eq!(range_of_prefix(b""), (Unbounded, Unbounded));
+/// ```
 pub fn range_of_prefix(prefix: &[u8]) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
     if prefix.is_empty() {
         (Unbounded, Unbounded)
@@ -189,3 +189,59 @@ impl BlockCache {
         self.inner.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::hummock::{BlockBuilder, BlockBuilderOptions};
+
+    fn build_block(value: &[u8]) -> Box<Block> {
+        let mut builder = BlockBuilder::new(BlockBuilderOptions::default());
+        builder.add(b"k1\0\0\0\0\0\0\0\0", value);
+        let capacity = builder.uncompressed_block_size();
+        let buf = builder.build().to_vec();
+        Box::new(Block::decode(buf.into(), capacity).unwrap())
+    }
+
+    #[test]
+    fn test_block_cache_get_insert() {
+        let cache = BlockCache::new(1024 * 1024, 0);
+        assert!(cache.get(1, 0).is_none());
+
+        let block = build_block(b"v1");
+        let raw_data = block.raw_data().to_vec();
+        cache.insert(1, 0, block);
+
+        let holder = cache.get(1, 0).unwrap();
+        assert_eq!(holder.raw_data(), raw_data.as_slice());
+        assert!(cache.get(1, 1).is_none());
+        assert!(cache.get(2, 0).is_none());
+        assert!(cache.size() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_block_cache_get_or_insert_with() {
+        let cache = BlockCache::new(1024 * 1024, 0);
+        let fetch_count = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..3 {
+            let fetch_count = fetch_count.clone();
+            cache
+                .get_or_insert_with(1, 0, move || {
+                    let fetch_count = fetch_count.clone();
+                    async move {
+                        fetch_count.fetch_add(1, Ordering::Relaxed);
+                        Ok(build_block(b"v1"))
+                    }
+                })
+                .await
+                .unwrap();
+        }
+
+        // The block should be fetched only once and served from the cache afterwards.
+        assert_eq!(fetch_count.load(Ordering::Relaxed), 1);
+        assert!(cache.get(1, 0).is_some());
+    }
+}
@@ -82,7 +82,7 @@ impl CompactionFilter for TtlCompactionFilter {
             Some(ttl_second_u32) => {
                 assert!(*ttl_second_u32 != TABLE_OPTION_DUMMY_RETENTION_SECOND);
                 // default to zero.
-                let ttl_mill = (*ttl_second_u32 * 1000) as u64;
+                let ttl_mill = *ttl_second_u32 as u64 * 1000;
                 let min_epoch = Epoch(self.expire_epoch).subtract_ms(ttl_mill);
                 self.last_table_and_ttl = Some((table_id, ttl_mill));
                 Epoch(epoch) <= min_epoch
@@ -120,3 +120,42 @@ impl MultiCompactionFilter {
         self.filter_vec.push(filter);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::TableId;
+    use risingwave_common::util::epoch::Epoch;
+
+    use super::*;
+
+    #[test]
+    fn test_state_clean_up_compaction_filter() {
+        let mut filter = StateCleanUpCompactionFilter::new(HashSet::from_iter([1, 3]));
+        for (table_id, removed) in [(1, false), (1, false), (2, true), (2, true), (3, false)] {
+            let key = FullKey::for_test(TableId::new(table_id), &b"key"[..], 0);
+            assert_eq!(filter.should_delete(key), removed);
+        }
+    }
+
+    #[test]
+    fn test_ttl_compaction_filter() {
+        const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+        let now = Epoch::from_physical_time(1000 * DAY_MS);
+        // Table 1 retains 1 day, while table 2 retains 100 days, whose ttl in milliseconds
+        // overflows `u32`.
+        let mut filter =
+            TtlCompactionFilter::new(HashMap::from_iter([(1, 86400), (2, 8640000)]), now.0);
+
+        let mut should_delete = |table_id, days_ago| {
+            let epoch = now.subtract_ms(days_ago * DAY_MS).0;
+            let key = FullKey::for_test(TableId::new(table_id), &b"key"[..], epoch);
+            filter.should_delete(key)
+        };
+        assert!(!should_delete(1, 0));
+        assert!(should_delete(1, 2));
+        assert!(!should_delete(2, 2));
+        assert!(!should_delete(2, 99));
+        assert!(should_delete(2, 101));
+        assert!(!should_delete(3, 1000));
+    }
+}
@@ -597,6 +597,7 @@ impl HummockVersionReader {
                 read_options.table_id,
                 &table_key_range,
             );
+            local_stats.sst_pruned_count += (level.table_infos.len() - table_infos.len()) as u64;
             if table_infos.is_empty() {
                 continue;
             }
@@ -613,6 +614,8 @@ impl HummockVersionReader {
                 };
                 assert!(start_table_idx < table_infos.len() && end_table_idx < table_infos.len());
                 let matched_table_infos = &table_infos[start_table_idx..=end_table_idx];
+                local_stats.sst_pruned_count +=
+                    (table_infos.len() - matched_table_infos.len()) as u64;
 
                 let mut sstables = vec![];
                 for sstable_info in matched_table_infos {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::future::Future;
 use std::ops::Bound::{Excluded, Included, Unbounded};
 use std::ops::{Bound, RangeBounds};
@@ -438,8 +438,9 @@ pub type MemoryStateStore = RangeKvStateStore<BTreeMapRangeKv>;
 /// An in-memory state store
 ///
 /// The in-memory state store is a [`BTreeMap`], which maps [`FullKey`] to value. It
-/// never does GC, so the memory usage will be high. Therefore, in-memory state store should never
-/// be used in production.
+/// never does GC automatically, so the memory usage will be high unless
+/// [`MemoryStateStore::vacuum`] is called. Therefore, in-memory state store should never be used
+/// in production.
 #[derive(Clone, Default)]
 pub struct RangeKvStateStore<R: RangeKv> {
     /// Stores (key, epoch) -> user value.
@@ -503,6 +504,27 @@ impl MemoryStateStore {
         static STORE: LazyLock<MemoryStateStore> = LazyLock::new(MemoryStateStore::new);
         STORE.clone()
     }
+
+    /// Removes the versions that will never be read by any snapshot at or after `safe_epoch`.
+    ///
+    /// For each user key, all versions newer than `safe_epoch` are kept, along with the latest
+    /// version not newer than `safe_epoch` unless it's a tombstone. Reads at epochs smaller than
+    /// `safe_epoch` are no longer guaranteed to be consistent after vacuuming.
+    pub fn vacuum(&self, safe_epoch: HummockEpoch) {
+        let mut inner = self.inner.write();
+        let mut last_user_key: Option<UserKey<Bytes>> = None;
+        // Versions of the same user key are ordered by epoch in descending order.
+        inner.retain(|key, value| {
+            if key.epoch > safe_epoch {
+                return true;
+            }
+            if last_user_key.as_ref() == Some(&key.user_key) {
+                return false;
+            }
+            last_user_key = Some(key.user_key.clone());
+            value.is_some()
+        });
+    }
 }
 
 impl<R: RangeKv> RangeKvStateStore<R> {
@@ -587,20 +609,38 @@ impl<R: RangeKv> StateStoreWrite for RangeKvStateStore<R> {
     fn ingest_batch(
         &self,
         kv_pairs: Vec<(Bytes, StorageValue)>,
-        _delete_ranges: Vec<(Bytes, Bytes)>,
+        delete_ranges: Vec<(Bytes, Bytes)>,
         write_options: WriteOptions,
     ) -> Self::IngestBatchFuture<'_> {
         async move {
             let epoch = write_options.epoch;
+            let table_id = write_options.table_id;
+
+            // Delete ranges are applied by writing tombstones for all existing keys in the ranges.
+            // Keys written in this batch are ingested after the tombstones and thus override them.
+            let mut delete_keys = BTreeSet::new();
+            for (start, end) in delete_ranges {
+                for (key, _) in self.inner.range(
+                    to_full_key_range(table_id, (Included(start), Excluded(end))),
+                    None,
+                )? {
+                    delete_keys.insert(Bytes::from(key.user_key.table_key.0));
+                }
+            }
+
             let mut size = 0;
-            self.inner
-                .ingest_batch(kv_pairs.into_iter().map(|(key, value)| {
-                    size += key.len() + value.size();
-                    (
-                        FullKey::new(write_options.table_id, TableKey(key), epoch),
-                        value.user_value,
-                    )
-                }))?;
+            self.inner.ingest_batch(
+                delete_keys
+                    .into_iter()
+                    .map(|key| (FullKey::new(table_id, TableKey(key), epoch), None))
+                    .chain(kv_pairs.into_iter().map(|(key, value)| {
+                        size += key.len() + value.size();
+                        (
+                            FullKey::new(table_id, TableKey(key), epoch),
+                            value.user_value,
+                        )
+                    })),
+            )?;
             Ok(size)
         }
     }
@@ -724,6 +764,213 @@ mod tests {
         test_snapshot_isolation_inner(state_store).await;
     }
 
+    #[cfg(not(madsim))]
+    #[tokio::test]
+    async fn test_sled_state_store_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let state_store = SledStateStore::new(dir.path());
+            state_store
+                .ingest_batch(
+                    vec![
+                        (b"a".to_vec().into(), StorageValue::new_put(b"v1".to_vec())),
+                        (b"b".to_vec().into(), StorageValue::new_put(b"v1".to_vec())),
+                    ],
+                    vec![],
+                    WriteOptions {
+                        epoch: 1,
+                        table_id: Default::default(),
+                    },
+                )
+                .await
+                .unwrap();
+            state_store.sync(1).await.unwrap();
+        }
+
+        // The data synced before the store is dropped should survive a restart.
+        let state_store = SledStateStore::new(dir.path());
+        assert_eq!(
+            state_store
+                .get(b"a", 1, ReadOptions::default())
+                .await
+                .unwrap(),
+            Some(b"v1".to_vec().into())
+        );
+        assert_eq!(
+            state_store
+                .iter(
+                    (Bound::Unbounded, Bound::Unbounded),
+                    1,
+                    ReadOptions::default()
+                )
+                .await
+                .unwrap()
+                .collect(None)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_range_memory() {
+        let state_store = MemoryStateStore::new();
+        state_store
+            .ingest_batch(
+                vec![
+                    (b"a1".to_vec().into(), StorageValue::new_put(b"v1".to_vec())),
+                    (b"a2".to_vec().into(), StorageValue::new_put(b"v1".to_vec())),
+                    (b"b1".to_vec().into(), StorageValue::new_put(b"v1".to_vec())),
+                ],
+                vec![],
+                WriteOptions {
+                    epoch: 1,
+                    table_id: Default::default(),
+                },
+            )
+            .await
+            .unwrap();
+        state_store
+            .ingest_batch(
+                vec![(b"a2".to_vec().into(), StorageValue::new_put(b"v2".to_vec()))],
+                vec![(b"a".to_vec().into(), b"b".to_vec().into())],
+                WriteOptions {
+                    epoch: 2,
+                    table_id: Default::default(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let get = |key: &'static [u8], epoch| {
+            let state_store = state_store.clone();
+            async move {
+                state_store
+                    .get(key, epoch, ReadOptions::default())
+                    .await
+                    .unwrap()
+            }
+        };
+        assert_eq!(get(b"a1", 1).await, Some(b"v1".to_vec().into()));
+        assert_eq!(get(b"a1", 2).await, None);
+        assert_eq!(get(b"a2", 2).await, Some(b"v2".to_vec().into()));
+        assert_eq!(get(b"b1", 2).await, Some(b"v1".to_vec().into()));
+    }
+
+    #[tokio::test]
+    async fn test_iter_with_prefix_memory() {
+        let state_store = MemoryStateStore::new();
+        state_store
+            .ingest_batch(
+                vec![
+                    (b"a".to_vec().into(), StorageValue::new_put(b"v".to_vec())),
+                    (
+                        b"a\xff".to_vec().into(),
+                        StorageValue::new_put(b"v".to_vec()),
+                    ),
+                    (
+                        b"a\xff\x00".to_vec().into(),
+                        StorageValue::new_put(b"v".to_vec()),
+                    ),
+                    (b"b".to_vec().into(), StorageValue::new_put(b"v".to_vec())),
+                    (
+                        b"\xff".to_vec().into(),
+                        StorageValue::new_put(b"v".to_vec()),
+                    ),
+                ],
+                vec![],
+                WriteOptions {
+                    epoch: 1,
+                    table_id: Default::default(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let scan_prefix = |prefix: &'static [u8]| {
+            let state_store = state_store.clone();
+            async move {
+                state_store
+                    .iter_with_prefix(prefix, 1, ReadOptions::default())
+                    .await
+                    .unwrap()
+                    .collect(None)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|(key, _)| key.user_key.table_key.0)
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(
+            scan_prefix(b"a\xff").await,
+            vec![b"a\xff".to_vec(), b"a\xff\x00".to_vec()]
+        );
+        assert_eq!(scan_prefix(b"b").await, vec![b"b".to_vec()]);
+        assert_eq!(scan_prefix(b"\xff").await, vec![b"\xff".to_vec()]);
+        assert_eq!(scan_prefix(b"").await.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_vacuum_memory() {
+        let state_store = MemoryStateStore::new();
+        for (epoch, value) in [
+            (1, Some(b"v1")),
+            (2, Some(b"v2")),
+            (3, None),
+            (4, Some(b"v4")),
+        ] {
+            let a = match value {
+                Some(value) => StorageValue::new_put(value.to_vec()),
+                None => StorageValue::new_delete(),
+            };
+            state_store
+                .ingest_batch(
+                    vec![
+                        (b"a".to_vec().into(), a),
+                        (b"b".to_vec().into(), StorageValue::new_delete()),
+                    ],
+                    vec![],
+                    WriteOptions {
+                        epoch,
+                        table_id: Default::default(),
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        state_store.vacuum(2);
+        // "a" at epoch 2, 3, 4 and "b" at epoch 3, 4.
+        assert_eq!(state_store.inner.read().len(), 5);
+        assert_eq!(
+            state_store
+                .get(b"a", 2, ReadOptions::default())
+                .await
+                .unwrap(),
+            Some(b"v2".to_vec().into())
+        );
+
+        state_store.vacuum(3);
+        // "a" at epoch 4 and "b" at epoch 4.
+        assert_eq!(state_store.inner.read().len(), 2);
+        assert_eq!(
+            state_store
+                .get(b"a", 3, ReadOptions::default())
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            state_store
+                .get(b"a", 4, ReadOptions::default())
+                .await
+                .unwrap(),
+            Some(b"v4".to_vec().into())
+        );
+    }
+
     async fn test_snapshot_isolation_inner(state_store: RangeKvStateStore<impl RangeKv>) {
         state_store
             .ingest_batch(
@@ -35,6 +35,8 @@ pub struct StoreLocalStatistic {
     pub remote_io_time: Arc<AtomicU64>,
     pub bloom_filter_check_counts: u64,
     pub get_shared_buffer_hit_counts: u64,
+    // sstables skipped because their key range does not overlap with the read range.
+    pub sst_pruned_count: u64,
 
     #[cfg(all(debug_assertions, not(any(test, feature = "test"))))]
     reported: AtomicBool,
@@ -44,7 +46,7 @@ pub struct StoreLocalStatistic {
 
 impl StoreLocalStatistic {
     pub fn add(&mut self, other: &StoreLocalStatistic) {
-        self.cache_meta_block_miss += other.cache_data_block_miss;
+        self.cache_meta_block_miss += other.cache_meta_block_miss;
         self.cache_meta_block_total += other.cache_meta_block_total;
 
         self.cache_data_block_miss += other.cache_data_block_miss;
@@ -61,6 +63,7 @@ impl StoreLocalStatistic {
         self.bloom_filter_check_counts += other.bloom_filter_check_counts;
         self.total_key_count += other.total_key_count;
         self.get_shared_buffer_hit_counts += other.get_shared_buffer_hit_counts;
+        self.sst_pruned_count += other.sst_pruned_count;
 
         #[cfg(all(debug_assertions, not(any(test, feature = "test"))))]
         if other.added.fetch_or(true, Ordering::Relaxed) || other.reported.load(Ordering::Relaxed) {
@@ -140,6 +143,10 @@ impl StoreLocalStatistic {
                 .inc_by(self.get_shared_buffer_hit_counts);
         }
 
+        if self.sst_pruned_count > 0 {
+            metrics.sst_pruned_counts.inc_by(self.sst_pruned_count);
+        }
+
         if self.total_key_count > 0 {
             metrics
                 .iter_scan_key_counts
@@ -35,6 +35,7 @@ macro_rules! for_all_metrics {
 
             bloom_filter_true_negative_counts: GenericCounter<AtomicU64>,
             bloom_filter_check_counts: GenericCounter<AtomicU64>,
+            sst_pruned_counts: GenericCounter<AtomicU64>,
 
             range_scan_size: Histogram,
             range_scan_duration: Histogram,
@@ -152,6 +153,13 @@ impl StateStoreMetrics {
         )
         .unwrap();
 
+        let sst_pruned_counts = register_int_counter_with_registry!(
+            "state_store_sst_pruned_counts",
+            "Total number of sstables skipped by key range pruning in iter requests",
+            registry
+        )
+        .unwrap();
+
         // ----- range_scan -----
         let opts = histogram_opts!(
             "state_store_range_scan_size",
@@ -431,6 +439,7 @@ impl StateStoreMetrics {
 
             bloom_filter_true_negative_counts,
             bloom_filter_check_counts,
+            sst_pruned_counts,
 
             range_scan_size,
             range_scan_duration,
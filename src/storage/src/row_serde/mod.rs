@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bytes::Buf;
 use risingwave_common::catalog::{ColumnDesc, ColumnId};
-use risingwave_common::row::{Row, Row2, RowExt};
+use risingwave_common::row::{Row, Row2, RowDeserializer, RowExt};
+use risingwave_common::util::value_encoding;
 
 pub mod row_serde_util;
 
@@ -54,4 +56,14 @@ impl ColumnMapping {
     pub fn project(&self, origin_row: Row) -> Row {
         origin_row.project(&self.output_indices).into_owned_row()
     }
+
+    /// Deserialize a value-encoded row and project it with this mapping. Columns not in the output
+    /// are skipped instead of being deserialized.
+    pub fn deserialize(
+        &self,
+        deserializer: &RowDeserializer,
+        value: impl Buf,
+    ) -> value_encoding::Result<Row> {
+        deserializer.deserialize_projected(value, &self.output_indices)
+    }
 }
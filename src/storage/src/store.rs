@@ -19,7 +19,7 @@ use std::sync::Arc;
 use bytes::Bytes;
 use risingwave_common::catalog::TableId;
 use risingwave_common::util::epoch::Epoch;
-use risingwave_hummock_sdk::key::FullKey;
+use risingwave_hummock_sdk::key::{range_of_prefix, FullKey};
 use risingwave_hummock_sdk::{HummockReadEpoch, LocalSstableInfo};
 
 use crate::error::StorageResult;
@@ -137,6 +137,19 @@ pub trait StateStoreRead: StaticSendSync {
         epoch: u64,
         read_options: ReadOptions,
     ) -> Self::IterFuture<'_>;
+
+    /// Opens and returns an iterator over all keys starting with the given memcomparable-encoded
+    /// `prefix`, e.g. a group key or a join key. Keys are returned in order.
+    ///
+    /// The `prefix_hint` in `read_options`, if any, should be a prefix of `prefix`.
+    fn iter_with_prefix(
+        &self,
+        prefix: &[u8],
+        epoch: u64,
+        read_options: ReadOptions,
+    ) -> Self::IterFuture<'_> {
+        self.iter(range_of_prefix(prefix), epoch, read_options)
+    }
 }
 
 pub trait ScanFutureTrait<'a> =
@@ -320,7 +333,7 @@ pub fn gen_min_epoch(base_epoch: u64, retention_seconds: Option<&u32>) -> u64 {
     match retention_seconds {
         Some(retention_seconds_u32) => {
             base_epoch
-                .subtract_ms((retention_seconds_u32 * 1000) as u64)
+                .subtract_ms(*retention_seconds_u32 as u64 * 1000)
                 .0
         }
         None => 0,
@@ -220,6 +220,10 @@ impl<S: StateStore> StorageTable<S> {
     pub fn pk_indices(&self) -> &[usize] {
         &self.pk_indices
     }
+
+    pub fn pk_serializer(&self) -> &OrderedRowSerde {
+        &self.pk_serializer
+    }
 }
 
 /// Point get
@@ -261,8 +265,7 @@ impl<S: StateStore> StorageTable<S> {
             table_id: self.table_id,
         };
         if let Some(value) = self.store.get(&serialized_pk, epoch, read_options).await? {
-            let full_row = self.row_deserializer.deserialize(value)?;
-            let result_row = self.mapping.project(full_row);
+            let result_row = self.mapping.deserialize(&self.row_deserializer, value)?;
             Ok(Some(result_row))
         } else {
             Ok(None)
@@ -547,8 +550,7 @@ impl<S: StateStore> StorageTableIterInner<S> {
             .await?
         {
             let (_, key) = parse_raw_key_to_vnode_and_key(&raw_key);
-            let full_row = self.row_deserializer.deserialize(value)?;
-            let row = self.mapping.project(full_row);
+            let row = self.mapping.deserialize(&self.row_deserializer, value)?;
             yield (key.to_vec(), row)
         }
     }
@@ -222,7 +222,7 @@ pub(super) trait StreamingApproxCountDistinct: Sized {
             if zero_registers == 0.0 {
                 raw_estimate
             } else {
-                m * (m.log2() - (zero_registers.log2()))
+                m * (m.ln() - (zero_registers.ln()))
             }
         } else {
             raw_estimate
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp::Ordering;
 use std::ops::Bound;
 use std::sync::Arc;
 
@@ -25,6 +24,7 @@ use risingwave_common::array::{Op, Row, StreamChunk};
 use risingwave_common::buffer::BitmapBuilder;
 use risingwave_common::catalog::Schema;
 use risingwave_common::row::{Row2, RowExt};
+use risingwave_common::util::ordered::OrderedRowSerde;
 use risingwave_hummock_sdk::HummockReadEpoch;
 use risingwave_storage::table::batch_table::storage_table::StorageTable;
 use risingwave_storage::table::TableIter;
@@ -191,7 +191,12 @@ where
                                 for chunk in upstream_chunk_buffer.drain(..) {
                                     if let Some(current_pos) = self.current_pos.as_ref() {
                                         yield Message::Chunk(Self::mapping_chunk(
-                                            Self::mark_chunk(chunk, current_pos, &table_pk_indices),
+                                            Self::mark_chunk(
+                                                chunk,
+                                                current_pos,
+                                                &table_pk_indices,
+                                                self.table.pk_serializer(),
+                                            ),
                                             &upstream_indices,
                                         ));
                                     }
@@ -317,22 +322,26 @@ where
     /// Mark chunk:
     /// For each row of the chunk, forward it to downstream if its pk <= `current_pos`, otherwise
     /// ignore it. We implement it by changing the visibility bitmap.
+    ///
+    /// The pk is compared in its memcomparable form, so that the order types of the table pk (e.g.
+    /// descending columns) are respected in the same way as the snapshot read.
     fn mark_chunk(
         chunk: StreamChunk,
         current_pos: &Row,
         table_pk_indices: &PkIndices,
+        pk_serializer: &OrderedRowSerde,
     ) -> StreamChunk {
         let chunk = chunk.compact();
         let (data, ops) = chunk.into_parts();
         let mut new_visibility = BitmapBuilder::with_capacity(ops.len());
-        // Use project to avoid allocation.
-        for v in data.rows().map(|row| {
-            match row.project(table_pk_indices).iter().cmp(current_pos.iter()) {
-                Ordering::Less | Ordering::Equal => true,
-                Ordering::Greater => false,
-            }
-        }) {
-            new_visibility.append(v);
+        let mut current_pos_key = vec![];
+        pk_serializer.serialize(current_pos, &mut current_pos_key);
+        // Reuse the buffer and use project to avoid allocation.
+        let mut pk_key = vec![];
+        for row in data.rows() {
+            pk_key.clear();
+            pk_serializer.serialize(row.project(table_pk_indices), &mut pk_key);
+            new_visibility.append(pk_key <= current_pos_key);
         }
         let (columns, _) = data.into_parts();
         StreamChunk::new(ops, columns, Some(new_visibility.finish()))
@@ -375,3 +384,43 @@ where
         &self.info.identity
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_common::util::sort_util::OrderType;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+
+    #[test]
+    fn test_mark_chunk_descending_pk() {
+        let chunk = StreamChunk::from_pretty(
+            " I I
+            + 7 1
+            + 5 2
+            + 3 3",
+        );
+        // The snapshot of a table with a descending pk is read from 7 downwards, so the rows
+        // before and at the current position are 7 and 5.
+        let pk_serializer =
+            OrderedRowSerde::new(vec![DataType::Int64], vec![OrderType::Descending]);
+        let current_pos = Row::new(vec![Some(ScalarImpl::Int64(5))]);
+        let marked = BackfillExecutor::<MemoryStateStore>::mark_chunk(
+            chunk,
+            &current_pos,
+            &vec![0],
+            &pk_serializer,
+        );
+        assert_eq!(
+            marked,
+            StreamChunk::from_pretty(
+                " I I
+                + 7 1
+                + 5 2
+                + 3 3 D",
+            )
+        );
+    }
+}
@@ -612,22 +612,31 @@ impl Dispatcher for HashDataDispatcher {
                 }
 
                 if !visible {
+                    // The `UpdateDelete` is visible while its `UpdateInsert` is not.
+                    if op == Op::UpdateInsert && last_vnode_when_update_delete.take().is_some() {
+                        new_ops.push(Op::Delete);
+                    }
                     new_ops.push(op);
                     return;
                 }
 
                 // The 'update' message, noted by an `UpdateDelete` and a successive `UpdateInsert`,
                 // need to be rewritten to common `Delete` and `Insert` if they were dispatched to
-                // different actors.
+                // different actors, or if only one of them is visible.
                 if op == Op::UpdateDelete {
                     last_vnode_when_update_delete = Some(vnode);
                 } else if op == Op::UpdateInsert {
-                    if vnode != last_vnode_when_update_delete.unwrap() {
-                        new_ops.push(Op::Delete);
-                        new_ops.push(Op::Insert);
-                    } else {
-                        new_ops.push(Op::UpdateDelete);
-                        new_ops.push(Op::UpdateInsert);
+                    match last_vnode_when_update_delete.take() {
+                        Some(last_vnode) if last_vnode == vnode => {
+                            new_ops.push(Op::UpdateDelete);
+                            new_ops.push(Op::UpdateInsert);
+                        }
+                        Some(_) => {
+                            new_ops.push(Op::Delete);
+                            new_ops.push(Op::Insert);
+                        }
+                        // The `UpdateDelete` is invisible and has been pushed already.
+                        None => new_ops.push(Op::Insert),
                     }
                 } else {
                     new_ops.push(op);
@@ -652,6 +661,10 @@ impl Dispatcher for HashDataDispatcher {
                 }
             }
 
+            // An `UpdateDelete` at the end of the chunk without its `UpdateInsert`.
+            if last_vnode_when_update_delete.is_some() {
+                new_ops.push(Op::Delete);
+            }
             let ops = new_ops;
 
             // individually output StreamChunk integrated with vis_map
@@ -968,6 +981,39 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_hash_dispatcher_half_visible_update() {
+        // This test only works when VirtualNode::COUNT is 256.
+        static_assertions::const_assert_eq!(VirtualNode::COUNT, 256);
+
+        let output_data = Arc::new(Mutex::new(Vec::new()));
+        let outputs = vec![Box::new(MockOutput::new(1, output_data.clone())) as BoxedOutput];
+        let hash_mapping = vec![1; VirtualNode::COUNT];
+        let mut hash_dispatcher = HashDataDispatcher::new(outputs, vec![0], hash_mapping, 0);
+
+        let chunk = StreamChunk::from_pretty(
+            "  I I
+            U- 1 1 D
+            U+ 1 2
+            U- 2 1
+            U+ 2 2 D
+            U- 3 1",
+        );
+        hash_dispatcher.dispatch_data(chunk).await.unwrap();
+
+        assert_eq!(
+            *output_data.lock().unwrap()[0].as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I I
+                U- 1 1 D
+                +  1 2
+                -  2 1
+                U+ 2 2 D
+                -  3 1",
+            )
+        );
+    }
+
     #[tokio::test]
     async fn test_configuration_change() {
         let _schema = Schema { fields: vec![] };
@@ -37,8 +37,8 @@ use risingwave_pb::stream_plan::stream_message::StreamMessage;
 use risingwave_pb::stream_plan::update_mutation::{DispatcherUpdate, MergeUpdate};
 use risingwave_pb::stream_plan::{
     AddMutation, Barrier as ProstBarrier, Dispatcher as ProstDispatcher, PauseMutation,
-    ResumeMutation, SourceChangeSplitMutation, StopMutation, StreamMessage as ProstStreamMessage,
-    UpdateMutation, Watermark as ProstWatermark,
+    ResumeMutation, SourceChangeSplitMutation, SourceRateLimit, SourceRateLimitMutation,
+    StopMutation, StreamMessage as ProstStreamMessage, UpdateMutation, Watermark as ProstWatermark,
 };
 use smallvec::SmallVec;
 
@@ -212,6 +212,8 @@ pub enum Mutation {
     SourceChangeSplit(HashMap<ActorId, Vec<SplitImpl>>),
     Pause,
     Resume,
+    /// The new rate limits of sources, keyed by source id.
+    SourceRateLimit(HashMap<u32, SourceRateLimit>),
 }
 
 #[derive(Debug, Clone)]
@@ -395,6 +397,11 @@ impl Mutation {
             }
             Mutation::Pause => ProstMutation::Pause(PauseMutation {}),
             Mutation::Resume => ProstMutation::Resume(ResumeMutation {}),
+            Mutation::SourceRateLimit(limits) => {
+                ProstMutation::SourceRateLimit(SourceRateLimitMutation {
+                    source_rate_limits: limits.clone(),
+                })
+            }
         }
     }
 
@@ -480,6 +487,9 @@ impl Mutation {
             }
             ProstMutation::Pause(_) => Mutation::Pause,
             ProstMutation::Resume(_) => Mutation::Resume,
+            ProstMutation::SourceRateLimit(limits) => {
+                Mutation::SourceRateLimit(limits.source_rate_limits.clone())
+            }
         };
         Ok(mutation)
     }
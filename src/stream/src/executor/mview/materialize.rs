@@ -198,6 +198,12 @@ impl<S: StateStore> MaterializeExecutor<S> {
                             }
                         }
                         false => {
+                            // An insert and a delete of an identical row in the chunk have no
+                            // effect on the table, so neither is written nor emitted.
+                            let chunk = chunk.cancel_out();
+                            if chunk.cardinality() == 0 {
+                                continue;
+                            }
                             self.state_table.write_chunk(chunk.clone());
                             Message::Chunk(chunk)
                         }
@@ -630,6 +636,87 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_materialize_cancel_out() {
+        let memory_state_store = MemoryStateStore::new();
+        let table_id = TableId::new(1);
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let column_ids = vec![0.into(), 1.into()];
+
+        let chunk = StreamChunk::from_pretty(
+            " i i
+            + 1 4
+            + 2 5
+            - 1 4",
+        );
+        let source = MockSource::with_messages(
+            schema.clone(),
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+            ],
+        );
+
+        let column_descs = vec![
+            ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+            ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+        ];
+        let table = StorageTable::for_test(
+            memory_state_store.clone(),
+            table_id,
+            column_descs,
+            vec![OrderType::Ascending],
+            vec![0],
+        );
+
+        let mut materialize_executor = Box::new(
+            MaterializeExecutor::for_test(
+                Box::new(source),
+                memory_state_store,
+                table_id,
+                vec![OrderPair::new(0, OrderType::Ascending)],
+                column_ids,
+                1,
+                None,
+                0,
+                false,
+            )
+            .await,
+        )
+        .execute();
+        materialize_executor.next().await.transpose().unwrap();
+
+        // The insert and delete of (1, 4) cancel out.
+        match materialize_executor.next().await.transpose().unwrap() {
+            Some(Message::Chunk(chunk)) => assert_eq!(
+                chunk,
+                StreamChunk::from_pretty(
+                    " i i
+                    + 2 5"
+                )
+            ),
+            _ => unreachable!(),
+        }
+        match materialize_executor.next().await.transpose().unwrap() {
+            Some(Message::Barrier(_)) => {
+                let row = table
+                    .get_row(
+                        &Row::new(vec![Some(1_i32.into())]),
+                        HummockReadEpoch::NoWait(u64::MAX),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(row, None);
+            }
+            _ => unreachable!(),
+        }
+    }
+
     #[tokio::test]
     async fn test_check_insert_conflict() {
         // Prepare storage and memtable.
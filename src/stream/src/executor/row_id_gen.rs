@@ -72,10 +72,11 @@ impl RowIdGenExecutor {
         let mut builder = I64ArrayBuilder::new(len);
 
         for (datum, op) in column.array_ref().iter().zip_eq(ops) {
-            // Only refill row_id for insert operation.
-            match op {
-                Op::Insert => builder.append(Some(self.row_id_generator.next().await)),
-                _ => builder.append(Some(i64::try_from(datum.unwrap()).unwrap())),
+            // Only fill row_id for insert operation, unless it is already generated by the batch
+            // `InsertExecutor`.
+            match (op, datum) {
+                (Op::Insert, None) => builder.append(Some(self.row_id_generator.next().await)),
+                (_, datum) => builder.append(Some(i64::try_from(datum.unwrap()).unwrap())),
             }
         }
 
@@ -185,6 +186,23 @@ mod tests {
             assert!(row_id.is_some());
         });
 
+        // Insert operation with row id generated by the batch `InsertExecutor`
+        let chunk = StreamChunk::from_pretty(
+            "      I        I
+            + 32874283748  3",
+        );
+        tx.push_chunk(chunk);
+        let chunk: StreamChunk = row_id_gen_executor
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_chunk()
+            .unwrap();
+        let row_id_col: &PrimitiveArray<i64> = chunk.column_at(row_id_index).array_ref().into();
+        // Should keep the generated row id.
+        assert_eq!(row_id_col.value_at(0).unwrap(), 32874283748);
+
         // Update operation
         let chunk2 = StreamChunk::from_pretty(
             "      I        I
@@ -17,6 +17,7 @@ pub use source_executor::*;
 
 pub mod source_executor_v2;
 
+mod rate_limiter;
 mod reader;
 pub mod state_table_handler;
 
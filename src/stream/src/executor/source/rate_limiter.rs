@@ -0,0 +1,159 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use risingwave_pb::stream_plan::SourceRateLimit;
+use risingwave_source::StreamChunkWithState;
+use tokio::time::Instant;
+
+/// A token bucket refilled at `rate` tokens per second, holding up to `capacity` tokens.
+struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket holding `burst_ms` milliseconds of `rate`.
+    fn new(rate: u64, burst_ms: u64, now: Instant) -> Self {
+        let rate = rate as f64;
+        let capacity = rate * burst_ms as f64 / 1000.0;
+        Self {
+            rate,
+            capacity,
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    /// Takes `amount` tokens, going into debt if there are not enough of them. Returns the instant
+    /// at which the debt is paid off, if any.
+    fn take(&mut self, amount: u64, now: Instant) -> Option<Instant> {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.rate).min(self.capacity);
+        self.last_refill = now;
+
+        self.tokens -= amount as f64;
+        (self.tokens < 0.0).then(|| now + Duration::from_secs_f64(-self.tokens / self.rate))
+    }
+}
+
+/// Limits the rows and bytes read by a source executor with token buckets.
+///
+/// A chunk is never split or held back: it is charged after being read, and the reader is then
+/// throttled until the buckets are out of debt. The limits are given per split and scaled by the
+/// number of splits assigned to the executor.
+pub(super) struct SourceRateLimiter {
+    rows: Option<TokenBucket>,
+    bytes: Option<TokenBucket>,
+}
+
+impl SourceRateLimiter {
+    /// Returns `None` if `limit` does not limit anything.
+    pub fn new(limit: &SourceRateLimit, split_num: usize) -> Option<Self> {
+        let now = Instant::now();
+        // Table sources have no splits, but are read as a single one.
+        let split_num = split_num.max(1) as u64;
+        let bucket = |rate: u64| {
+            (rate > 0)
+                .then(|| TokenBucket::new(rate.saturating_mul(split_num), limit.burst_ms, now))
+        };
+
+        let limiter = Self {
+            rows: bucket(limit.rows_per_second),
+            bytes: bucket(limit.bytes_per_second),
+        };
+        (limiter.rows.is_some() || limiter.bytes.is_some()).then_some(limiter)
+    }
+
+    /// Charges `chunk` to the buckets, by its rows and the size of its payload. Returns the instant
+    /// until which the source must not be read, if any.
+    pub fn consume(&mut self, chunk: &StreamChunkWithState) -> Option<Instant> {
+        let now = Instant::now();
+        let rows = self
+            .rows
+            .as_mut()
+            .and_then(|rows| rows.take(chunk.chunk.cardinality() as u64, now));
+        let bytes = self
+            .bytes
+            .as_mut()
+            .and_then(|bytes| bytes.take(chunk.payload_size as u64, now));
+        // `None` is less than any `Some`.
+        rows.max(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Op, StreamChunk};
+
+    use super::*;
+
+    fn rate_limit(rows_per_second: u64, bytes_per_second: u64, burst_ms: u64) -> SourceRateLimit {
+        SourceRateLimit {
+            rows_per_second,
+            bytes_per_second,
+            burst_ms,
+        }
+    }
+
+    #[test]
+    fn test_token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(100, 500, start);
+
+        // A full bucket allows a burst of 50 tokens.
+        assert_eq!(bucket.take(50, start), None);
+        // Then the debt of 10 tokens is paid off in 100ms.
+        assert_eq!(
+            bucket.take(10, start),
+            Some(start + Duration::from_millis(100))
+        );
+
+        // Refilled with 60 tokens in 600ms, minus the debt.
+        let now = start + Duration::from_millis(600);
+        assert_eq!(bucket.take(50, now), None);
+        assert_eq!(bucket.take(1, now), Some(now + Duration::from_millis(10)));
+
+        // The bucket never holds more than its capacity.
+        let now = now + Duration::from_secs(10);
+        assert_eq!(bucket.take(50, now), None);
+        assert!(bucket.take(1, now).is_some());
+    }
+
+    #[test]
+    fn test_source_rate_limiter() {
+        assert!(SourceRateLimiter::new(&rate_limit(0, 0, 1000), 4).is_none());
+
+        // 4 splits at 10 rows per second each, without bursts.
+        let mut limiter = SourceRateLimiter::new(&rate_limit(10, 0, 0), 4).unwrap();
+        let chunk: StreamChunkWithState =
+            StreamChunk::new(vec![Op::Insert; 20], vec![], None).into();
+        let now = Instant::now();
+        let deadline = limiter.consume(&chunk).unwrap();
+        assert!(deadline >= now + Duration::from_millis(500));
+        assert!(deadline <= Instant::now() + Duration::from_millis(500));
+
+        // Chunks are charged by the size of their payload.
+        let mut limiter = SourceRateLimiter::new(&rate_limit(0, 100, 0), 1).unwrap();
+        let mut chunk = StreamChunkWithState::from(StreamChunk::default());
+        assert_eq!(limiter.consume(&chunk), None);
+        chunk.payload_size = 50;
+        let now = Instant::now();
+        let deadline = limiter.consume(&chunk).unwrap();
+        assert!(deadline >= now + Duration::from_millis(500));
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::future::Future;
 use std::pin::Pin;
 use std::task::Poll;
 
@@ -21,9 +22,12 @@ use futures::stream::{select_with_strategy, BoxStream, PollNext, SelectWithStrat
 use futures::{Stream, StreamExt, TryStreamExt};
 use futures_async_stream::try_stream;
 use risingwave_common::bail;
+use risingwave_pb::stream_plan::SourceRateLimit;
 use risingwave_source::*;
 use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::time::Sleep;
 
+use super::rate_limiter::SourceRateLimiter;
 use crate::executor::error::{StreamExecutorError, StreamExecutorResult};
 use crate::executor::Barrier;
 
@@ -36,6 +40,10 @@ pub(super) struct SourceReaderStream {
     inner: SourceReaderStreamInner,
     /// Whether the source stream is paused.
     paused: bool,
+    /// The rate limiter of the source stream, or `None` if unlimited.
+    rate_limiter: Option<SourceRateLimiter>,
+    /// Set if the source stream is throttled by the rate limiter, until the sleep completes.
+    throttle: Option<Pin<Box<Sleep>>>,
 }
 
 impl SourceReaderStream {
@@ -79,6 +87,8 @@ impl SourceReaderStream {
                     .boxed(),
             ),
             paused: false,
+            rate_limiter: None,
+            throttle: None,
         }
     }
 
@@ -123,6 +133,13 @@ impl SourceReaderStream {
         assert!(self.paused, "not paused");
         self.paused = false;
     }
+
+    /// Set the rate limit of the source stream, given `split_num` splits are being read. The
+    /// buckets start full, and a throttled source stream is released at once.
+    pub fn set_rate_limit(&mut self, limit: &SourceRateLimit, split_num: usize) {
+        self.rate_limiter = SourceRateLimiter::new(limit, split_num);
+        self.throttle = None;
+    }
 }
 
 impl Stream for SourceReaderStream {
@@ -132,11 +149,27 @@ impl Stream for SourceReaderStream {
         mut self: Pin<&mut Self>,
         ctx: &mut std::task::Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        if self.paused {
-            self.inner.get_mut().0.poll_next_unpin(ctx)
-        } else {
-            self.inner.poll_next_unpin(ctx)
+        if let Some(throttle) = &mut self.throttle {
+            if throttle.as_mut().poll(ctx).is_ready() {
+                self.throttle = None;
+            }
+        }
+
+        // Barriers are still received when the source stream is paused or throttled.
+        if self.paused || self.throttle.is_some() {
+            return self.inner.get_mut().0.poll_next_unpin(ctx);
         }
+
+        let this = &mut *self;
+        let poll = this.inner.poll_next_unpin(ctx);
+        if let (Poll::Ready(Some(Ok(Either::Right(chunk)))), Some(rate_limiter)) =
+            (&poll, &mut this.rate_limiter)
+        {
+            this.throttle = rate_limiter
+                .consume(chunk)
+                .map(|deadline| Box::pin(tokio::time::sleep_until(deadline)));
+        }
+        poll
     }
 }
 
@@ -144,7 +177,7 @@ impl Stream for SourceReaderStream {
 mod tests {
     use assert_matches::assert_matches;
     use futures::{pin_mut, FutureExt};
-    use risingwave_common::array::StreamChunk;
+    use risingwave_common::array::{Op, StreamChunk};
     use tokio::sync::mpsc;
 
     use super::*;
@@ -198,4 +231,52 @@ mod tests {
         // Then we can receive the chunk sent when the stream is paused.
         assert_matches!(next!().unwrap(), Either::Right(_));
     }
+
+    #[tokio::test]
+    async fn test_rate_limit() {
+        let (barrier_tx, barrier_rx) = mpsc::unbounded_channel();
+
+        let table_source = TableSource::new(vec![]);
+        let source_stream = table_source
+            .stream_reader(vec![])
+            .await
+            .unwrap()
+            .into_stream();
+
+        let stream = SourceReaderStream::new(barrier_rx, source_stream);
+        pin_mut!(stream);
+
+        macro_rules! next {
+            () => {
+                stream
+                    .next()
+                    .now_or_never()
+                    .flatten()
+                    .map(|result| result.unwrap())
+            };
+        }
+
+        // Read at most 1 row per second without bursts.
+        let rate_limit = SourceRateLimit {
+            rows_per_second: 1,
+            bytes_per_second: 0,
+            burst_ms: 0,
+        };
+        stream.set_rate_limit(&rate_limit, 1);
+
+        // The first chunk is read at once, then the stream is throttled for 100 seconds.
+        let chunk = || StreamChunk::new(vec![Op::Insert; 100], vec![], None);
+        table_source.write_chunk(chunk()).unwrap();
+        table_source.write_chunk(chunk()).unwrap();
+        assert_matches!(next!().unwrap(), Either::Right(_));
+
+        // Barriers pass the throttled stream, but chunks don't.
+        barrier_tx.send(Barrier::new_test_barrier(1)).unwrap();
+        assert_matches!(next!().unwrap(), Either::Left(_));
+        assert!(next!().is_none());
+
+        // Lifting the rate limit releases the stream.
+        stream.set_rate_limit(&SourceRateLimit::default(), 1);
+        assert_matches!(next!().unwrap(), Either::Right(_));
+    }
 }
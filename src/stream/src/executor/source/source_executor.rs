@@ -24,6 +24,7 @@ use risingwave_common::array::{ArrayBuilder, I64ArrayBuilder, Op, StreamChunk};
 use risingwave_common::catalog::{ColumnId, Schema, TableId};
 use risingwave_common::util::epoch::UNIX_SINGULARITY_DATE_EPOCH;
 use risingwave_connector::source::{ConnectorState, SplitId, SplitImpl, SplitMetaData};
+use risingwave_pb::stream_plan::SourceRateLimit;
 use risingwave_source::connector_source::SourceContext;
 use risingwave_source::row_id::RowIdGenerator;
 use risingwave_source::*;
@@ -74,6 +75,9 @@ pub struct SourceExecutor<S: StateStore> {
 
     /// Expected barrier latency
     expected_barrier_latency_ms: u64,
+
+    /// Rate limit per split, changed by `Mutation::SourceRateLimit`.
+    rate_limit: SourceRateLimit,
 }
 
 impl<S: StateStore> SourceExecutor<S> {
@@ -94,6 +98,7 @@ impl<S: StateStore> SourceExecutor<S> {
         _op_info: String,
         streaming_metrics: Arc<StreamingMetrics>,
         expected_barrier_latency_ms: u64,
+        rate_limit: SourceRateLimit,
     ) -> StreamResult<Self> {
         // Using vnode range start for row id generator.
         let vnode_id = vnodes.next_set_bit(0).unwrap_or(0);
@@ -117,6 +122,7 @@ impl<S: StateStore> SourceExecutor<S> {
             split_state_store: state_table,
             state_cache: HashMap::new(),
             expected_barrier_latency_ms,
+            rate_limit,
         })
     }
 
@@ -313,6 +319,7 @@ impl<S: StateStore> SourceExecutor<S> {
 
         // Merge the chunks from source and the barriers into a single stream.
         let mut stream = SourceReaderStream::new(barrier_receiver, source_chunk_reader);
+        stream.set_rate_limit(&self.rate_limit, self.stream_source_splits.len());
         if start_with_paused {
             stream.pause_source();
         }
@@ -343,6 +350,22 @@ impl<S: StateStore> SourceExecutor<S> {
                             }
                             Mutation::Pause => stream.pause_source(),
                             Mutation::Resume => stream.resume_source(),
+                            Mutation::SourceRateLimit(rate_limits) => {
+                                if let Some(rate_limit) =
+                                    rate_limits.get(&self.source_id.table_id())
+                                {
+                                    tracing::info!(
+                                        actor_id = self.ctx.id,
+                                        rate_limit = ?rate_limit,
+                                        "set source rate limit"
+                                    );
+                                    self.rate_limit = rate_limit.clone();
+                                    stream.set_rate_limit(
+                                        &self.rate_limit,
+                                        self.stream_source_splits.len(),
+                                    );
+                                }
+                            }
                             Mutation::Update {
                                 vnode_bitmaps,
                                 actor_splits,
@@ -376,6 +399,7 @@ impl<S: StateStore> SourceExecutor<S> {
                 Either::Right(StreamChunkWithState {
                     mut chunk,
                     split_offset_mapping,
+                    ..
                 }) => {
                     if last_barrier_time.elapsed().as_millis() > max_wait_barrier_time_ms {
                         // Exceeds the max wait barrier time, the source will be paused. Currently
@@ -474,6 +498,8 @@ impl<S: StateStore> SourceExecutor<S> {
             .build_stream_source_reader(source_desc, Some(target_state.clone()))
             .await?;
         stream.replace_source_stream(reader);
+        // The rate limit is per split.
+        stream.set_rate_limit(&self.rate_limit, target_state.len());
 
         self.stream_source_splits = target_state;
 
@@ -604,6 +630,7 @@ mod tests {
             "SourceExecutor".to_string(),
             Arc::new(StreamingMetrics::new(prometheus::Registry::new())),
             u64::MAX,
+            SourceRateLimit::default(),
         )
         .unwrap();
         let mut executor = Box::new(executor).execute();
@@ -708,6 +735,7 @@ mod tests {
             "SourceExecutor".to_string(),
             Arc::new(StreamingMetrics::unused()),
             u64::MAX,
+            SourceRateLimit::default(),
         )
         .unwrap();
         let mut executor = Box::new(executor).execute();
@@ -838,6 +866,7 @@ mod tests {
             "SourceExecutor".to_string(),
             Arc::new(StreamingMetrics::unused()),
             u64::MAX,
+            SourceRateLimit::default(),
         )
         .unwrap();
 
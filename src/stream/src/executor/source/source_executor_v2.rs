@@ -19,6 +19,7 @@ use futures::StreamExt;
 use futures_async_stream::try_stream;
 use risingwave_common::catalog::{ColumnId, TableId};
 use risingwave_connector::source::{ConnectorState, SplitId, SplitMetaData};
+use risingwave_pb::stream_plan::SourceRateLimit;
 use risingwave_source::connector_source::{SourceContext, SourceDescBuilderV2, SourceDescV2};
 use risingwave_source::{BoxSourceWithStateStream, StreamChunkWithState};
 use risingwave_storage::StateStore;
@@ -79,6 +80,9 @@ pub struct SourceExecutorV2<S: StateStore> {
 
     /// Expected barrier latency.
     expected_barrier_latency_ms: u64,
+
+    /// Rate limit per split, changed by `Mutation::SourceRateLimit`.
+    rate_limit: SourceRateLimit,
 }
 
 impl<S: StateStore> SourceExecutorV2<S> {
@@ -92,6 +96,7 @@ impl<S: StateStore> SourceExecutorV2<S> {
         barrier_receiver: UnboundedReceiver<Barrier>,
         expected_barrier_latency_ms: u64,
         executor_id: u64,
+        rate_limit: SourceRateLimit,
     ) -> Self {
         Self {
             ctx,
@@ -102,6 +107,7 @@ impl<S: StateStore> SourceExecutorV2<S> {
             metrics,
             barrier_receiver: Some(barrier_receiver),
             expected_barrier_latency_ms,
+            rate_limit,
         }
     }
 
@@ -205,6 +211,8 @@ impl<S: StateStore> SourceExecutorV2<S> {
             .build_stream_source_reader(source_desc, Some(target_state.clone()))
             .await?;
         stream.replace_source_stream(reader);
+        // The rate limit is per split.
+        stream.set_rate_limit(&self.rate_limit, target_state.len());
 
         self.stream_source_core
             .as_mut()
@@ -308,6 +316,14 @@ impl<S: StateStore> SourceExecutorV2<S> {
 
         // Merge the chunks from source and the barriers into a single stream.
         let mut stream = SourceReaderStream::new(barrier_receiver, source_chunk_reader);
+        stream.set_rate_limit(
+            &self.rate_limit,
+            self.stream_source_core
+                .as_ref()
+                .unwrap()
+                .stream_source_splits
+                .len(),
+        );
 
         // If the first barrier is configuration change, then the source executor must be newly
         // created, and we should start with the paused state.
@@ -342,6 +358,22 @@ impl<S: StateStore> SourceExecutorV2<S> {
                             }
                             Mutation::Pause => stream.pause_source(),
                             Mutation::Resume => stream.resume_source(),
+                            Mutation::SourceRateLimit(rate_limits) => {
+                                let core = self.stream_source_core.as_ref().unwrap();
+                                if let Some(rate_limit) = rate_limits.get(&core.table_id.table_id())
+                                {
+                                    tracing::info!(
+                                        actor_id = self.ctx.id,
+                                        rate_limit = ?rate_limit,
+                                        "set source rate limit"
+                                    );
+                                    self.rate_limit = rate_limit.clone();
+                                    stream.set_rate_limit(
+                                        &self.rate_limit,
+                                        core.stream_source_splits.len(),
+                                    );
+                                }
+                            }
                             Mutation::Update { actor_splits, .. } => {
                                 self.apply_split_change(&source_desc, &mut stream, actor_splits)
                                     .await?;
@@ -358,6 +390,7 @@ impl<S: StateStore> SourceExecutorV2<S> {
                 Either::Right(StreamChunkWithState {
                     chunk,
                     split_offset_mapping,
+                    ..
                 }) => {
                     if last_barrier_time.elapsed().as_millis() > max_wait_barrier_time_ms {
                         // Exceeds the max wait barrier time, the source will be paused. Currently
@@ -557,6 +590,7 @@ mod tests {
             barrier_rx,
             u64::MAX,
             1,
+            SourceRateLimit::default(),
         );
         let mut executor = Box::new(executor).execute();
 
@@ -649,6 +683,7 @@ mod tests {
             barrier_rx,
             u64::MAX,
             1,
+            SourceRateLimit::default(),
         );
 
         let mut materialize = MaterializeExecutor::for_test(
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use futures::StreamExt;
+use global_stats_alloc::operator_allocation_stat;
 use risingwave_common::catalog::Schema;
 
 use super::monitor::StreamingMetrics;
@@ -128,11 +129,14 @@ impl WrapperExecutor {
         extra: ExtraInfo,
         stream: impl MessageStream + 'static,
     ) -> BoxedMessageStream {
-        if cfg!(debug_assertions) {
+        let identity = info.identity.clone();
+        let stream = if cfg!(debug_assertions) {
             Self::wrap_debug(enable_executor_row_count, info, extra, stream).boxed()
         } else {
             Self::wrap_release(enable_executor_row_count, info, extra, stream).boxed()
-        }
+        };
+        // Count the heap memory allocated by the executor, under the counter of its actor.
+        operator_allocation_stat(stream, identity).boxed()
     }
 }
 
@@ -59,7 +59,7 @@ impl ExecutorBuilder for GroupTopNExecutorBuilder {
             group_by,
             state_table,
             lru_manager: stream.context.lru_manager.clone(),
-            cache_size: 1 << 16,
+            cache_size: stream.config.developer.unsafe_stream_group_top_n_cache_size,
             with_ties: node.with_ties,
             group_key_types,
         };
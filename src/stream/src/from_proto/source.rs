@@ -14,7 +14,7 @@
 
 use risingwave_common::catalog::{ColumnId, Field, Schema, TableId};
 use risingwave_common::types::DataType;
-use risingwave_pb::stream_plan::SourceNode;
+use risingwave_pb::stream_plan::{SourceNode, SourceRateLimit};
 use risingwave_source::SourceDescBuilder;
 use tokio::sync::mpsc::unbounded_channel;
 
@@ -74,6 +74,13 @@ impl ExecutorBuilder for SourceExecutorBuilder {
             .vnode_bitmap
             .expect("vnodes not set for source executor");
 
+        let developer_config = &stream.config.developer;
+        let rate_limit = SourceRateLimit {
+            rows_per_second: developer_config.stream_source_rate_limit_rows_per_second,
+            bytes_per_second: developer_config.stream_source_rate_limit_bytes_per_second,
+            burst_ms: developer_config.stream_source_rate_limit_burst_ms,
+        };
+
         let state_table_handler =
             SourceStateTableHandler::from_table_catalog(node.state_table.as_ref().unwrap(), store)
                 .await;
@@ -94,6 +101,7 @@ impl ExecutorBuilder for SourceExecutorBuilder {
             params.op_info,
             params.executor_stats,
             stream.config.barrier_interval_ms as u64,
+            rate_limit,
         )?))
     }
 }
@@ -71,6 +71,9 @@ pub struct LocalBarrierManager {
 
     /// Save collect `CompleteReceiver`.
     collect_complete_receiver: HashMap<u64, CompleteReceiver>,
+
+    /// The epoch of the last barrier collected from each actor.
+    collected_epochs: HashMap<ActorId, u64>,
 }
 
 /// Information used after collection.
@@ -90,6 +93,7 @@ impl LocalBarrierManager {
             span: tracing::Span::none(),
             state,
             collect_complete_receiver: HashMap::default(),
+            collected_epochs: HashMap::default(),
         }
     }
 
@@ -216,6 +220,7 @@ impl LocalBarrierManager {
     /// When a [`StreamConsumer`] (typically [`DispatchExecutor`]) get a barrier, it should report
     /// and collect this barrier with its own `actor_id` using this function.
     pub fn collect(&mut self, actor_id: ActorId, barrier: &Barrier) {
+        self.collected_epochs.insert(actor_id, barrier.epoch.curr);
         match &mut self.state {
             #[cfg(test)]
             BarrierState::Local => {}
@@ -226,6 +231,18 @@ impl LocalBarrierManager {
         }
     }
 
+    /// Returns the epoch of the last barrier collected from the actor.
+    pub fn collected_epoch(&self, actor_id: ActorId) -> Option<u64> {
+        self.collected_epochs.get(&actor_id).copied()
+    }
+
+    /// Forget the barrier progress of the actors, after they are dropped.
+    pub fn remove_collected_epochs(&mut self, actor_ids: impl IntoIterator<Item = ActorId>) {
+        for actor_id in actor_ids {
+            self.collected_epochs.remove(&actor_id);
+        }
+    }
+
     /// When a actor exit unexpectedly, it should report this event using this function, so meta
     /// will notice actor's exit while collecting.
     pub fn notify_failure(&mut self, actor_id: ActorId, err: StreamError) {
@@ -58,7 +58,9 @@ async fn test_managed_barrier_collection() -> StreamResult<()> {
 
     // Report to local barrier manager
     for (i, (actor_id, barrier)) in collected_barriers.into_iter().enumerate() {
+        assert_eq!(manager.collected_epoch(actor_id), None);
         manager.collect(actor_id, &barrier);
+        assert_eq!(manager.collected_epoch(actor_id), Some(epoch));
         let notified = complete_receiver
             .complete_receiver
             .as_mut()
@@ -68,6 +70,10 @@ async fn test_managed_barrier_collection() -> StreamResult<()> {
         assert_eq!(notified, i == count - 1);
     }
 
+    manager.remove_collected_epochs([233]);
+    assert_eq!(manager.collected_epoch(233), None);
+    assert_eq!(manager.collected_epoch(234), Some(epoch));
+
     Ok(())
 }
 
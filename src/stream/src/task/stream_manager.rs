@@ -15,11 +15,13 @@
 use core::time::Duration;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
 use async_recursion::async_recursion;
 use async_stack_trace::{StackTraceManager, StackTraceReport, TraceConfig};
+use global_stats_alloc::{allocation_stat, TaskLocalBytesAllocated};
 use itertools::Itertools;
 use risingwave_common::bail;
 use risingwave_common::buffer::Bitmap;
@@ -28,9 +30,11 @@ use risingwave_common::config::StreamingConfig;
 use risingwave_common::util::addr::HostAddr;
 use risingwave_hummock_sdk::LocalSstableInfo;
 use risingwave_pb::common::ActorInfo;
+use risingwave_pb::meta::heartbeat_request::extra_info::Info;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::StreamNode;
 use risingwave_pb::{stream_plan, stream_service};
+use risingwave_rpc_client::ExtraInfoSource;
 use risingwave_storage::{dispatch_state_store, StateStore, StateStoreImpl};
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
@@ -64,9 +68,18 @@ pub struct LocalStreamManagerCore {
     /// Stores all actor information, taken after actor built.
     actors: HashMap<ActorId, stream_plan::StreamActor>,
 
+    /// Fragment ids of the built actors.
+    actor_fragments: HashMap<ActorId, FragmentId>,
+
     /// Stores all actor tokio runtime monitoring tasks.
     actor_monitor_tasks: HashMap<ActorId, ActorHandle>,
 
+    /// The heap memory allocated by each actor and not freed yet.
+    actor_memory_usage: Arc<parking_lot::Mutex<HashMap<ActorId, TaskLocalBytesAllocated>>>,
+
+    /// The number of actors whose future is alive, reported to meta via heartbeat.
+    running_actor_count: Arc<AtomicUsize>,
+
     /// The state store implement
     state_store: StateStoreImpl,
 
@@ -88,6 +101,17 @@ pub struct LocalStreamManager {
     state_store: StateStoreImpl,
     context: Arc<SharedContext>,
     streaming_metrics: Arc<StreamingMetrics>,
+    actor_memory_usage: Arc<parking_lot::Mutex<HashMap<ActorId, TaskLocalBytesAllocated>>>,
+    running_actor_count: Arc<AtomicUsize>,
+}
+
+/// Status of a built actor on this compute node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActorStatus {
+    pub actor_id: ActorId,
+    pub fragment_id: FragmentId,
+    /// The epoch of the last barrier collected from the actor, if any.
+    pub collected_epoch: Option<u64>,
 }
 
 pub struct ExecutorParams {
@@ -144,6 +168,8 @@ impl LocalStreamManager {
             state_store: core.state_store.clone(),
             context: core.context.clone(),
             streaming_metrics: core.streaming_metrics.clone(),
+            actor_memory_usage: core.actor_memory_usage.clone(),
+            running_actor_count: core.running_actor_count.clone(),
             core: Mutex::new(core),
         }
     }
@@ -200,6 +226,11 @@ impl LocalStreamManager {
         }
     }
 
+    /// Get the counters of the heap memory allocated by each actor and its operators.
+    pub fn get_actor_memory_usage(&self) -> HashMap<ActorId, TaskLocalBytesAllocated> {
+        self.actor_memory_usage.lock().clone()
+    }
+
     /// Broadcast a barrier to all senders. Save a receiver in barrier manager
     pub fn send_barrier(
         &self,
@@ -354,6 +385,21 @@ impl LocalStreamManager {
         core.build_actors(actors, env).await
     }
 
+    /// Get the status of all built actors, ordered by actor id.
+    pub async fn get_actor_status(&self) -> Vec<ActorStatus> {
+        let core = self.core.lock().await;
+        let barrier_manager = self.context.lock_barrier_manager();
+        core.actor_fragments
+            .iter()
+            .map(|(&actor_id, &fragment_id)| ActorStatus {
+                actor_id,
+                fragment_id,
+                collected_epoch: barrier_manager.collected_epoch(actor_id),
+            })
+            .sorted_by_key(|status| status.actor_id)
+            .collect()
+    }
+
     pub async fn config(&self) -> StreamingConfig {
         let core = self.core.lock().await;
         core.config.clone()
@@ -368,6 +414,31 @@ fn update_upstreams(context: &SharedContext, ids: &[UpDownActorIds]) {
         .count();
 }
 
+/// Reports the number of running actors to meta via heartbeat.
+#[async_trait::async_trait]
+impl ExtraInfoSource for LocalStreamManager {
+    async fn get_extra_info(&self) -> Option<Info> {
+        let running_actor_count = self.running_actor_count.load(Ordering::Relaxed);
+        Some(Info::RunningActorCount(running_actor_count as u32))
+    }
+}
+
+/// Counts an actor as running until its future completes or is dropped.
+struct RunningActorGuard(Arc<AtomicUsize>);
+
+impl RunningActorGuard {
+    fn new(running_actor_count: Arc<AtomicUsize>) -> Self {
+        running_actor_count.fetch_add(1, Ordering::Relaxed);
+        Self(running_actor_count)
+    }
+}
+
+impl Drop for RunningActorGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
 impl LocalStreamManagerCore {
     fn new(
         addr: HostAddr,
@@ -412,7 +483,10 @@ impl LocalStreamManagerCore {
             handles: HashMap::new(),
             context: Arc::new(context),
             actors: HashMap::new(),
+            actor_fragments: HashMap::new(),
             actor_monitor_tasks: HashMap::new(),
+            actor_memory_usage: Default::default(),
+            running_actor_count: Default::default(),
             state_store,
             streaming_metrics,
             config,
@@ -618,6 +692,7 @@ impl LocalStreamManagerCore {
                 .await?;
 
             let dispatcher = self.create_dispatcher(executor, &actor.dispatcher, actor_id)?;
+            self.actor_fragments.insert(actor_id, actor.fragment_id);
             let actor = Actor::new(
                 dispatcher,
                 subtasks,
@@ -634,7 +709,9 @@ impl LocalStreamManagerCore {
 
             let handle = {
                 let context = self.context.clone();
+                let running_actor = RunningActorGuard::new(self.running_actor_count.clone());
                 let actor = async move {
+                    let _running_actor = running_actor;
                     if let Err(err) = actor.run().await {
                         // TODO: check error type and panic if it's unexpected.
                         tracing::error!(actor=%actor_id, error=%err, "actor exit");
@@ -650,7 +727,11 @@ impl LocalStreamManagerCore {
                     ),
                     None => actor,
                 };
-                let instrumented = monitor.instrument(traced);
+                let bytes = TaskLocalBytesAllocated::new();
+                self.actor_memory_usage
+                    .lock()
+                    .insert(actor_id, bytes.clone());
+                let instrumented = monitor.instrument(allocation_stat(traced, bytes));
                 self.runtime.spawn(instrumented)
             };
             self.handles.insert(actor_id, handle);
@@ -754,8 +835,13 @@ impl LocalStreamManagerCore {
         self.actor_monitor_tasks
             .remove(&actor_id)
             .inspect(|handle| handle.abort());
+        self.actor_memory_usage.lock().remove(&actor_id);
         self.context.actor_infos.write().remove(&actor_id);
         self.actors.remove(&actor_id);
+        self.actor_fragments.remove(&actor_id);
+        self.context
+            .lock_barrier_manager()
+            .remove_collected_epochs([actor_id]);
         // Task should have already stopped when this method is invoked.
         self.handles
             .remove(&actor_id)
@@ -769,11 +855,15 @@ impl LocalStreamManagerCore {
             handle.abort();
         }
         self.actors.clear();
+        self.context
+            .lock_barrier_manager()
+            .remove_collected_epochs(self.actor_fragments.drain().map(|(actor_id, _)| actor_id));
         self.context.clear_channels();
         if let Some((stack_trace_manager, _)) = self.stack_trace_manager.as_mut() {
             std::mem::take(stack_trace_manager);
         }
         self.actor_monitor_tasks.clear();
+        self.actor_memory_usage.lock().clear();
         self.context.actor_infos.write().clear();
     }
 
@@ -15,8 +15,9 @@
 #![cfg_attr(not(madsim), allow(dead_code))]
 #![feature(once_cell)]
 
+use std::borrow::Cow;
 use std::path::Path;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::Duration;
 
 use clap::Parser;
@@ -105,6 +106,13 @@ pub struct Args {
     /// test data.
     #[clap(long)]
     sqlsmith: Option<usize>,
+
+    /// Round the floating-point columns of both the query results and the expected results to the
+    /// given number of decimal places, with trailing zeros removed, before comparing them.
+    ///
+    /// Currently only available when `-j` is not set.
+    #[clap(long)]
+    float_digits: Option<usize>,
 }
 
 static ARGS: LazyLock<Args> = LazyLock::new(Args::parse);
@@ -410,6 +418,7 @@ async fn run_slt_task(glob: &str, host: &str) {
         .simple_query("SET CREATE_COMPACTION_GROUP_FOR_MV TO true;")
         .await
         .expect("failed to set");
+    let float_columns = risingwave.float_columns.clone();
     let mut tester = sqllogictest::Runner::new(risingwave);
     let files = glob::glob(glob).expect("failed to read glob pattern");
     for file in files {
@@ -428,6 +437,7 @@ async fn run_slt_task(glob: &str, host: &str) {
             if let sqllogictest::Record::Halt { .. } = record {
                 break;
             }
+            let record = prepare_float_rounding(record, &float_columns);
             let (is_create, is_drop, is_write) =
                 if let sqllogictest::Record::Statement { sql, .. } = &record {
                     let sql =
@@ -539,11 +549,87 @@ fn hack_kafka_test(path: &Path) -> tempfile::NamedTempFile {
     file
 }
 
+/// Flags of the columns of the running query with floating-point results, which are rounded
+/// according to `--float-digits`. It's shared between the test runner and the connection.
+type FloatColumns = Arc<Mutex<Vec<bool>>>;
+
+/// Rounds the floating-point columns in the expected results of a query record, and marks them to
+/// be rounded in the actual results as well.
+fn prepare_float_rounding(
+    mut record: sqllogictest::Record,
+    float_columns: &FloatColumns,
+) -> sqllogictest::Record {
+    let columns = match (&mut record, ARGS.float_digits) {
+        (
+            sqllogictest::Record::Query {
+                type_string,
+                expected_results,
+                ..
+            },
+            Some(digits),
+        ) => {
+            let columns: Vec<bool> = type_string.chars().map(|c| c == 'R').collect();
+            *expected_results = expected_results
+                .lines()
+                .map(|line| round_expected_row(line, &columns, digits))
+                .collect::<Vec<_>>()
+                .join("\n");
+            columns
+        }
+        _ => vec![],
+    };
+    *float_columns.lock().unwrap() = columns;
+    record
+}
+
+/// Rounds the floating-point columns of an expected row. The row is left as is if its number of
+/// values doesn't match the columns, e.g., when a value contains spaces.
+fn round_expected_row(line: &str, float_columns: &[bool], digits: usize) -> String {
+    let values: Vec<&str> = line.split_whitespace().collect();
+    if values.len() != float_columns.len() {
+        return line.to_string();
+    }
+    values
+        .iter()
+        .zip(float_columns)
+        .map(|(v, &is_float)| {
+            if is_float {
+                round_float(v, digits)
+            } else {
+                Cow::Borrowed(*v)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Rounds `v` to `digits` decimal places if it's a finite floating-point number.
+fn round_float(v: &str, digits: usize) -> Cow<'_, str> {
+    match v.parse::<f64>() {
+        Ok(f) if f.is_finite() => {
+            let rounded = format!("{:.*}", digits, f);
+            let rounded = if rounded.contains('.') {
+                rounded.trim_end_matches('0').trim_end_matches('.')
+            } else {
+                &rounded
+            };
+            // Avoid printing `-0` for small negative values.
+            if rounded == "-0" {
+                Cow::Borrowed("0")
+            } else {
+                Cow::Owned(rounded.to_string())
+            }
+        }
+        _ => Cow::Borrowed(v),
+    }
+}
+
 struct Risingwave {
     client: tokio_postgres::Client,
     task: tokio::task::JoinHandle<()>,
     host: String,
     dbname: String,
+    float_columns: FloatColumns,
 }
 
 impl Risingwave {
@@ -571,6 +657,7 @@ impl Risingwave {
             task,
             host,
             dbname,
+            float_columns: Default::default(),
         })
     }
 }
@@ -590,8 +677,11 @@ impl sqllogictest::AsyncDB for Risingwave {
 
         if self.client.is_closed() {
             // connection error, reset the client
+            let float_columns = self.float_columns.clone();
             *self = Self::connect(self.host.clone(), self.dbname.clone()).await?;
+            self.float_columns = float_columns;
         }
+        let float_columns = self.float_columns.lock().unwrap().clone();
 
         let mut output = String::new();
         let rows = self.client.simple_query(sql).await?;
@@ -604,7 +694,12 @@ impl sqllogictest::AsyncDB for Risingwave {
                         }
                         match row.get(i) {
                             Some(v) if v.is_empty() => write!(output, "(empty)").unwrap(),
-                            Some(v) => write!(output, "{}", v).unwrap(),
+                            Some(v) => match ARGS.float_digits {
+                                Some(digits) if float_columns.get(i) == Some(&true) => {
+                                    write!(output, "{}", round_float(v, digits)).unwrap()
+                                }
+                                _ => write!(output, "{}", v).unwrap(),
+                            },
                             None => write!(output, "NULL").unwrap(),
                         }
                     }
@@ -625,3 +720,33 @@ impl sqllogictest::AsyncDB for Risingwave {
         tokio::time::sleep(dur).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_float() {
+        assert_eq!(round_float("0.30000000000000004", 5), "0.3");
+        assert_eq!(round_float("1.23456", 2), "1.23");
+        assert_eq!(round_float("100", 2), "100");
+        assert_eq!(round_float("1e-9", 5), "0");
+        assert_eq!(round_float("-1e-9", 5), "0");
+        assert_eq!(round_float("Infinity", 5), "Infinity");
+        assert_eq!(round_float("abc", 5), "abc");
+    }
+
+    #[test]
+    fn test_round_expected_row() {
+        // Only the floating-point columns are rounded.
+        assert_eq!(
+            round_expected_row("1.23456 1.23456 abc", &[true, false, false], 2),
+            "1.23 1.23456 abc"
+        );
+        // Rows whose values don't match the columns are left as is.
+        assert_eq!(
+            round_expected_row("1.23456 a b", &[true, false], 2),
+            "1.23456 a b"
+        );
+    }
+}
@@ -94,6 +94,7 @@ fn create_tvf(name: &str, alias: TableAlias, args: Vec<FunctionArg>) -> TableWit
         name: ObjectName(vec![name.into()]),
         alias: Some(alias),
         args,
+        with_ordinality: false,
     };
     TableWithJoins {
         relation: factor,
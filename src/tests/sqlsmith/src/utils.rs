@@ -112,6 +112,6 @@ pub fn data_type_name_to_ast_data_type(type_name: DataTypeName) -> Option<DataTy
         DataTypeName::Timestampz => Some(DataType::Timestamp(true)),
         DataTypeName::Time => Some(DataType::Time(false)),
         DataTypeName::Interval => Some(DataType::Interval),
-        DataTypeName::Struct | DataTypeName::List => None,
+        DataTypeName::Struct | DataTypeName::List | DataTypeName::Jsonb => None,
     }
 }
@@ -40,7 +40,7 @@ pub struct SqlsmithEnv {
 /// Executes sql queries, prints recoverable errors.
 /// Panic recovery happens separately.
 async fn handle(session: Arc<SessionImpl>, stmt: Statement, sql: &str) -> Result<()> {
-    handler::handle(session.clone(), stmt, sql, false)
+    handler::handle(session.clone(), stmt, sql, vec![])
         .await
         .map(|_| ())
         .map_err(|e| format!("Error Reason:\n{}", e).into())
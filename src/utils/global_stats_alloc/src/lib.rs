@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod task_local_alloc;
+
 use stats_alloc::StatsAlloc;
+pub use task_local_alloc::*;
 use tikv_jemallocator::Jemalloc;
 
 pub static INSTRUMENTED_JEMALLOC: StatsAlloc<Jemalloc> = StatsAlloc::new(Jemalloc);
@@ -0,0 +1,314 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A global allocator that attributes heap memory to the async task that allocated it.
+//!
+//! Every allocation carries a trailing header pointing to the counter of the task it was allocated
+//! in, or null if it was allocated outside of [`allocation_stat`]. The counter is charged on
+//! allocation and credited on deallocation, no matter which task frees the memory, so that it
+//! always tells the bytes allocated by the task and not freed yet.
+//!
+//! The header grows every allocation by 8 bytes, which may push it to a larger size class of the
+//! inner allocator and shows up in its statistics. So the binaries only install [`TaskLocalAlloc`]
+//! when built with the `task-local-alloc` feature. Without it, the counters stay at zero and
+//! [`task_local_alloc_installed`] returns false.
+
+use std::alloc::{GlobalAlloc, Layout};
+use std::future::Future;
+use std::pin::Pin;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures::Stream;
+
+/// The size of the header appended to every allocation.
+const HEADER_SIZE: usize = std::mem::size_of::<*const Counter>();
+
+/// Set once [`TaskLocalAlloc`] serves an allocation. As the global allocator, it serves the first
+/// one before `main`.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+tokio::task_local! {
+    static BYTES_ALLOCATED: TaskLocalBytesAllocated;
+}
+
+#[derive(Default, Debug)]
+struct Counter {
+    bytes: AtomicUsize,
+    /// The counters of the operators polled under this one, see [`operator_allocation_stat`].
+    operators: Mutex<Vec<(String, TaskLocalBytesAllocated)>>,
+}
+
+/// The bytes allocated by a task and not freed yet. The counter is shared by the allocations of
+/// the task, so it stays valid until both the task and its memory are gone.
+#[derive(Clone, Default, Debug)]
+pub struct TaskLocalBytesAllocated(Arc<Counter>);
+
+impl TaskLocalBytesAllocated {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bytes allocated by the task and not freed yet, including those of its
+    /// operators.
+    pub fn val(&self) -> usize {
+        self.0.bytes.load(Ordering::Relaxed)
+            + self
+                .0
+                .operators
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(_, bytes)| bytes.val())
+                .sum::<usize>()
+    }
+
+    /// Returns the bytes allocated by each operator of the task and not freed yet, excluding
+    /// those of the operators it polls.
+    pub fn operators(&self) -> Vec<(String, usize)> {
+        let mut operators = vec![];
+        self.collect_operators(&mut operators);
+        operators
+    }
+
+    fn collect_operators(&self, operators: &mut Vec<(String, usize)>) {
+        for (identity, bytes) in self.0.operators.lock().unwrap().iter() {
+            operators.push((identity.clone(), bytes.0.bytes.load(Ordering::Relaxed)));
+            bytes.collect_operators(operators);
+        }
+    }
+
+    fn new_operator(&self, identity: String) -> Self {
+        let operator = Self::new();
+        self.0
+            .operators
+            .lock()
+            .unwrap()
+            .push((identity, operator.clone()));
+        operator
+    }
+}
+
+/// Returns whether [`TaskLocalAlloc`] is the global allocator and counting allocations.
+pub fn task_local_alloc_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Counts the heap memory allocated while polling `future` in `bytes`, until it is freed. Tasks
+/// spawned by `future` are not counted, unless they are wrapped as well.
+pub async fn allocation_stat<F: Future>(future: F, bytes: TaskLocalBytesAllocated) -> F::Output {
+    BYTES_ALLOCATED.scope(bytes, future).await
+}
+
+/// Counts the heap memory allocated while polling `stream` in a counter of operator `identity`,
+/// registered under the counter of the task polling it. The stream is left as is if it is polled
+/// outside of [`allocation_stat`].
+pub fn operator_allocation_stat<S: Stream + Unpin>(
+    stream: S,
+    identity: String,
+) -> OperatorAllocationStat<S> {
+    OperatorAllocationStat {
+        stream,
+        identity: Some(identity),
+        bytes: None,
+    }
+}
+
+/// The stream returned by [`operator_allocation_stat`].
+pub struct OperatorAllocationStat<S> {
+    stream: S,
+    /// Taken once the counter is registered on the first poll.
+    identity: Option<String>,
+    bytes: Option<TaskLocalBytesAllocated>,
+}
+
+impl<S: Stream + Unpin> Stream for OperatorAllocationStat<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let Some(identity) = this.identity.take() {
+            this.bytes = BYTES_ALLOCATED
+                .try_with(|bytes| bytes.new_operator(identity))
+                .ok();
+        }
+        let stream = Pin::new(&mut this.stream);
+        match &this.bytes {
+            Some(bytes) => BYTES_ALLOCATED.sync_scope(bytes.clone(), || stream.poll_next(cx)),
+            None => stream.poll_next(cx),
+        }
+    }
+}
+
+/// Wraps allocator `A` to count allocations in the [`TaskLocalBytesAllocated`] of the current
+/// task. See the module documentation.
+pub struct TaskLocalAlloc<A>(A);
+
+impl<A> TaskLocalAlloc<A> {
+    pub const fn new(inner: A) -> Self {
+        Self(inner)
+    }
+}
+
+/// Returns the layout of an allocation of `layout` with the header.
+///
+/// # Safety
+///
+/// `layout.size() + HEADER_SIZE` must not overflow `isize` when rounded up to the alignment, which
+/// holds for any allocation that can be served.
+unsafe fn with_header(layout: Layout) -> Layout {
+    Layout::from_size_align_unchecked(layout.size() + HEADER_SIZE, layout.align())
+}
+
+/// Charges `size` bytes to the counter of the current task, and returns the counter to be stored
+/// in the header. Returns null outside of a task with a counter.
+fn charge_current_task(size: usize) -> *const Counter {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    BYTES_ALLOCATED
+        .try_with(|bytes| {
+            bytes.0.bytes.fetch_add(size, Ordering::Relaxed);
+            Arc::into_raw(bytes.0.clone())
+        })
+        .unwrap_or(ptr::null())
+}
+
+/// Credits `size` bytes to the counter in a header, and releases the reference of the header.
+unsafe fn credit(counter: *const Counter, size: usize) {
+    if !counter.is_null() {
+        let counter = Arc::from_raw(counter);
+        counter.bytes.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe fn header(ptr: *mut u8, size: usize) -> *mut *const Counter {
+    ptr.add(size) as *mut *const Counter
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TaskLocalAlloc<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.0.alloc(with_header(layout));
+        if !ptr.is_null() {
+            header(ptr, layout.size()).write_unaligned(charge_current_task(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.0.alloc_zeroed(with_header(layout));
+        if !ptr.is_null() {
+            header(ptr, layout.size()).write_unaligned(charge_current_task(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        credit(header(ptr, layout.size()).read_unaligned(), layout.size());
+        self.0.dealloc(ptr, with_header(layout));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let counter = header(ptr, layout.size()).read_unaligned();
+        let new_ptr = self
+            .0
+            .realloc(ptr, with_header(layout), new_size + HEADER_SIZE);
+        // On failure, the old allocation is left untouched, including its header.
+        if !new_ptr.is_null() {
+            // The memory stays with the task that allocated it in the first place.
+            if !counter.is_null() {
+                (*counter).bytes.fetch_add(new_size, Ordering::Relaxed);
+                (*counter).bytes.fetch_sub(layout.size(), Ordering::Relaxed);
+            }
+            header(new_ptr, new_size).write_unaligned(counter);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::System;
+
+    use futures::StreamExt;
+
+    use super::*;
+
+    static ALLOC: TaskLocalAlloc<System> = TaskLocalAlloc::new(System);
+
+    #[tokio::test]
+    async fn test_task_local_alloc() {
+        let bytes = TaskLocalBytesAllocated::new();
+        let layout = Layout::from_size_align(100, 8).unwrap();
+
+        // Allocations outside of a counted task are not charged.
+        let untracked = unsafe { ALLOC.alloc(layout) };
+        assert_eq!(bytes.val(), 0);
+
+        let tracked = allocation_stat(
+            async { unsafe { ALLOC.alloc_zeroed(layout) } },
+            bytes.clone(),
+        )
+        .await;
+        assert_eq!(bytes.val(), 100);
+        assert_eq!(tracked as usize % 8, 0);
+
+        // Reallocation and deallocation are credited to the allocating task, wherever they happen.
+        let tracked = unsafe { ALLOC.realloc(tracked, layout, 300) };
+        assert_eq!(bytes.val(), 300);
+        unsafe { ALLOC.dealloc(tracked, Layout::from_size_align(300, 8).unwrap()) };
+        assert_eq!(bytes.val(), 0);
+
+        allocation_stat(
+            async { unsafe { ALLOC.dealloc(untracked, layout) } },
+            bytes.clone(),
+        )
+        .await;
+        assert_eq!(bytes.val(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_operator_allocation_stat() {
+        let bytes = TaskLocalBytesAllocated::new();
+        let layout = Layout::from_size_align(100, 8).unwrap();
+
+        let ptrs = allocation_stat(
+            async {
+                let inner = futures::stream::iter([0]).map(|_| unsafe { ALLOC.alloc(layout) });
+                let inner = operator_allocation_stat(inner, "Inner".to_string());
+                let outer = inner.map(|ptr| (ptr, unsafe { ALLOC.alloc(layout) }));
+                let mut outer = operator_allocation_stat(outer, "Outer".to_string());
+                let ptrs = outer.next().await.unwrap();
+                (ptrs, unsafe { ALLOC.alloc(layout) })
+            },
+            bytes.clone(),
+        )
+        .await;
+
+        // Each allocation is charged to the innermost operator polling it, and the task counts all.
+        assert_eq!(bytes.val(), 300);
+        assert_eq!(
+            bytes.operators(),
+            vec![("Outer".to_string(), 100), ("Inner".to_string(), 100)]
+        );
+
+        let ((inner, outer), task) = ptrs;
+        for ptr in [inner, outer, task] {
+            unsafe { ALLOC.dealloc(ptr, layout) };
+        }
+        assert_eq!(bytes.val(), 0);
+    }
+}
@@ -12,11 +12,61 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::distributions::{Distribution, Standard};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
-criterion_group!(benches, decimal);
+criterion_group!(benches, primitives, decimal);
 criterion_main!(benches);
 
+fn bench_type<T: Serialize + DeserializeOwned>(c: &mut Criterion, name: &str, values: Vec<T>) {
+    c.bench_function(&format!("serialize_{}", name), |b| {
+        let mut i = 0;
+        b.iter(|| {
+            black_box(memcomparable::to_vec(&values[i]).unwrap());
+            i += 1;
+            if i == values.len() {
+                i = 0;
+            }
+        })
+    });
+
+    c.bench_function(&format!("deserialize_{}", name), |b| {
+        let encodings = values
+            .iter()
+            .map(|v| memcomparable::to_vec(v).unwrap())
+            .collect::<Vec<_>>();
+        let mut i = 0;
+        b.iter(|| {
+            black_box(memcomparable::from_slice::<T>(&encodings[i]).unwrap());
+            i += 1;
+            if i == encodings.len() {
+                i = 0;
+            }
+        })
+    });
+}
+
+fn random_values<T>() -> Vec<T>
+where
+    Standard: Distribution<T>,
+{
+    (0..10).map(|_| rand::random()).collect()
+}
+
+fn primitives(c: &mut Criterion) {
+    bench_type(c, "i16", random_values::<i16>());
+    bench_type(c, "i32", random_values::<i32>());
+    bench_type(c, "i64", random_values::<i64>());
+    bench_type(c, "f32", random_values::<f32>());
+    bench_type(c, "f64", random_values::<f64>());
+    bench_type(c, "bool", random_values::<bool>());
+    bench_type(c, "option_i64", random_values::<Option<i64>>());
+    let strings = (0..10).map(|i| "risingwave".repeat(i + 1)).collect();
+    bench_type::<String>(c, "string", strings);
+}
+
 #[cfg(not(feature = "decimal"))]
 fn decimal(_c: &mut Criterion) {}
 
@@ -78,7 +78,7 @@ impl PgStatement {
         &self,
         portal_name: String,
         params: &[Bytes],
-        result_format: bool,
+        result_format_codes: &[i16],
         param_format: bool,
     ) -> PsqlResult<PgPortal<VS>>
     where
@@ -86,20 +86,18 @@ impl PgStatement {
     {
         let instance_query_string = self.prepared_statement.instance(params, param_format)?;
 
-        let row_description: Vec<PgFieldDescriptor> = if result_format {
-            let mut row_description = self.row_description.clone();
-            row_description
-                .iter_mut()
-                .for_each(|desc| desc.set_to_binary());
-            row_description
-        } else {
-            self.row_description.clone()
-        };
+        let result_formats = self.result_formats(result_format_codes)?;
+        let mut row_description = self.row_description.clone();
+        row_description
+            .iter_mut()
+            .zip_eq(&result_formats)
+            .filter(|(_, binary)| **binary)
+            .for_each(|(desc, _)| desc.set_to_binary());
 
         Ok(PgPortal {
             name: portal_name,
             query_string: instance_query_string,
-            result_format,
+            result_formats,
             is_query: self.is_query,
             row_description,
             result: None,
@@ -107,6 +105,30 @@ impl PgStatement {
         })
     }
 
+    /// Resolve the result format codes of a Bind message to one format per result column:
+    /// false for TEXT and true for BINARY. Like Postgres, codes other than 0 and 1 are rejected.
+    fn result_formats(&self, result_format_codes: &[i16]) -> PsqlResult<Vec<bool>> {
+        let is_binary = |code: &i16| match *code {
+            0 => Ok(false),
+            1 => Ok(true),
+            code => Err(PsqlError::Internal(anyhow!(
+                "unsupported format code: {}",
+                code
+            ))),
+        };
+        let column_num = self.row_description.len();
+        match result_format_codes {
+            [] => Ok(vec![false; column_num]),
+            [code] => Ok(vec![is_binary(code)?; column_num]),
+            codes if codes.len() == column_num => codes.iter().map(is_binary).collect(),
+            codes => Err(PsqlError::Internal(anyhow!(
+                "bind message has {} result formats but query has {} columns",
+                codes.len(),
+                column_num
+            ))),
+        }
+    }
+
     /// We define the statement start with ("select","values","show","with","describe") is query
     /// statement. Because these statement will return a result set.
     pub fn is_query(&self) -> bool {
@@ -120,7 +142,7 @@ where
 {
     name: String,
     query_string: String,
-    result_format: bool,
+    result_formats: Vec<bool>,
     is_query: bool,
     row_description: Vec<PgFieldDescriptor>,
     result: Option<PgResponse<VS>>,
@@ -156,7 +178,7 @@ where
             result
         } else {
             let result = session
-                .run_statement(self.query_string.as_str(), self.result_format)
+                .run_statement(self.query_string.as_str(), self.result_formats.clone())
                 .await
                 .map_err(|err| PsqlError::ExecuteError(err))?;
             self.result = Some(result);
@@ -506,7 +528,7 @@ impl PreparedStatement {
                     };
                     format!("'{}'::INTERVAL", tmp)
                 }
-                DataType::Struct(_) | DataType::List { .. } => {
+                DataType::Struct(_) | DataType::List { .. } | DataType::Jsonb => {
                     return Err(PsqlError::Internal(anyhow!(
                         "Unsupported param type {:?}",
                         type_oid
@@ -540,7 +562,7 @@ impl PreparedStatement {
                     params.push("'2022-10-01 12:00:00+01:00'::timestamptz".to_string())
                 }
                 DataType::Interval => params.push("'2 months ago'::interval".to_string()),
-                DataType::Struct(_) | DataType::List { .. } => {
+                DataType::Struct(_) | DataType::List { .. } | DataType::Jsonb => {
                     return Err(PsqlError::Internal(anyhow!(
                         "Unsupported param type {:?}",
                         oid
@@ -585,6 +607,7 @@ impl PreparedStatement {
 #[cfg(test)]
 mod tests {
     use chrono::{DateTime, Utc};
+    use futures::stream::BoxStream;
     use pg_interval::Interval;
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use postgres_types::private::BytesMut;
@@ -593,7 +616,9 @@ mod tests {
     };
     use tokio_postgres::types::{ToSql, Type};
 
-    use crate::pg_extended::PreparedStatement;
+    use crate::pg_extended::{PgPortal, PgStatement, PreparedStatement};
+    use crate::pg_field_descriptor::PgFieldDescriptor;
+    use crate::pg_response::RowSetResult;
 
     #[test]
     fn test_prepared_statement_without_param() {
@@ -911,4 +936,51 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_result_format_codes() {
+        let raw_statement = "SELECT v1, v2 FROM test_table".to_string();
+        let statement = PgStatement::new(
+            "test".to_string(),
+            PreparedStatement::parse_statement(raw_statement, vec![]).unwrap(),
+            vec![
+                PgFieldDescriptor::new("v1".to_string(), DataType::INT32.to_oid(), 4),
+                PgFieldDescriptor::new("v2".to_string(), DataType::INT64.to_oid(), 8),
+            ],
+            true,
+        );
+        let format_codes = |result_format_codes: &[i16]| {
+            let portal: PgPortal<BoxStream<'static, RowSetResult>> = statement
+                .instance(String::new(), &[], result_format_codes, false)
+                .unwrap();
+            portal
+                .row_desc()
+                .iter()
+                .map(|desc| desc.get_format_code())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(format_codes(&[]), vec![0, 0]);
+        assert_eq!(format_codes(&[0]), vec![0, 0]);
+        assert_eq!(format_codes(&[1]), vec![1, 1]);
+        assert_eq!(format_codes(&[0, 1]), vec![0, 1]);
+        assert_eq!(format_codes(&[1, 0]), vec![1, 0]);
+        assert_eq!(format_codes(&[1, 1]), vec![1, 1]);
+
+        let instance = |result_format_codes: &[i16]| {
+            statement.instance::<BoxStream<'static, RowSetResult>>(
+                String::new(),
+                &[],
+                result_format_codes,
+                false,
+            )
+        };
+        // The number of codes must be 0, 1 or the number of columns.
+        assert!(instance(&[0, 1, 1]).is_err());
+        // Only 0 (text) and 1 (binary) are valid format codes.
+        assert!(instance(&[2]).is_err());
+        assert!(instance(&[-1]).is_err());
+        assert!(instance(&[0, 2]).is_err());
+        assert!(instance(&[1, 2]).is_err());
+    }
 }
@@ -84,10 +84,12 @@ pub struct FeBindMessage {
     //  true: binary
     pub param_format_code: bool,
 
-    // result_format_code:
-    //  false: text
-    //  true: binary
-    pub result_format_code: bool,
+    // result_format_codes:
+    //  0: text
+    //  1: binary
+    // No code means all columns are text, a single code applies to all columns, otherwise there is
+    // one code per result column.
+    pub result_format_codes: Vec<i16>,
 
     pub params: Vec<Bytes>,
     pub portal_name: Bytes,
@@ -197,19 +199,11 @@ impl FeBindMessage {
             .collect();
         // Read ResultFormatCode
         let len = buf.get_i16();
-
-        assert!(len==0||len==1,"Only support default result format(len==0) or uniform result format(len==1), can't support mix format now.");
-
-        let result_format_code = if len == 0 {
-            // default format:text
-            false
-        } else {
-            buf.get_i16() == 1
-        };
+        let result_format_codes = (0..len).map(|_| buf.get_i16()).collect();
 
         Ok(FeMessage::Bind(FeBindMessage {
             param_format_code,
-            result_format_code,
+            result_format_codes,
             params,
             portal_name,
             statement_name,
@@ -53,6 +53,9 @@ where
     state: PgProtocolState,
     /// Whether the connection is terminated.
     is_terminate: bool,
+    /// Whether to discard incoming extended query messages until a `Sync` is received. It is set
+    /// when an error occurs while processing an extended query message.
+    ignore_until_sync: bool,
 
     session_mgr: Arc<SM>,
     session: Option<Arc<SM::Session>>,
@@ -77,6 +80,13 @@ pub struct TlsConfig {
 }
 
 impl TlsConfig {
+    pub fn new(cert: impl Into<PathBuf>, key: impl Into<PathBuf>) -> Self {
+        Self {
+            cert: cert.into(),
+            key: key.into(),
+        }
+    }
+
     pub fn new_default() -> Self {
         let cert = PathBuf::new().join("tests/ssl/demo.crt");
         let key = PathBuf::new().join("tests/ssl/demo.key");
@@ -129,13 +139,14 @@ where
     SM: SessionManager<VS>,
     VS: Stream<Item = RowSetResult> + Unpin + Send,
 {
-    pub fn new(stream: S, session_mgr: Arc<SM>, tls_config: Option<TlsConfig>) -> Self {
+    pub fn new(stream: S, session_mgr: Arc<SM>, tls_context: Option<SslContext>) -> Self {
         Self {
             stream: Conn::Unencrypted(PgStream {
                 stream: Some(stream),
                 write_buf: BytesMut::with_capacity(10 * 1024),
             }),
             is_terminate: false,
+            ignore_until_sync: false,
             state: PgProtocolState::Startup,
             session_mgr,
             session: None,
@@ -143,9 +154,7 @@ where
             unnamed_portal: None,
             named_statements: Default::default(),
             named_portals: Default::default(),
-            tls_context: tls_config
-                .as_ref()
-                .and_then(|e| build_ssl_ctx_from_config(e).ok()),
+            tls_context,
         }
     }
 
@@ -155,6 +164,17 @@ where
     }
 
     async fn do_process(&mut self, msg: FeMessage) -> bool {
+        if self.ignore_until_sync {
+            match msg {
+                FeMessage::Sync => self.ignore_until_sync = false,
+                FeMessage::Terminate => {}
+                _ => {
+                    trace!("ignore message until sync");
+                    return false;
+                }
+            }
+        }
+
         match self.do_process_inner(msg).await {
             Ok(v) => v,
             Err(e) => {
@@ -187,6 +207,9 @@ where
                     PsqlError::Internal(_)
                     | PsqlError::ParseError(_)
                     | PsqlError::ExecuteError(_) => {
+                        // According to the protocol, the backend discards extended query messages
+                        // until a `Sync` is reached after an error.
+                        self.ignore_until_sync = true;
                         self.stream
                             .write_no_flush(&BeMessage::ErrorResponse(Box::new(e)))
                             .unwrap();
@@ -314,7 +337,7 @@ where
         let session = self.session.clone().unwrap();
         // execute query
         let mut res = session
-            .run_statement(sql, false)
+            .run_statement(sql, vec![])
             .await
             .map_err(|err| PsqlError::QueryError(err))?;
 
@@ -323,7 +346,9 @@ where
                 .write_no_flush(&BeMessage::NoticeResponse(&notice))?;
         }
 
-        if res.is_query() {
+        if res.is_empty() {
+            self.stream.write_no_flush(&BeMessage::EmptyQueryResponse)?;
+        } else if res.is_query() {
             self.stream
                 .write_no_flush(&BeMessage::RowDescription(&res.get_row_desc()))?;
 
@@ -431,7 +456,7 @@ where
         let portal = statement.instance(
             portal_name.clone(),
             &msg.params,
-            msg.result_format_code,
+            &msg.result_format_codes,
             msg.param_format_code,
         )?;
 
@@ -695,7 +720,7 @@ where
     }
 }
 
-fn build_ssl_ctx_from_config(tls_config: &TlsConfig) -> PsqlResult<SslContext> {
+pub(crate) fn build_ssl_ctx_from_config(tls_config: &TlsConfig) -> PsqlResult<SslContext> {
     let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
 
     let key_path = &tls_config.key;
@@ -716,3 +741,17 @@ fn build_ssl_ctx_from_config(tls_config: &TlsConfig) -> PsqlResult<SslContext> {
 
     Ok(acceptor.into_context())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_ssl_ctx_from_config() {
+        let config = TlsConfig::new("tests/ssl/demo.crt", "tests/ssl/demo.key");
+        assert!(build_ssl_ctx_from_config(&config).is_ok());
+
+        let config = TlsConfig::new("tests/ssl/not_exist.crt", "tests/ssl/demo.key");
+        assert!(build_ssl_ctx_from_config(&config).is_err());
+    }
+}
@@ -18,12 +18,13 @@ use std::result::Result;
 use std::sync::Arc;
 
 use futures::Stream;
+use openssl::ssl::SslContext;
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::net::TcpListener;
 use tracing::debug;
 
 use crate::pg_field_descriptor::PgFieldDescriptor;
-use crate::pg_protocol::{PgProtocol, TlsConfig};
+use crate::pg_protocol::{build_ssl_ctx_from_config, PgProtocol, TlsConfig};
 use crate::pg_response::{PgResponse, RowSetResult};
 
 pub type BoxedError = Box<dyn std::error::Error + Send + Sync>;
@@ -46,9 +47,10 @@ where
 /// A psql connection. Each connection binds with a database. Switching database will need to
 /// recreate another connection.
 ///
-/// format:
+/// formats: the result format of each output column.
 /// false: TEXT
 /// true: BINARY
+/// Columns without a format are in TEXT.
 #[async_trait::async_trait]
 pub trait Session<VS>: Send + Sync
 where
@@ -57,7 +59,7 @@ where
     async fn run_statement(
         self: Arc<Self>,
         sql: &str,
-        format: bool,
+        formats: Vec<bool>,
     ) -> Result<PgResponse<VS>, BoxedError>;
     async fn infer_return_type(
         self: Arc<Self>,
@@ -105,6 +107,20 @@ where
     let listener = TcpListener::bind(addr).await.unwrap();
     // accept connections and process them, spawning a new thread for each one
     tracing::info!("Server Listening at {}", addr);
+    // The context is built once and shared by all the connections.
+    let tls_context =
+        ssl_config.and_then(|ssl_config| match build_ssl_ctx_from_config(&ssl_config) {
+            Ok(tls_context) => Some(tls_context),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load TLS certificate {:?} and key {:?}, SSL will be refused: {}",
+                    ssl_config.cert,
+                    ssl_config.key,
+                    e
+                );
+                None
+            }
+        });
     loop {
         let session_mgr = session_mgr.clone();
         let conn_ret = listener.accept().await;
@@ -112,8 +128,8 @@ where
             Ok((stream, peer_addr)) => {
                 tracing::info!("New connection: {}", peer_addr);
                 stream.set_nodelay(true)?;
-                let ssl_config = ssl_config.clone();
-                let fut = handle_connection(stream, session_mgr, ssl_config);
+                let tls_context = tls_context.clone();
+                let fut = handle_connection(stream, session_mgr, tls_context);
                 tokio::spawn(async {
                     if let Err(e) = fut.await {
                         debug!("error handling connection : {}", e);
@@ -132,14 +148,14 @@ where
 pub fn handle_connection<S, SM, VS>(
     stream: S,
     session_mgr: Arc<SM>,
-    tls_config: Option<TlsConfig>,
+    tls_context: Option<SslContext>,
 ) -> impl Future<Output = Result<(), anyhow::Error>>
 where
     S: AsyncWrite + AsyncRead + Unpin,
     SM: SessionManager<VS>,
     VS: Stream<Item = RowSetResult> + Unpin + Send + 'static,
 {
-    let mut pg_proto = PgProtocol::new(stream, session_mgr, tls_config);
+    let mut pg_proto = PgProtocol::new(stream, session_mgr, tls_context);
     async {
         loop {
             let msg = pg_proto.read_message().await?;
@@ -194,9 +210,16 @@ mod tests {
         async fn run_statement(
             self: Arc<Self>,
             sql: &str,
-            _format: bool,
+            _formats: Vec<bool>,
         ) -> Result<PgResponse<BoxStream<'static, RowSetResult>>, Box<dyn Error + Send + Sync>>
         {
+            if sql
+                .trim_matches(|c: char| c == ';' || c.is_whitespace())
+                .is_empty()
+            {
+                return Ok(PgResponse::empty_result(StatementType::EMPTY));
+            }
+
             // split a statement and trim \' around the input param to construct result.
             // Ex:
             //    SELECT 'a','b' -> result: a , b
@@ -396,4 +419,66 @@ mod tests {
             assert_eq!(value, "BB");
         }
     }
+
+    #[tokio::test]
+    async fn test_psql_simple_mode_empty_query() {
+        let session_mgr = Arc::new(MockSessionManager {});
+        tokio::spawn(async move { pg_serve("127.0.0.1:10001", session_mgr, None).await });
+        // wait for server to start
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        let (client, connection) = tokio_postgres::connect("host=localhost port=10001", NoTls)
+            .await
+            .unwrap();
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                eprintln!("connection error: {}", e);
+            }
+        });
+
+        // An empty query should be answered with `EmptyQueryResponse` instead of a command tag.
+        client.simple_query(";").await.unwrap();
+
+        let rows = client.simple_query("SELECT 'AA';").await.unwrap();
+        match &rows[0] {
+            tokio_postgres::SimpleQueryMessage::Row(row) => assert_eq!(row.get(0), Some("AA")),
+            _ => panic!("expect a data row"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_psql_extended_mode_error_until_sync() {
+        let session_mgr = Arc::new(MockSessionManager {});
+        tokio::spawn(async move { pg_serve("127.0.0.1:10002", session_mgr, None).await });
+        // wait for server to start
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        let (client, connection) = tokio_postgres::connect("host=localhost port=10002", NoTls)
+            .await
+            .unwrap();
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                eprintln!("connection error: {}", e);
+            }
+        });
+
+        // `prepare` sends Parse, Describe and Sync. The Parse fails, so the Describe must be
+        // discarded rather than answered with a second error, and the Sync must still be answered
+        // with `ReadyForQuery`.
+        for _ in 0..3 {
+            assert!(client.prepare("SELECT $1::NOT_A_TYPE;").await.is_err());
+
+            let rows = client.query("SELECT 'AA';", &[]).await.unwrap();
+            let value: &str = rows[0].get(0);
+            assert_eq!(value, "AA");
+        }
+
+        // Simple queries are not affected by an earlier error in the extended query protocol.
+        assert!(client.prepare("SELECT $1::NOT_A_TYPE;").await.is_err());
+        let rows = client.simple_query("SELECT 'BB';").await.unwrap();
+        match &rows[0] {
+            tokio_postgres::SimpleQueryMessage::Row(row) => assert_eq!(row.get(0), Some("BB")),
+            _ => panic!("expect a data row"),
+        }
+    }
 }